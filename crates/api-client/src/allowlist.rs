@@ -0,0 +1,369 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upstream host allowlist enforced by outbound HTTP clients
+//!
+//! An [`UpstreamAllowlist`] is installed into a `reqwest` client builder with
+//! [`UpstreamAllowlist::apply`]. The client then resolves host names through a
+//! resolver that rejects hosts missing from the allowlist and hosts resolving to
+//! private, loopback or link-local addresses, and refuses to follow redirects to
+//! such hosts. Since IP literal URLs skip name resolution, clients also check
+//! their configured URLs with [`UpstreamAllowlist::check_url`].
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use reqwest::{
+    ClientBuilder,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::{Host, Url};
+
+/// Maximum number of redirects followed, matching the `reqwest` default policy
+const MAX_REDIRECTS: usize = 10;
+
+/// Reasons an outbound request is rejected by the allowlist
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UpstreamAllowlistError {
+    /// The URL cannot be parsed
+    #[error("invalid URL {0}")]
+    InvalidUrl(String),
+
+    /// The URL has no host to check
+    #[error("URL {0} has no host")]
+    MissingHost(String),
+
+    /// The host does not match any allowlist entry
+    #[error("host {0} is not in the upstream allowlist")]
+    HostNotAllowed(String),
+
+    /// The host is or resolves to a private network address
+    #[error("host {host} resolves to private address {address}")]
+    PrivateAddress {
+        /// Requested host
+        host: String,
+        /// Rejected address
+        address: IpAddr,
+    },
+}
+
+/// Hosts that outbound clients may connect to
+///
+/// With an empty `allowed_hosts` list any public host is permitted. Entries are
+/// either exact host names or IP addresses, or `*.example.com` to match every
+/// subdomain of `example.com`. Hosts that are explicitly listed may resolve to
+/// private addresses; all other private, loopback and link-local addresses are
+/// rejected unless `allow_private_networks` is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpstreamAllowlist {
+    /// Permitted hosts; empty permits any public host
+    pub allowed_hosts: Vec<String>,
+    /// Permit private, loopback and link-local addresses for every host
+    pub allow_private_networks: bool,
+}
+
+impl UpstreamAllowlist {
+    /// Create an allowlist permitting only the given hosts
+    pub fn new<I, S>(allowed_hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allowed_hosts: allowed_hosts.into_iter().map(Into::into).collect(),
+            allow_private_networks: false,
+        }
+    }
+
+    /// Permit private, loopback and link-local addresses for every allowed host
+    #[must_use]
+    pub fn with_private_networks(mut self, allow_private_networks: bool) -> Self {
+        self.allow_private_networks = allow_private_networks;
+        self
+    }
+
+    /// Whether `host` matches an allowlist entry
+    fn is_listed(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.allowed_hosts.iter().any(|entry| {
+            let entry = entry.trim().trim_end_matches('.').to_ascii_lowercase();
+            match entry.strip_prefix("*.") {
+                Some(domain) => host
+                    .strip_suffix(domain)
+                    .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+                None => host == entry,
+            }
+        })
+    }
+
+    /// Check a host name before it is resolved
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpstreamAllowlistError::HostNotAllowed`] if the allowlist is
+    /// non-empty and `host` matches none of its entries
+    pub fn check_host(&self, host: &str) -> Result<(), UpstreamAllowlistError> {
+        if self.allowed_hosts.is_empty() || self.is_listed(host) {
+            Ok(())
+        } else {
+            Err(UpstreamAllowlistError::HostNotAllowed(host.to_string()))
+        }
+    }
+
+    /// Check an address that `host` resolved to
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpstreamAllowlistError::PrivateAddress`] if `address` is private
+    /// and neither `host` is listed nor private networks are allowed
+    pub fn check_address(&self, host: &str, address: IpAddr) -> Result<(), UpstreamAllowlistError> {
+        if is_private_address(address) && !self.allow_private_networks && !self.is_listed(host) {
+            return Err(UpstreamAllowlistError::PrivateAddress {
+                host: host.to_string(),
+                address,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the host of `url`, including IP literal hosts
+    ///
+    /// Domain hosts are only checked against the allowlist here; the addresses
+    /// they resolve to are checked when a request is made.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL has no host or its host is not permitted
+    pub fn check_url(&self, url: &Url) -> Result<(), UpstreamAllowlistError> {
+        match url.host() {
+            Some(Host::Domain(domain)) => self.check_host(domain),
+            Some(Host::Ipv4(address)) => self.check_ip_literal(IpAddr::V4(address)),
+            Some(Host::Ipv6(address)) => self.check_ip_literal(IpAddr::V6(address)),
+            None => Err(UpstreamAllowlistError::MissingHost(url.to_string())),
+        }
+    }
+
+    /// Parse `url` and check its host, see [`UpstreamAllowlist::check_url`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid, has no host or its host is not permitted
+    pub fn check_url_str(&self, url: &str) -> Result<(), UpstreamAllowlistError> {
+        let parsed =
+            Url::parse(url).map_err(|_| UpstreamAllowlistError::InvalidUrl(url.to_string()))?;
+        self.check_url(&parsed)
+    }
+
+    fn check_ip_literal(&self, address: IpAddr) -> Result<(), UpstreamAllowlistError> {
+        let host = address.to_string();
+        self.check_host(&host)?;
+        self.check_address(&host, address)
+    }
+
+    /// Install the allowlist into an HTTP client builder
+    ///
+    /// Replaces the builder's DNS resolver and redirect policy.
+    pub fn apply(&self, builder: ClientBuilder) -> ClientBuilder {
+        let allowlist = Arc::new(self.clone());
+        let redirect_allowlist = Arc::clone(&allowlist);
+        builder
+            .dns_resolver(Arc::new(AllowlistResolver { allowlist }))
+            .redirect(redirect::Policy::custom(move |attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    return attempt.error("too many redirects");
+                }
+                match redirect_allowlist.check_url(attempt.url()) {
+                    Ok(()) => attempt.follow(),
+                    Err(error) => attempt.error(error),
+                }
+            }))
+    }
+}
+
+/// Resolver that only returns addresses permitted by the allowlist
+struct AllowlistResolver {
+    allowlist: Arc<UpstreamAllowlist>,
+}
+
+impl Resolve for AllowlistResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let allowlist = Arc::clone(&self.allowlist);
+        Box::pin(async move {
+            let host = name.as_str();
+            allowlist.check_host(host)?;
+
+            let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, 0)).await?.collect();
+            for address in &addresses {
+                allowlist.check_address(host, address.ip())?;
+            }
+            let addresses: Addrs = Box::new(addresses.into_iter());
+            Ok(addresses)
+        })
+    }
+}
+
+/// Whether `address` belongs to a private, loopback, link-local or otherwise
+/// non-public range
+pub fn is_private_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => is_private_ipv4(address),
+        IpAddr::V6(address) => {
+            is_private_ipv6(address) || embedded_ipv4(address).is_some_and(is_private_ipv4)
+        }
+    }
+}
+
+/// IPv4 address carried inside an IPv4-mapped, IPv4-compatible, NAT64
+/// (`64:ff9b::/96`) or 6to4 (`2002::/16`) address, which routes to that host
+fn embedded_ipv4(address: Ipv6Addr) -> Option<Ipv4Addr> {
+    let segments = address.segments();
+    let [.., a, b, c, d] = address.octets();
+    match segments {
+        [0, 0, 0, 0, 0, 0xffff | 0, ..] | [0x64, 0xff9b, 0, 0, 0, 0, ..] => {
+            Some(Ipv4Addr::new(a, b, c, d))
+        }
+        [0x2002, high, low, ..] => {
+            let [a, b] = high.to_be_bytes();
+            let [c, d] = low.to_be_bytes();
+            Some(Ipv4Addr::new(a, b, c, d))
+        }
+        _ => None,
+    }
+}
+
+fn is_private_ipv4(address: Ipv4Addr) -> bool {
+    let [first, second, ..] = address.octets();
+    address.is_private()
+        || address.is_loopback()
+        || address.is_link_local()
+        || address.is_broadcast()
+        // "This network", 0.0.0.0/8
+        || first == 0
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (first == 100 && (second & 0b1100_0000) == 64)
+}
+
+fn is_private_ipv6(address: Ipv6Addr) -> bool {
+    address.is_loopback()
+        || address.is_unspecified()
+        || address.is_unique_local()
+        || address.is_unicast_link_local()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn private_ip_is_blocked_by_default() {
+        let allowlist = UpstreamAllowlist::default();
+
+        for blocked in [
+            "http://127.0.0.1:8080/",
+            "http://10.1.2.3/",
+            "http://192.168.0.10/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/",
+            "http://[::1]/",
+            "http://[fd00::1]/",
+            "http://[::ffff:10.0.0.1]/",
+            "http://0.0.0.0/",
+            "http://0.1.2.3/",
+            // IPv4-compatible, NAT64 and 6to4 forms of private IPv4 hosts
+            "http://[::127.0.0.1]/",
+            "http://[::10.0.0.1]/",
+            "http://[64:ff9b::10.0.0.1]/",
+            "http://[64:ff9b::169.254.169.254]/",
+            "http://[2002:c0a8:0001::1]/",
+            "http://[2002:7f00:1::]/",
+        ] {
+            assert!(
+                matches!(
+                    allowlist.check_url(&url(blocked)),
+                    Err(UpstreamAllowlistError::PrivateAddress { .. })
+                ),
+                "{blocked} should be blocked"
+            );
+        }
+        assert!(allowlist.check_url(&url("https://8.8.8.8/")).is_ok());
+        assert!(
+            allowlist
+                .check_url(&url("https://[64:ff9b::8.8.8.8]/"))
+                .is_ok()
+        );
+        assert!(
+            allowlist
+                .check_url(&url("https://[2002:808:808::1]/"))
+                .is_ok()
+        );
+        assert!(
+            allowlist
+                .check_url(&url("https://[2606:4700::1111]/"))
+                .is_ok()
+        );
+        assert!(
+            allowlist
+                .check_url(&url("https://api.openai.com/v1"))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn listed_hosts_are_permitted_and_others_rejected() {
+        let allowlist = UpstreamAllowlist::new(["api.openai.com", "*.moralis.io", "127.0.0.1"]);
+
+        assert!(
+            allowlist
+                .check_url(&url("https://api.openai.com/v1"))
+                .is_ok()
+        );
+        assert!(
+            allowlist
+                .check_url(&url("https://API.OpenAI.com/v1"))
+                .is_ok()
+        );
+        assert!(
+            allowlist
+                .check_url(&url("https://deep-index.moralis.io/api/v2"))
+                .is_ok()
+        );
+        // Explicitly listed private hosts are permitted
+        assert!(allowlist.check_url(&url("http://127.0.0.1:3000/")).is_ok());
+
+        assert_eq!(
+            allowlist.check_url(&url("https://moralis.io/")),
+            Err(UpstreamAllowlistError::HostNotAllowed(
+                "moralis.io".to_string()
+            ))
+        );
+        assert_eq!(
+            allowlist.check_url(&url("https://evilmoralis.io/")),
+            Err(UpstreamAllowlistError::HostNotAllowed(
+                "evilmoralis.io".to_string()
+            ))
+        );
+        assert!(allowlist.check_url(&url("http://10.0.0.1/")).is_err());
+    }
+
+    #[test]
+    fn private_networks_can_be_allowed() {
+        let allowlist = UpstreamAllowlist::default().with_private_networks(true);
+        assert!(allowlist.check_url(&url("http://10.0.0.1/")).is_ok());
+        assert!(
+            allowlist
+                .check_address("internal", IpAddr::V4(Ipv4Addr::LOCALHOST))
+                .is_ok()
+        );
+    }
+}
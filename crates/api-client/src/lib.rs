@@ -12,6 +12,8 @@
 //! - **`ApiClient` Trait**: Common interface for all external API clients with async support
 //! - **Health Check System**: Standardized health status reporting across all clients
 //! - **Error Handling**: Comprehensive `ApiError` types for different failure scenarios
+//! - **Retry Policy**: Shared exponential backoff with capped jitter for all upstream clients
+//! - **Upstream Allowlist**: Host and private network restrictions for outbound HTTP clients
 //! - **Data Types**: Common structures for contract metadata and blockchain data
 //!
 //! # Key Features
@@ -26,10 +28,14 @@ use serde::{Deserialize, Serialize};
 use shared_types::ChainId;
 use thiserror::Error;
 
+pub mod allowlist;
 pub mod health;
+pub mod retry;
 pub mod types;
 
+pub use allowlist::*;
 pub use health::*;
+pub use retry::*;
 pub use types::*;
 
 /// Generic trait for external API clients
@@ -92,6 +98,16 @@ impl Default for RateLimitConfig {
     }
 }
 
+impl RateLimitConfig {
+    /// Seconds a client should wait after a rate-limited response
+    ///
+    /// Uses the upstream `Retry-After` header when it carries a delay in seconds
+    /// or an HTTP date, and the configured `retry_after_seconds` otherwise.
+    pub fn retry_after(&self, headers: &reqwest::header::HeaderMap) -> u64 {
+        retry_after_header(headers).unwrap_or(self.retry_after_seconds)
+    }
+}
+
 /// Common errors that can occur when working with API clients
 #[derive(Debug, Error)]
 #[allow(missing_docs)]
@@ -128,3 +144,96 @@ pub enum ApiError {
     #[error(transparent)]
     Custom { error: anyhow::Error },
 }
+
+impl ApiError {
+    /// Coarse category of the error, safe to expose without upstream detail
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::Http { .. } => ErrorCategory::Network,
+            Self::RateLimitExceeded { .. } => ErrorCategory::RateLimited,
+            Self::Authentication { .. } => ErrorCategory::Authentication,
+            Self::InvalidResponse { .. } => ErrorCategory::InvalidResponse,
+            Self::ServiceUnavailable { .. } => ErrorCategory::Unavailable,
+            Self::Configuration { .. } => ErrorCategory::Configuration,
+            Self::Timeout { .. } => ErrorCategory::Timeout,
+            Self::Custom { .. } => ErrorCategory::Upstream,
+        }
+    }
+
+    /// Seconds the upstream asked us to wait, for rate-limited errors
+    pub fn retry_after_seconds(&self) -> Option<u64> {
+        match self {
+            Self::RateLimitExceeded {
+                retry_after_seconds,
+            } => Some(*retry_after_seconds),
+            _ => None,
+        }
+    }
+}
+
+/// Coarse classification of API errors
+///
+/// Unlike the error message, the category never carries upstream detail and can
+/// be returned to public callers as a stable error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Network or HTTP transport failure
+    Network,
+    /// Upstream rate limit exceeded
+    RateLimited,
+    /// Upstream rejected our credentials
+    Authentication,
+    /// Upstream returned a response that could not be parsed
+    InvalidResponse,
+    /// Upstream service unavailable
+    Unavailable,
+    /// Local configuration problem
+    Configuration,
+    /// Request timed out
+    Timeout,
+    /// Upstream returned an error response
+    Upstream,
+}
+
+impl ErrorCategory {
+    /// Every category, in declaration order
+    pub const ALL: [ErrorCategory; 8] = [
+        Self::Network,
+        Self::RateLimited,
+        Self::Authentication,
+        Self::InvalidResponse,
+        Self::Unavailable,
+        Self::Configuration,
+        Self::Timeout,
+        Self::Upstream,
+    ];
+
+    /// Whether errors of this category are transient and worth retrying
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Network | Self::RateLimited | Self::Unavailable | Self::Timeout
+        )
+    }
+
+    /// Stable string form of the category
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Network => "network",
+            Self::RateLimited => "rate_limited",
+            Self::Authentication => "authentication",
+            Self::InvalidResponse => "invalid_response",
+            Self::Unavailable => "unavailable",
+            Self::Configuration => "configuration",
+            Self::Timeout => "timeout",
+            Self::Upstream => "upstream",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,407 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shared retry and backoff strategy for outbound requests
+//!
+//! All upstream clients use the same exponential backoff with capped, jittered
+//! delays so that retries from different clients do not synchronize during a
+//! shared outage.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use serde::{Deserialize, Serialize};
+
+/// Default delay before the first retry in milliseconds
+pub const DEFAULT_RETRY_INITIAL_DELAY_MS: u64 = 100;
+
+/// Default upper bound for a single retry delay in milliseconds
+pub const DEFAULT_RETRY_MAX_DELAY_MS: u64 = 10_000;
+
+/// Default total number of attempts, including the first one
+pub const DEFAULT_RETRY_MAX_ATTEMPTS: u32 = 3;
+
+/// Default fraction of each delay that is randomized
+pub const DEFAULT_RETRY_JITTER_FACTOR: f64 = 0.5;
+
+/// Exponential backoff policy with capped jitter
+///
+/// The delay before retry `n` (zero-based) is `min(initial * 2^n, max)`, reduced
+/// by a random amount of up to `jitter_factor` of itself. Delays therefore never
+/// exceed `max_delay_ms` and never fall below `(1 - jitter_factor)` of the
+/// un-jittered delay.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Delay before the first retry in milliseconds
+    pub initial_delay_ms: u64,
+    /// Upper bound for a single retry delay in milliseconds
+    pub max_delay_ms: u64,
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Fraction of each delay that is randomized (0.0 disables jitter, 1.0 is full jitter)
+    pub jitter_factor: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: DEFAULT_RETRY_INITIAL_DELAY_MS,
+            max_delay_ms: DEFAULT_RETRY_MAX_DELAY_MS,
+            max_attempts: DEFAULT_RETRY_MAX_ATTEMPTS,
+            jitter_factor: DEFAULT_RETRY_JITTER_FACTOR,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the delay before the first retry
+    #[must_use]
+    pub fn with_initial_delay_ms(mut self, initial_delay_ms: u64) -> Self {
+        self.initial_delay_ms = initial_delay_ms;
+        self
+    }
+
+    /// Set the upper bound for a single retry delay
+    #[must_use]
+    pub fn with_max_delay_ms(mut self, max_delay_ms: u64) -> Self {
+        self.max_delay_ms = max_delay_ms;
+        self
+    }
+
+    /// Set the total number of attempts, including the first one
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the jitter factor; values outside `0.0..=1.0` are clamped when computing delays
+    #[must_use]
+    pub fn with_jitter_factor(mut self, jitter_factor: f64) -> Self {
+        self.jitter_factor = jitter_factor;
+        self
+    }
+
+    /// Un-jittered delay before the given zero-based retry
+    pub fn base_delay(&self, retry: u32) -> Duration {
+        let multiplier = 1u64.checked_shl(retry).unwrap_or(u64::MAX);
+        let delay_ms = self
+            .initial_delay_ms
+            .saturating_mul(multiplier)
+            .min(self.max_delay_ms);
+        Duration::from_millis(delay_ms)
+    }
+
+    /// Delay before the given zero-based retry for a random sample in `0.0..1.0`
+    pub fn jittered_delay(&self, retry: u32, sample: f64) -> Duration {
+        let jitter = self.jitter_factor.clamp(0.0, 1.0) * sample.clamp(0.0, 1.0);
+        self.base_delay(retry).mul_f64(1.0 - jitter)
+    }
+
+    /// Jittered delays between attempts, one fewer than `max_attempts`
+    ///
+    /// Compatible with retry helpers that accept an iterator of delays.
+    pub fn delays(&self) -> impl Iterator<Item = Duration> + use<> {
+        let policy = self.clone();
+        (0..self.max_attempts.saturating_sub(1))
+            .map(move |retry| policy.jittered_delay(retry, rand::random::<f64>()))
+    }
+
+    /// Run an async operation, retrying failures accepted by `is_retryable`
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error if all attempts fail or the error is not retryable
+    pub async fn retry<T, E, F, Fut>(
+        &self,
+        operation: F,
+        is_retryable: impl Fn(&E) -> bool,
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.retry_with_hint(operation, is_retryable, |_| None)
+            .await
+    }
+
+    /// Run an async operation like [`Self::retry`], waiting the delay the
+    /// upstream asked for instead of the backoff when `requested_delay` has one
+    ///
+    /// Requested delays longer than `max_delay_ms` are not waited out: the
+    /// error is returned right away so the caller can report the delay.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error if all attempts fail, the error is not retryable
+    /// or the requested delay exceeds `max_delay_ms`
+    pub async fn retry_with_hint<T, E, F, Fut>(
+        &self,
+        mut operation: F,
+        is_retryable: impl Fn(&E) -> bool,
+        requested_delay: impl Fn(&E) -> Option<Duration>,
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let max_delay = Duration::from_millis(self.max_delay_ms);
+        let mut delays = self.delays();
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) if is_retryable(&error) => {
+                    let Some(backoff) = delays.next() else {
+                        return Err(error);
+                    };
+                    let delay = match requested_delay(&error) {
+                        Some(delay) if delay > max_delay => return Err(error),
+                        Some(delay) => delay,
+                        None => backoff,
+                    };
+                    tokio::time::sleep(delay).await;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// Delay in seconds requested by a `Retry-After` header, if any
+///
+/// See [`parse_retry_after`] for the accepted forms.
+pub fn retry_after_header(headers: &HeaderMap) -> Option<u64> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+/// Parse a `Retry-After` value into a delay in seconds from `now`
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds, or an HTTP
+/// date such as `Wed, 21 Oct 2015 07:28:00 GMT`, rounded up to whole seconds.
+/// Dates in the past yield `0`.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(seconds);
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let millis = (date.with_timezone(&Utc) - now).num_milliseconds();
+    Some(u64::try_from(millis).unwrap_or(0).div_ceil(1_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn base_delay_grows_exponentially_up_to_max() {
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(100)
+            .with_max_delay_ms(1_000);
+
+        assert_eq!(policy.base_delay(0), Duration::from_millis(100));
+        assert_eq!(policy.base_delay(1), Duration::from_millis(200));
+        assert_eq!(policy.base_delay(3), Duration::from_millis(800));
+        assert_eq!(policy.base_delay(4), Duration::from_millis(1_000));
+        assert_eq!(policy.base_delay(63), Duration::from_millis(1_000));
+        assert_eq!(policy.base_delay(200), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn jittered_delay_stays_within_bounds() {
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(100)
+            .with_max_delay_ms(1_000)
+            .with_jitter_factor(0.5);
+
+        for retry in 0..10 {
+            let base = policy.base_delay(retry);
+            assert_eq!(policy.jittered_delay(retry, 0.0), base);
+            assert_eq!(policy.jittered_delay(retry, 1.0), base.mul_f64(0.5));
+            assert!(policy.jittered_delay(retry, 0.7) <= base);
+        }
+    }
+
+    #[test]
+    fn jitter_factor_is_clamped() {
+        let policy = RetryPolicy::default().with_jitter_factor(3.0);
+        assert_eq!(policy.jittered_delay(0, 1.0), Duration::ZERO);
+
+        let policy = RetryPolicy::default().with_jitter_factor(-1.0);
+        assert_eq!(policy.jittered_delay(0, 1.0), policy.base_delay(0));
+    }
+
+    #[test]
+    fn delays_include_jitter_and_respect_attempts() {
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(1_000)
+            .with_max_delay_ms(1_000)
+            .with_max_attempts(50)
+            .with_jitter_factor(0.5);
+
+        let delays: Vec<_> = policy.delays().collect();
+        assert_eq!(delays.len(), 49);
+        assert!(
+            delays
+                .iter()
+                .all(|d| *d >= Duration::from_millis(500) && *d <= Duration::from_millis(1_000))
+        );
+        // 49 samples landing on the same value would mean jitter is not applied
+        assert!(delays.iter().any(|d| *d != delays[0]));
+
+        assert_eq!(
+            RetryPolicy::default().with_max_attempts(0).delays().count(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_stops_after_max_attempts() {
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(1)
+            .with_max_attempts(3);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), &str> = policy
+            .retry(
+                || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    async { Err("transient") }
+                },
+                |_| true,
+            )
+            .await;
+
+        assert_eq!(result, Err("transient"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_skips_non_retryable_errors() {
+        let policy = RetryPolicy::default().with_initial_delay_ms(1);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), &str> = policy
+            .retry(
+                || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    async { Err("fatal") }
+                },
+                |_| false,
+            )
+            .await;
+
+        assert_eq!(result, Err("fatal"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retry_with_hint_waits_the_requested_delay() {
+        // The backoff alone would far exceed the test timeout
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(60_000)
+            .with_max_delay_ms(60_000)
+            .with_max_attempts(2);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<u32, &str> = tokio::time::timeout(
+            Duration::from_secs(5),
+            policy.retry_with_hint(
+                || {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        if attempt == 0 {
+                            Err("busy")
+                        } else {
+                            Ok(attempt)
+                        }
+                    }
+                },
+                |_| true,
+                |_| Some(Duration::from_millis(1)),
+            ),
+        )
+        .await
+        .expect("requested delay should replace the backoff");
+
+        assert_eq!(result, Ok(1));
+    }
+
+    #[tokio::test]
+    async fn retry_with_hint_gives_up_on_delays_beyond_max() {
+        let policy = RetryPolicy::default()
+            .with_initial_delay_ms(1)
+            .with_max_delay_ms(1_000)
+            .with_max_attempts(3);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), &str> = policy
+            .retry_with_hint(
+                || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    async { Err("busy") }
+                },
+                |_| true,
+                |_| Some(Duration::from_secs(120)),
+            )
+            .await;
+
+        assert_eq!(result, Err("busy"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_seconds_and_dates() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(parse_retry_after("120", now), Some(120));
+        assert_eq!(parse_retry_after(" 0 ", now), Some(0));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(30)
+        );
+        // Partial seconds round up
+        let now_plus_half = now + chrono::Duration::milliseconds(500);
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now_plus_half),
+            Some(30)
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(0)
+        );
+        assert_eq!(parse_retry_after("-5", now), None);
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn retry_returns_first_success() {
+        let policy = RetryPolicy::default().with_initial_delay_ms(1);
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<u32, &str> = policy
+            .retry(
+                || {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        if attempt == 0 {
+                            Err("transient")
+                        } else {
+                            Ok(attempt)
+                        }
+                    }
+                },
+                |_| true,
+            )
+            .await;
+
+        assert_eq!(result, Ok(1));
+    }
+}
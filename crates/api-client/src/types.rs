@@ -4,7 +4,7 @@
 
 //! Common data types for API client contracts and metadata
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use alloy_primitives::Address;
 use chrono::{DateTime, Utc};
@@ -35,10 +35,70 @@ pub struct ContractMetadata {
     pub is_verified: Option<bool>,
     /// Contract type (ERC-20, ERC-721, ERC-1155, etc.)
     pub contract_type: Option<ContractType>,
+    /// Collection website (if available)
+    #[serde(default)]
+    pub external_url: Option<String>,
+    /// Collection Twitter/X handle or URL (if available)
+    #[serde(default)]
+    pub twitter: Option<String>,
+    /// Collection Discord invite URL (if available)
+    #[serde(default)]
+    pub discord: Option<String>,
     /// Additional metadata fields specific to different APIs
     pub additional_data: HashMap<String, serde_json::Value>,
 }
 
+/// Metadata for a single token within a contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    /// Metadata of the contract the token belongs to
+    pub contract: ContractMetadata,
+    /// Token identifier within the contract
+    pub token_id: String,
+    /// Token name (if available)
+    pub name: Option<String>,
+    /// Token description (if available)
+    pub description: Option<String>,
+    /// Token image URL (if available)
+    pub image: Option<String>,
+    /// Token website (if available)
+    pub external_url: Option<String>,
+}
+
+impl TokenMetadata {
+    /// Contract metadata describing this token, for token-level classification
+    ///
+    /// Token fields take precedence over the collection's, and the token ID is
+    /// recorded in `additional_data` so token verdicts are cached separately from
+    /// the contract's.
+    pub fn to_classification_metadata(&self) -> ContractMetadata {
+        let mut metadata = self.contract.clone();
+        if let Some(name) = &self.name {
+            metadata.name = Some(name.clone());
+        }
+        if let Some(external_url) = &self.external_url {
+            metadata.external_url = Some(external_url.clone());
+        }
+        if let Some(description) = &self.description {
+            metadata.additional_data.insert(
+                "description".to_string(),
+                serde_json::Value::String(description.clone()),
+            );
+        }
+        if let Some(image) = &self.image {
+            metadata.additional_data.insert(
+                "image".to_string(),
+                serde_json::Value::String(image.clone()),
+            );
+        }
+        metadata.additional_data.insert(
+            "token_id".to_string(),
+            serde_json::Value::String(self.token_id.clone()),
+        );
+        metadata
+    }
+}
+
 /// Type of smart contract
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "UPPERCASE")]
@@ -56,6 +116,100 @@ pub enum ContractType {
     Unknown,
 }
 
+/// Default upper bound for supply and holder counts parsed from provider responses
+pub const DEFAULT_MAX_PARSED_COUNT: u64 = 1_000_000_000_000_000;
+
+/// Parse a count reported by a provider as a JSON number or string
+///
+/// Integral values above `max`, including values that overflow `u64`, saturate
+/// to `max`. Negative, fractional, non-finite and non-numeric values yield `None`.
+pub fn parse_count(value: &serde_json::Value, max: u64) -> Option<u64> {
+    match value {
+        serde_json::Value::Number(number) => match number.as_u64() {
+            Some(count) => Some(count.min(max)),
+            None => number.as_f64().and_then(|count| count_from_f64(count, max)),
+        },
+        serde_json::Value::String(text) => {
+            let text = text.trim();
+            if !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit()) {
+                // Digit strings too long for `u64` overflow, so saturate them
+                Some(text.parse::<u64>().map_or(max, |count| count.min(max)))
+            } else {
+                text.parse::<f64>()
+                    .ok()
+                    .and_then(|count| count_from_f64(count, max))
+            }
+        }
+        _ => None,
+    }
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)] // Range checked before casting
+fn count_from_f64(count: f64, max: u64) -> Option<u64> {
+    if !count.is_finite() || count < 0.0 || count.fract() != 0.0 {
+        return None;
+    }
+    if count >= max as f64 {
+        Some(max)
+    } else {
+        Some(count as u64)
+    }
+}
+
+/// Coarse size class of a collection's total supply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SupplyBucket {
+    /// No tokens minted
+    Empty,
+    /// A single token
+    Single,
+    /// Up to 100 tokens
+    Small,
+    /// Up to 10,000 tokens
+    Medium,
+    /// Up to 1,000,000 tokens
+    Large,
+    /// More than 1,000,000 tokens
+    Massive,
+}
+
+impl SupplyBucket {
+    /// Bucket for the given total supply
+    pub fn from_supply(supply: u64) -> Self {
+        match supply {
+            0 => Self::Empty,
+            1 => Self::Single,
+            2..=100 => Self::Small,
+            101..=10_000 => Self::Medium,
+            10_001..=1_000_000 => Self::Large,
+            _ => Self::Massive,
+        }
+    }
+
+    /// Get the string representation used in model input
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Empty => "empty",
+            Self::Single => "single",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+            Self::Massive => "massive",
+        }
+    }
+}
+
+impl fmt::Display for SupplyBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Spam analysis result for a contract
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpamAnalysis {
@@ -97,6 +251,9 @@ impl ContractMetadata {
             creator_address: None,
             is_verified: None,
             contract_type: None,
+            external_url: None,
+            twitter: None,
+            discord: None,
             additional_data: HashMap::new(),
         }
     }
@@ -109,6 +266,23 @@ impl ContractMetadata {
         )
     }
 
+    /// Check if the collection links to a website, Twitter or Discord
+    ///
+    /// Scam collections often lack social links, so their absence is a spam signal.
+    pub fn has_social_presence(&self) -> bool {
+        [&self.external_url, &self.twitter, &self.discord]
+            .into_iter()
+            .flatten()
+            .any(|link| !link.trim().is_empty())
+    }
+
+    /// Size class of the total supply, if it is known and numeric
+    pub fn supply_bucket(&self) -> Option<SupplyBucket> {
+        let supply = self.total_supply.as_deref()?;
+        parse_count(&serde_json::Value::String(supply.to_string()), u64::MAX)
+            .map(SupplyBucket::from_supply)
+    }
+
     /// Check if this contract appears to be a fungible token (ERC-20)
     pub fn is_fungible_token(&self) -> bool {
         matches!(self.contract_type, Some(ContractType::Erc20))
@@ -202,8 +376,111 @@ mod tests {
         assert!(display.contains("..."));
     }
 
+    #[test]
+    fn contract_social_presence() {
+        let mut metadata = ContractMetadata::minimal(Address::ZERO);
+        assert!(!metadata.has_social_presence());
+
+        metadata.discord = Some("  ".to_string());
+        assert!(!metadata.has_social_presence());
+
+        metadata.twitter = Some("@collection".to_string());
+        assert!(metadata.has_social_presence());
+    }
+
     #[test]
     fn contract_type_default() {
         assert_eq!(ContractType::default(), ContractType::Unknown);
     }
+
+    #[test]
+    fn parse_count_handles_non_numeric_and_overflowing_values() {
+        use serde_json::json;
+
+        let max = 1_000_000;
+        assert_eq!(parse_count(&json!(42), max), Some(42));
+        assert_eq!(parse_count(&json!(" 10000 "), max), Some(10_000));
+        assert_eq!(parse_count(&json!("1e3"), max), Some(1_000));
+        assert_eq!(parse_count(&json!(5_000_000), max), Some(max));
+        assert_eq!(
+            parse_count(
+                &json!(
+                    "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+                ),
+                max
+            ),
+            Some(max)
+        );
+        assert_eq!(parse_count(&json!(1e300), max), Some(max));
+        assert_eq!(
+            parse_count(&json!("18446744073709551616"), u64::MAX),
+            Some(u64::MAX)
+        );
+
+        for invalid in [
+            json!("unlimited"),
+            json!(""),
+            json!("-5"),
+            json!(-5),
+            json!(1.5),
+            json!("NaN"),
+            json!("inf"),
+            json!(null),
+            json!(true),
+        ] {
+            assert_eq!(parse_count(&invalid, max), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn supply_bucket_from_total_supply() {
+        let mut metadata = ContractMetadata::minimal(Address::ZERO);
+        assert_eq!(metadata.supply_bucket(), None);
+
+        for (supply, bucket) in [
+            ("0", SupplyBucket::Empty),
+            ("1", SupplyBucket::Single),
+            ("100", SupplyBucket::Small),
+            ("10000", SupplyBucket::Medium),
+            ("10001", SupplyBucket::Large),
+            ("99999999999999999999999", SupplyBucket::Massive),
+        ] {
+            metadata.total_supply = Some(supply.to_string());
+            assert_eq!(metadata.supply_bucket(), Some(bucket), "{supply}");
+        }
+
+        metadata.total_supply = Some("not a number".to_string());
+        assert_eq!(metadata.supply_bucket(), None);
+    }
+
+    #[test]
+    fn token_metadata_overrides_collection_fields() {
+        let mut contract = ContractMetadata::minimal(Address::ZERO);
+        contract.name = Some("Collection".to_string());
+        contract.symbol = Some("COL".to_string());
+        contract.additional_data.insert(
+            "description".to_string(),
+            serde_json::Value::String("A collection".to_string()),
+        );
+
+        let token = TokenMetadata {
+            contract,
+            token_id: "42".to_string(),
+            name: Some("Claim your airdrop".to_string()),
+            description: None,
+            image: None,
+            external_url: Some("https://claim.example".to_string()),
+        };
+        let metadata = token.to_classification_metadata();
+
+        assert_eq!(metadata.name.as_deref(), Some("Claim your airdrop"));
+        assert_eq!(metadata.symbol.as_deref(), Some("COL"));
+        assert_eq!(
+            metadata.external_url.as_deref(),
+            Some("https://claim.example")
+        );
+        // Missing token fields keep the collection's value
+        assert_eq!(metadata.additional_data["description"], "A collection");
+        assert_eq!(metadata.additional_data["token_id"], "42");
+    }
 }
@@ -10,30 +10,61 @@
 use std::{
     collections::HashMap,
     net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::PathBuf,
     time::Duration,
 };
 
 use anyhow::{Result, anyhow, ensure};
+use api_client::{
+    DEFAULT_MAX_PARSED_COUNT, DEFAULT_RETRY_INITIAL_DELAY_MS, DEFAULT_RETRY_JITTER_FACTOR,
+    DEFAULT_RETRY_MAX_DELAY_MS, RateLimitConfig, RetryPolicy, UpstreamAllowlist,
+};
+use axum::http::Method;
 use config::{Config, ConfigError, Environment as ConfigEnv, File};
+use external_apis::{
+    CircuitBreakerConfig, DEFAULT_NEGATIVE_CACHE_TTL, MetadataMergeMode,
+    circuit_breaker::{DEFAULT_COOLDOWN, DEFAULT_FAILURE_THRESHOLD, DEFAULT_FAILURE_WINDOW},
+};
 use serde::{Deserialize, Deserializer, Serialize, de};
 use serde_with::{DisplayFromStr, serde_as};
-use shared_types::ChainId;
+use shared_types::{ChainCapability, ChainId};
+use spam_predictor::{
+    ConfidenceBandThresholds, ModelInputField, ModelRegistry, ModelSpec, ModelVersion,
+    OpenAiProvider, PromptVersion,
+};
 use tracing::warn;
 use url::Url;
 use utoipa::ToSchema;
 
-use crate::error::{ServerError, ServerResult};
+use crate::{
+    error::{ServerError, ServerResult},
+    flags::FeatureFlags,
+    metrics::DEFAULT_DURATION_BUCKETS,
+};
 
 // Configuration constants
 const DEFAULT_SERVER_PORT: u16 = 3000;
+const DEFAULT_MIN_SERVER_PORT: u16 = 1024;
 const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
 const TESTING_TIMEOUT_SECONDS: u64 = 5;
 const MAX_TIMEOUT_SECONDS: u64 = 300;
 const DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS: u64 = 5;
+const DEFAULT_CONNECT_TIMEOUT_SECONDS: u64 = 1;
 const DEFAULT_RATE_LIMIT_REQUESTS_PER_MINUTE: u32 = 60;
+const DEFAULT_MIN_PRODUCTION_REQUESTS_PER_MINUTE: u32 = 10;
+const DEFAULT_MAX_REQUESTS_PER_MINUTE: u32 = 10_000;
+const DEFAULT_MAX_ADDRESSES_PER_REQUEST: u32 = 50;
+const DEFAULT_MAX_CHAINS_PER_REQUEST: u32 = 5;
+const DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION: u32 = 100;
 const DEFAULT_METRICS_ENDPOINT_PATH: &str = "/metrics";
 const DEFAULT_METRICS_PORT: u16 = 9102;
+const DEFAULT_METRICS_HEALTH_PATH: &str = "/health";
+const CORS_ANY_ORIGIN: &str = "*";
+const DEFAULT_CORS_METHODS: [&str; 4] = ["GET", "POST", "PUT", "OPTIONS"];
 const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_CREATOR_ENRICHMENT_TIMEOUT_SECONDS: u64 = 5;
+const DEFAULT_FRESH_ADDRESS_MAX_TRANSACTIONS: u64 = 5;
+const DEFAULT_PRODUCTION_MODEL_DENYLIST: [&str; 2] = [":test", "OLD"];
 
 /// A validated server port that ensures the value is appropriate for the environment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -123,6 +154,11 @@ impl TimeoutSeconds {
         Self(Duration::from_secs(DEFAULT_TIMEOUT_SECONDS))
     }
 
+    /// Create the default connection establishment timeout (1 second)
+    pub const fn connect_default() -> Self {
+        Self(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECONDS))
+    }
+
     /// Create a safe testing timeout (5 seconds)
     pub const fn testing() -> Self {
         Self(Duration::from_secs(TESTING_TIMEOUT_SECONDS))
@@ -197,6 +233,14 @@ pub struct ExternalApiCacheConfig {
     pub max_entries: usize,
     /// Cleanup interval in seconds
     pub cleanup_interval_seconds: u64,
+    /// How long an address no provider has metadata for is remembered, in
+    /// seconds (0 disables negative caching)
+    #[serde(default = "default_negative_ttl_seconds")]
+    pub negative_ttl_seconds: u64,
+}
+
+fn default_negative_ttl_seconds() -> u64 {
+    DEFAULT_NEGATIVE_CACHE_TTL.as_secs()
 }
 
 impl Default for ExternalApiCacheConfig {
@@ -206,10 +250,126 @@ impl Default for ExternalApiCacheConfig {
             ttl_seconds: 21600,             // 6 hours
             max_entries: 50000,             // 50k entries
             cleanup_interval_seconds: 3600, // 1 hour
+            negative_ttl_seconds: default_negative_ttl_seconds(),
+        }
+    }
+}
+
+/// Prediction cache snapshot configuration for warm starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheSnapshotConfig {
+    /// Local path or `http(s)://` URL of a snapshot loaded into the prediction
+    /// cache at startup (nothing is loaded when unset)
+    pub source: Option<String>,
+    /// Local path the prediction cache is periodically written to (snapshots
+    /// are not written when unset)
+    pub path: Option<String>,
+    /// Interval between snapshot writes in seconds
+    pub interval_seconds: u64,
+}
+
+impl Default for CacheSnapshotConfig {
+    fn default() -> Self {
+        Self {
+            source: None,
+            path: None,
+            interval_seconds: 300, // 5 minutes
+        }
+    }
+}
+
+/// Automatic reload of the model and prompt registries when their files change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoReloadConfig {
+    /// Watch the registry files and reload them on change
+    pub enabled: bool,
+    /// Quiet period in milliseconds after the last file change before
+    /// reloading, so a burst of writes triggers a single reload
+    pub debounce_ms: u64,
+}
+
+impl Default for AutoReloadConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            debounce_ms: 500,
+        }
+    }
+}
+
+/// Backoff applied between retries of outbound requests
+///
+/// Shared by the Moralis, Pinax and `OpenAI` clients so that retries against
+/// different upstreams are jittered consistently during a shared outage. The
+/// number of attempts stays per-client (`max_retries`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryBackoffConfig {
+    /// Delay before the first retry in milliseconds
+    pub initial_delay_ms: u64,
+    /// Upper bound for a single retry delay in milliseconds
+    pub max_delay_ms: u64,
+    /// Fraction of each delay that is randomized (0.0 to 1.0)
+    pub jitter_factor: f64,
+}
+
+impl Default for RetryBackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: DEFAULT_RETRY_INITIAL_DELAY_MS,
+            max_delay_ms: DEFAULT_RETRY_MAX_DELAY_MS,
+            jitter_factor: DEFAULT_RETRY_JITTER_FACTOR,
+        }
+    }
+}
+
+impl RetryBackoffConfig {
+    /// Build a retry policy with this backoff and the given number of attempts
+    pub fn policy(&self, max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::default()
+            .with_initial_delay_ms(self.initial_delay_ms)
+            .with_max_delay_ms(self.max_delay_ms)
+            .with_jitter_factor(self.jitter_factor)
+            .with_max_attempts(max_attempts)
+    }
+}
+
+/// Circuit breaking of metadata providers that keep failing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalApiCircuitBreakerConfig {
+    /// Skip providers whose breaker is open
+    pub enabled: bool,
+    /// Consecutive failures that open a provider's breaker
+    pub failure_threshold: u32,
+    /// Window in seconds the consecutive failures must fall within
+    pub failure_window_seconds: u64,
+    /// Seconds a provider is skipped before a probe request is let through
+    pub cooldown_seconds: u64,
+}
+
+impl Default for ExternalApiCircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            failure_window_seconds: DEFAULT_FAILURE_WINDOW.as_secs(),
+            cooldown_seconds: DEFAULT_COOLDOWN.as_secs(),
         }
     }
 }
 
+impl ExternalApiCircuitBreakerConfig {
+    /// Breaker thresholds for the registry, if circuit breaking is enabled
+    pub fn breaker_config(&self) -> Option<CircuitBreakerConfig> {
+        self.enabled.then(|| CircuitBreakerConfig {
+            failure_threshold: self.failure_threshold,
+            failure_window: Duration::from_secs(self.failure_window_seconds),
+            cooldown: Duration::from_secs(self.cooldown_seconds),
+        })
+    }
+}
+
 /// External API configuration
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ExternalApiConfig {
@@ -217,8 +377,106 @@ pub struct ExternalApiConfig {
     pub moralis: MoralisConfig,
     /// Pinax API configuration
     pub pinax: PinaxConfig,
+    /// `SimpleHash` API configuration
+    #[serde(default)]
+    pub simplehash: SimpleHashConfig,
     /// Cache configuration for external API results
     pub cache: ExternalApiCacheConfig,
+    /// Creator address enrichment configuration
+    pub creator_enrichment: CreatorEnrichmentConfig,
+    /// Backoff between retries of outbound requests
+    #[serde(default)]
+    pub retry: RetryBackoffConfig,
+    /// Circuit breaking of failing metadata providers
+    #[serde(default)]
+    pub circuit_breaker: ExternalApiCircuitBreakerConfig,
+    /// Hosts that outbound requests may reach
+    #[serde(default)]
+    pub upstream_allowlist: UpstreamAllowlistConfig,
+    /// Consult a second provider after each fetched answer and resolve
+    /// conflicting name or contract type metadata
+    #[serde(default)]
+    pub metadata_cross_check: bool,
+    /// Whether the first provider with metadata wins (`first_wins`) or the
+    /// fields of every provider are merged by priority (`merge`)
+    #[serde(default)]
+    pub metadata_merge_mode: MetadataMergeMode,
+}
+
+/// Restriction of outbound requests to permitted upstream hosts
+///
+/// Enabled by default: the Moralis, Pinax, `SimpleHash`, creator enrichment and
+/// `OpenAI` clients and cache snapshot fetches only connect to hosts in
+/// `allowed_hosts`, or to any public host when the list is empty. Hosts
+/// resolving to private, loopback or link-local addresses are rejected unless
+/// listed explicitly or `allow_private_networks` is set, which it is by default
+/// in development.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpstreamAllowlistConfig {
+    /// Enforce the allowlist on outbound requests
+    pub enabled: bool,
+    /// Permitted hosts, exact or `*.example.com` for subdomains
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    /// Permit private network addresses for every allowed host
+    #[serde(default)]
+    pub allow_private_networks: bool,
+}
+
+impl Default for UpstreamAllowlistConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            allowed_hosts: Vec::new(),
+            allow_private_networks: false,
+        }
+    }
+}
+
+impl UpstreamAllowlistConfig {
+    /// The allowlist to install into outbound clients, if enabled
+    pub fn allowlist(&self) -> Option<UpstreamAllowlist> {
+        self.enabled.then(|| {
+            UpstreamAllowlist::new(self.allowed_hosts.iter().cloned())
+                .with_private_networks(self.allow_private_networks)
+        })
+    }
+}
+
+/// Creator address enrichment configuration
+///
+/// When enabled, the deployer of each contract is reverse-resolved through the
+/// configured name service and its transaction count is queried over JSON-RPC,
+/// producing a creator reputation feature for spam classification.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatorEnrichmentConfig {
+    /// Enable/disable creator enrichment
+    pub enabled: bool,
+    /// JSON-RPC endpoints used to query creator transaction counts, per chain
+    #[serde(default)]
+    #[serde_as(as = "HashMap<DisplayFromStr, _>")]
+    pub rpc_urls: HashMap<ChainId, Url>,
+    /// Reverse-resolution name service base URL (optional)
+    #[serde(default)]
+    pub name_service_url: Option<Url>,
+    /// Request timeout in seconds
+    pub timeout_seconds: TimeoutSeconds,
+    /// Transaction count at or below which a creator is considered fresh
+    pub fresh_address_max_transactions: u64,
+}
+
+impl Default for CreatorEnrichmentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rpc_urls: HashMap::new(),
+            name_service_url: None,
+            timeout_seconds: TimeoutSeconds::new(DEFAULT_CREATOR_ENRICHMENT_TIMEOUT_SECONDS)
+                .expect("default creator enrichment timeout is valid"),
+            fresh_address_max_transactions: DEFAULT_FRESH_ADDRESS_MAX_TRANSACTIONS,
+        }
+    }
 }
 
 /// Moralis API configuration
@@ -230,12 +488,45 @@ pub struct MoralisConfig {
     pub api_key: ApiKey,
     /// Request timeout in seconds
     pub timeout_seconds: TimeoutSeconds,
+    /// Connection establishment timeout in seconds, so unreachable hosts fail
+    /// fast instead of waiting for `timeout_seconds`
+    #[serde(default = "TimeoutSeconds::connect_default")]
+    pub connect_timeout_seconds: TimeoutSeconds,
     /// Health check timeout in seconds
     pub health_check_timeout_seconds: TimeoutSeconds,
     /// Maximum number of retry attempts
     pub max_retries: u32,
     /// Enable/disable the Moralis client
     pub enabled: bool,
+    /// Upper bound for the total supply parsed from responses; larger values
+    /// saturate to it
+    #[serde(default = "default_max_parsed_count")]
+    pub max_parsed_count: u64,
+    /// Seconds clients are told to wait when Moralis rate limits us and sends
+    /// no `Retry-After` header of its own
+    #[serde(default = "default_retry_after_seconds")]
+    pub retry_after_seconds: u64,
+    /// Failover priority; providers with a higher priority are tried first
+    #[serde(default)]
+    pub priority: u8,
+}
+
+fn default_max_parsed_count() -> u64 {
+    DEFAULT_MAX_PARSED_COUNT
+}
+
+fn default_retry_after_seconds() -> u64 {
+    RateLimitConfig::default().retry_after_seconds
+}
+
+impl MoralisConfig {
+    /// Rate limit handling for the Moralis client
+    pub fn rate_limit(&self) -> RateLimitConfig {
+        RateLimitConfig {
+            retry_after_seconds: self.retry_after_seconds,
+            ..RateLimitConfig::default()
+        }
+    }
 }
 
 impl Default for MoralisConfig {
@@ -245,10 +536,14 @@ impl Default for MoralisConfig {
                 .expect("valid default Moralis URL"),
             api_key: ApiKey::testing(), // Will be overridden in production
             timeout_seconds: TimeoutSeconds::default(),
+            connect_timeout_seconds: TimeoutSeconds::connect_default(),
             health_check_timeout_seconds: TimeoutSeconds::new(DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS)
                 .expect("default health check timeout is valid"),
             max_retries: DEFAULT_MAX_RETRIES,
             enabled: false,
+            max_parsed_count: DEFAULT_MAX_PARSED_COUNT,
+            retry_after_seconds: default_retry_after_seconds(),
+            priority: 0,
         }
     }
 }
@@ -266,12 +561,33 @@ pub struct PinaxConfig {
     pub db_name: String,
     /// Request timeout in seconds
     pub timeout_seconds: TimeoutSeconds,
+    /// Connection establishment timeout in seconds, so unreachable hosts fail
+    /// fast instead of waiting for `timeout_seconds`
+    #[serde(default = "TimeoutSeconds::connect_default")]
+    pub connect_timeout_seconds: TimeoutSeconds,
     /// Health check timeout in seconds
     pub health_check_timeout_seconds: TimeoutSeconds,
     /// Maximum number of retry attempts
     pub max_retries: u32,
     /// Enable/disable the Pinax client
     pub enabled: bool,
+    /// Seconds clients are told to wait when Pinax rate limits us and sends
+    /// no `Retry-After` header of its own
+    #[serde(default = "default_retry_after_seconds")]
+    pub retry_after_seconds: u64,
+    /// Failover priority; providers with a higher priority are tried first
+    #[serde(default)]
+    pub priority: u8,
+}
+
+impl PinaxConfig {
+    /// Rate limit handling for the Pinax client
+    pub fn rate_limit(&self) -> RateLimitConfig {
+        RateLimitConfig {
+            retry_after_seconds: self.retry_after_seconds,
+            ..RateLimitConfig::default()
+        }
+    }
 }
 
 impl Default for PinaxConfig {
@@ -282,10 +598,74 @@ impl Default for PinaxConfig {
             api_auth: ApiKey::testing(),
             db_name: "mainnet:evm-nft-tokens@v0.6.2".to_string(),
             timeout_seconds: TimeoutSeconds::default(),
+            connect_timeout_seconds: TimeoutSeconds::connect_default(),
+            health_check_timeout_seconds: TimeoutSeconds::new(DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS)
+                .expect("default health check timeout is valid"),
+            max_retries: DEFAULT_MAX_RETRIES,
+            enabled: false,
+            retry_after_seconds: default_retry_after_seconds(),
+            priority: 0,
+        }
+    }
+}
+
+/// `SimpleHash` API configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimpleHashConfig {
+    /// Base URL for `SimpleHash` API
+    pub base_url: Url,
+    /// API key for authentication
+    pub api_key: ApiKey,
+    /// Request timeout in seconds
+    pub timeout_seconds: TimeoutSeconds,
+    /// Connection establishment timeout in seconds, so unreachable hosts fail
+    /// fast instead of waiting for `timeout_seconds`
+    #[serde(default = "TimeoutSeconds::connect_default")]
+    pub connect_timeout_seconds: TimeoutSeconds,
+    /// Health check timeout in seconds
+    pub health_check_timeout_seconds: TimeoutSeconds,
+    /// Maximum number of retry attempts
+    pub max_retries: u32,
+    /// Enable/disable the `SimpleHash` client
+    pub enabled: bool,
+    /// Upper bound for the supply and holder counts parsed from responses;
+    /// larger values saturate to it
+    #[serde(default = "default_max_parsed_count")]
+    pub max_parsed_count: u64,
+    /// Seconds clients are told to wait when `SimpleHash` rate limits us and
+    /// sends no `Retry-After` header of its own
+    #[serde(default = "default_retry_after_seconds")]
+    pub retry_after_seconds: u64,
+    /// Failover priority; providers with a higher priority are tried first
+    #[serde(default)]
+    pub priority: u8,
+}
+
+impl SimpleHashConfig {
+    /// Rate limit handling for the `SimpleHash` client
+    pub fn rate_limit(&self) -> RateLimitConfig {
+        RateLimitConfig {
+            retry_after_seconds: self.retry_after_seconds,
+            ..RateLimitConfig::default()
+        }
+    }
+}
+
+impl Default for SimpleHashConfig {
+    fn default() -> Self {
+        Self {
+            base_url: Url::parse("https://api.simplehash.com")
+                .expect("valid default SimpleHash URL"),
+            api_key: ApiKey::testing(),
+            timeout_seconds: TimeoutSeconds::default(),
+            connect_timeout_seconds: TimeoutSeconds::connect_default(),
             health_check_timeout_seconds: TimeoutSeconds::new(DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS)
                 .expect("default health check timeout is valid"),
             max_retries: DEFAULT_MAX_RETRIES,
             enabled: false,
+            max_parsed_count: DEFAULT_MAX_PARSED_COUNT,
+            retry_after_seconds: default_retry_after_seconds(),
+            priority: 0,
         }
     }
 }
@@ -321,6 +701,10 @@ pub struct ChainPinaxConfig {
     pub timeout_seconds: Option<TimeoutSeconds>,
     /// Chain-specific max retries (optional)
     pub max_retries: Option<u32>,
+    /// Whether the chain database has a `contract_creations` table to read
+    /// contract creation fields from; off unless verified for the chain
+    #[serde(default)]
+    pub contract_creations: bool,
 }
 
 impl Default for ChainConfig {
@@ -339,12 +723,15 @@ impl Default for ChainPinaxConfig {
             db_name: "mainnet:evm-nft-tokens@v0.6.2".to_string(),
             timeout_seconds: None,
             max_retries: None,
+            contract_creations: false,
         }
     }
 }
 
 /// Spam predictor configuration
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)] // Independent on/off switches by design
 pub struct SpamPredictorConfig {
     /// `OpenAI` API key for GPT model access
     pub openai_api_key: ApiKey,
@@ -352,12 +739,20 @@ pub struct SpamPredictorConfig {
     pub openai_base_url: Option<Url>,
     /// `OpenAI` organization ID (optional)
     pub openai_organization_id: Option<String>,
+    /// Chat completions provider; Azure `OpenAI` needs `openai_base_url` set to
+    /// the resource endpoint and treats model IDs as deployment names
+    #[serde(default)]
+    pub openai_provider: OpenAiProvider,
     /// Path to model registry YAML file
     pub model_registry_path: String,
     /// Path to prompt registry JSON file
     pub prompt_registry_path: String,
     /// Request timeout in seconds
     pub timeout_seconds: TimeoutSeconds,
+    /// Connection establishment timeout in seconds, so unreachable hosts fail
+    /// fast instead of waiting for `timeout_seconds`
+    #[serde(default = "TimeoutSeconds::connect_default")]
+    pub connect_timeout_seconds: TimeoutSeconds,
     /// Maximum tokens for model responses
     pub max_tokens: Option<u32>,
     /// Temperature for model responses (0.0 to 2.0)
@@ -366,6 +761,96 @@ pub struct SpamPredictorConfig {
     pub cache_ttl_seconds: u64,
     /// Maximum number of cached predictions
     pub max_cache_size: usize,
+    /// Log every prediction cache eviction with its reason at info level
+    /// instead of debug level
+    #[serde(default = "default_log_cache_evictions")]
+    pub log_cache_evictions: bool,
+    /// Key cached predictions on a hash of the resolved model ID and prompt
+    /// text, so prompts edited in place under the same version are not served
+    /// stale predictions
+    #[serde(default = "default_prompt_content_hashing")]
+    pub prompt_content_hashing: bool,
+    /// Chain-specific default model, used instead of the global default
+    #[serde(default)]
+    #[serde_as(as = "HashMap<DisplayFromStr, _>")]
+    pub chain_model_defaults: HashMap<ChainId, ModelSpec>,
+    /// Chain-specific default prompt version, used instead of the global default
+    #[serde(default)]
+    #[serde_as(as = "HashMap<DisplayFromStr, _>")]
+    pub chain_prompt_defaults: HashMap<ChainId, PromptVersion>,
+    /// Model versions tried in order, for the same model type, when `OpenAI`
+    /// reports the requested model as not found (e.g. a decommissioned fine-tune)
+    #[serde(default)]
+    pub fallback_model_versions: Vec<ModelVersion>,
+    /// Models tried in order when the requested model is unavailable (rate
+    /// limited, failing or not found), after any fallback versions
+    #[serde(default)]
+    pub fallback_models: Vec<ModelSpec>,
+    /// Substrings of model IDs that must never be served in production, such as
+    /// test or retired fine-tunes
+    #[serde(default)]
+    pub production_model_denylist: Vec<String>,
+    /// Skip and log invalid model or prompt version entries in the registry
+    /// files instead of failing the whole load
+    #[serde(default)]
+    pub registry_tolerant_load: bool,
+    /// Scores separating the `low`, `medium` and `high` confidence bands
+    #[serde(default)]
+    pub confidence_band_thresholds: ConfidenceBandThresholds,
+    /// Confidence, between 0.0 and 1.0, below which a spam or legitimate
+    /// prediction is reported as inconclusive (0.0 trusts every answer)
+    #[serde(default)]
+    pub confidence_threshold: f64,
+    /// Answer with deterministic classifications derived from the contract
+    /// metadata instead of calling `OpenAI` (dry run, refused in production)
+    #[serde(default)]
+    pub mock_predictions: bool,
+    /// Contract metadata fields serialized into the model input, in prompt order
+    #[serde(default = "ModelInputField::default_fields")]
+    pub model_input_fields: Vec<ModelInputField>,
+    /// Prediction cache snapshot loading and writing
+    #[serde(default)]
+    pub cache_snapshot: CacheSnapshotConfig,
+    /// Reload the model and prompt registries when their files change
+    #[serde(default)]
+    pub auto_reload: AutoReloadConfig,
+    /// Fraction of fresh predictions, between 0.0 and 1.0, whose redacted
+    /// model input and raw response are captured for fine-tuning
+    #[serde(default)]
+    pub training_capture_rate: f64,
+    /// Local JSON lines file captured training records are appended to
+    #[serde(default)]
+    pub training_capture_path: Option<String>,
+    /// Redis server cached predictions are stored in, shared by every instance
+    /// using it (requires the `redis` feature); the in-memory cache when unset
+    #[serde(default)]
+    pub redis_url: Option<String>,
+}
+
+fn default_log_cache_evictions() -> bool {
+    true
+}
+
+fn default_prompt_content_hashing() -> bool {
+    true
+}
+
+impl SpamPredictorConfig {
+    /// Default model for the chain, falling back to the global default
+    pub fn model_spec_for(&self, chain_id: ChainId) -> ModelSpec {
+        self.chain_model_defaults
+            .get(&chain_id)
+            .cloned()
+            .unwrap_or_else(ModelSpec::spam_classification_latest)
+    }
+
+    /// Default prompt version for the chain, falling back to the global default
+    pub fn prompt_version_for(&self, chain_id: ChainId) -> PromptVersion {
+        self.chain_prompt_defaults
+            .get(&chain_id)
+            .cloned()
+            .unwrap_or_else(PromptVersion::v1_0_0)
+    }
 }
 
 impl Default for SpamPredictorConfig {
@@ -374,13 +859,32 @@ impl Default for SpamPredictorConfig {
             openai_api_key: ApiKey::testing(),
             openai_base_url: None,
             openai_organization_id: None,
+            openai_provider: OpenAiProvider::default(),
             model_registry_path: "assets/configs/models.yaml".to_string(),
             prompt_registry_path: "assets/prompts/ft_prompt.json".to_string(),
             timeout_seconds: TimeoutSeconds::default(),
+            connect_timeout_seconds: TimeoutSeconds::connect_default(),
             max_tokens: Some(10),
             temperature: Some(0.0),
             cache_ttl_seconds: 3600, // 1 hour
             max_cache_size: 10000,
+            log_cache_evictions: true,
+            prompt_content_hashing: true,
+            chain_model_defaults: HashMap::new(),
+            chain_prompt_defaults: HashMap::new(),
+            fallback_model_versions: Vec::new(),
+            fallback_models: Vec::new(),
+            production_model_denylist: DEFAULT_PRODUCTION_MODEL_DENYLIST.map(String::from).to_vec(),
+            registry_tolerant_load: false,
+            confidence_band_thresholds: ConfidenceBandThresholds::default(),
+            confidence_threshold: 0.0,
+            mock_predictions: false,
+            model_input_fields: ModelInputField::default_fields(),
+            cache_snapshot: CacheSnapshotConfig::default(),
+            auto_reload: AutoReloadConfig::default(),
+            training_capture_rate: 0.0,
+            training_capture_path: None,
+            redis_url: None,
         }
     }
 }
@@ -390,8 +894,63 @@ impl Default for SpamPredictorConfig {
 pub struct RateLimitingConfig {
     /// Enable/disable rate limiting
     pub enabled: bool,
-    /// Maximum requests per minute per IP address
+    /// Maximum requests per minute per IP address, refilling the token bucket
+    /// continuously; outside production `0` disables rate limiting
     pub requests_per_minute: u32,
+    /// Token bucket size, the number of requests an idle client may send at
+    /// once; defaults to `requests_per_minute`
+    #[serde(default)]
+    pub burst_capacity: Option<u32>,
+    /// Lowest `requests_per_minute` accepted in production, guarding against
+    /// near-zero limits that would lock every client out
+    #[serde(default = "default_min_production_requests_per_minute")]
+    pub min_production_requests_per_minute: u32,
+    /// Highest `requests_per_minute` accepted in any environment
+    #[serde(default = "default_max_requests_per_minute")]
+    pub max_requests_per_minute: u32,
+    /// Maximum number of addresses accepted in a single contract status request
+    #[serde(default = "default_max_addresses")]
+    pub max_addresses: u32,
+    /// Maximum number of distinct chains a single contract status request may
+    /// span; `1` disables mixed-chain batches
+    #[serde(default = "default_max_chains")]
+    pub max_chains: u32,
+    /// Maximum number of `subscribe` frames a single contract status
+    /// WebSocket connection may send
+    #[serde(default = "default_max_subscriptions_per_connection")]
+    pub max_subscriptions_per_connection: u32,
+}
+
+fn default_min_production_requests_per_minute() -> u32 {
+    DEFAULT_MIN_PRODUCTION_REQUESTS_PER_MINUTE
+}
+
+fn default_max_requests_per_minute() -> u32 {
+    DEFAULT_MAX_REQUESTS_PER_MINUTE
+}
+
+fn default_max_addresses() -> u32 {
+    DEFAULT_MAX_ADDRESSES_PER_REQUEST
+}
+
+fn default_max_chains() -> u32 {
+    DEFAULT_MAX_CHAINS_PER_REQUEST
+}
+
+fn default_max_subscriptions_per_connection() -> u32 {
+    DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION
+}
+
+impl RateLimitingConfig {
+    /// Whether requests are actually limited
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.requests_per_minute > 0
+    }
+
+    /// Token bucket size per IP address
+    pub fn bucket_capacity(&self) -> u32 {
+        self.burst_capacity.unwrap_or(self.requests_per_minute)
+    }
 }
 
 impl Default for RateLimitingConfig {
@@ -399,6 +958,12 @@ impl Default for RateLimitingConfig {
         Self {
             enabled: true,
             requests_per_minute: DEFAULT_RATE_LIMIT_REQUESTS_PER_MINUTE,
+            burst_capacity: None,
+            min_production_requests_per_minute: DEFAULT_MIN_PRODUCTION_REQUESTS_PER_MINUTE,
+            max_requests_per_minute: DEFAULT_MAX_REQUESTS_PER_MINUTE,
+            max_addresses: DEFAULT_MAX_ADDRESSES_PER_REQUEST,
+            max_chains: DEFAULT_MAX_CHAINS_PER_REQUEST,
+            max_subscriptions_per_connection: DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION,
         }
     }
 }
@@ -412,6 +977,14 @@ pub struct ConcurrencyConfig {
     pub max_concurrent_spam_analysis: u16,
     /// Timeout in seconds for individual address processing
     pub individual_address_timeout_seconds: TimeoutSeconds,
+    /// Timeout in milliseconds for each address in a batch, overriding
+    /// `individual_address_timeout_seconds` when set
+    #[serde(default)]
+    pub per_address_timeout_ms: Option<u64>,
+    /// Default latency budget per request in milliseconds (SLO); requests may
+    /// override it with `max_latency_ms`. Unset disables the fast-fail.
+    #[serde(default)]
+    pub max_latency_ms: Option<u64>,
 }
 
 impl Default for ConcurrencyConfig {
@@ -420,6 +993,37 @@ impl Default for ConcurrencyConfig {
             max_concurrent_external_api_calls: 10,
             max_concurrent_spam_analysis: 20,
             individual_address_timeout_seconds: TimeoutSeconds::default(),
+            per_address_timeout_ms: None,
+            max_latency_ms: None,
+        }
+    }
+}
+
+impl ConcurrencyConfig {
+    /// Time allowed for analyzing a single address before it fails with a timeout
+    pub fn per_address_timeout(&self) -> Duration {
+        self.per_address_timeout_ms.map_or_else(
+            || self.individual_address_timeout_seconds.value(),
+            Duration::from_millis,
+        )
+    }
+}
+
+/// Token sampling for the collection score endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CollectionScoreConfig {
+    /// Number of tokens sampled when a request does not set `sample_size`
+    pub default_sample_size: u16,
+    /// Largest `sample_size` a request may ask for
+    pub max_sample_size: u16,
+}
+
+impl Default for CollectionScoreConfig {
+    fn default() -> Self {
+        Self {
+            default_sample_size: 10,
+            max_sample_size: 50,
         }
     }
 }
@@ -436,6 +1040,39 @@ pub enum Environment {
     Testing,
 }
 
+/// Shape of the contract status response body
+///
+/// Clients select a version with `Accept: application/vnd.nft-api.<version>+json`;
+/// requests without a versioned media type get the configured default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFormatVersion {
+    /// Flat map of contract address to result
+    #[default]
+    V1,
+    /// `{results, summary}` object with per-status counts
+    V2,
+}
+
+impl ResultFormatVersion {
+    /// Parse the version segment of a vendor media type (e.g. `v2`)
+    pub fn from_version_str(version: &str) -> Option<Self> {
+        match version {
+            "v1" => Some(Self::V1),
+            "v2" => Some(Self::V2),
+            _ => None,
+        }
+    }
+
+    /// Vendor media type identifying this version
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::V1 => "application/vnd.nft-api.v1+json",
+            Self::V2 => "application/vnd.nft-api.v2+json",
+        }
+    }
+}
+
 /// Server configuration for different environments
 ///
 /// ## Required Environment Variables for Production
@@ -461,6 +1098,8 @@ pub struct ServerConfig {
     pub host: IpAddr,
     /// Server port (validated for environment compatibility)
     pub port: ServerPort,
+    /// Lowest port the server may bind to; lower ports warn, or fail in production
+    pub min_server_port: u16,
     /// Request timeout in seconds (validated range: 1-60)
     pub timeout_seconds: TimeoutSeconds,
     /// Environment type
@@ -473,8 +1112,48 @@ pub struct ServerConfig {
     pub rate_limiting: RateLimitingConfig,
     /// Concurrency configuration
     pub concurrency: ConcurrencyConfig,
+    /// Token sampling for collection scores
+    #[serde(default)]
+    pub collection_score: CollectionScoreConfig,
     /// Prometheus metrics configuration
     pub metrics: MetricsConfig,
+    /// Serve HTTPS on the main port (plain HTTP when unset)
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// API key granting access to admin-only views (optional, admin views disabled when unset)
+    pub admin_api_key: Option<ApiKey>,
+    /// Client API keys required on `/v1` routes
+    #[serde(default)]
+    pub auth: AuthConfig,
+    /// Cross-origin resource sharing policy
+    #[serde(default)]
+    pub cors: CorsConfig,
+    /// Secret used to sign `/v1` response bodies with HMAC-SHA256 in the
+    /// `x-signature` header (responses are unsigned when unset)
+    #[serde(default)]
+    pub response_signing_secret: Option<ApiKey>,
+    /// Include raw upstream error detail in `reasoning` for failed results
+    ///
+    /// Defaults to `false` in production and `true` elsewhere when unset. When
+    /// disabled, failed results only carry a generic message and an `error_code`.
+    #[serde(default)]
+    pub expose_error_details: Option<bool>,
+    /// Reject provider API keys that do not match the provider's key format
+    /// (e.g. the `sk-` prefix of `OpenAI` keys) instead of only warning
+    ///
+    /// Defaults to `true` in production and `false` elsewhere when unset.
+    #[serde(default)]
+    pub strict_api_key_format: Option<bool>,
+    /// Response shape used when the `Accept` header does not name a version
+    #[serde(default)]
+    pub default_result_format: ResultFormatVersion,
+    /// Reflect the verdict of single-contract requests in the HTTP status code
+    /// (e.g. `422` for spam) instead of always answering `200`
+    #[serde(default)]
+    pub status_to_http_code: bool,
+    /// Initial values of the runtime feature flags (changeable via `/admin/flags`)
+    #[serde(default)]
+    pub feature_flags: FeatureFlags,
     /// Chain-specific configurations
     #[serde_as(as = "HashMap<DisplayFromStr, _>")]
     pub chains: HashMap<ChainId, ChainConfig>,
@@ -487,19 +1166,98 @@ impl Default for ServerConfig {
         Self {
             host: IpAddr::V4(Ipv4Addr::LOCALHOST),
             port: ServerPort::default_development(),
+            min_server_port: DEFAULT_MIN_SERVER_PORT,
             timeout_seconds: TimeoutSeconds::default(),
             environment: Environment::Development,
             external_apis: ExternalApiConfig::default(),
             spam_predictor: SpamPredictorConfig::default(),
             rate_limiting: RateLimitingConfig::default(),
             concurrency: ConcurrencyConfig::default(),
+            collection_score: CollectionScoreConfig::default(),
             metrics: MetricsConfig::default(),
+            tls: None,
+            admin_api_key: None,
+            auth: AuthConfig::default(),
+            cors: CorsConfig::default(),
+            response_signing_secret: None,
+            expose_error_details: None,
+            strict_api_key_format: None,
+            default_result_format: ResultFormatVersion::default(),
+            status_to_http_code: false,
+            feature_flags: FeatureFlags::default(),
             chains: Self::default_chains(),
             extensions: HashMap::new(),
         }
     }
 }
 
+/// API key authentication of `/v1` routes
+///
+/// Clients send a key as `Authorization: Bearer <key>` or `X-API-Key: <key>`.
+/// `/health`, `/metrics`, the API docs and the admin routes (guarded by
+/// `admin_api_key`) are exempt.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthConfig {
+    /// Require an allowed key on `/v1` routes; must be set explicitly in
+    /// production, and unset means disabled elsewhere
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Keys accepted from clients
+    #[serde(default)]
+    pub api_keys: Vec<ApiKey>,
+}
+
+impl AuthConfig {
+    /// Whether requests must carry an allowed API key
+    pub fn is_enabled(&self) -> bool {
+        self.enabled == Some(true)
+    }
+}
+
+/// Cross-origin resource sharing policy of the HTTP API
+///
+/// The defaults allow any origin without credentials, which suits public,
+/// key-less deployments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    /// Origins allowed to call the API from a browser (e.g.
+    /// `https://app.example.com`); `*` allows any origin
+    pub allowed_origins: Vec<String>,
+    /// HTTP methods allowed in cross-origin requests
+    pub allowed_methods: Vec<String>,
+    /// Allow cookies and `Authorization` headers in cross-origin requests
+    pub allow_credentials: bool,
+}
+
+impl CorsConfig {
+    /// Whether any origin is allowed
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|origin| origin == CORS_ANY_ORIGIN)
+    }
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![CORS_ANY_ORIGIN.to_string()],
+            allowed_methods: DEFAULT_CORS_METHODS.map(String::from).to_vec(),
+            allow_credentials: false,
+        }
+    }
+}
+
+/// Native TLS termination for deployments without a fronting proxy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TlsConfig {
+    /// Path to the PEM-encoded certificate chain
+    pub cert_path: PathBuf,
+    /// Path to the PEM-encoded private key
+    pub key_path: PathBuf,
+}
+
 /// Prometheus metrics configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsConfig {
@@ -507,6 +1265,20 @@ pub struct MetricsConfig {
     pub endpoint_path: String,
     /// Port for the metrics HTTP server (default 9102)
     pub port: u16,
+    /// HTTP path on the metrics port answering liveness probes (e.g. "/health")
+    #[serde(default = "default_metrics_health_path")]
+    pub health_path: String,
+    /// Bucket boundaries in seconds for the request duration histograms
+    #[serde(default = "default_histogram_buckets")]
+    pub histogram_buckets: Vec<f64>,
+}
+
+fn default_metrics_health_path() -> String {
+    DEFAULT_METRICS_HEALTH_PATH.to_string()
+}
+
+fn default_histogram_buckets() -> Vec<f64> {
+    DEFAULT_DURATION_BUCKETS.to_vec()
 }
 
 impl Default for MetricsConfig {
@@ -514,6 +1286,8 @@ impl Default for MetricsConfig {
         Self {
             endpoint_path: DEFAULT_METRICS_ENDPOINT_PATH.to_string(),
             port: DEFAULT_METRICS_PORT,
+            health_path: DEFAULT_METRICS_HEALTH_PATH.to_string(),
+            histogram_buckets: default_histogram_buckets(),
         }
     }
 }
@@ -536,6 +1310,7 @@ impl ServerConfig {
                     db_name: "mainnet:evm-nft-tokens@v0.6.2".to_string(),
                     timeout_seconds: None,
                     max_retries: None,
+                    contract_creations: false,
                 }),
             },
         );
@@ -550,6 +1325,7 @@ impl ServerConfig {
                     db_name: "matic:evm-nft-tokens@v0.5.1".to_string(),
                     timeout_seconds: None,
                     max_retries: None,
+                    contract_creations: false,
                 }),
             },
         );
@@ -564,6 +1340,7 @@ impl ServerConfig {
                     db_name: "base:evm-nft-tokens@v0.5.1".to_string(),
                     timeout_seconds: None,
                     max_retries: None,
+                    contract_creations: false,
                 }),
             },
         );
@@ -578,6 +1355,7 @@ impl ServerConfig {
                     db_name: "avalanche:evm-nft-tokens@v0.5.1".to_string(),
                     timeout_seconds: None,
                     max_retries: None,
+                    contract_creations: false,
                 }),
             },
         );
@@ -592,6 +1370,7 @@ impl ServerConfig {
                     db_name: "arbitrum-one:evm-nft-tokens@v0.5.1".to_string(),
                     timeout_seconds: None,
                     max_retries: None,
+                    contract_creations: false,
                 }),
             },
         );
@@ -624,10 +1403,76 @@ impl ServerConfig {
     pub fn validate(&self) -> Result<()> {
         self.validate_basic_configuration()?;
         self.validate_api_credentials()?;
+        self.validate_chain_providers()?;
         self.validate_production_safety()?;
         Ok(())
     }
 
+    /// Names of the enabled providers able to serve `chain_id`
+    ///
+    /// Moralis serves every chain with Moralis coverage through the global
+    /// configuration, and `SimpleHash` serves every supported chain. Pinax
+    /// additionally needs a per-chain `pinax` section, since the global
+    /// `db_name` only covers a single network.
+    pub fn viable_providers(&self, chain_id: ChainId) -> Vec<&'static str> {
+        let mut providers = Vec::new();
+        if self.external_apis.moralis.enabled
+            && chain_id.supports_capability(ChainCapability::MoralisMetadata)
+        {
+            providers.push("moralis");
+        }
+        if self.external_apis.pinax.enabled
+            && chain_id.supports_capability(ChainCapability::PinaxAnalytics)
+            && self
+                .chains
+                .get(&chain_id)
+                .is_some_and(|chain_config| chain_config.pinax.is_some())
+        {
+            providers.push("pinax");
+        }
+        if self.external_apis.simplehash.enabled {
+            providers.push("simplehash");
+        }
+        providers
+    }
+
+    /// Validate that every enabled chain has at least one provider able to serve it
+    ///
+    /// Without one, every request for the chain would end in an error. Running
+    /// with every provider disabled is the documented mode for placeholder
+    /// credentials and only warns.
+    fn validate_chain_providers(&self) -> Result<()> {
+        if !self.external_apis.moralis.enabled
+            && !self.external_apis.pinax.enabled
+            && !self.external_apis.simplehash.enabled
+        {
+            warn!("no metadata provider is enabled, contract status results will have no data");
+            return Ok(());
+        }
+
+        let mut unserved: Vec<ChainId> = self
+            .chains
+            .iter()
+            .filter(|(chain_id, chain_config)| {
+                chain_config.enabled && self.viable_providers(**chain_id).is_empty()
+            })
+            .map(|(chain_id, _)| *chain_id)
+            .collect();
+        unserved.sort_by_key(|chain_id| chain_id.chain_id());
+
+        ensure!(
+            unserved.is_empty(),
+            "Enabled chains have no enabled provider able to serve them: {} (enable Moralis, \
+             Pinax with a per-chain pinax section or SimpleHash, or disable these chains)",
+            unserved
+                .iter()
+                .map(|chain_id| format!("{} ({})", chain_id.name(), chain_id.chain_id()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        Ok(())
+    }
+
     /// Validate basic configuration parameters
     fn validate_basic_configuration(&self) -> Result<()> {
         // Port validation is handled by the u16 type - no need to check upper bound
@@ -637,52 +1482,224 @@ impl ServerConfig {
             "Port {} is invalid - must be greater than 0",
             port
         );
+        self.validate_port_privileges()?;
 
-        // Validate rate limiting bounds
-        if self.rate_limiting.enabled {
-            ensure!(
-                self.rate_limiting.requests_per_minute > 0,
-                "Rate limiting requests_per_minute must be greater than 0 when enabled"
-            );
-            ensure!(
-                self.rate_limiting.requests_per_minute <= 10_000,
-                "Rate limiting requests_per_minute of {} is too high - maximum is 10,000",
-                self.rate_limiting.requests_per_minute
-            );
-        }
+        self.validate_rate_limiting_configuration()?;
 
         // Validate timeout values
         ensure!(
             self.timeout_seconds.0.as_secs() >= 1,
             "Timeout must be at least 1 second"
         );
+        self.validate_connect_timeouts()?;
 
-        // Validate external API URLs are properly formatted
-        if self.external_apis.moralis.enabled {
-            let base_url = self.external_apis.moralis.base_url.as_str();
+        self.validate_metadata_provider_settings()?;
+
+        if self.external_apis.creator_enrichment.enabled {
+            let enrichment = &self.external_apis.creator_enrichment;
             ensure!(
-                base_url.starts_with("http://") || base_url.starts_with("https://"),
-                "Moralis base_url must be a valid HTTP(S) URL"
+                !enrichment.rpc_urls.is_empty() || enrichment.name_service_url.is_some(),
+                "Creator enrichment is enabled but neither rpc_urls nor name_service_url is set"
             );
+            for url in enrichment
+                .rpc_urls
+                .values()
+                .chain(enrichment.name_service_url.iter())
+            {
+                ensure!(
+                    matches!(url.scheme(), "http" | "https"),
+                    "Creator enrichment URL must be a valid HTTP(S) URL: {}",
+                    url
+                );
+            }
         }
 
-        if self.external_apis.pinax.enabled {
-            let endpoint = self.external_apis.pinax.endpoint.as_str();
+        let retry = &self.external_apis.retry;
+        ensure!(
+            retry.initial_delay_ms <= retry.max_delay_ms,
+            "external_apis.retry.initial_delay_ms must not exceed max_delay_ms"
+        );
+        ensure!(
+            (0.0..=1.0).contains(&retry.jitter_factor),
+            "external_apis.retry.jitter_factor must be between 0.0 and 1.0"
+        );
+
+        let circuit_breaker = &self.external_apis.circuit_breaker;
+        if circuit_breaker.enabled {
             ensure!(
-                endpoint.starts_with("http://") || endpoint.starts_with("https://"),
-                "Pinax endpoint must be a valid HTTP(S) URL"
+                circuit_breaker.failure_threshold > 0,
+                "external_apis.circuit_breaker.failure_threshold must be greater than 0"
+            );
+            ensure!(
+                circuit_breaker.cooldown_seconds > 0,
+                "external_apis.circuit_breaker.cooldown_seconds must be greater than 0"
             );
         }
 
+        self.validate_upstream_allowlist()?;
+
         // Validate concurrency configuration
         self.validate_concurrency_configuration()?;
 
+        self.validate_collection_score_configuration()?;
+
+        self.validate_tls_configuration()?;
+
+        self.validate_metrics_configuration()?;
+
+        self.validate_training_capture()?;
+
+        self.validate_prediction_cache_backend()?;
+
+        self.validate_openai_provider()?;
+
+        self.validate_auth_configuration()?;
+
+        self.validate_cors_configuration()?;
+
         // Validate chain configurations
         self.validate_chain_configurations()?;
 
         Ok(())
     }
 
+    /// Validate the URLs and limits of enabled metadata providers
+    fn validate_metadata_provider_settings(&self) -> Result<()> {
+        if self.external_apis.moralis.enabled {
+            let base_url = self.external_apis.moralis.base_url.as_str();
+            ensure!(
+                base_url.starts_with("http://") || base_url.starts_with("https://"),
+                "Moralis base_url must be a valid HTTP(S) URL"
+            );
+            ensure!(
+                self.external_apis.moralis.max_parsed_count > 0,
+                "external_apis.moralis.max_parsed_count must be greater than 0"
+            );
+            ensure!(
+                self.external_apis.moralis.retry_after_seconds > 0,
+                "external_apis.moralis.retry_after_seconds must be greater than 0"
+            );
+        }
+
+        if self.external_apis.pinax.enabled {
+            let endpoint = self.external_apis.pinax.endpoint.as_str();
+            ensure!(
+                endpoint.starts_with("http://") || endpoint.starts_with("https://"),
+                "Pinax endpoint must be a valid HTTP(S) URL"
+            );
+            ensure!(
+                self.external_apis.pinax.retry_after_seconds > 0,
+                "external_apis.pinax.retry_after_seconds must be greater than 0"
+            );
+        }
+
+        if self.external_apis.simplehash.enabled {
+            let base_url = self.external_apis.simplehash.base_url.as_str();
+            ensure!(
+                base_url.starts_with("http://") || base_url.starts_with("https://"),
+                "SimpleHash base_url must be a valid HTTP(S) URL"
+            );
+            ensure!(
+                self.external_apis.simplehash.max_parsed_count > 0,
+                "external_apis.simplehash.max_parsed_count must be greater than 0"
+            );
+            ensure!(
+                self.external_apis.simplehash.retry_after_seconds > 0,
+                "external_apis.simplehash.retry_after_seconds must be greater than 0"
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate that connect timeouts fit within their request timeouts
+    fn validate_connect_timeouts(&self) -> Result<()> {
+        let moralis = &self.external_apis.moralis;
+        let pinax = &self.external_apis.pinax;
+        let simplehash = &self.external_apis.simplehash;
+        let spam_predictor = &self.spam_predictor;
+        for (name, connect_timeout, timeout) in [
+            (
+                "external_apis.moralis",
+                moralis.connect_timeout_seconds,
+                moralis.timeout_seconds,
+            ),
+            (
+                "external_apis.pinax",
+                pinax.connect_timeout_seconds,
+                pinax.timeout_seconds,
+            ),
+            (
+                "external_apis.simplehash",
+                simplehash.connect_timeout_seconds,
+                simplehash.timeout_seconds,
+            ),
+            (
+                "spam_predictor",
+                spam_predictor.connect_timeout_seconds,
+                spam_predictor.timeout_seconds,
+            ),
+        ] {
+            ensure!(
+                connect_timeout.value() <= timeout.value(),
+                "{name}.connect_timeout_seconds ({:?}) cannot exceed {name}.timeout_seconds ({:?})",
+                connect_timeout.value(),
+                timeout.value()
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate that every configured upstream URL is permitted by the allowlist
+    fn validate_upstream_allowlist(&self) -> Result<()> {
+        let Some(allowlist) = self.external_apis.upstream_allowlist.allowlist() else {
+            return Ok(());
+        };
+        ensure!(
+            allowlist
+                .allowed_hosts
+                .iter()
+                .all(|host| !host.trim().is_empty()),
+            "external_apis.upstream_allowlist.allowed_hosts must not contain empty entries"
+        );
+
+        let mut urls: Vec<&Url> = Vec::new();
+        if self.external_apis.moralis.enabled {
+            urls.push(&self.external_apis.moralis.base_url);
+            urls.extend(
+                self.chains
+                    .values()
+                    .filter_map(|chain| chain.moralis.as_ref()?.base_url.as_ref()),
+            );
+        }
+        if self.external_apis.pinax.enabled {
+            urls.push(&self.external_apis.pinax.endpoint);
+        }
+        if self.external_apis.simplehash.enabled {
+            urls.push(&self.external_apis.simplehash.base_url);
+        }
+        let enrichment = &self.external_apis.creator_enrichment;
+        if enrichment.enabled {
+            urls.extend(enrichment.rpc_urls.values());
+            urls.extend(enrichment.name_service_url.iter());
+        }
+        urls.extend(self.spam_predictor.openai_base_url.iter());
+        let snapshot_url = self
+            .spam_predictor
+            .cache_snapshot
+            .source
+            .as_deref()
+            .filter(|source| source.starts_with("http://") || source.starts_with("https://"))
+            .and_then(|source| Url::parse(source).ok());
+        urls.extend(snapshot_url.iter());
+
+        for url in urls {
+            allowlist
+                .check_url(url)
+                .map_err(|e| anyhow!("Upstream URL {url} is not permitted: {e}"))?;
+        }
+        Ok(())
+    }
+
     /// Validate concurrency configuration parameters
     fn validate_concurrency_configuration(&self) -> Result<()> {
         ensure!(
@@ -705,16 +1722,222 @@ impl ServerConfig {
             self.concurrency.max_concurrent_spam_analysis
         );
 
+        ensure!(
+            self.concurrency.per_address_timeout_ms != Some(0),
+            "per_address_timeout_ms must be greater than 0 when set"
+        );
+
         // Individual address timeout should not exceed the global timeout
-        let individual_timeout = self.concurrency.individual_address_timeout_seconds.value();
+        let individual_timeout = self.concurrency.per_address_timeout();
         let global_timeout = self.timeout_seconds.value();
         ensure!(
             individual_timeout <= global_timeout,
-            "individual_address_timeout_seconds ({:?}) cannot exceed global timeout_seconds ({:?})",
+            "per-address timeout ({:?}) cannot exceed global timeout_seconds ({:?})",
             individual_timeout,
             global_timeout
         );
 
+        ensure!(
+            self.concurrency.max_latency_ms != Some(0),
+            "max_latency_ms must be greater than 0 when set"
+        );
+
+        Ok(())
+    }
+
+    /// Validate rate limiting against the bounds for the environment
+    fn validate_rate_limiting_configuration(&self) -> Result<()> {
+        let rate_limiting = &self.rate_limiting;
+        let floor = rate_limiting.min_production_requests_per_minute;
+        let ceiling = rate_limiting.max_requests_per_minute;
+        ensure!(
+            floor > 0,
+            "rate_limiting.min_production_requests_per_minute must be greater than 0"
+        );
+        ensure!(
+            floor <= ceiling,
+            "rate_limiting.min_production_requests_per_minute ({floor}) cannot exceed \
+             max_requests_per_minute ({ceiling})"
+        );
+        ensure!(
+            rate_limiting.max_addresses > 0,
+            "rate_limiting.max_addresses must be greater than 0"
+        );
+        ensure!(
+            rate_limiting.max_chains > 0,
+            "rate_limiting.max_chains must be greater than 0"
+        );
+        ensure!(
+            rate_limiting.max_subscriptions_per_connection > 0,
+            "rate_limiting.max_subscriptions_per_connection must be greater than 0"
+        );
+        ensure!(
+            rate_limiting.burst_capacity != Some(0),
+            "rate_limiting.burst_capacity must be greater than 0"
+        );
+        if !rate_limiting.enabled {
+            return Ok(());
+        }
+
+        let requests_per_minute = rate_limiting.requests_per_minute;
+        ensure!(
+            requests_per_minute <= ceiling,
+            "rate_limiting.requests_per_minute of {requests_per_minute} is too high - \
+             the maximum is {ceiling} (rate_limiting.max_requests_per_minute)"
+        );
+        if self.environment == Environment::Production {
+            ensure!(
+                requests_per_minute >= floor,
+                "rate_limiting.requests_per_minute of {requests_per_minute} is below the \
+                 production minimum of {floor} and would lock clients out - raise it or \
+                 lower rate_limiting.min_production_requests_per_minute"
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate collection score sampling limits
+    fn validate_collection_score_configuration(&self) -> Result<()> {
+        let collection_score = &self.collection_score;
+        ensure!(
+            collection_score.default_sample_size > 0,
+            "collection_score.default_sample_size must be greater than 0"
+        );
+        ensure!(
+            collection_score.max_sample_size <= 100,
+            "collection_score.max_sample_size of {} is too high - maximum is 100",
+            collection_score.max_sample_size
+        );
+        ensure!(
+            collection_score.default_sample_size <= collection_score.max_sample_size,
+            "collection_score.default_sample_size ({}) cannot exceed max_sample_size ({})",
+            collection_score.default_sample_size,
+            collection_score.max_sample_size
+        );
+        Ok(())
+    }
+
+    /// Validate that the TLS certificate and key files exist
+    ///
+    /// Their contents are parsed when the server starts.
+    fn validate_tls_configuration(&self) -> Result<()> {
+        if let Some(tls) = &self.tls {
+            ensure!(
+                tls.cert_path.is_file(),
+                "tls.cert_path {} is not a readable file",
+                tls.cert_path.display()
+            );
+            ensure!(
+                tls.key_path.is_file(),
+                "tls.key_path {} is not a readable file",
+                tls.key_path.display()
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate the training-data capture rate and sink
+    fn validate_training_capture(&self) -> Result<()> {
+        let spam_predictor = &self.spam_predictor;
+        ensure!(
+            (0.0..=1.0).contains(&spam_predictor.training_capture_rate),
+            "Spam Predictor training_capture_rate must be between 0.0 and 1.0"
+        );
+        ensure!(
+            spam_predictor.training_capture_rate <= 0.0
+                || spam_predictor.training_capture_path.is_some(),
+            "Spam Predictor training_capture_path must be set when training_capture_rate is above 0"
+        );
+        Ok(())
+    }
+
+    /// Validate that the metrics port routes are distinct absolute paths
+    fn validate_metrics_configuration(&self) -> Result<()> {
+        let metrics = &self.metrics;
+        ensure!(
+            metrics.endpoint_path.starts_with('/') && metrics.health_path.starts_with('/'),
+            "metrics.endpoint_path and metrics.health_path must start with '/'"
+        );
+        ensure!(
+            metrics.endpoint_path != metrics.health_path,
+            "metrics.health_path must differ from metrics.endpoint_path"
+        );
+        ensure!(
+            !metrics.histogram_buckets.is_empty(),
+            "metrics.histogram_buckets must not be empty"
+        );
+        ensure!(
+            metrics
+                .histogram_buckets
+                .iter()
+                .all(|bound| bound.is_finite() && *bound > 0.0),
+            "metrics.histogram_buckets must be positive and finite"
+        );
+        ensure!(
+            metrics
+                .histogram_buckets
+                .windows(2)
+                .all(|pair| pair[0] < pair[1]),
+            "metrics.histogram_buckets must be strictly increasing"
+        );
+        Ok(())
+    }
+
+    /// Validate that a configured Redis prediction cache can be used by this build
+    fn validate_prediction_cache_backend(&self) -> Result<()> {
+        ensure!(
+            self.spam_predictor.redis_url.is_none() || cfg!(feature = "redis"),
+            "Spam Predictor redis_url requires building with the `redis` feature"
+        );
+        Ok(())
+    }
+
+    /// Validate that enabled authentication has keys to accept
+    fn validate_auth_configuration(&self) -> Result<()> {
+        ensure!(
+            !self.auth.is_enabled() || !self.auth.api_keys.is_empty(),
+            "auth.api_keys must not be empty when auth is enabled"
+        );
+        Ok(())
+    }
+
+    /// Validate that CORS origins are `*` or bare origins and methods are valid
+    fn validate_cors_configuration(&self) -> Result<()> {
+        for origin in &self.cors.allowed_origins {
+            if origin == CORS_ANY_ORIGIN {
+                continue;
+            }
+            let is_origin = Url::parse(origin).is_ok_and(|url| {
+                matches!(url.scheme(), "http" | "https")
+                    && url.origin().ascii_serialization() == *origin
+            });
+            ensure!(
+                is_origin,
+                "cors.allowed_origins entry {origin} must be `*` or an origin such as \
+                 https://app.example.com (scheme, host and optional port, no trailing slash)"
+            );
+        }
+        for method in &self.cors.allowed_methods {
+            ensure!(
+                Method::from_bytes(method.as_bytes()).is_ok(),
+                "cors.allowed_methods entry {method} is not a valid HTTP method"
+            );
+        }
+        Ok(())
+    }
+
+    /// Validate that the Azure `OpenAI` provider has an endpoint and API version
+    fn validate_openai_provider(&self) -> Result<()> {
+        if let OpenAiProvider::Azure { api_version } = &self.spam_predictor.openai_provider {
+            ensure!(
+                self.spam_predictor.openai_base_url.is_some(),
+                "spam_predictor.openai_base_url must be set to the resource endpoint for the Azure OpenAI provider"
+            );
+            ensure!(
+                !api_version.trim().is_empty(),
+                "spam_predictor.openai_provider.api_version must not be empty"
+            );
+        }
         Ok(())
     }
 
@@ -780,8 +2003,24 @@ impl ServerConfig {
         Ok(())
     }
 
-    /// Validate API credentials are not placeholders
-    fn validate_api_credentials(&self) -> Result<()> {
+    /// Validate the `OpenAI` key prefix, failing in strict mode and warning otherwise
+    fn validate_openai_api_key_format(&self, api_key: &str) -> Result<()> {
+        if api_key.starts_with("sk-") {
+            return Ok(());
+        }
+        ensure!(
+            !self.enforces_strict_api_key_format(),
+            "Spam Predictor OpenAI API key doesn't match expected format (should start with \
+             'sk-'). Set strict_api_key_format = false to only warn."
+        );
+        if !api_key.starts_with("test-") {
+            warn!("OpenAI API key doesn't match expected format (should start with 'sk-')");
+        }
+        Ok(())
+    }
+
+    /// Validate metadata provider credentials are not placeholders
+    fn validate_provider_credentials(&self) -> Result<()> {
         // Validate Moralis configuration if enabled
         if self.external_apis.moralis.enabled {
             let api_key = self.external_apis.moralis.api_key.value();
@@ -816,6 +2055,23 @@ impl ServerConfig {
             }
         }
 
+        // Validate SimpleHash configuration if enabled
+        if self.external_apis.simplehash.enabled {
+            let api_key = self.external_apis.simplehash.api_key.value();
+            if api_key == "test-api-key" || api_key.starts_with("REPLACE_WITH_") {
+                return Err(anyhow!(
+                    "SimpleHash API is enabled but still has placeholder API key. Set SERVER_EXTERNAL_APIS_SIMPLEHASH_API_KEY or update config file."
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate API credentials are not placeholders
+    fn validate_api_credentials(&self) -> Result<()> {
+        self.validate_provider_credentials()?;
+
         // Validate Spam Predictor configuration (always required)
         {
             let api_key = self.spam_predictor.openai_api_key.value();
@@ -829,10 +2085,7 @@ impl ServerConfig {
                 ));
             }
 
-            // Basic validation for OpenAI API key format
-            if !api_key.starts_with("sk-") && !api_key.starts_with("test-") {
-                warn!("OpenAI API key doesn't match expected format (should start with 'sk-')");
-            }
+            self.validate_openai_api_key_format(api_key)?;
 
             // Validate temperature range
             if let Some(temperature) = self.spam_predictor.temperature
@@ -863,6 +2116,32 @@ impl ServerConfig {
                 return Err(anyhow!("Spam Predictor max cache size cannot be 0"));
             }
 
+            self.spam_predictor
+                .confidence_band_thresholds
+                .validate()
+                .map_err(|e| anyhow!("Spam Predictor confidence_band_thresholds: {e}"))?;
+
+            if !(0.0..=1.0).contains(&self.spam_predictor.confidence_threshold) {
+                return Err(anyhow!(
+                    "Spam Predictor confidence_threshold {} is invalid (must be 0.0-1.0)",
+                    self.spam_predictor.confidence_threshold
+                ));
+            }
+
+            if self.spam_predictor.cache_snapshot.path.is_some()
+                && self.spam_predictor.cache_snapshot.interval_seconds == 0
+            {
+                return Err(anyhow!(
+                    "Spam Predictor cache_snapshot.interval_seconds cannot be 0 when a snapshot path is set"
+                ));
+            }
+
+            if self.spam_predictor.model_input_fields.is_empty() {
+                return Err(anyhow!(
+                    "Spam Predictor model_input_fields must include at least one field"
+                ));
+            }
+
             // Validate that configuration files exist
             if !std::path::Path::new(&self.spam_predictor.model_registry_path).exists() {
                 return Err(anyhow!(
@@ -886,19 +2165,91 @@ impl ServerConfig {
         Ok(())
     }
 
+    /// Guard against binding below `min_server_port` (privileged ports by default)
+    ///
+    /// Binding a privileged port as a non-root user fails at bind time with an
+    /// opaque OS error, so production refuses such ports up front.
+    fn validate_port_privileges(&self) -> Result<()> {
+        let port = self.port.port();
+        if port >= self.min_server_port {
+            return Ok(());
+        }
+
+        let message = format!(
+            "Port {port} is below the minimum server port {} - binding privileged ports requires \
+             elevated permissions. Bind to a high port behind a reverse proxy instead, or lower \
+             min_server_port if this is intended",
+            self.min_server_port
+        );
+        if self.environment == Environment::Production {
+            return Err(anyhow!(message));
+        }
+        warn!("{message}");
+        Ok(())
+    }
+
+    /// Refuse model IDs matching the production denylist
+    ///
+    /// Catches test or placeholder fine-tunes left in the model registry before
+    /// they serve production traffic.
+    fn validate_production_models(&self) -> Result<()> {
+        let path = &self.spam_predictor.model_registry_path;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read model registry {path}: {e}"))?;
+        let registry = if self.spam_predictor.registry_tolerant_load {
+            ModelRegistry::from_yaml_str_tolerant(&content)
+        } else {
+            ModelRegistry::from_yaml_str(&content)
+        }
+        .map_err(|e| anyhow!("Failed to parse model registry {path}: {e}"))?;
+
+        let denylisted = registry.denylisted_models(&self.spam_predictor.production_model_denylist);
+        if denylisted.is_empty() {
+            return Ok(());
+        }
+
+        let models = denylisted
+            .iter()
+            .map(|(model_type, version, model_id)| format!("{model_type}:{version} = {model_id}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(anyhow!(
+            "Model registry {path} contains model IDs denylisted for production: {models}. \
+             Replace them or adjust spam_predictor.production_model_denylist"
+        ))
+    }
+
     /// Validate production deployment safety
     fn validate_production_safety(&self) -> Result<()> {
         // In production environment, ensure rate limiting is enabled
         if self.environment == Environment::Production {
+            self.validate_production_models()?;
+
+            if self.spam_predictor.mock_predictions {
+                return Err(anyhow!(
+                    "spam_predictor.mock_predictions must be disabled in production environment"
+                ));
+            }
+
             if !self.rate_limiting.enabled {
                 return Err(anyhow!(
                     "Rate limiting must be enabled in production environment for security"
                 ));
             }
 
-            if self.rate_limiting.requests_per_minute == 0 {
+            // Leaving the API open must be a deliberate choice
+            if self.auth.enabled.is_none() {
+                return Err(anyhow!(
+                    "auth.enabled must be set explicitly in production: true to require \
+                     API keys on /v1 routes, false to leave them open"
+                ));
+            }
+
+            // Browsers refuse credentialed responses to any origin
+            if self.cors.allow_credentials && self.cors.allows_any_origin() {
                 return Err(anyhow!(
-                    "Rate limiting requests per minute cannot be 0 in production"
+                    "cors.allow_credentials cannot be combined with a `*` entry in \
+                     cors.allowed_origins in production; list the allowed origins instead"
                 ));
             }
 
@@ -933,6 +2284,7 @@ impl ServerConfig {
     /// # Errors
     ///
     /// Returns `ConfigError` if configuration cannot be loaded or is invalid.
+    #[allow(clippy::too_many_lines)]
     pub fn load() -> Result<Self, ConfigError> {
         let env_var = std::env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_string());
 
@@ -940,6 +2292,7 @@ impl ServerConfig {
             // Start with default values
             .set_default("host", "127.0.0.1")?
             .set_default("port", DEFAULT_SERVER_PORT)?
+            .set_default("min_server_port", DEFAULT_MIN_SERVER_PORT)?
             .set_default("timeout_seconds", DEFAULT_TIMEOUT_SECONDS)?
             .set_default("environment", "development")?
             // External API defaults
@@ -948,16 +2301,29 @@ impl ServerConfig {
                 "https://deep-index.moralis.io/api/v2",
             )?
             .set_default("external_apis.moralis.api_key", "test-api-key")?
+            .set_default(
+                "external_apis.moralis.max_parsed_count",
+                DEFAULT_MAX_PARSED_COUNT,
+            )?
             .set_default(
                 "external_apis.moralis.timeout_seconds",
                 DEFAULT_TIMEOUT_SECONDS,
             )?
+            .set_default(
+                "external_apis.moralis.connect_timeout_seconds",
+                DEFAULT_CONNECT_TIMEOUT_SECONDS,
+            )?
             .set_default(
                 "external_apis.moralis.health_check_timeout_seconds",
                 DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS,
             )?
             .set_default("external_apis.moralis.max_retries", DEFAULT_MAX_RETRIES)?
             .set_default("external_apis.moralis.enabled", false)?
+            .set_default(
+                "external_apis.moralis.retry_after_seconds",
+                default_retry_after_seconds(),
+            )?
+            .set_default("external_apis.moralis.priority", 0u32)?
             // Pinax API defaults
             .set_default(
                 "external_apis.pinax.endpoint",
@@ -973,40 +2339,184 @@ impl ServerConfig {
                 "external_apis.pinax.timeout_seconds",
                 DEFAULT_TIMEOUT_SECONDS,
             )?
+            .set_default(
+                "external_apis.pinax.connect_timeout_seconds",
+                DEFAULT_CONNECT_TIMEOUT_SECONDS,
+            )?
             .set_default(
                 "external_apis.pinax.health_check_timeout_seconds",
                 DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS,
             )?
             .set_default("external_apis.pinax.max_retries", DEFAULT_MAX_RETRIES)?
             .set_default("external_apis.pinax.enabled", false)?
+            .set_default(
+                "external_apis.pinax.retry_after_seconds",
+                default_retry_after_seconds(),
+            )?
+            .set_default("external_apis.pinax.priority", 0u32)?
+            // SimpleHash API defaults
+            .set_default(
+                "external_apis.simplehash.base_url",
+                "https://api.simplehash.com",
+            )?
+            .set_default("external_apis.simplehash.api_key", "test-api-key")?
+            .set_default(
+                "external_apis.simplehash.timeout_seconds",
+                DEFAULT_TIMEOUT_SECONDS,
+            )?
+            .set_default(
+                "external_apis.simplehash.connect_timeout_seconds",
+                DEFAULT_CONNECT_TIMEOUT_SECONDS,
+            )?
+            .set_default(
+                "external_apis.simplehash.health_check_timeout_seconds",
+                DEFAULT_HEALTH_CHECK_TIMEOUT_SECONDS,
+            )?
+            .set_default("external_apis.simplehash.max_retries", DEFAULT_MAX_RETRIES)?
+            .set_default("external_apis.simplehash.enabled", false)?
+            .set_default(
+                "external_apis.simplehash.max_parsed_count",
+                DEFAULT_MAX_PARSED_COUNT,
+            )?
+            .set_default(
+                "external_apis.simplehash.retry_after_seconds",
+                default_retry_after_seconds(),
+            )?
+            .set_default("external_apis.simplehash.priority", 0u32)?
             // External API cache defaults
             .set_default("external_apis.cache.enabled", true)?
             .set_default("external_apis.cache.ttl_seconds", 21600i64)? // 6 hours
             .set_default("external_apis.cache.max_entries", 50000i64)?
             .set_default("external_apis.cache.cleanup_interval_seconds", 3600i64)? // 1 hour
-            // Spam predictor defaults
-            .set_default("spam_predictor.openai_api_key", "test-openai-key")?
-            .set_default("spam_predictor.openai_base_url", None::<String>)?
-            .set_default("spam_predictor.openai_organization_id", None::<String>)?
             .set_default(
-                "spam_predictor.model_registry_path",
-                "assets/configs/models.yaml",
+                "external_apis.cache.negative_ttl_seconds",
+                default_negative_ttl_seconds(),
             )?
+            // Creator enrichment defaults
+            .set_default("external_apis.creator_enrichment.enabled", false)?
             .set_default(
-                "spam_predictor.prompt_registry_path",
-                "assets/prompts/ft_prompt.json",
+                "external_apis.creator_enrichment.timeout_seconds",
+                DEFAULT_CREATOR_ENRICHMENT_TIMEOUT_SECONDS,
             )?
-            .set_default("spam_predictor.timeout_seconds", DEFAULT_TIMEOUT_SECONDS)?
-            .set_default("spam_predictor.max_tokens", 10u32)?
-            .set_default("spam_predictor.temperature", 0.0f64)?
+            .set_default(
+                "external_apis.creator_enrichment.fresh_address_max_transactions",
+                DEFAULT_FRESH_ADDRESS_MAX_TRANSACTIONS,
+            )?
+            // Outbound retry backoff defaults
+            .set_default(
+                "external_apis.retry.initial_delay_ms",
+                DEFAULT_RETRY_INITIAL_DELAY_MS,
+            )?
+            .set_default(
+                "external_apis.retry.max_delay_ms",
+                DEFAULT_RETRY_MAX_DELAY_MS,
+            )?
+            .set_default(
+                "external_apis.retry.jitter_factor",
+                DEFAULT_RETRY_JITTER_FACTOR,
+            )?
+            // Circuit breaker defaults
+            .set_default("external_apis.circuit_breaker.enabled", true)?
+            .set_default(
+                "external_apis.circuit_breaker.failure_threshold",
+                DEFAULT_FAILURE_THRESHOLD,
+            )?
+            .set_default(
+                "external_apis.circuit_breaker.failure_window_seconds",
+                DEFAULT_FAILURE_WINDOW.as_secs(),
+            )?
+            .set_default(
+                "external_apis.circuit_breaker.cooldown_seconds",
+                DEFAULT_COOLDOWN.as_secs(),
+            )?
+            // Outbound requests may reach any public host; development also
+            // permits private networks for locally running upstreams
+            .set_default("external_apis.upstream_allowlist.enabled", true)?
+            .set_default(
+                "external_apis.upstream_allowlist.allow_private_networks",
+                env_var.eq_ignore_ascii_case("development"),
+            )?
+            .set_default("external_apis.metadata_cross_check", false)?
+            .set_default("external_apis.metadata_merge_mode", "first_wins")?
+            // Spam predictor defaults
+            .set_default("spam_predictor.openai_api_key", "test-openai-key")?
+            .set_default("spam_predictor.openai_base_url", None::<String>)?
+            .set_default("spam_predictor.openai_organization_id", None::<String>)?
+            .set_default(
+                "spam_predictor.model_registry_path",
+                "assets/configs/models.yaml",
+            )?
+            .set_default(
+                "spam_predictor.prompt_registry_path",
+                "assets/prompts/ft_prompt.json",
+            )?
+            .set_default("spam_predictor.timeout_seconds", DEFAULT_TIMEOUT_SECONDS)?
+            .set_default(
+                "spam_predictor.connect_timeout_seconds",
+                DEFAULT_CONNECT_TIMEOUT_SECONDS,
+            )?
+            .set_default("spam_predictor.max_tokens", 10u32)?
+            .set_default("spam_predictor.temperature", 0.0f64)?
             .set_default("spam_predictor.cache_ttl_seconds", 3600i64)?
             .set_default("spam_predictor.max_cache_size", 10000i64)?
+            .set_default("spam_predictor.log_cache_evictions", true)?
+            .set_default("spam_predictor.prompt_content_hashing", true)?
+            .set_default(
+                "spam_predictor.fallback_model_versions",
+                Vec::<String>::new(),
+            )?
+            .set_default(
+                "spam_predictor.production_model_denylist",
+                DEFAULT_PRODUCTION_MODEL_DENYLIST.to_vec(),
+            )?
+            .set_default("spam_predictor.registry_tolerant_load", false)?
+            .set_default(
+                "spam_predictor.confidence_band_thresholds.medium",
+                spam_predictor::types::DEFAULT_CONFIDENCE_MEDIUM_THRESHOLD,
+            )?
+            .set_default(
+                "spam_predictor.confidence_band_thresholds.high",
+                spam_predictor::types::DEFAULT_CONFIDENCE_HIGH_THRESHOLD,
+            )?
+            .set_default("spam_predictor.confidence_threshold", 0.0f64)?
+            .set_default("spam_predictor.mock_predictions", false)?
+            .set_default("spam_predictor.cache_snapshot.source", None::<String>)?
+            .set_default("spam_predictor.cache_snapshot.path", None::<String>)?
+            .set_default("spam_predictor.cache_snapshot.interval_seconds", 300u64)?
+            .set_default("spam_predictor.training_capture_rate", 0.0f64)?
+            .set_default("spam_predictor.training_capture_path", None::<String>)?
+            .set_default("spam_predictor.redis_url", None::<String>)?
+            .set_default("spam_predictor.auto_reload.enabled", false)?
+            .set_default("spam_predictor.auto_reload.debounce_ms", 500u64)?
+            .set_default(
+                "spam_predictor.model_input_fields",
+                ModelInputField::DEFAULT
+                    .map(ModelInputField::as_str)
+                    .to_vec(),
+            )?
             // Rate limiting defaults
             .set_default("rate_limiting.enabled", true)?
             .set_default(
                 "rate_limiting.requests_per_minute",
                 DEFAULT_RATE_LIMIT_REQUESTS_PER_MINUTE,
             )?
+            .set_default(
+                "rate_limiting.min_production_requests_per_minute",
+                DEFAULT_MIN_PRODUCTION_REQUESTS_PER_MINUTE,
+            )?
+            .set_default(
+                "rate_limiting.max_requests_per_minute",
+                DEFAULT_MAX_REQUESTS_PER_MINUTE,
+            )?
+            .set_default(
+                "rate_limiting.max_addresses",
+                DEFAULT_MAX_ADDRESSES_PER_REQUEST,
+            )?
+            .set_default("rate_limiting.max_chains", DEFAULT_MAX_CHAINS_PER_REQUEST)?
+            .set_default(
+                "rate_limiting.max_subscriptions_per_connection",
+                DEFAULT_MAX_SUBSCRIPTIONS_PER_CONNECTION,
+            )?
             // Concurrency defaults
             .set_default("concurrency.max_concurrent_external_api_calls", 10u32)?
             .set_default("concurrency.max_concurrent_spam_analysis", 20u32)?
@@ -1014,9 +2524,27 @@ impl ServerConfig {
                 "concurrency.individual_address_timeout_seconds",
                 DEFAULT_TIMEOUT_SECONDS,
             )?
+            // Collection score defaults
+            .set_default("collection_score.default_sample_size", 10u32)?
+            .set_default("collection_score.max_sample_size", 50u32)?
             // Metrics defaults
             .set_default("metrics.endpoint_path", DEFAULT_METRICS_ENDPOINT_PATH)?
             .set_default("metrics.port", i64::from(DEFAULT_METRICS_PORT))?
+            .set_default("metrics.health_path", DEFAULT_METRICS_HEALTH_PATH)?
+            // Admin views are disabled unless a key is configured
+            .set_default("admin_api_key", None::<String>)?
+            .set_default("response_signing_secret", None::<String>)?
+            // Error detail exposure follows the environment unless set explicitly
+            .set_default("expose_error_details", None::<bool>)?
+            .set_default("strict_api_key_format", None::<bool>)?
+            .set_default("default_result_format", "v1")?
+            .set_default("status_to_http_code", false)?
+            // Runtime feature flag defaults
+            .set_default("feature_flags.degraded_mode", false)?
+            .set_default("feature_flags.predictor_enabled", true)?
+            .set_default("feature_flags.serve_stale_on_error", false)?
+            .set_default("feature_flags.explain_on_spam", true)?
+            .set_default("feature_flags.return_metadata_on_predictor_failure", false)?
             // Add optional configuration files
             .add_source(File::with_name("config.json").required(false))
             // Add environment-specific config file
@@ -1062,21 +2590,60 @@ impl ServerConfig {
         Self {
             host: IpAddr::V4(Ipv4Addr::LOCALHOST),
             port: ServerPort::testing(), // let OS choose available port
+            min_server_port: DEFAULT_MIN_SERVER_PORT,
             timeout_seconds: TimeoutSeconds::testing(),
             environment: Environment::Testing,
-            external_apis: ExternalApiConfig::default(),
+            external_apis: ExternalApiConfig {
+                // Mock upstreams listen on loopback
+                upstream_allowlist: UpstreamAllowlistConfig {
+                    allow_private_networks: true,
+                    ..UpstreamAllowlistConfig::default()
+                },
+                ..ExternalApiConfig::default()
+            },
             spam_predictor: spam_predictor_config,
             rate_limiting: RateLimitingConfig {
                 enabled: false,
                 requests_per_minute: 0,
+                ..RateLimitingConfig::default()
             },
             concurrency: ConcurrencyConfig::default(),
+            collection_score: CollectionScoreConfig::default(),
             metrics: MetricsConfig::default(),
-            chains: Self::default_chains(),
+            tls: None,
+            admin_api_key: None,
+            auth: AuthConfig::default(),
+            cors: CorsConfig::default(),
+            response_signing_secret: None,
+            expose_error_details: None,
+            strict_api_key_format: None,
+            default_result_format: ResultFormatVersion::default(),
+            status_to_http_code: false,
+            feature_flags: FeatureFlags::default(),
+            // Enable every chain so tests can exercise all of them
+            chains: Self::default_chains()
+                .into_iter()
+                .map(|(chain_id, mut chain_config)| {
+                    chain_config.enabled = true;
+                    (chain_id, chain_config)
+                })
+                .collect(),
             extensions: HashMap::new(),
         }
     }
 
+    /// Whether failed results should include raw upstream error detail
+    pub fn exposes_error_details(&self) -> bool {
+        self.expose_error_details
+            .unwrap_or(self.environment != Environment::Production)
+    }
+
+    /// Whether malformed provider API keys fail validation rather than warn
+    pub fn enforces_strict_api_key_format(&self) -> bool {
+        self.strict_api_key_format
+            .unwrap_or(self.environment == Environment::Production)
+    }
+
     /// Get socket address for binding
     pub fn socket_addr(&self) -> SocketAddr {
         SocketAddr::new(self.host, self.port.value())
@@ -1182,6 +2749,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expose_error_details_follows_environment() {
+        let mut config = ServerConfig::default();
+        assert!(config.exposes_error_details());
+
+        config.environment = Environment::Production;
+        assert!(!config.exposes_error_details());
+
+        config.expose_error_details = Some(true);
+        assert!(config.exposes_error_details());
+    }
+
+    #[test]
+    fn strict_api_key_format_rejects_malformed_openai_key() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("org-not-an-openai-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+
+        // Lenient outside production: the malformed key is only warned about
+        assert!(!config.enforces_strict_api_key_format());
+        assert!(config.validate().is_ok());
+
+        // Strict mode turns the warning into a hard error
+        config.strict_api_key_format = Some(true);
+        let error = config
+            .validate()
+            .expect_err("strict mode should reject a malformed OpenAI key");
+        assert!(error.to_string().contains("should start with 'sk-'"));
+
+        // A well-formed key passes in strict mode
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        assert!(config.validate().is_ok());
+
+        // Production is strict unless explicitly relaxed
+        config.strict_api_key_format = None;
+        config.environment = Environment::Production;
+        assert!(config.enforces_strict_api_key_format());
+        config.strict_api_key_format = Some(false);
+        assert!(!config.enforces_strict_api_key_format());
+    }
+
     #[test]
     fn validate_production_safety() {
         let mut config = ServerConfig {
@@ -1211,6 +2823,31 @@ mod tests {
         config.rate_limiting.enabled = true;
         config.rate_limiting.requests_per_minute = DEFAULT_RATE_LIMIT_REQUESTS_PER_MINUTE;
 
+        // Production should require an explicit authentication decision
+        let error = config
+            .validate()
+            .expect_err("unset auth should be refused in production");
+        assert!(
+            error
+                .to_string()
+                .contains("auth.enabled must be set explicitly")
+        );
+
+        // Enabled authentication needs keys to accept
+        config.auth.enabled = Some(true);
+        let error = config
+            .validate()
+            .expect_err("auth without keys should be refused");
+        assert!(error.to_string().contains("auth.api_keys"));
+        config.auth.api_keys = vec![ApiKey::new("client-key".to_string()).expect("valid key")];
+        assert!(config.validate().is_ok());
+
+        // Explicitly leaving the API open is accepted
+        config.auth = AuthConfig {
+            enabled: Some(false),
+            api_keys: Vec::new(),
+        };
+
         // Production allows binding to all interfaces (with warning) for container deployments
         config.host = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
         let validation_result = config.validate();
@@ -1221,6 +2858,647 @@ mod tests {
 
         // Also works with specific host binding
         assert!(config.validate().is_ok());
+
+        // Production never serves mock predictions
+        config.spam_predictor.mock_predictions = true;
+        let error = config
+            .validate()
+            .expect_err("mock predictions should be refused in production");
+        assert!(error.to_string().contains("mock_predictions"));
+        config.spam_predictor.mock_predictions = false;
+
+        // Credentials may only be shared with listed origins
+        config.cors.allow_credentials = true;
+        let error = config
+            .validate()
+            .expect_err("credentialed wildcard CORS should be refused in production");
+        assert!(error.to_string().contains("cors.allow_credentials"));
+        config.cors.allowed_origins = vec!["https://app.example.com".to_string()];
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_cors_configuration() {
+        let mut config = ServerConfig::for_testing();
+        assert!(config.validate_cors_configuration().is_ok());
+
+        // A credentialed wildcard is only refused in production
+        config.cors.allow_credentials = true;
+        assert!(config.validate_cors_configuration().is_ok());
+
+        for origin in [
+            "https://app.example.com/",
+            "https://app.example.com/path",
+            "app.example.com",
+            "ftp://app.example.com",
+        ] {
+            config.cors.allowed_origins = vec![origin.to_string()];
+            let error = config
+                .validate_cors_configuration()
+                .expect_err("malformed origin should be refused");
+            assert!(
+                error.to_string().contains("cors.allowed_origins"),
+                "{origin}"
+            );
+        }
+        config.cors.allowed_origins = vec!["http://localhost:8080".to_string()];
+        assert!(config.validate_cors_configuration().is_ok());
+
+        config.cors.allowed_methods = vec!["GET POST".to_string()];
+        let error = config
+            .validate_cors_configuration()
+            .expect_err("malformed method should be refused");
+        assert!(error.to_string().contains("cors.allowed_methods"));
+    }
+
+    #[test]
+    fn validate_rate_limiting_bounds_per_environment() {
+        let mut config = ServerConfig {
+            environment: Environment::Production,
+            auth: AuthConfig {
+                enabled: Some(false),
+                ..AuthConfig::default()
+            },
+            ..Default::default()
+        };
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+
+        // A fat-fingered limit below the production floor is refused
+        config.rate_limiting.requests_per_minute = 1;
+        let error = config
+            .validate()
+            .expect_err("a limit below the production floor should be refused");
+        assert!(
+            error
+                .to_string()
+                .contains("below the production minimum of 10")
+        );
+
+        // The floor itself is configurable
+        config.rate_limiting.min_production_requests_per_minute = 1;
+        assert!(config.validate().is_ok());
+
+        // So is the ceiling, in every environment
+        config.rate_limiting.requests_per_minute = 600;
+        config.rate_limiting.max_requests_per_minute = 500;
+        let error = config
+            .validate()
+            .expect_err("a limit above the ceiling should be refused");
+        assert!(error.to_string().contains("the maximum is 500"));
+
+        // Development accepts near-zero limits and 0 to disable limiting
+        config.environment = Environment::Development;
+        config.rate_limiting = RateLimitingConfig::default();
+        config.rate_limiting.requests_per_minute = 1;
+        assert!(config.validate().is_ok());
+        config.rate_limiting.requests_per_minute = 0;
+        assert!(config.validate().is_ok());
+        assert!(!config.rate_limiting.is_active());
+
+        // A request must be allowed at least one address
+        config.rate_limiting.max_addresses = 0;
+        let error = config
+            .validate()
+            .expect_err("a zero address limit should be refused");
+        assert!(error.to_string().contains("max_addresses"));
+
+        // And at least one chain
+        config.rate_limiting.max_addresses = 50;
+        config.rate_limiting.max_chains = 0;
+        let error = config
+            .validate()
+            .expect_err("a zero chain limit should be refused");
+        assert!(error.to_string().contains("max_chains"));
+
+        // An empty token bucket would refuse every request
+        config.rate_limiting.max_chains = 5;
+        config.rate_limiting.burst_capacity = Some(0);
+        let error = config
+            .validate()
+            .expect_err("a zero burst capacity should be refused");
+        assert!(error.to_string().contains("burst_capacity"));
+
+        // And a stream connection must be allowed at least one subscription
+        config.rate_limiting.burst_capacity = None;
+        config.rate_limiting.max_subscriptions_per_connection = 0;
+        let error = config
+            .validate()
+            .expect_err("a zero subscription limit should be refused");
+        assert!(
+            error
+                .to_string()
+                .contains("max_subscriptions_per_connection")
+        );
+    }
+
+    #[test]
+    fn per_address_timeout_overrides_seconds_and_is_bounded() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+
+        assert_eq!(
+            config.concurrency.per_address_timeout(),
+            config
+                .concurrency
+                .individual_address_timeout_seconds
+                .value()
+        );
+        config.concurrency.per_address_timeout_ms = Some(250);
+        assert_eq!(
+            config.concurrency.per_address_timeout(),
+            Duration::from_millis(250)
+        );
+        assert!(config.validate().is_ok());
+
+        config.concurrency.per_address_timeout_ms = Some(0);
+        let error = config
+            .validate()
+            .expect_err("a zero per-address timeout should be refused");
+        assert!(error.to_string().contains("per_address_timeout_ms"));
+
+        config.concurrency.per_address_timeout_ms =
+            Some(u64::try_from(config.timeout_seconds.value().as_millis()).expect("fits u64") + 1);
+        let error = config
+            .validate()
+            .expect_err("a per-address timeout above the request timeout should be refused");
+        assert!(
+            error
+                .to_string()
+                .contains("cannot exceed global timeout_seconds")
+        );
+    }
+
+    #[test]
+    fn validate_enabled_chains_have_a_viable_provider() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+
+        // Pinax is the only enabled provider and Base has no Pinax database configured
+        config.external_apis.pinax.enabled = true;
+        config.external_apis.pinax.api_user = ApiKey::new("pinax-user".to_string()).unwrap();
+        config.external_apis.pinax.api_auth = ApiKey::new("pinax-auth".to_string()).unwrap();
+        let base = config
+            .chains
+            .get_mut(&ChainId::Base)
+            .expect("base chain config");
+        base.enabled = true;
+        base.pinax = None;
+
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("no enabled provider"), "{error}");
+        assert!(error.contains("Base (8453)"), "{error}");
+        assert!(!error.contains("Ethereum"), "{error}");
+        assert_eq!(config.viable_providers(ChainId::Ethereum), vec!["pinax"]);
+        assert!(config.viable_providers(ChainId::Base).is_empty());
+
+        // Disabling the chain resolves the error
+        config
+            .chains
+            .get_mut(&ChainId::Base)
+            .expect("base chain config")
+            .enabled = false;
+        assert!(config.validate().is_ok());
+
+        // So does enabling Moralis, which serves every chain
+        config
+            .chains
+            .get_mut(&ChainId::Base)
+            .expect("base chain config")
+            .enabled = true;
+        config.external_apis.moralis.enabled = true;
+        config.external_apis.moralis.api_key = ApiKey::new("moralis-key".to_string()).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.viable_providers(ChainId::Base), vec!["moralis"]);
+
+        // Or SimpleHash, which serves every chain as well
+        config.external_apis.moralis.enabled = false;
+        config.external_apis.simplehash.enabled = true;
+        config.external_apis.simplehash.api_key =
+            ApiKey::new("simplehash-key".to_string()).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.viable_providers(ChainId::Base), vec!["simplehash"]);
+    }
+
+    #[test]
+    fn validate_privileged_port_guard() {
+        let mut config = ServerConfig {
+            environment: Environment::Production,
+            auth: AuthConfig {
+                enabled: Some(false),
+                ..AuthConfig::default()
+            },
+            ..Default::default()
+        };
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        config.rate_limiting.enabled = true;
+
+        // Privileged port is refused in production with a reverse proxy hint
+        config.port = ServerPort::new(443, Environment::Production).expect("valid port");
+        let error = config
+            .validate()
+            .expect_err("privileged port should be refused");
+        assert!(
+            error
+                .to_string()
+                .contains("below the minimum server port 1024")
+        );
+        assert!(error.to_string().contains("reverse proxy"));
+
+        // High ports are fine
+        config.port = ServerPort::new(8080, Environment::Production).expect("valid port");
+        assert!(config.validate().is_ok());
+
+        // Lowering the minimum explicitly allows privileged ports
+        config.port = ServerPort::new(443, Environment::Production).expect("valid port");
+        config.min_server_port = 1;
+        assert!(config.validate().is_ok());
+
+        // Outside production the guard only warns
+        config.environment = Environment::Development;
+        config.min_server_port = DEFAULT_MIN_SERVER_PORT;
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_confidence_band_thresholds() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.confidence_band_thresholds = ConfidenceBandThresholds {
+            medium: 0.9,
+            high: 0.8,
+        };
+        let error = config
+            .validate()
+            .expect_err("medium above high should be refused");
+        assert!(error.to_string().contains("confidence_band_thresholds"));
+    }
+
+    #[test]
+    fn validate_confidence_threshold() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert!(config.spam_predictor.confidence_threshold.abs() < f64::EPSILON);
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.confidence_threshold = 0.7;
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.confidence_threshold = 1.2;
+        let error = config
+            .validate()
+            .expect_err("a threshold above 1.0 should be refused");
+        assert!(error.to_string().contains("confidence_threshold"));
+    }
+
+    #[test]
+    fn validate_model_input_fields() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert_eq!(
+            config.spam_predictor.model_input_fields,
+            ModelInputField::default_fields()
+        );
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.model_input_fields = Vec::new();
+        let error = config
+            .validate()
+            .expect_err("an empty field allowlist should be refused");
+        assert!(error.to_string().contains("model_input_fields"));
+    }
+
+    #[test]
+    fn validate_training_capture() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert!(config.spam_predictor.training_capture_rate.abs() < f64::EPSILON);
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.training_capture_rate = 0.1;
+        let error = config
+            .validate()
+            .expect_err("sampling without a sink should be refused");
+        assert!(error.to_string().contains("training_capture_path"));
+
+        config.spam_predictor.training_capture_path = Some("capture.jsonl".to_string());
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.training_capture_rate = 1.5;
+        let error = config
+            .validate()
+            .expect_err("a rate above 1.0 should be refused");
+        assert!(error.to_string().contains("training_capture_rate"));
+    }
+
+    #[test]
+    fn validate_connect_timeouts() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert_eq!(
+            config.external_apis.pinax.connect_timeout_seconds.value(),
+            Duration::from_secs(1)
+        );
+        assert!(config.validate().is_ok());
+
+        config.external_apis.pinax.timeout_seconds = TimeoutSeconds::new(5).unwrap();
+        config.external_apis.pinax.connect_timeout_seconds = TimeoutSeconds::new(10).unwrap();
+        let error = config
+            .validate()
+            .expect_err("connect timeout above the request timeout should be refused");
+        assert!(
+            error
+                .to_string()
+                .contains("external_apis.pinax.connect_timeout_seconds")
+        );
+    }
+
+    #[test]
+    fn validate_metrics_health_path() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert_eq!(config.metrics.health_path, "/health");
+        assert!(config.validate().is_ok());
+
+        config.metrics.health_path = "/metrics".to_string();
+        let error = config
+            .validate()
+            .expect_err("health path shadowing the metrics path should be refused");
+        assert!(error.to_string().contains("must differ"));
+
+        config.metrics.health_path = "healthz".to_string();
+        let error = config
+            .validate()
+            .expect_err("relative health path should be refused");
+        assert!(error.to_string().contains("must start with '/'"));
+    }
+
+    #[test]
+    fn validate_metrics_histogram_buckets() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert_eq!(config.metrics.histogram_buckets.first(), Some(&0.01));
+        assert_eq!(config.metrics.histogram_buckets.last(), Some(&30.0));
+        assert!(config.validate().is_ok());
+
+        config.metrics.histogram_buckets = vec![0.1, 0.5, 0.5, 1.0];
+        let error = config
+            .validate()
+            .expect_err("repeated bucket boundaries should be refused");
+        assert!(error.to_string().contains("strictly increasing"));
+
+        config.metrics.histogram_buckets = vec![1.0, 0.5];
+        assert!(config.validate().is_err());
+
+        config.metrics.histogram_buckets = vec![0.1, f64::INFINITY];
+        let error = config
+            .validate()
+            .expect_err("infinite bucket boundaries should be refused");
+        assert!(error.to_string().contains("positive and finite"));
+
+        config.metrics.histogram_buckets = Vec::new();
+        let error = config
+            .validate()
+            .expect_err("empty buckets should be refused");
+        assert!(error.to_string().contains("must not be empty"));
+    }
+
+    #[test]
+    fn validate_redis_url_requires_feature() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        config.spam_predictor.redis_url = Some("redis://127.0.0.1:6379".to_string());
+
+        let result = config.validate();
+        if cfg!(feature = "redis") {
+            assert!(result.is_ok());
+        } else {
+            let error = result.expect_err("redis_url without the redis feature should be refused");
+            assert!(error.to_string().contains("`redis` feature"));
+        }
+    }
+
+    #[test]
+    fn validate_upstream_allowlist() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        config.spam_predictor.openai_base_url =
+            Some(Url::parse("http://10.0.0.5:8080/v1").expect("valid URL"));
+        let error = config
+            .validate()
+            .expect_err("a private upstream should be refused by default");
+        assert!(error.to_string().contains("10.0.0.5"));
+
+        // Private upstreams are allowed when private networks are
+        config
+            .external_apis
+            .upstream_allowlist
+            .allow_private_networks = true;
+        assert!(config.validate().is_ok());
+        config
+            .external_apis
+            .upstream_allowlist
+            .allow_private_networks = false;
+
+        // Or while the allowlist is disabled
+        config.external_apis.upstream_allowlist.enabled = false;
+        assert!(config.validate().is_ok());
+
+        config.external_apis.upstream_allowlist.enabled = true;
+
+        config.external_apis.upstream_allowlist.allowed_hosts =
+            vec!["10.0.0.5".to_string(), "*.moralis.io".to_string()];
+        assert!(config.validate().is_ok());
+
+        config.external_apis.upstream_allowlist.allowed_hosts = vec![String::new()];
+        let error = config
+            .validate()
+            .expect_err("an empty allowlist entry should be refused");
+        assert!(error.to_string().contains("allowed_hosts"));
+    }
+
+    #[test]
+    fn validate_openai_provider() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        config.spam_predictor.openai_provider = serde_json::from_value(
+            serde_json::json!({ "kind": "azure", "api_version": "2024-10-21" }),
+        )
+        .expect("azure provider should deserialize");
+
+        let error = config
+            .validate()
+            .expect_err("azure without an endpoint should be refused");
+        assert!(error.to_string().contains("openai_base_url"));
+
+        config.spam_predictor.openai_base_url =
+            Some(Url::parse("https://example.openai.azure.com").expect("valid URL"));
+        assert!(config.validate().is_ok());
+
+        config.spam_predictor.openai_provider = OpenAiProvider::Azure {
+            api_version: " ".to_string(),
+        };
+        let error = config
+            .validate()
+            .expect_err("an empty api_version should be refused");
+        assert!(error.to_string().contains("api_version"));
+    }
+
+    #[test]
+    fn validate_collection_score_configuration() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        assert!(config.validate().is_ok());
+
+        config.collection_score.default_sample_size = 0;
+        assert!(config.validate().is_err());
+
+        config.collection_score.default_sample_size = 20;
+        config.collection_score.max_sample_size = 10;
+        let error = config
+            .validate()
+            .expect_err("default sample size above the maximum should be refused");
+        assert!(error.to_string().contains("default_sample_size"));
+
+        config.collection_score.max_sample_size = 500;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn validate_tls_configuration() {
+        let mut config = ServerConfig::default();
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+
+        let cert = tempfile::NamedTempFile::new().expect("temp certificate");
+        config.tls = Some(TlsConfig {
+            cert_path: cert.path().to_path_buf(),
+            key_path: PathBuf::from("/nonexistent/key.pem"),
+        });
+        let error = config
+            .validate()
+            .expect_err("a missing TLS key should be refused");
+        assert!(error.to_string().contains("tls.key_path"));
+
+        let key = tempfile::NamedTempFile::new().expect("temp key");
+        config.tls = Some(TlsConfig {
+            cert_path: cert.path().to_path_buf(),
+            key_path: key.path().to_path_buf(),
+        });
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_production_model_denylist() {
+        let models = tempfile::NamedTempFile::new().expect("temp model registry");
+        std::fs::write(
+            models.path(),
+            "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o-2024-08-06:semiotic-labs:test\n",
+        )
+        .expect("write model registry");
+
+        let mut config = ServerConfig {
+            environment: Environment::Production,
+            auth: AuthConfig {
+                enabled: Some(false),
+                ..AuthConfig::default()
+            },
+            ..Default::default()
+        };
+        config.spam_predictor.openai_api_key =
+            ApiKey::new("sk-test-valid-key".to_string()).expect("test key should be valid");
+        config.spam_predictor.model_registry_path = models.path().display().to_string();
+        config.spam_predictor.prompt_registry_path =
+            "../../assets/prompts/ft_prompt.json".to_string();
+        config.rate_limiting.enabled = true;
+
+        // A test fine-tune is refused in production
+        let error = config
+            .validate()
+            .expect_err("denylisted model should be refused");
+        assert!(error.to_string().contains("denylisted for production"));
+        assert!(
+            error
+                .to_string()
+                .contains("spam_classification:latest = ft:gpt-4o-2024-08-06:semiotic-labs:test")
+        );
+
+        // The same registry is fine outside production
+        config.environment = Environment::Development;
+        assert!(config.validate().is_ok());
+
+        // The shipped registry passes the default denylist
+        config.environment = Environment::Production;
+        config.spam_predictor.model_registry_path = "../../assets/configs/models.yaml".to_string();
+        assert!(config.validate().is_ok());
+
+        // Denylist patterns are configurable
+        config.spam_predictor.production_model_denylist = vec!["semiotic-labs".to_string()];
+        assert!(config.validate().is_err());
     }
 
     // Note: Environment variable support is provided via the config crate
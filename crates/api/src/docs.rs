@@ -14,11 +14,17 @@ use shared_types::{ChainId, ChainImplementationStatus};
 use utoipa::OpenApi;
 
 use crate::{
+    error::ErrorResponse,
+    flags::{FeatureFlags, FeatureFlagsUpdate},
     routes::handlers::{
-        ChainInfo, ChainsResponse, ContractStatusRequest, ContractStatusResponse,
-        ContractStatusResult,
+        CacheClearRequest, CacheClearResponse, ChainInfo, ChainsResponse, CollectionScoreRequest,
+        CollectionScoreResponse, ConfidenceBandRange, ContractAddressEntry,
+        ContractMetadataSummary, ContractStatusRequest, ContractStatusResponse,
+        ContractStatusResponseV2, ContractStatusResult, ContractStatusSummary,
+        ErrorCategorySemantics, ModelTypeInfo, ModelsResponse, ProviderStatsResponse,
+        SchemaResponse, StatusSemantics, StatusStreamMessage, StatusStreamRequest, TokenVerdict,
     },
-    state::{HealthCheck, HealthStatus},
+    state::{HealthCheck, HealthStatus, LivenessCheck},
 };
 
 /// `OpenAPI` documentation specification
@@ -26,7 +32,7 @@ use crate::{
 #[openapi(
     info(
         title = "NFT API Service",
-        description = "A secure blockchain token management API service for NFT spam detection analysis with comprehensive multi-chain support.\n\n**Supported Blockchain Networks:**\n- Ethereum Mainnet (Chain ID: 1)\n- Polygon (Chain ID: 137)\n- Base (Chain ID: 8453)\n- Avalanche C-Chain (Chain ID: 43114)\n- Arbitrum One (Chain ID: 42161)\n\n**Features:**\n- AI-powered spam classification using fine-tuned GPT models\n- Multi-chain architecture with chain-specific data sources\n- External blockchain data integration (Moralis API, Pinax Analytics)\n- Chain-specific capability validation and error handling\n- Built-in rate limiting with chain-aware middleware\n- Comprehensive health monitoring with chain status reporting\n- Detailed chain validation with informative error messages\n\n**Chain-Specific Data Sources:**\n- Moralis API: NFT metadata and contract information for all chains\n- Pinax Analytics: Blockchain analytics with chain-specific databases\n- AI Spam Predictor: Chain-agnostic spam classification\n\n**Rate Limiting:**\nAPI endpoints are rate limited with chain-specific tracking.\n\n**API Versioning:**\nThis API uses URL-based versioning. Current version is v1. All contract analysis endpoints are prefixed with `/v1/`.\n\n**Error Handling:**\nAPI provides detailed error responses with chain-specific context and validation messages.",
+        description = "A secure blockchain token management API service for NFT spam detection analysis with comprehensive multi-chain support.\n\n**Supported Blockchain Networks:**\n- Ethereum Mainnet (Chain ID: 1)\n- Polygon (Chain ID: 137)\n- Base (Chain ID: 8453)\n- Avalanche C-Chain (Chain ID: 43114)\n- Arbitrum One (Chain ID: 42161)\n\n**Features:**\n- AI-powered spam classification using fine-tuned GPT models\n- Multi-chain architecture with chain-specific data sources\n- External blockchain data integration (Moralis API, Pinax Analytics)\n- Chain-specific capability validation and error handling\n- Built-in rate limiting with chain-aware middleware\n- Comprehensive health monitoring with chain status reporting\n- Detailed chain validation with informative error messages\n\n**Chain-Specific Data Sources:**\n- Moralis API: NFT metadata and contract information for all chains\n- Pinax Analytics: Blockchain analytics with chain-specific databases\n- AI Spam Predictor: Chain-agnostic spam classification\n\n**Rate Limiting:**\nAPI endpoints are rate limited with chain-specific tracking.\n\n**API Versioning:**\nThis API uses URL-based versioning. Current version is v1. All contract analysis endpoints are prefixed with `/v1/`. The contract status response shape is versioned through the `Accept` header: `application/vnd.nft-api.v1+json` (default) returns a flat address map, `application/vnd.nft-api.v2+json` returns `{results, summary}`.\n\n**Error Handling:**\nAPI provides detailed error responses with chain-specific context and validation messages.",
         version = "0.1.0",
         contact(
             name = "Semiotic Labs",
@@ -42,8 +48,21 @@ use crate::{
     ),
     paths(
         crate::routes::handlers::health_handler,
+        crate::routes::handlers::liveness_handler,
+        crate::routes::handlers::readiness_handler,
         crate::routes::handlers::chains_handler,
         crate::routes::handlers::contract_status_handler,
+        crate::routes::handlers::contract_status_by_path_handler,
+        crate::routes::handlers::contract_status_ws_handler,
+        crate::routes::handlers::collection_score_handler,
+        crate::routes::handlers::schema_handler,
+        crate::routes::handlers::models_handler,
+        crate::routes::handlers::provider_stats_handler,
+        crate::routes::handlers::cache_stats_handler,
+        crate::routes::handlers::cache_clear_handler,
+        crate::routes::handlers::get_feature_flags_handler,
+        crate::routes::handlers::update_feature_flags_handler,
+        crate::routes::handlers::reload_registries_handler,
     ),
     components(
         schemas(
@@ -51,14 +70,37 @@ use crate::{
             ChainInfo,
             ChainImplementationStatus,
             ChainsResponse,
+            CollectionScoreRequest,
+            CollectionScoreResponse,
+            ContractAddressEntry,
+            ContractMetadataSummary,
             ContractStatusRequest,
             ContractStatusResponse,
+            ContractStatusResponseV2,
             ContractStatusResult,
+            ContractStatusSummary,
+            StatusStreamRequest,
+            StatusStreamMessage,
+            FeatureFlags,
+            FeatureFlagsUpdate,
             HealthCheck,
             HealthStatus,
+            LivenessCheck,
+            TokenVerdict,
+            ProviderStatsResponse,
+            SchemaResponse,
+            StatusSemantics,
+            ConfidenceBandRange,
+            ErrorCategorySemantics,
+            ModelsResponse,
+            ModelTypeInfo,
+            CacheClearRequest,
+            CacheClearResponse,
+            ErrorResponse,
         )
     ),
     tags(
+        (name = "admin", description = "Operator endpoints guarded by the `x-admin-api-key` header - Provider statistics for capacity planning, runtime feature flags and registry reloads."),
         (name = "health", description = "System health monitoring - Check API service status, external API client health, chain-specific configurations, and internal service availability. Includes comprehensive chain health reporting with capability validation. Used for monitoring, alerting, and chain status verification."),
         (name = "chains", description = "Blockchain network information - Provides information about all supported blockchain networks and their capabilities."),
         (name = "contracts", description = "Multi-chain blockchain contract analysis - AI-powered spam detection for NFT contracts across Ethereum, Polygon, Base, Avalanche, and Arbitrum networks. Utilizes chain-specific external data sources (Moralis API, Pinax Analytics) with intelligent capability validation and comprehensive error handling. Supports batch analysis and provides detailed chain-specific response messages.")
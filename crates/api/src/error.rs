@@ -11,11 +11,16 @@ use std::net::SocketAddr;
 
 use axum::{
     Json,
-    http::StatusCode,
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{RETRY_AFTER, WWW_AUTHENTICATE},
+    },
     response::{IntoResponse, Response},
 };
+use serde::{Deserialize, Serialize};
 use shared_types::{ChainCapability, ChainId, ChainStatus};
 use thiserror::Error;
+use utoipa::ToSchema;
 
 /// Comprehensive error types for server operations
 #[derive(Error, Debug)]
@@ -79,6 +84,20 @@ pub enum ServerError {
         timeout_seconds: u64,
     },
 
+    /// The request exceeded the configured request timeout
+    #[error("Analysis exceeded the configured request timeout of {timeout_seconds} seconds")]
+    RequestTimeout {
+        /// Configured request timeout in seconds, also suggested as the retry delay
+        timeout_seconds: u64,
+    },
+
+    /// The client exceeded its request rate limit
+    #[error("Rate limit exceeded, retry after {retry_after_seconds} seconds")]
+    RateLimited {
+        /// Seconds until the client may send another request
+        retry_after_seconds: u64,
+    },
+
     /// Signal handling errors
     #[error("Signal handling error: {message}")]
     Signal {
@@ -90,6 +109,22 @@ pub enum ServerError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// The request did not carry an allowed client API key
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// Access to an admin-only resource was denied
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
+    /// A registry reload was requested while another one is running
+    #[error("A registry reload is already in progress")]
+    ReloadInProgress,
+
+    /// None of the media types in the `Accept` header can be produced
+    #[error("Not acceptable: {0}")]
+    NotAcceptable(String),
+
     /// JSON parsing errors with detailed context
     #[error("Invalid JSON request: {message}")]
     JsonError {
@@ -117,6 +152,15 @@ pub enum ChainValidationError {
         chain_name: String,
     },
 
+    /// Chain is supported but disabled in this deployment's configuration
+    #[error("Chain {chain_name} (ID: {chain_id}) is disabled in this deployment")]
+    DisabledChain {
+        /// Chain ID that is disabled
+        chain_id: u64,
+        /// Chain name for display
+        chain_name: String,
+    },
+
     /// Chain is planned but not yet implemented
     #[error("Chain {chain_name} (ID: {chain_id}) is not yet implemented")]
     PlannedChain {
@@ -192,6 +236,14 @@ impl ChainValidationError {
         }
     }
 
+    /// Create an error for a chain disabled in configuration
+    pub fn disabled_chain(chain_id: ChainId) -> Self {
+        Self::DisabledChain {
+            chain_id: chain_id.chain_id(),
+            chain_name: chain_id.name().to_string(),
+        }
+    }
+
     /// Create an error for a planned chain
     pub fn planned_chain(chain_id: ChainId) -> Self {
         Self::PlannedChain {
@@ -209,6 +261,7 @@ impl ChainValidationError {
             ChainCapability::PinaxAnalytics,
             ChainCapability::SpamPrediction,
             ChainCapability::RealTimeUpdates,
+            ChainCapability::TokenMetadata,
         ];
 
         let supported = chain_id.capabilities();
@@ -253,6 +306,7 @@ impl ChainValidationError {
     pub fn status_code(&self) -> StatusCode {
         match self {
             Self::UnsupportedChain { .. } => StatusCode::BAD_REQUEST,
+            Self::DisabledChain { .. } => StatusCode::NOT_FOUND,
             Self::PlannedChain { .. } | Self::UnsupportedCapability { .. } => {
                 StatusCode::NOT_IMPLEMENTED
             }
@@ -262,6 +316,19 @@ impl ChainValidationError {
         }
     }
 
+    /// Machine-readable code reported in the `error` field
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnsupportedChain { .. } => "chain_not_supported",
+            Self::DisabledChain { .. } => "chain_disabled",
+            Self::PlannedChain { .. } => "chain_not_implemented",
+            Self::PartialChain { .. } => "chain_partially_supported",
+            Self::DeprecatedChain { .. } => "chain_deprecated",
+            Self::UnsupportedCapability { .. } => "capability_not_supported",
+            Self::ConfigurationError { .. } => "chain_configuration_error",
+        }
+    }
+
     /// Convert error to detailed JSON response body
     #[allow(clippy::too_many_lines)]
     pub fn to_json_response(&self) -> serde_json::Value {
@@ -280,6 +347,20 @@ impl ChainValidationError {
                     }
                 })
             }
+            Self::DisabledChain {
+                chain_id,
+                chain_name,
+            } => {
+                serde_json::json!({
+                    "error": "chain_disabled",
+                    "message": format!("Chain {} (ID: {}) is disabled in this deployment", chain_name, chain_id),
+                    "details": {
+                        "chain_id": chain_id,
+                        "chain_name": chain_name,
+                        "status": "disabled"
+                    }
+                })
+            }
             Self::PlannedChain {
                 chain_id,
                 chain_name,
@@ -385,53 +466,136 @@ impl ChainValidationError {
     }
 }
 
+/// JSON body of every error response
+///
+/// Chain validation errors use the same shape, with their own codes such as
+/// `chain_not_supported`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "error": "validation_error",
+    "message": "Validation error: addresses must not be empty",
+    "status": 400,
+    "details": {}
+}))]
+pub struct ErrorResponse {
+    /// Machine-readable error code, stable across releases
+    pub error: String,
+    /// Human-readable description of the error
+    pub message: String,
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Structured context of the error, empty when there is none
+    #[schema(value_type = Object)]
+    pub details: serde_json::Value,
+}
+
+impl ServerError {
+    /// HTTP status code the error is reported with
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Config { .. }
+            | Self::Bind { .. }
+            | Self::Startup { .. }
+            | Self::Shutdown { .. }
+            | Self::Runtime { .. }
+            | Self::TaskJoin { .. }
+            | Self::Signal { .. } => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Dependency { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Timeout { .. } | Self::RequestTimeout { .. } => StatusCode::REQUEST_TIMEOUT,
+            Self::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::ValidationError(..) | Self::JsonError { .. } => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(..) => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(..) => StatusCode::FORBIDDEN,
+            Self::ReloadInProgress => StatusCode::CONFLICT,
+            Self::NotAcceptable(..) => StatusCode::NOT_ACCEPTABLE,
+            Self::ChainValidation(chain_err) => chain_err.status_code(),
+        }
+    }
+
+    /// Machine-readable code reported in the `error` field
+    ///
+    /// Process-level failures share `internal_error`, so no server internals
+    /// leak through the code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Config { .. } => "config_error",
+            Self::Bind { .. }
+            | Self::Startup { .. }
+            | Self::Shutdown { .. }
+            | Self::Runtime { .. }
+            | Self::TaskJoin { .. }
+            | Self::Signal { .. } => "internal_error",
+            Self::Dependency { .. } => "dependency_unavailable",
+            Self::Timeout { .. } => "timeout",
+            Self::RequestTimeout { .. } => "request_timeout",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::ValidationError(..) => "validation_error",
+            Self::JsonError { .. } => "invalid_json",
+            Self::Unauthorized(..) => "unauthorized",
+            Self::Forbidden(..) => "forbidden",
+            Self::ReloadInProgress => "reload_in_progress",
+            Self::NotAcceptable(..) => "not_acceptable",
+            Self::ChainValidation(chain_err) => chain_err.code(),
+        }
+    }
+
+    /// Structured context reported in the `details` field
+    fn details(&self) -> serde_json::Value {
+        match self {
+            Self::Timeout { timeout_seconds } => {
+                serde_json::json!({ "timeout_seconds": timeout_seconds })
+            }
+            Self::RequestTimeout {
+                timeout_seconds: retry_after_seconds,
+            }
+            | Self::RateLimited {
+                retry_after_seconds,
+            } => serde_json::json!({ "retry_after_seconds": retry_after_seconds }),
+            _ => serde_json::json!({}),
+        }
+    }
+}
+
 impl IntoResponse for ServerError {
     fn into_response(self) -> Response {
-        let (status, json_body) = match &self {
-            ServerError::Config { .. }
-            | ServerError::Bind { .. }
-            | ServerError::Startup { .. }
-            | ServerError::Shutdown { .. }
-            | ServerError::Runtime { .. }
-            | ServerError::TaskJoin { .. }
-            | ServerError::Signal { .. } => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                serde_json::json!({
-                    "error": self.to_string(),
-                    "status": StatusCode::INTERNAL_SERVER_ERROR.as_u16()
-                }),
-            ),
-            ServerError::Dependency { .. } => (
-                StatusCode::SERVICE_UNAVAILABLE,
-                serde_json::json!({
-                    "error": self.to_string(),
-                    "status": StatusCode::SERVICE_UNAVAILABLE.as_u16()
-                }),
-            ),
-            ServerError::Timeout { .. } => (
-                StatusCode::REQUEST_TIMEOUT,
-                serde_json::json!({
-                    "error": self.to_string(),
-                    "status": StatusCode::REQUEST_TIMEOUT.as_u16()
-                }),
-            ),
-            ServerError::ValidationError(..) | ServerError::JsonError { .. } => (
-                StatusCode::BAD_REQUEST,
-                serde_json::json!({
-                    "error": self.to_string(),
-                    "status": StatusCode::BAD_REQUEST.as_u16()
-                }),
-            ),
+        let status = self.status_code();
+        let json_body = match &self {
             ServerError::ChainValidation(chain_err) => {
-                let status = chain_err.status_code();
                 let mut json_response = chain_err.to_json_response();
                 json_response["status"] = serde_json::Value::Number(status.as_u16().into());
-                (status, json_response)
+                json_response
             }
+            _ => serde_json::json!(ErrorResponse {
+                error: self.code().to_string(),
+                message: self.to_string(),
+                status: status.as_u16(),
+                details: self.details(),
+            }),
         };
 
-        let body = Json(json_body);
-        (status, body).into_response()
+        let mut response = (status, Json(json_body)).into_response();
+        self.insert_response_headers(response.headers_mut());
+        response
+    }
+}
+
+impl ServerError {
+    /// Headers telling the client how to retry or authenticate
+    fn insert_response_headers(&self, headers: &mut HeaderMap) {
+        match self {
+            ServerError::Unauthorized(..) => {
+                headers.insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            }
+            ServerError::RequestTimeout {
+                timeout_seconds: retry_after_seconds,
+            }
+            | ServerError::RateLimited {
+                retry_after_seconds,
+            } => {
+                headers.insert(RETRY_AFTER, HeaderValue::from(*retry_after_seconds));
+            }
+            _ => {}
+        }
     }
 }
 
@@ -441,3 +605,136 @@ impl From<tokio::task::JoinError> for ServerError {
         Self::TaskJoin { source }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn join_error() -> tokio::task::JoinError {
+        let task = tokio::spawn(std::future::pending::<()>());
+        task.abort();
+        task.await.expect_err("aborted task")
+    }
+
+    async fn response_body(error: ServerError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("readable body");
+        (status, serde_json::from_slice(&body).expect("JSON body"))
+    }
+
+    #[tokio::test]
+    async fn every_variant_has_a_structured_json_body() {
+        let io_error = || std::io::Error::other("boom");
+        let cases = [
+            (
+                ServerError::Config {
+                    message: "bad".to_string(),
+                },
+                "config_error",
+            ),
+            (
+                ServerError::Bind {
+                    address: SocketAddr::from(([127, 0, 0, 1], 80)),
+                    source: io_error(),
+                },
+                "internal_error",
+            ),
+            (
+                ServerError::Startup { source: io_error() },
+                "internal_error",
+            ),
+            (
+                ServerError::Shutdown { source: io_error() },
+                "internal_error",
+            ),
+            (
+                ServerError::Runtime {
+                    message: "bad".to_string(),
+                },
+                "internal_error",
+            ),
+            (
+                ServerError::Dependency {
+                    message: "down".to_string(),
+                },
+                "dependency_unavailable",
+            ),
+            (
+                ServerError::TaskJoin {
+                    source: join_error().await,
+                },
+                "internal_error",
+            ),
+            (ServerError::Timeout { timeout_seconds: 5 }, "timeout"),
+            (
+                ServerError::RequestTimeout { timeout_seconds: 5 },
+                "request_timeout",
+            ),
+            (
+                ServerError::RateLimited {
+                    retry_after_seconds: 7,
+                },
+                "rate_limited",
+            ),
+            (
+                ServerError::Signal {
+                    message: "bad".to_string(),
+                },
+                "internal_error",
+            ),
+            (
+                ServerError::ValidationError("bad".to_string()),
+                "validation_error",
+            ),
+            (ServerError::Unauthorized("no".to_string()), "unauthorized"),
+            (ServerError::Forbidden("no".to_string()), "forbidden"),
+            (ServerError::ReloadInProgress, "reload_in_progress"),
+            (
+                ServerError::NotAcceptable("v9".to_string()),
+                "not_acceptable",
+            ),
+            (
+                ServerError::JsonError {
+                    message: "bad".to_string(),
+                },
+                "invalid_json",
+            ),
+            (
+                ServerError::ChainValidation(ChainValidationError::disabled_chain(ChainId::Base)),
+                "chain_disabled",
+            ),
+        ];
+
+        for (error, code) in cases {
+            let expected_status = error.status_code();
+            let message = error.to_string();
+            let (status, body) = response_body(error).await;
+            assert_eq!(status, expected_status, "{code}");
+            assert_eq!(body["error"], code);
+            // Chain validation messages leave out the `Chain validation error` prefix
+            assert!(
+                body["message"]
+                    .as_str()
+                    .is_some_and(|body_message| message.ends_with(body_message)),
+                "{code}: {body}"
+            );
+            assert_eq!(body["status"], status.as_u16(), "{code}");
+            assert!(body["details"].is_object(), "{code}: {body}");
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_hints_are_reported_in_details() {
+        let (_, body) = response_body(ServerError::RateLimited {
+            retry_after_seconds: 7,
+        })
+        .await;
+        assert_eq!(body["details"]["retry_after_seconds"], 7);
+
+        let (_, body) = response_body(ServerError::RequestTimeout { timeout_seconds: 5 }).await;
+        assert_eq!(body["details"]["retry_after_seconds"], 5);
+    }
+}
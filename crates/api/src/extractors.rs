@@ -7,13 +7,21 @@
 //! This module provides custom extractors that offer better error messages
 //! than the default Axum extractors, particularly for JSON parsing failures.
 
+use std::convert::Infallible;
+
 use axum::{
-    extract::{FromRequest, Request},
+    body::Bytes,
+    extract::{FromRequest, FromRequestParts, Request},
+    http::{
+        header::{ACCEPT, ACCEPT_LANGUAGE},
+        request::Parts,
+    },
     response::{IntoResponse, Response},
 };
 use serde::de::DeserializeOwned;
+use shared_types::Locale;
 
-use crate::error::ServerError;
+use crate::{config::ResultFormatVersion, error::ServerError};
 
 mod error_hints {
     pub const ADDRESS_FORMAT: &str = "addresses must be valid hexadecimal strings";
@@ -55,7 +63,41 @@ where
     type Rejection = ServerError;
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        Self::extract_json(req, state).await
+        let bytes = Self::read_body(req, state).await?;
+
+        // Check for empty body
+        if bytes.is_empty() {
+            return Err(ServerError::JsonError {
+                message: error_hints::EMPTY_BODY.to_string(),
+            });
+        }
+
+        Self::parse(&bytes)
+    }
+}
+
+/// JSON extractor for optional request bodies
+///
+/// An empty body extracts as `None`; anything else is parsed like
+/// [`JsonExtractor`], with the same error messages.
+#[derive(Debug)]
+pub struct OptionalJsonExtractor<T>(pub Option<T>);
+
+impl<T, S> FromRequest<S> for OptionalJsonExtractor<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ServerError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let bytes = JsonExtractor::<T>::read_body(req, state).await?;
+        if bytes.is_empty() {
+            return Ok(Self(None));
+        }
+
+        let JsonExtractor(value) = JsonExtractor::parse(&bytes)?;
+        Ok(Self(Some(value)))
     }
 }
 
@@ -63,7 +105,8 @@ impl<T> JsonExtractor<T>
 where
     T: DeserializeOwned,
 {
-    async fn extract_json<S>(req: Request, state: &S) -> Result<Self, ServerError>
+    /// Read the request body after checking its content type and size
+    async fn read_body<S>(req: Request, state: &S) -> Result<Bytes, ServerError>
     where
         S: Send + Sync,
     {
@@ -79,7 +122,7 @@ where
             });
         }
 
-        let bytes = match axum::body::Bytes::from_request(req, state).await {
+        let bytes = match Bytes::from_request(req, state).await {
             Ok(bytes) => bytes,
             Err(rejection) => {
                 return Err(ServerError::JsonError {
@@ -99,15 +142,12 @@ where
             });
         }
 
-        // Check for empty body
-        if bytes.is_empty() {
-            return Err(ServerError::JsonError {
-                message: error_hints::EMPTY_BODY.to_string(),
-            });
-        }
+        Ok(bytes)
+    }
 
-        // Attempt to parse as JSON with detailed error reporting
-        match serde_json::from_slice::<T>(&bytes) {
+    /// Parse a non-empty body as JSON with detailed error reporting
+    fn parse(bytes: &[u8]) -> Result<Self, ServerError> {
+        match serde_json::from_slice::<T>(bytes) {
             Ok(value) => Ok(JsonExtractor(value)),
             Err(err) => {
                 let error_message = if err.is_syntax() {
@@ -121,7 +161,7 @@ where
                 } else if err.is_data() {
                     format!(
                         "JSON data validation failed: {}",
-                        get_data_validation_hint_with_context(&err, &bytes)
+                        get_data_validation_hint_with_context(&err, bytes)
                     )
                 } else if err.is_eof() {
                     error_hints::TRUNCATED_JSON.to_string()
@@ -146,6 +186,101 @@ where
     }
 }
 
+/// Prefix and suffix of the versioned vendor media type
+const VENDOR_MEDIA_TYPE_PREFIX: &str = "application/vnd.nft-api.";
+const VENDOR_MEDIA_TYPE_SUFFIX: &str = "+json";
+
+/// Result format version requested through the `Accept` header
+///
+/// Holds `None` when the header is absent or names no versioned media type, in
+/// which case the configured default applies. Rejects with `406 Not Acceptable`
+/// when the header only names versions this server does not produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcceptResultFormat(pub Option<ResultFormatVersion>);
+
+impl<S> FromRequestParts<S> for AcceptResultFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = ServerError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(accept) = parts.headers.get(ACCEPT) else {
+            return Ok(Self(None));
+        };
+        let accept = accept.to_str().map_err(|_| {
+            ServerError::NotAcceptable("Accept header is not valid ASCII".to_string())
+        })?;
+        negotiate_result_format(accept).map(Self)
+    }
+}
+
+/// Locale for human-readable messages negotiated from the `Accept-Language` header
+///
+/// Falls back to English when the header is absent or names no supported language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcceptLanguage(pub Locale);
+
+impl<S> FromRequestParts<S> for AcceptLanguage
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let locale = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map_or_else(Locale::default, Locale::negotiate);
+        Ok(Self(locale))
+    }
+}
+
+/// Pick the first supported vendor version in an `Accept` header value
+///
+/// Unversioned media ranges such as `application/json` or `*/*` fall back to
+/// the default, so only a header made up entirely of unknown vendor versions
+/// is rejected.
+fn negotiate_result_format(accept: &str) -> Result<Option<ResultFormatVersion>, ServerError> {
+    let mut unsupported = Vec::new();
+    let mut accepts_default = false;
+
+    for media_range in accept.split(',') {
+        let media_type = media_range
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if media_type.is_empty() {
+            continue;
+        }
+
+        let version = media_type
+            .strip_prefix(VENDOR_MEDIA_TYPE_PREFIX)
+            .and_then(|rest| rest.strip_suffix(VENDOR_MEDIA_TYPE_SUFFIX));
+        match version {
+            Some(version) => match ResultFormatVersion::from_version_str(version) {
+                Some(format) => return Ok(Some(format)),
+                None => unsupported.push(media_type),
+            },
+            None => accepts_default = true,
+        }
+    }
+
+    if accepts_default || unsupported.is_empty() {
+        Ok(None)
+    } else {
+        Err(ServerError::NotAcceptable(format!(
+            "unsupported result format {}, expected {} or {}",
+            unsupported.join(", "),
+            ResultFormatVersion::V1.media_type(),
+            ResultFormatVersion::V2.media_type()
+        )))
+    }
+}
+
 /// Provides helpful hints for JSON syntax errors
 fn get_json_syntax_hint(err: &serde_json::Error) -> &'static str {
     let err_msg = err.to_string();
@@ -302,6 +437,33 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn optional_body_may_be_empty() {
+        let req = create_request("");
+        let OptionalJsonExtractor(data) =
+            OptionalJsonExtractor::<TestStruct>::from_request(req, &())
+                .await
+                .unwrap();
+        assert_eq!(data, None);
+
+        let req = create_request(r#"{"name": "Alice", "age": 30}"#);
+        let OptionalJsonExtractor(data) =
+            OptionalJsonExtractor::<TestStruct>::from_request(req, &())
+                .await
+                .unwrap();
+        assert_eq!(
+            data,
+            Some(TestStruct {
+                name: "Alice".to_string(),
+                age: 30
+            })
+        );
+
+        let req = create_request(r#"{"name": "Alice"}"#);
+        let result = OptionalJsonExtractor::<TestStruct>::from_request(req, &()).await;
+        assert!(matches!(result, Err(ServerError::JsonError { .. })));
+    }
+
     #[tokio::test]
     async fn syntax_error_handling() {
         let req = create_request(r#"{"name": "Alice", "age": 30"#); // Missing closing brace
@@ -556,4 +718,27 @@ mod tests {
             _ => panic!("expected JsonError"),
         }
     }
+
+    #[test]
+    fn negotiate_result_format_versions() {
+        assert_eq!(negotiate_result_format("*/*").unwrap(), None);
+        assert_eq!(negotiate_result_format("application/json").unwrap(), None);
+        assert_eq!(
+            negotiate_result_format("application/vnd.nft-api.v1+json").unwrap(),
+            Some(ResultFormatVersion::V1)
+        );
+        assert_eq!(
+            negotiate_result_format("Application/Vnd.Nft-Api.V2+JSON; q=0.9, */*;q=0.1").unwrap(),
+            Some(ResultFormatVersion::V2)
+        );
+        // An unknown version with a generic fallback still gets the default
+        assert_eq!(
+            negotiate_result_format("application/vnd.nft-api.v9+json, application/json").unwrap(),
+            None
+        );
+        assert!(matches!(
+            negotiate_result_format("application/vnd.nft-api.v9+json"),
+            Err(ServerError::NotAcceptable(_))
+        ));
+    }
 }
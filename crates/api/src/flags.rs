@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime feature flags
+//!
+//! Flags start from the `feature_flags` configuration section and can be
+//! changed at runtime through the `/admin/flags` endpoint, so operators can
+//! toggle behaviors without a redeploy. Runtime changes are not persisted and
+//! are lost on restart.
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Behaviors that can be toggled at runtime
+#[allow(clippy::struct_excessive_bools)] // Independent on/off switches by design
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct FeatureFlags {
+    /// Report the service as degraded in health checks
+    pub degraded_mode: bool,
+    /// Run AI spam classification; when disabled, contracts with metadata are
+    /// reported as inconclusive without calling the model
+    pub predictor_enabled: bool,
+    /// Serve expired cached metadata when every provider fails
+    pub serve_stale_on_error: bool,
+    /// Include the model reasoning in spam verdicts
+    pub explain_on_spam: bool,
+    /// Return the fetched metadata with a `predictor_unavailable` status when
+    /// the spam predictor fails, instead of a `predictor_error`
+    pub return_metadata_on_predictor_failure: bool,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            degraded_mode: false,
+            predictor_enabled: true,
+            serve_stale_on_error: false,
+            explain_on_spam: true,
+            return_metadata_on_predictor_failure: false,
+        }
+    }
+}
+
+/// Partial update of the feature flags, unset fields keep their current value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlagsUpdate {
+    /// New value for `degraded_mode`
+    pub degraded_mode: Option<bool>,
+    /// New value for `predictor_enabled`
+    pub predictor_enabled: Option<bool>,
+    /// New value for `serve_stale_on_error`
+    pub serve_stale_on_error: Option<bool>,
+    /// New value for `explain_on_spam`
+    pub explain_on_spam: Option<bool>,
+    /// New value for `return_metadata_on_predictor_failure`
+    pub return_metadata_on_predictor_failure: Option<bool>,
+}
+
+impl FeatureFlags {
+    /// Apply the fields set in `update`
+    #[must_use]
+    pub fn with_update(self, update: FeatureFlagsUpdate) -> Self {
+        Self {
+            degraded_mode: update.degraded_mode.unwrap_or(self.degraded_mode),
+            predictor_enabled: update.predictor_enabled.unwrap_or(self.predictor_enabled),
+            serve_stale_on_error: update
+                .serve_stale_on_error
+                .unwrap_or(self.serve_stale_on_error),
+            explain_on_spam: update.explain_on_spam.unwrap_or(self.explain_on_spam),
+            return_metadata_on_predictor_failure: update
+                .return_metadata_on_predictor_failure
+                .unwrap_or(self.return_metadata_on_predictor_failure),
+        }
+    }
+}
+
+/// Shared, runtime-mutable feature flags
+#[derive(Debug, Clone, Default)]
+pub struct FeatureFlagRegistry {
+    flags: Arc<RwLock<FeatureFlags>>,
+}
+
+impl FeatureFlagRegistry {
+    /// Create a registry starting from the configured flags
+    pub fn new(flags: FeatureFlags) -> Self {
+        Self {
+            flags: Arc::new(RwLock::new(flags)),
+        }
+    }
+
+    /// Snapshot of the current flags
+    pub fn current(&self) -> FeatureFlags {
+        *self.flags.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Apply `update` and return the resulting flags
+    pub fn update(&self, update: FeatureFlagsUpdate) -> FeatureFlags {
+        let mut flags = self.flags.write().unwrap_or_else(PoisonError::into_inner);
+        *flags = flags.with_update(update);
+        *flags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_only_changes_set_flags() {
+        let registry = FeatureFlagRegistry::new(FeatureFlags::default());
+        let updated = registry.update(FeatureFlagsUpdate {
+            predictor_enabled: Some(false),
+            ..FeatureFlagsUpdate::default()
+        });
+
+        assert_eq!(
+            updated,
+            FeatureFlags {
+                predictor_enabled: false,
+                ..FeatureFlags::default()
+            }
+        );
+        // Clones share the same flags
+        assert_eq!(registry.clone().current(), updated);
+    }
+}
@@ -13,10 +13,14 @@
 //! - [`config`]: Server configuration and environment management with hierarchical loading
 //! - [`error`]: Error types and HTTP response handling with proper status codes
 //! - [`state`]: Shared application state management with cancellation token support
+//! - [`flags`]: Runtime feature flags, seeded from configuration and toggled by admins
 //! - [`server`]: Main server implementation, lifecycle, and coordinated shutdown
 //! - [`routes`]: Route configuration and HTTP request handlers with cancellation awareness
 //! - [`middleware`]: Rate limiting, request tracing, and cross-cutting concerns
 //! - [`openapi`]: `OpenAPI` specification and Swagger UI endpoints for API documentation
+//! - [`reload`]: Automatic reload of the model and prompt registries on file changes
+//! - [`signing`]: HMAC signing of response and callback bodies
+//! - [`telemetry`]: Tracing subscriber setup with optional OTLP span export
 //!
 //! # Key Features
 //!
@@ -25,21 +29,25 @@
 //! - **Rate Limiting**: IP-based request limiting with configurable requests per minute
 //! - **Health Monitoring**: Aggregated health checks across all external API providers
 //! - **Production Safety**: Validates credentials, enforces security policies
-//! - **Comprehensive Middleware**: Request tracing, CORS, timeouts, and error handling
+//! - **Comprehensive Middleware**: Request tracing, configurable CORS, timeouts, and error handling
 
 pub mod config;
 pub mod docs;
 pub mod error;
 pub mod extractors;
+pub mod flags;
 pub mod metrics;
 pub mod middleware;
 pub mod openapi;
+pub mod reload;
 pub mod routes;
 pub mod server;
+pub mod signing;
 pub mod state;
+pub mod telemetry;
 
-pub use config::{Environment, ServerConfig};
-pub use error::{ServerError, ServerResult};
+pub use config::{CorsConfig, Environment, ServerConfig, TlsConfig};
+pub use error::{ErrorResponse, ServerError, ServerResult};
 pub use server::{Server, ShutdownConfig};
 pub use shared_types::{ChainId, ChainImplementationStatus};
 pub use state::{HealthCheck, ServerState};
@@ -7,18 +7,16 @@
 //! A blockchain token management API service.
 
 use anyhow::Result;
-use api::{Server, ServerConfig, ShutdownConfig};
+use api::{
+    Server, ServerConfig, ShutdownConfig,
+    telemetry::{self, TelemetryConfig},
+};
 use tracing::info;
-use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    // Held until the server exits so that pending spans are flushed
+    let _telemetry = telemetry::init(&TelemetryConfig::from_env())?;
 
     info!("Starting NFT API server with coordinated shutdown support");
 
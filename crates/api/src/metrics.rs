@@ -7,7 +7,7 @@
 //! Provides global metrics using the default Prometheus registry via macros and
 //! an Axum-compatible metrics handler.
 
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
 
 use axum::{
     http::{StatusCode, header},
@@ -18,6 +18,37 @@ use prometheus::{
     register_histogram_vec, register_int_counter_vec,
 };
 use shared_types::ChainId;
+use spam_predictor::cache::CacheStats;
+use tracing::warn;
+
+/// Default duration histogram buckets in seconds, spanning 10ms to 30s
+pub const DEFAULT_DURATION_BUCKETS: &[f64] =
+    &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Bucket boundaries used by the request duration histograms
+static DURATION_BUCKETS: OnceLock<Vec<f64>> = OnceLock::new();
+
+/// Set the bucket boundaries of the request duration histograms
+///
+/// The histograms are registered globally on first use, so only the first
+/// call takes effect; it must happen before any duration is observed.
+/// Later calls with different buckets are ignored with a warning.
+pub fn configure_duration_buckets(buckets: &[f64]) {
+    let configured = DURATION_BUCKETS.get_or_init(|| buckets.to_vec());
+    if configured != buckets {
+        warn!(
+            ?configured,
+            requested = ?buckets,
+            "Duration histogram buckets are already registered, ignoring new buckets"
+        );
+    }
+}
+
+fn duration_buckets() -> Vec<f64> {
+    DURATION_BUCKETS
+        .get_or_init(|| DEFAULT_DURATION_BUCKETS.to_vec())
+        .clone()
+}
 
 /// Total number of API requests received, labeled by `chain_id`.
 pub static REQUESTS_BY_CHAIN: LazyLock<IntCounterVec> = LazyLock::new(|| {
@@ -29,13 +60,33 @@ pub static REQUESTS_BY_CHAIN: LazyLock<IntCounterVec> = LazyLock::new(|| {
     .expect("Failed to create nft_api_requests_total counter vec")
 });
 
-/// Histogram for external API request durations in seconds.
+/// Total number of contract status requests abandoned before completion, labeled by `chain_id`.
+pub static CANCELLED_REQUESTS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nft_api_cancelled_requests_total",
+        "Total number of requests abandoned before completion (e.g. client disconnect), labeled by chain_id",
+        &["chain_id"]
+    )
+    .expect("Failed to create nft_api_cancelled_requests_total counter vec")
+});
+
+/// Total number of contract status requests that exceeded their latency budget, labeled by `chain_id`.
+pub static SLO_VIOLATIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nft_api_slo_violations_total",
+        "Total number of requests where analysis exceeded the max_latency_ms budget, labeled by chain_id",
+        &["chain_id"]
+    )
+    .expect("Failed to create nft_api_slo_violations_total counter vec")
+});
+
+/// Histogram for metadata lookup durations in seconds, labeled by `provider`, `chain` and `result`.
 pub static METADATA_API_REQUEST_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
     register_histogram_vec!(
         "nft_api_metadata_api_request_duration",
-        "Metadata API request durations in seconds",
-        &["api_name", "result"],
-        vec![0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]
+        "Metadata API request durations in seconds, labeled by the provider that answered, chain name and result",
+        &["provider", "chain", "result"],
+        duration_buckets()
     )
     .expect("Failed to create metadata API request duration histogram")
 });
@@ -46,7 +97,7 @@ pub static SPAM_PREDICTOR_REQUEST_DURATION: LazyLock<HistogramVec> = LazyLock::n
         "nft_api_spam_predictor_request_duration",
         "Spam predictor request durations in seconds",
         &["result"],
-        vec![0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]
+        duration_buckets()
     )
     .expect("Failed to create spam predictor request duration histogram")
 });
@@ -99,6 +150,15 @@ pub static CACHE_SIZE: LazyLock<Gauge> = LazyLock::new(|| {
     .expect("Failed to create cache size gauge")
 });
 
+/// Spam prediction cache utilization gauge
+pub static SPAM_CACHE_UTILIZATION: LazyLock<Gauge> = LazyLock::new(|| {
+    register_gauge!(
+        "nft_api_spam_cache_utilization_ratio",
+        "Current spam prediction cache utilization as a ratio (0.0 to 1.0)"
+    )
+    .expect("Failed to create spam cache utilization gauge")
+});
+
 /// Increment the requests counter with `chain_id` label
 ///
 /// # Arguments
@@ -109,15 +169,41 @@ pub fn inc_requests_by_chain(chain_id: ChainId) {
         .inc();
 }
 
+/// Increment the cancelled requests counter with `chain_id` label
+///
+/// # Arguments
+/// * `chain_id` - The chain ID of the cancelled request
+pub fn inc_cancelled_requests(chain_id: ChainId) {
+    CANCELLED_REQUESTS
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
+/// Increment the SLO violations counter with `chain_id` label
+///
+/// # Arguments
+/// * `chain_id` - The chain ID of the request that exceeded its latency budget
+pub fn inc_slo_violations(chain_id: ChainId) {
+    SLO_VIOLATIONS
+        .with_label_values(&[&chain_id.to_string()])
+        .inc();
+}
+
 /// Observe the duration of a metadata API request
 ///
 /// # Arguments
-/// * `api_name` - The name of the metadata API
+/// * `provider` - The provider that answered (`moralis`, `pinax`), or `cache`/`none`
+/// * `chain_id` - The chain the metadata was requested for
 /// * `result` - The result of the metadata API request
 /// * `duration_secs` - The duration of the request in seconds
-pub fn observe_metadata_api_duration(api_name: &str, result: &str, duration_secs: f64) {
+pub fn observe_metadata_api_duration(
+    provider: &str,
+    chain_id: ChainId,
+    result: &str,
+    duration_secs: f64,
+) {
     METADATA_API_REQUEST_DURATION
-        .with_label_values(&[api_name, result])
+        .with_label_values(&[provider, chain_id.name(), result])
         .observe(duration_secs);
 }
 
@@ -172,6 +258,17 @@ pub fn update_cache_metrics(utilization_ratio: f64, hit_rate: f64, entry_count:
     CACHE_SIZE.set(entry_count as f64);
 }
 
+/// Update spam prediction cache metrics
+///
+/// Hits, misses and evictions are counted as they happen by the cache itself;
+/// only the utilization is sampled here.
+///
+/// # Arguments
+/// * `stats` - Current spam prediction cache statistics
+pub fn update_spam_cache_metrics(stats: &CacheStats) {
+    SPAM_CACHE_UTILIZATION.set(stats.utilization_rate);
+}
+
 /// Axum handler that exports metrics in Prometheus text format
 ///
 /// # Panics
@@ -194,3 +291,11 @@ pub async fn metrics_handler() -> Response<String> {
         .body(String::from_utf8(buffer).expect("metrics buffer should be valid UTF-8"))
         .expect("Failed to create metrics response")
 }
+
+/// Axum handler answering liveness probes on the metrics port
+///
+/// The metrics server runs as its own task, so a response here shows the
+/// exporter is alive independently of the main API port.
+pub async fn metrics_health_handler() -> StatusCode {
+    StatusCode::OK
+}
@@ -16,33 +16,42 @@ use std::{
 
 use axum::{
     extract::{ConnectInfo, State},
-    http::{HeaderValue, Request, StatusCode},
+    http::{HeaderMap, HeaderValue, Request, header::AUTHORIZATION},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 use dashmap::DashMap;
 use shared_types::{ChainCapability, ChainId, ChainStatus};
+use subtle::{Choice, ConstantTimeEq};
 use tokio::time::timeout;
 use tracing::{Instrument, Level, debug, info, span, warn};
 
-use crate::{config::RateLimitingConfig, error::ChainValidationError};
+use crate::{
+    config::{AuthConfig, RateLimitingConfig},
+    error::{ChainValidationError, ServerError},
+};
 
 // Rate limiting constants
-const RATE_LIMIT_WINDOW_SECONDS: u64 = 60;
 const MAX_RATE_LIMIT_ENTRIES: usize = 10_000;
 
 /// Rate limiting middleware state
+///
+/// Each IP address gets a token bucket holding up to
+/// [`RateLimitingConfig::bucket_capacity`] tokens, refilled continuously at
+/// `requests_per_minute` tokens per minute. A request takes one token, so
+/// idle clients may burst up to the capacity and busy ones are smoothed to
+/// the refill rate.
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
     config: RateLimitingConfig,
     // Lock-free concurrent rate limiting using DashMap
-    requests: Arc<DashMap<IpAddr, RequestCounter>>,
+    requests: Arc<DashMap<IpAddr, TokenBucket>>,
 }
 
 #[derive(Debug, Clone)]
-struct RequestCounter {
-    count: u32,
-    window_start: Instant,
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
 }
 
 impl RateLimiter {
@@ -56,59 +65,74 @@ impl RateLimiter {
 
     /// Check if rate limiting is enabled
     pub fn is_enabled(&self) -> bool {
-        self.config.enabled
+        self.config.is_active()
     }
 
     /// Check if a request from the given IP should be rate limited
     pub fn is_rate_limited(&self, ip: IpAddr) -> bool {
-        if !self.config.enabled {
-            return false;
+        self.check(ip).is_err()
+    }
+
+    /// Take a token for a request from `ip`, or return how long until the
+    /// next token is available
+    pub fn check(&self, ip: IpAddr) -> Result<(), Duration> {
+        if !self.config.is_active() {
+            return Ok(());
         }
 
         let now = Instant::now();
-        let window_duration = Duration::from_secs(RATE_LIMIT_WINDOW_SECONDS);
+        let capacity = f64::from(self.config.bucket_capacity());
+        let tokens_per_second = f64::from(self.config.requests_per_minute) / 60.0;
 
         // Periodically clean up expired entries to prevent memory leaks
         if self.requests.len() > MAX_RATE_LIMIT_ENTRIES {
-            self.cleanup_expired_entries(now, window_duration);
+            self.cleanup_expired_entries(
+                now,
+                Duration::from_secs_f64(capacity / tokens_per_second),
+            );
         }
 
-        // Lock-free atomic operation to check/update rate limit
-        let is_limited = self
-            .requests
-            .entry(ip)
-            .and_modify(|counter| {
-                if now.duration_since(counter.window_start) > window_duration {
-                    // Reset window
-                    counter.count = 1;
-                    counter.window_start = now;
-                } else {
-                    // Increment in current window
-                    counter.count += 1;
-                }
-            })
-            .or_insert_with(|| RequestCounter {
-                count: 1,
-                window_start: now,
-            });
-
-        let current_count = is_limited.count;
-
-        if current_count > self.config.requests_per_minute {
-            debug!("rate limiting IP: {} ({} requests)", ip, current_count);
-            true
+        // Lock-free atomic operation to refill and take a token
+        let mut bucket = self.requests.entry(ip).or_insert_with(|| TokenBucket {
+            tokens: capacity,
+            last_refill: now,
+        });
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * tokens_per_second).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
         } else {
-            false
+            let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / tokens_per_second);
+            debug!("rate limiting IP: {} (next token in {:?})", ip, wait);
+            Err(wait)
         }
     }
 
-    /// Clean up expired entries using efficient retain operation
-    fn cleanup_expired_entries(&self, now: Instant, window_duration: Duration) {
+    /// Take a token for a request from `ip`, or return the
+    /// `ServerError::RateLimited` to answer it with
+    ///
+    /// The retry delay rounds the time until the next token up to whole
+    /// seconds.
+    pub fn check_client(&self, ip: IpAddr) -> Result<(), ServerError> {
+        self.check(ip).map_err(|wait| {
+            warn!("Rate limit exceeded for IP: {}", ip);
+            ServerError::RateLimited {
+                retry_after_seconds: (wait.as_secs() + u64::from(wait.subsec_nanos() > 0)).max(1),
+            }
+        })
+    }
+
+    /// Clean up buckets that have refilled completely, which behave the same
+    /// as absent ones, using efficient retain operation
+    fn cleanup_expired_entries(&self, now: Instant, refill_duration: Duration) {
         let entries_before = self.requests.len();
 
         // Use DashMap's retain for efficient concurrent cleanup
         self.requests
-            .retain(|_, counter| now.duration_since(counter.window_start) <= window_duration);
+            .retain(|_, bucket| now.duration_since(bucket.last_refill) < refill_duration);
 
         let entries_after = self.requests.len();
         let cleaned_up = entries_before.saturating_sub(entries_after);
@@ -128,10 +152,10 @@ impl RateLimiter {
             let mut oldest_entries: Vec<_> = self
                 .requests
                 .iter()
-                .map(|entry| (*entry.key(), entry.value().window_start))
+                .map(|entry| (*entry.key(), entry.value().last_refill))
                 .collect();
 
-            oldest_entries.sort_by_key(|(_, window_start)| *window_start);
+            oldest_entries.sort_by_key(|(_, last_refill)| *last_refill);
 
             let entries_to_remove = entries_after - MAX_RATE_LIMIT_ENTRIES / 2;
             for (ip, _) in oldest_entries.into_iter().take(entries_to_remove) {
@@ -142,20 +166,89 @@ impl RateLimiter {
 }
 
 /// Rate limiting middleware function
+///
+/// Limited requests get a structured `429 Too Many Requests` whose
+/// `Retry-After` header and `retry_after_seconds` field round the time until
+/// the client's next token up to whole seconds.
 pub async fn rate_limiting_middleware(
     ConnectInfo(addr): ConnectInfo<std::net::SocketAddr>,
     State(rate_limiter): State<RateLimiter>,
     req: Request<axum::body::Body>,
     next: Next,
-) -> Result<Response, StatusCode> {
-    let client_ip = addr.ip();
+) -> Response {
+    if let Err(e) = rate_limiter.check_client(addr.ip()) {
+        return e.into_response();
+    }
+
+    next.run(req).await
+}
+
+/// Header carrying a client API key as an alternative to `Authorization: Bearer`
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Client API keys accepted by [`api_key_auth_middleware`]
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    keys: Arc<Vec<String>>,
+}
 
-    if rate_limiter.is_rate_limited(client_ip) {
-        warn!("Rate limit exceeded for IP: {}", client_ip);
-        return Err(StatusCode::TOO_MANY_REQUESTS);
+impl ApiKeyAuth {
+    /// Keys to check requests against, or `None` when authentication is disabled
+    pub fn from_config(config: &AuthConfig) -> Option<Self> {
+        config.is_enabled().then(|| Self {
+            keys: Arc::new(
+                config
+                    .api_keys
+                    .iter()
+                    .map(|key| key.value().to_string())
+                    .collect(),
+            ),
+        })
+    }
+
+    /// Whether the request carries an allowed key in either supported header
+    ///
+    /// Every configured key is compared in constant time, so response timing
+    /// reveals neither which key matched nor how much of one did.
+    fn is_authorized(&self, headers: &HeaderMap) -> bool {
+        let bearer = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        let api_key = headers
+            .get(API_KEY_HEADER)
+            .and_then(|value| value.to_str().ok());
+        [bearer, api_key]
+            .into_iter()
+            .flatten()
+            .fold(Choice::from(0), |authorized, key| {
+                self.keys.iter().fold(authorized, |authorized, allowed| {
+                    authorized | key.trim().as_bytes().ct_eq(allowed.as_bytes())
+                })
+            })
+            .into()
+    }
+}
+
+/// Reject requests without an allowed client API key with a structured `401`
+pub async fn api_key_auth_middleware(
+    State(auth): State<ApiKeyAuth>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    if !auth.is_authorized(req.headers()) {
+        debug!(
+            path = req.uri().path(),
+            "rejecting request without a valid API key"
+        );
+        return ServerError::Unauthorized(
+            "a valid API key is required in the Authorization: Bearer or X-API-Key header"
+                .to_string(),
+        )
+        .into_response();
     }
 
-    Ok(next.run(req).await)
+    next.run(req).await
 }
 
 /// Chain validation result with context information
@@ -280,10 +373,7 @@ fn add_chain_warning_headers(response: &mut Response, validation_result: &ChainV
 }
 
 /// Add X-Chain-Status header
-fn add_chain_status_header(
-    headers: &mut axum::http::HeaderMap,
-    validation_result: &ChainValidationResult,
-) {
+fn add_chain_status_header(headers: &mut HeaderMap, validation_result: &ChainValidationResult) {
     if let Ok(status_value) = HeaderValue::from_str(&validation_result.status.to_string()) {
         headers.insert("X-Chain-Status", status_value);
     } else {
@@ -295,10 +385,7 @@ fn add_chain_status_header(
 }
 
 /// Add X-Chain-Capabilities header
-fn add_capabilities_header(
-    headers: &mut axum::http::HeaderMap,
-    validation_result: &ChainValidationResult,
-) {
+fn add_capabilities_header(headers: &mut HeaderMap, validation_result: &ChainValidationResult) {
     let capabilities_str = validation_result
         .capabilities
         .iter()
@@ -317,10 +404,7 @@ fn add_capabilities_header(
 }
 
 /// Add X-Chain-Limitations header if limitations exist
-fn add_limitations_header(
-    headers: &mut axum::http::HeaderMap,
-    validation_result: &ChainValidationResult,
-) {
+fn add_limitations_header(headers: &mut HeaderMap, validation_result: &ChainValidationResult) {
     if validation_result.limitations.is_empty() {
         return;
     }
@@ -337,10 +421,7 @@ fn add_limitations_header(
 }
 
 /// Add RFC 7234 compatible Warning header
-fn add_warning_header(
-    headers: &mut axum::http::HeaderMap,
-    validation_result: &ChainValidationResult,
-) {
+fn add_warning_header(headers: &mut HeaderMap, validation_result: &ChainValidationResult) {
     let warning_msg = format!(
         "199 - \"Chain {} has limited functionality: {}\"",
         validation_result.chain_id.name(),
@@ -400,7 +481,11 @@ fn add_warning_header(
 /// - `X-Chain-Capabilities`: Comma-separated list of supported capabilities
 /// - `X-Chain-Limitations`: Semicolon-separated list of current limitations
 /// - `Warning`: RFC 7234 compatible warning for limited functionality
-pub async fn chain_validation_middleware(req: Request<axum::body::Body>, next: Next) -> Response {
+pub async fn chain_validation_middleware(
+    State(request_timeout): State<Duration>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
     let chain_id = match parse_chain_id_from_path(req.uri().path()) {
         ChainIdParseResult::Found(chain_id) => chain_id,
         ChainIdParseResult::NotFound => {
@@ -409,7 +494,10 @@ pub async fn chain_validation_middleware(req: Request<axum::body::Body>, next: N
         }
         ChainIdParseResult::Invalid(chain_id_str) => {
             warn!("invalid chain ID in request path: {}", chain_id_str);
-            return StatusCode::BAD_REQUEST.into_response();
+            return ServerError::ValidationError(format!(
+                "invalid chain ID in request path: {chain_id_str}"
+            ))
+            .into_response();
         }
     };
 
@@ -430,19 +518,48 @@ pub async fn chain_validation_middleware(req: Request<axum::body::Body>, next: N
         capabilities = ?validation_result.capabilities.iter().map(ToString::to_string).collect::<Vec<_>>()
     );
 
-    let Ok(mut response) = timeout(Duration::from_secs(30), next.run(req).instrument(span)).await
-    else {
+    let Ok(mut response) = timeout(request_timeout, next.run(req).instrument(span)).await else {
         warn!(
             "request timed out for chain: {}",
             validation_result.chain_id.name()
         );
-        return StatusCode::REQUEST_TIMEOUT.into_response();
+        return request_timeout_response(request_timeout);
     };
 
     add_chain_warning_headers(&mut response, &validation_result);
     response
 }
 
+/// Abort requests that run longer than the configured request timeout
+///
+/// Unlike a bare `408`, the response carries the structured error body and a
+/// `Retry-After` header suggesting when the client may retry.
+pub async fn request_timeout_middleware(
+    State(request_timeout): State<Duration>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let path = req.uri().path().to_owned();
+    if let Ok(response) = timeout(request_timeout, next.run(req)).await {
+        response
+    } else {
+        warn!(
+            path,
+            timeout_seconds = request_timeout.as_secs(),
+            "request exceeded the configured timeout"
+        );
+        request_timeout_response(request_timeout)
+    }
+}
+
+/// Structured `408` response for a request that exceeded `request_timeout`
+fn request_timeout_response(request_timeout: Duration) -> Response {
+    ServerError::RequestTimeout {
+        timeout_seconds: request_timeout.as_secs(),
+    }
+    .into_response()
+}
+
 /// Extract `chain_id` parameter from URL path
 ///
 /// Supports various path patterns:
@@ -495,6 +612,8 @@ pub fn validate_chain_capability(
 
 #[cfg(test)]
 mod tests {
+    use axum::http::StatusCode;
+
     use super::*;
 
     #[test]
@@ -502,6 +621,7 @@ mod tests {
         let config = RateLimitingConfig {
             enabled: true,
             requests_per_minute: 10,
+            ..RateLimitingConfig::default()
         };
         let limiter = RateLimiter::new(config);
         assert!(limiter.config.enabled);
@@ -513,6 +633,7 @@ mod tests {
         let config = RateLimitingConfig {
             enabled: false,
             requests_per_minute: 1,
+            ..RateLimitingConfig::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -523,11 +644,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rate_limiter_zero_requests_per_minute_disables_limiting() {
+        let config = RateLimitingConfig {
+            enabled: true,
+            requests_per_minute: 0,
+            ..RateLimitingConfig::default()
+        };
+        let limiter = RateLimiter::new(config);
+        assert!(!limiter.is_enabled());
+
+        let ip = "127.0.0.1".parse().unwrap();
+        assert!(!limiter.is_rate_limited(ip));
+    }
+
     #[test]
     fn rate_limiter_within_limits() {
         let config = RateLimitingConfig {
             enabled: true,
             requests_per_minute: 5,
+            ..RateLimitingConfig::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -544,6 +680,7 @@ mod tests {
         let config = RateLimitingConfig {
             enabled: true,
             requests_per_minute: 3,
+            ..RateLimitingConfig::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -566,6 +703,7 @@ mod tests {
         let config = RateLimitingConfig {
             enabled: true,
             requests_per_minute: 2,
+            ..RateLimitingConfig::default()
         };
         let limiter = RateLimiter::new(config);
 
@@ -583,6 +721,46 @@ mod tests {
         assert!(limiter.is_rate_limited(ip2));
     }
 
+    #[test]
+    fn rate_limiter_allows_burst_up_to_capacity() {
+        let config = RateLimitingConfig {
+            enabled: true,
+            requests_per_minute: 60,
+            burst_capacity: Some(5),
+            ..RateLimitingConfig::default()
+        };
+        let limiter = RateLimiter::new(config);
+
+        let ip = "127.0.0.1".parse().unwrap();
+        for _ in 0..5 {
+            assert!(limiter.check(ip).is_ok());
+        }
+
+        // The bucket is empty, and one token refills per second
+        let wait = limiter.check(ip).expect_err("burst exhausted");
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn rate_limiter_refills_at_steady_rate() {
+        // 100 tokens per second, no burst beyond a single request
+        let config = RateLimitingConfig {
+            enabled: true,
+            requests_per_minute: 6000,
+            burst_capacity: Some(1),
+            ..RateLimitingConfig::default()
+        };
+        let limiter = RateLimiter::new(config);
+
+        let ip = "127.0.0.1".parse().unwrap();
+        for _ in 0..3 {
+            assert!(limiter.check(ip).is_ok());
+            let wait = limiter.check(ip).expect_err("bucket empty");
+            assert!(wait <= Duration::from_millis(10));
+            std::thread::sleep(wait + Duration::from_millis(5));
+        }
+    }
+
     #[test]
     fn extract_chain_id_from_path() {
         // Standard chains path pattern
@@ -759,9 +937,11 @@ mod tests {
         assert!(result.supports_capability(ChainCapability::MoralisMetadata));
         assert!(result.supports_capability(ChainCapability::PinaxAnalytics));
         assert!(result.supports_capability(ChainCapability::SpamPrediction));
+        assert!(result.supports_capability(ChainCapability::RealTimeUpdates));
+        assert!(result.supports_capability(ChainCapability::TokenMetadata));
 
         // Verify all expected capabilities are present
-        assert_eq!(result.capabilities.len(), 3);
+        assert_eq!(result.capabilities.len(), 5);
         assert!(
             result
                 .capabilities
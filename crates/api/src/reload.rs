@@ -0,0 +1,143 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Automatic reload of the spam predictor registries
+//!
+//! With `spam_predictor.auto_reload.enabled`, the directories holding the model
+//! and prompt registry files are watched for changes. Directories rather than
+//! the files themselves are watched so that atomic replacements, such as the
+//! symlink swap Kubernetes performs when a mounted `ConfigMap` is updated, are
+//! noticed too. Bursts of changes are debounced into a single reload, and a
+//! failed reload keeps the previous registries active. Reloads go through
+//! [`ServerState::reload_registries`], so they never overlap with a reload
+//! requested through `/v1/admin/reload`.
+
+use std::{
+    collections::HashSet,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use crate::{
+    error::{ServerError, ServerResult},
+    state::ServerState,
+};
+
+/// Watch the registry files and reload the predictor when they change
+///
+/// Does nothing unless auto reload is enabled. The watcher stops when
+/// `cancellation_token` is cancelled.
+///
+/// # Errors
+///
+/// Returns `ServerError::Config` if a registry directory cannot be watched.
+pub fn start_registry_watcher(
+    state: ServerState,
+    cancellation_token: CancellationToken,
+) -> ServerResult<()> {
+    let config = state.config();
+    let auto_reload = &config.spam_predictor.auto_reload;
+    if !auto_reload.enabled {
+        return Ok(());
+    }
+    let debounce = Duration::from_millis(auto_reload.debounce_ms);
+
+    let registry_paths = [
+        &config.spam_predictor.model_registry_path,
+        &config.spam_predictor.prompt_registry_path,
+    ]
+    .map(|path| std::path::absolute(path).unwrap_or_else(|_| PathBuf::from(path)));
+    let file_names: HashSet<OsString> = registry_paths
+        .iter()
+        .filter_map(|path| path.file_name().map(ToOwned::to_owned))
+        .collect();
+
+    let (change_tx, mut change_rx) = mpsc::unbounded_channel();
+    let mut watcher =
+        notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) if is_registry_change(&event, &file_names) => {
+                // The receiver is gone once the watcher task has stopped
+                let _ = change_tx.send(());
+            }
+            Ok(_) => {}
+            Err(e) => warn!(error = %e, "registry file watcher error"),
+        })
+        .map_err(|e| watch_error(&registry_paths[0], &e))?;
+
+    let directories: HashSet<&Path> = registry_paths
+        .iter()
+        .filter_map(|path| path.parent())
+        .collect();
+    for directory in directories {
+        watcher
+            .watch(directory, RecursiveMode::NonRecursive)
+            .map_err(|e| watch_error(directory, &e))?;
+    }
+    info!(
+        debounce_ms = auto_reload.debounce_ms,
+        "watching registry files for changes"
+    );
+
+    tokio::spawn(async move {
+        // Dropping the watcher stops it, so it lives as long as the task
+        let _watcher: RecommendedWatcher = watcher;
+
+        loop {
+            tokio::select! {
+                () = cancellation_token.cancelled() => {
+                    info!("registry file watcher cancelled");
+                    break;
+                }
+                change = change_rx.recv() => {
+                    if change.is_none() {
+                        break;
+                    }
+                }
+            }
+
+            // Wait for the files to settle before reloading
+            while let Ok(Some(())) = tokio::time::timeout(debounce, change_rx.recv()).await {}
+
+            debug!("registry files changed, reloading spam predictor configuration");
+            if let Err(e) = state.reload_registries().await {
+                warn!(
+                    error = %e,
+                    "failed to reload registry files, keeping the previous configuration"
+                );
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Whether `event` modified one of the watched registry files
+///
+/// Access events are ignored, since reloading reads the files. Entries starting
+/// with `..` are the data directory links Kubernetes swaps on `ConfigMap` updates.
+fn is_registry_change(event: &Event, file_names: &HashSet<OsString>) -> bool {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return false;
+    }
+    event.paths.iter().any(|path| {
+        path.file_name().is_some_and(|name| {
+            file_names.contains(name) || name.to_string_lossy().starts_with("..")
+        })
+    })
+}
+
+fn watch_error(path: &Path, error: &notify::Error) -> ServerError {
+    ServerError::Config {
+        message: format!(
+            "Failed to watch {} for registry changes: {error}",
+            path.display()
+        ),
+    }
+}
@@ -8,23 +8,46 @@
 
 pub mod handlers;
 
+use std::time::Duration;
+
 use axum::{
     Router, middleware,
     routing::{get, post},
 };
-use handlers::{chains_handler, contract_status_handler, health_handler};
+use handlers::{
+    cache_clear_handler, cache_stats_handler, chains_handler, collection_score_handler,
+    contract_status_by_path_handler, contract_status_handler, contract_status_ws_handler,
+    get_feature_flags_handler, health_handler, liveness_handler, models_handler,
+    provider_stats_handler, readiness_handler, reload_registries_handler, schema_handler,
+    update_feature_flags_handler,
+};
 
 use crate::{
-    middleware::{RateLimiter, chain_validation_middleware, rate_limiting_middleware},
+    middleware::{
+        ApiKeyAuth, RateLimiter, api_key_auth_middleware, chain_validation_middleware,
+        rate_limiting_middleware, request_timeout_middleware,
+    },
     openapi::{openapi_spec, swagger_ui},
+    signing::{PayloadSigner, response_signing_middleware},
     state::ServerState,
 };
 
-/// Create application routes with conditional rate limiting
-#[allow(clippy::needless_pass_by_value)] // We need to clone the rate limiter for middleware
-pub fn create_routes(rate_limiter: RateLimiter) -> Router<ServerState> {
-    // Health endpoint is not rate limited for monitoring purposes
-    let health_routes = Router::new().route("/health", get(health_handler));
+/// Create application routes with conditional authentication, rate limiting
+/// and response signing
+///
+/// Requests running longer than `request_timeout` are answered with a
+/// structured `408` carrying a `Retry-After` header.
+pub fn create_routes(
+    rate_limiter: RateLimiter,
+    auth: Option<ApiKeyAuth>,
+    signer: Option<PayloadSigner>,
+    request_timeout: Duration,
+) -> Router<ServerState> {
+    // Health endpoints are not rate limited for monitoring purposes
+    let health_routes = Router::new()
+        .route("/health", get(health_handler))
+        .route("/health/live", get(liveness_handler))
+        .route("/health/ready", get(readiness_handler));
 
     // Documentation endpoints are not rate limited
     let docs_routes = Router::new()
@@ -34,10 +57,32 @@ pub fn create_routes(rate_limiter: RateLimiter) -> Router<ServerState> {
     // API endpoints - conditionally apply rate limiting
     let mut api_routes = Router::new()
         .route("/chains", get(chains_handler))
-        .route("/contract/status", post(contract_status_handler));
+        .route("/contract/status", post(contract_status_handler))
+        .route(
+            "/chains/{chain_id}/contracts/{address}/status",
+            get(contract_status_by_path_handler),
+        )
+        .route("/ws/status", get(contract_status_ws_handler))
+        .route("/collection/score", post(collection_score_handler))
+        .route("/schema", get(schema_handler))
+        .route("/models", get(models_handler))
+        .route("/cache/stats", get(cache_stats_handler))
+        .route("/cache/clear", post(cache_clear_handler));
 
     // Add chain validation middleware (always enabled for chain-specific endpoints)
-    api_routes = api_routes.layer(middleware::from_fn(chain_validation_middleware));
+    api_routes = api_routes.layer(middleware::from_fn_with_state(
+        request_timeout,
+        chain_validation_middleware,
+    ));
+
+    // Require client API keys when configured; health, docs and admin routes
+    // stay exempt. Added before rate limiting so that runs first.
+    if let Some(auth) = auth {
+        api_routes = api_routes.layer(middleware::from_fn_with_state(
+            auth,
+            api_key_auth_middleware,
+        ));
+    }
 
     // Only apply rate limiting middleware if enabled
     if rate_limiter.is_enabled() {
@@ -47,10 +92,41 @@ pub fn create_routes(rate_limiter: RateLimiter) -> Router<ServerState> {
         ));
     }
 
+    // Sign responses last so the signature covers the body as sent
+    if let Some(signer) = signer {
+        api_routes = api_routes.layer(middleware::from_fn_with_state(
+            signer,
+            response_signing_middleware,
+        ));
+    }
+
     let v1 = Router::new().nest("/v1", api_routes);
 
+    // Admin endpoints are guarded by the admin API key and share each client's
+    // rate limit, so the key cannot be guessed faster than the API is served
+    let mut admin_routes = Router::new()
+        .route("/admin/providers/stats", get(provider_stats_handler))
+        .route(
+            "/admin/flags",
+            get(get_feature_flags_handler).put(update_feature_flags_handler),
+        )
+        .route("/v1/admin/reload", post(reload_registries_handler))
+        // Kept alongside the other `/admin` routes it was first served with
+        .route("/admin/registries/reload", post(reload_registries_handler));
+    if rate_limiter.is_enabled() {
+        admin_routes = admin_routes.layer(middleware::from_fn_with_state(
+            rate_limiter,
+            rate_limiting_middleware,
+        ));
+    }
+
     Router::new()
         .merge(health_routes)
         .merge(docs_routes)
         .merge(v1)
+        .merge(admin_routes)
+        .layer(middleware::from_fn_with_state(
+            request_timeout,
+            request_timeout_middleware,
+        ))
 }
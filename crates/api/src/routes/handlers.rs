@@ -8,25 +8,136 @@
 //! including health checks, API endpoints, and cancellation-aware handlers
 //! for coordinated graceful shutdown.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
 
 use alloy_primitives::Address;
-use axum::{Json, extract::State, response::IntoResponse};
-use external_apis::ApiRegistry;
+use api_client::{ContractMetadata, ContractType, ErrorCategory};
+use axum::{
+    Json,
+    extract::{
+        ConnectInfo, Path, Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{CONTENT_LANGUAGE, CONTENT_TYPE, RETRY_AFTER, VARY},
+    },
+    response::{IntoResponse, Response},
+};
+use external_apis::{
+    ApiProvider, ApiRegistry, MetadataConflict, MetadataLookup, NonEmptyVec, PinaxBatch,
+    ProviderStats,
+};
 use futures::{StreamExt, stream};
-use serde::{Deserialize, Serialize};
-use shared_types::{ChainId, ChainImplementationStatus, ContractSpamStatus};
-use spam_predictor::SpamPredictor;
-use tokio::time::timeout;
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{self, IntoDeserializer, MapAccess, Visitor},
+};
+use serde_json::json;
+use shared_types::{
+    ChainCapability, ChainId, ChainImplementationStatus, ContractSpamStatus, Locale,
+};
+use spam_predictor::{
+    CacheScope, ConfidenceBand, ConfidenceBandThresholds, ModelSpec, ModelVersion, PromptVersion,
+    SpamDistribution, SpamPredictionRequest, SpamPredictor, cache::CacheStats,
+};
+use subtle::ConstantTimeEq;
+use tokio::time::timeout_at;
 use tracing::{debug, error, info, instrument, warn};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
-    error::ServerError,
-    extractors::JsonExtractor,
-    state::{HealthCheck, ServerState},
+    config::{ResultFormatVersion, ServerConfig},
+    error::{ChainValidationError, ErrorResponse, ServerError},
+    extractors::{AcceptLanguage, AcceptResultFormat, JsonExtractor, OptionalJsonExtractor},
+    flags::{FeatureFlags, FeatureFlagsUpdate},
+    state::{HealthCheck, LivenessCheck, ServerState},
 };
 
+/// Model, prompt and output options selected for the spam analysis of a batch
+#[derive(Debug, Clone)]
+struct PredictionSettings {
+    /// Model used for classification
+    model_spec: ModelSpec,
+    /// Prompt version used for classification
+    prompt_version: PromptVersion,
+    /// Whether a probability distribution was requested
+    distribution: bool,
+    /// Which confidence fields to return
+    confidence: ConfidenceFormat,
+    /// Thresholds mapping scores to confidence bands
+    confidence_band_thresholds: ConfidenceBandThresholds,
+    /// Language of human-readable messages
+    locale: Locale,
+    /// Feature flags at the time the batch started
+    flags: FeatureFlags,
+    /// Token to classify instead of the whole collection
+    token_id: Option<String>,
+    /// Whether to return the model's unparsed answer (admin debug requests only)
+    include_raw_response: bool,
+}
+
+impl PredictionSettings {
+    /// Settings for a contract status request on `chain_id`
+    fn for_query(
+        state: &ServerState,
+        chain_id: ChainId,
+        query: &ContractStatusQuery,
+        locale: Locale,
+    ) -> Self {
+        let config = state.config();
+        Self {
+            model_spec: config.spam_predictor.model_spec_for(chain_id),
+            prompt_version: config.spam_predictor.prompt_version_for(chain_id),
+            distribution: query.distribution,
+            confidence: query.confidence,
+            confidence_band_thresholds: config.spam_predictor.confidence_band_thresholds,
+            locale,
+            flags: state.feature_flags().current(),
+            token_id: None,
+            include_raw_response: query.debug,
+        }
+    }
+
+    /// Replace the chain's default model and prompt versions with the pinned ones
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::ValidationError` if a pinned version is not registered.
+    fn with_pinned_versions(
+        mut self,
+        model_version: Option<&ModelVersion>,
+        prompt_version: Option<&PromptVersion>,
+        predictor: &SpamPredictor,
+    ) -> Result<Self, ServerError> {
+        if let Some(version) = model_version {
+            let spec = ModelSpec::new(self.model_spec.model_type().clone(), version.clone());
+            if !predictor.has_model_spec(&spec) {
+                return Err(ServerError::ValidationError(format!(
+                    "model_version {version} is not registered for {}",
+                    spec.model_type()
+                )));
+            }
+            self.model_spec = spec;
+        }
+        if let Some(version) = prompt_version {
+            if !predictor.has_prompt_version(version) {
+                return Err(ServerError::ValidationError(format!(
+                    "prompt_version {version} is not registered"
+                )));
+            }
+            self.prompt_version = version.clone();
+        }
+        Ok(self)
+    }
+}
+
 /// Result of spam analysis operation
 #[derive(Debug, Clone)]
 struct SpamAnalysisResult {
@@ -36,10 +147,64 @@ struct SpamAnalysisResult {
     message: String,
     /// Optional reasoning from AI analysis
     reasoning: Option<String>,
+    /// Error code for failed analysis
+    error_code: Option<ErrorCategory>,
+    /// Probability distribution, when requested and supported by the prompt
+    distribution: Option<SpamDistribution>,
+    /// Numeric confidence, unless only the band was requested
+    confidence: Option<f64>,
+    /// Confidence band, unless only the score was requested
+    confidence_band: Option<ConfidenceBand>,
     /// Processing time for analysis in milliseconds
     processing_time_ms: Option<u64>,
     /// Whether result was cached
     cached: bool,
+    /// Model's unparsed answer, only kept for admin debug requests
+    raw_model_response: Option<String>,
+    /// Fetched metadata, kept when the predictor failed and the
+    /// `return_metadata_on_predictor_failure` flag is set
+    metadata: Option<ContractMetadataSummary>,
+}
+
+/// Records a contract status batch that was dropped before completing
+///
+/// When a client disconnects, axum drops the handler future together with every
+/// in-flight upstream call. This guard makes that case visible in logs and metrics.
+#[derive(Debug)]
+struct BatchCancellationGuard {
+    chain_ids: Vec<ChainId>,
+    addresses_count: usize,
+    completed: bool,
+}
+
+impl BatchCancellationGuard {
+    fn new(chain_ids: Vec<ChainId>, addresses_count: usize) -> Self {
+        Self {
+            chain_ids,
+            addresses_count,
+            completed: false,
+        }
+    }
+
+    /// Mark the batch as completed so dropping the guard records nothing
+    fn complete(&mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for BatchCancellationGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            for &chain_id in &self.chain_ids {
+                crate::metrics::inc_cancelled_requests(chain_id);
+            }
+            info!(
+                chain_ids = ?self.chain_ids,
+                addresses_count = self.addresses_count,
+                "contract status request dropped before completion, pending upstream calls aborted"
+            );
+        }
+    }
 }
 
 /// Health check endpoint handler
@@ -51,9 +216,7 @@ struct SpamAnalysisResult {
     description = "Returns comprehensive health status of the API service including version, environment, timestamp, and status of all external API clients and internal services (spam-predictor).",
     responses(
         (status = 200, description = "Health check completed successfully", body = HealthCheck),
-        (status = 503, description = "Service unavailable due to critical system failures", body = String,
-            example = json!("Critical system failure: Unable to initialize core services")
-        )
+        (status = 503, description = "Service unavailable due to critical system failures", body = ErrorResponse)
     )
 )]
 pub async fn health_handler(
@@ -63,6 +226,55 @@ pub async fn health_handler(
     Ok(Json(health))
 }
 
+/// Liveness probe handler
+///
+/// Answers without contacting any dependency, so provider outages never make
+/// an orchestrator restart the process.
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    summary = "Liveness probe",
+    description = "Confirms the process is running and serving requests. Does not check external API clients or the spam predictor, \
+        so it stays `200` while a dependency is down. Intended for Kubernetes liveness probes.",
+    responses(
+        (status = 200, description = "Server process is running", body = LivenessCheck)
+    )
+)]
+pub async fn liveness_handler() -> Json<LivenessCheck> {
+    Json(LivenessCheck::current())
+}
+
+/// Readiness probe handler
+///
+/// Runs the same checks as [`health_handler`] but answers `503` while any
+/// external API client or internal service is down.
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    summary = "Readiness probe",
+    description = "Returns the same health report as `/health`, with status `200` when the service can handle traffic and `503` \
+        while any external API client or the spam predictor is down. Degraded dependencies count as ready. \
+        Intended for Kubernetes readiness probes.",
+    responses(
+        (status = 200, description = "All dependencies are up or degraded", body = HealthCheck),
+        (status = 503, description = "At least one dependency is down", body = HealthCheck)
+    )
+)]
+pub async fn readiness_handler(
+    State(state): State<ServerState>,
+) -> Result<impl IntoResponse, ServerError> {
+    let health = state.health_check().await?;
+    let status = if health.is_ready() {
+        StatusCode::OK
+    } else {
+        warn!(api_clients = ?health.api_clients, "readiness check failed: dependency down");
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok((status, Json(health)))
+}
+
 /// Contract status analysis request
 ///
 /// Contains the contract address(es) to analyze for spam classification
@@ -89,26 +301,257 @@ pub async fn health_handler(
         json!({
             "chain_id": 42161,
             "addresses": ["0x32400084c286cf3e17e7b677ea9583e60a000324"]
+        }),
+        json!({
+            "chain_id": "polygon",
+            "addresses": ["0x1234567890abcdef1234567890abcdef12345678"]
+        }),
+        json!({
+            "chain_id": "137",
+            "addresses": ["0x1234567890abcdef1234567890abcdef12345678"]
+        }),
+        json!({
+            "addresses": [
+                { "address": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d", "chain_id": 1 },
+                { "address": "0x1234567890abcdef1234567890abcdef12345678", "chain_id": "polygon" }
+            ]
         })
     )
 )]
 pub struct ContractStatusRequest {
-    /// Blockchain chain identifier
-    #[schema(example = 1)]
-    chain_id: ChainId,
-    /// Contract addresses to analyze
-    #[schema(value_type = Vec<String>, example = json!(["0x1234567890abcdef1234567890abcdef12345678"]))]
-    addresses: Vec<Address>,
+    /// Blockchain chain identifier, as a number (`1`), numeric string (`"1"`) or
+    /// case-insensitive chain name (`"ethereum"`); required unless every
+    /// address entry names its own chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(schema_with = chain_id_schema)]
+    chain_id: Option<ChainId>,
+    /// Contract addresses to analyze (legacy clients may send `contracts`); at
+    /// least one and at most `rate_limiting.max_addresses` per request (50 by
+    /// default)
+    #[serde(alias = "contracts")]
+    #[schema(
+        value_type = Vec<ContractAddressEntry>,
+        min_items = 1,
+        example = json!(["0x1234567890abcdef1234567890abcdef12345678"])
+    )]
+    addresses: NonEmptyVec<ContractAddressEntry>,
+    /// Latency budget for the whole request in milliseconds; addresses still
+    /// being analyzed when it runs out fail fast with `slo_exceeded: true`.
+    /// Defaults to the server's `concurrency.max_latency_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 2000)]
+    max_latency_ms: Option<u64>,
+    /// Decimal ID of a single token to classify instead of the whole
+    /// collection; requires exactly one address and a provider with
+    /// token-level metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "1234")]
+    token_id: Option<String>,
+    /// Model registry version to classify with instead of the chain's default
+    /// (e.g. `v0` or `latest`); must be registered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "v0")]
+    model_version: Option<String>,
+    /// Prompt registry version to classify with instead of the chain's default
+    /// (e.g. `1.0.0`); must be registered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "1.0.0")]
+    prompt_version: Option<String>,
 }
 
 impl ContractStatusRequest {
-    /// Validates that the request contains at least one address
-    pub fn validate(&self) -> Result<(), &'static str> {
-        if self.addresses.is_empty() {
-            return Err("addresses list cannot be empty");
+    /// Validates that the request contains between one and `max_addresses`
+    /// addresses spread over at most `max_chains` chains
+    ///
+    /// # Errors
+    ///
+    /// Returns a message describing the first violated constraint.
+    pub fn validate(&self, max_addresses: usize, max_chains: usize) -> Result<(), String> {
+        if self.addresses.len() > max_addresses {
+            return Err(format!(
+                "request exceeds maximum of {max_addresses} addresses"
+            ));
+        }
+        // Results are keyed by address, so each address may only be analyzed once
+        let mut chains_by_address = HashMap::new();
+        for entry in &self.addresses {
+            let Some(chain_id) = entry.chain_id().or(self.chain_id) else {
+                return Err(format!(
+                    "chain_id is required for address {} without its own chain_id",
+                    entry.address()
+                ));
+            };
+            if chains_by_address
+                .insert(entry.address(), chain_id)
+                .is_some_and(|previous| previous != chain_id)
+            {
+                return Err(format!(
+                    "address {} is requested on more than one chain",
+                    entry.address()
+                ));
+            }
+        }
+        let distinct_chains = self.chains().len();
+        if distinct_chains > max_chains {
+            return Err(format!(
+                "request spans {distinct_chains} distinct chains, exceeding the maximum of \
+                 {max_chains} chains per request"
+            ));
+        }
+        if self.max_latency_ms == Some(0) {
+            return Err("max_latency_ms must be greater than 0".to_string());
+        }
+        if let Some(token_id) = &self.token_id {
+            if self.addresses.len() != 1 {
+                return Err("token_id requires exactly one address".to_string());
+            }
+            if token_id.is_empty() || !token_id.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err("token_id must be a decimal integer".to_string());
+            }
         }
         Ok(())
     }
+
+    /// Model and prompt versions pinned by the request
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if a version is malformed.
+    fn pinned_versions(&self) -> Result<(Option<ModelVersion>, Option<PromptVersion>), String> {
+        let model_version = self
+            .model_version
+            .as_deref()
+            .map(ModelVersion::new)
+            .transpose()
+            .map_err(|e| format!("invalid model_version: {e}"))?;
+        let prompt_version = self
+            .prompt_version
+            .as_deref()
+            .map(PromptVersion::new)
+            .transpose()
+            .map_err(|e| format!("invalid prompt_version: {e}"))?;
+        Ok((model_version, prompt_version))
+    }
+
+    /// Addresses paired with the chain each one is analyzed on
+    ///
+    /// Entries without a chain of their own use the request's `chain_id`;
+    /// [`Self::validate`] rejects requests where neither is set.
+    fn resolved_addresses(&self) -> Vec<(Address, ChainId)> {
+        self.addresses
+            .iter()
+            .filter_map(|entry| Some((entry.address(), entry.chain_id().or(self.chain_id)?)))
+            .collect()
+    }
+
+    /// Distinct chains of the request, in order of first appearance
+    fn chains(&self) -> Vec<ChainId> {
+        let mut chains = Vec::new();
+        for (_, chain_id) in self.resolved_addresses() {
+            if !chains.contains(&chain_id) {
+                chains.push(chain_id);
+            }
+        }
+        chains
+    }
+}
+
+/// Contract address in a status request, optionally on its own chain
+///
+/// A plain address string is analyzed on the request's `chain_id`; an
+/// `{address, chain_id}` object lets one batch span several chains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum ContractAddressEntry {
+    /// Address analyzed on the request's `chain_id`
+    #[schema(value_type = String)]
+    Address(Address),
+    /// Address analyzed on its own chain
+    OnChain {
+        /// Contract address
+        #[schema(value_type = String)]
+        address: Address,
+        /// Chain the address is analyzed on, as a number, numeric string or chain name
+        #[schema(schema_with = chain_id_schema)]
+        chain_id: ChainId,
+    },
+}
+
+/// `OpenAPI` schema of a chain ID accepted in request bodies
+///
+/// [`ChainId`] deserializes from its numeric ID, the ID as a string and its
+/// case-insensitive name, while its derived schema only lists the names.
+fn chain_id_schema() -> utoipa::openapi::Schema {
+    let ids = ChainId::all().iter().map(|chain| chain.chain_id());
+    let integer = utoipa::openapi::ObjectBuilder::new()
+        .schema_type(utoipa::openapi::Type::Integer)
+        .description(Some("Numeric chain ID"))
+        .enum_values(Some(ids.clone()))
+        .examples([json!(1)]);
+    let string = utoipa::openapi::ObjectBuilder::new()
+        .schema_type(utoipa::openapi::Type::String)
+        .description(Some(
+            "Numeric chain ID as a string, or case-insensitive chain name (e.g. `ethereum`, `polygon`, `base`, `avalanche`, `arbitrum`)",
+        ))
+        .examples([json!("137"), json!("polygon")]);
+
+    utoipa::openapi::OneOfBuilder::new()
+        .item(integer)
+        .item(string)
+        .description(Some("Chain ID as a number, numeric string or chain name"))
+        .into()
+}
+
+impl ContractAddressEntry {
+    /// Contract address of the entry
+    pub fn address(&self) -> Address {
+        match *self {
+            Self::Address(address) | Self::OnChain { address, .. } => address,
+        }
+    }
+
+    /// Chain named by the entry itself
+    pub fn chain_id(&self) -> Option<ChainId> {
+        match *self {
+            Self::Address(_) => None,
+            Self::OnChain { chain_id, .. } => Some(chain_id),
+        }
+    }
+}
+
+// Implemented by hand rather than `untagged` so malformed plain addresses keep
+// the precise hex error that the JSON extractor turns into an address hint
+impl<'de> Deserialize<'de> for ContractAddressEntry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntryVisitor;
+
+        impl<'de> Visitor<'de> for EntryVisitor {
+            type Value = ContractAddressEntry;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an address string or an object with address and chain_id")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Address::deserialize(value.into_deserializer()).map(ContractAddressEntry::Address)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                #[derive(Deserialize)]
+                #[serde(deny_unknown_fields)]
+                struct OnChain {
+                    address: Address,
+                    chain_id: ChainId,
+                }
+
+                let OnChain { address, chain_id } =
+                    OnChain::deserialize(de::value::MapAccessDeserializer::new(map))?;
+                Ok(ContractAddressEntry::OnChain { address, chain_id })
+            }
+        }
+
+        deserializer.deserialize_any(EntryVisitor)
+    }
 }
 
 /// Individual contract analysis result
@@ -120,6 +563,9 @@ impl ContractStatusRequest {
             "status": "legitimate",
             "message": "contract metadata found on Ethereum, AI analysis classified as legitimate",
             "reasoning": "AI analysis classified as legitimate",
+            "confidence": 0.9,
+            "confidence_band": "high",
+            "providers_consulted": 1,
             "processing_time_ms": 150,
             "cached": false
         }),
@@ -128,6 +574,8 @@ impl ContractStatusRequest {
             "status": "spam",
             "message": "contract metadata found on Polygon, AI analysis classified as spam",
             "reasoning": "exhibits known scam patterns",
+            "confidence": 0.62,
+            "confidence_band": "medium",
             "processing_time_ms": 221,
             "cached": false
         }),
@@ -159,9 +607,25 @@ impl ContractStatusRequest {
             "chain_id": 1,
             "status": "error",
             "message": "unable to retrieve contract data from external services for Ethereum",
-            "reasoning": "External API error: timeout",
+            "reasoning": "analysis failed, see error_code",
+            "error_code": "timeout",
             "processing_time_ms": null,
             "cached": false
+        }),
+        json!({
+            "chain_id": 1,
+            "status": "predictor_unavailable",
+            "message": "contract metadata found on Ethereum, AI analysis unavailable, returning contract metadata only",
+            "reasoning": "analysis failed, see error_code",
+            "error_code": "upstream",
+            "providers_consulted": 1,
+            "processing_time_ms": 87,
+            "cached": false,
+            "metadata": {
+                "name": "Cool Cats",
+                "symbol": "COOL",
+                "contract_type": "ERC721"
+            }
         })
     )
 )]
@@ -172,14 +636,112 @@ pub struct ContractStatusResult {
     pub status: ContractSpamStatus,
     /// Human-readable message explaining the classification result
     pub message: String,
-    /// Optional reasoning from AI analysis
+    /// Optional reasoning from AI analysis, or error detail when exposed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reasoning: Option<String>,
+    /// Stable error code for failed results (e.g. `timeout`, `rate_limited`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub error_code: Option<ErrorCategory>,
+    /// Seconds to wait before retrying, when a data provider rate limited the lookup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_seconds: Option<u64>,
+    /// Probabilities for `spam`, `legitimate` and `inconclusive`, returned with
+    /// `?distribution=true` when the configured prompt supports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub distribution: Option<SpamDistribution>,
+    /// Confidence of the classification between 0.0 and 1.0, omitted with
+    /// `?confidence=band`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Coarse confidence (`low`, `medium` or `high`) derived from configurable
+    /// thresholds, omitted with `?confidence=score`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub confidence_band: Option<ConfidenceBand>,
+    /// Whether analysis was cut short by the request's `max_latency_ms` budget
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub slo_exceeded: bool,
+    /// Number of data providers called for this address; more than one means
+    /// failover kicked in. Omitted when no provider was called (e.g. cached)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub providers_consulted: u8,
     /// Processing time for analysis in milliseconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub processing_time_ms: Option<u64>,
     /// Whether result was cached
     pub cached: bool,
+    /// Unparsed answer of the model, returned only with `?debug=true` and a
+    /// valid admin API key; cached verdicts have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_model_response: Option<String>,
+    /// Fetched contract metadata, returned with `predictor_unavailable` results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ContractMetadataSummary>,
+    /// SQL query sent to Pinax for the address, reported in the `x-debug-query`
+    /// headers of debug responses rather than the body
+    #[serde(skip)]
+    pub(crate) pinax_query: Option<String>,
+}
+
+/// Contract metadata returned when it could not be classified
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "name": "Cool Cats",
+    "symbol": "COOL",
+    "contract_type": "ERC721"
+}))]
+pub struct ContractMetadataSummary {
+    /// Contract name, if known
+    pub name: Option<String>,
+    /// Contract symbol, if known
+    pub symbol: Option<String>,
+    /// Contract standard (`ERC20`, `ERC721`, `ERC1155`, ...), if known
+    #[schema(value_type = Option<String>)]
+    pub contract_type: Option<ContractType>,
+}
+
+impl From<&ContractMetadata> for ContractMetadataSummary {
+    fn from(metadata: &ContractMetadata) -> Self {
+        Self {
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            contract_type: metadata.contract_type.clone(),
+        }
+    }
+}
+
+/// Query parameters for the contract status endpoint
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ContractStatusQuery {
+    /// Include the spam/legitimate/inconclusive probability distribution in each
+    /// result; prompts without distribution support return categorical results only
+    #[serde(default)]
+    pub distribution: bool,
+    /// Return the executed Pinax SQL in `x-debug-query` headers, the Pinax
+    /// configuration provenance in `x-debug-config-source` headers and the
+    /// model's unparsed answer in `raw_model_response` (admin only, requires
+    /// the `x-admin-api-key` header)
+    #[serde(default)]
+    pub debug: bool,
+    /// Confidence fields to include in analyzed results
+    #[serde(default)]
+    pub confidence: ConfidenceFormat,
+}
+
+/// Confidence fields returned for analyzed results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfidenceFormat {
+    /// Both the numeric `confidence` and the `confidence_band`
+    #[default]
+    Both,
+    /// Only the numeric `confidence`
+    Score,
+    /// Only the `confidence_band`
+    Band,
 }
 
 /// Response from the contract status endpoint
@@ -256,10 +818,238 @@ pub struct ContractStatusResult {
     )
 )]
 pub struct ContractStatusResponse {
-    /// Analysis results keyed by contract address
+    /// Analysis results keyed by contract address, in address order
     #[serde(flatten)]
-    #[schema(value_type = HashMap<String, ContractStatusResult>)]
-    pub results: HashMap<Address, ContractStatusResult>,
+    #[schema(value_type = BTreeMap<String, ContractStatusResult>)]
+    pub results: BTreeMap<Address, ContractStatusResult>,
+}
+
+/// Versioned (`v2`) response from the contract status endpoint
+///
+/// Returned for `Accept: application/vnd.nft-api.v2+json`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "results": {
+        "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d": {
+            "chain_id": 1,
+            "status": "legitimate",
+            "message": "contract metadata found on Ethereum, AI analysis classified as legitimate",
+            "reasoning": "AI analysis classified as legitimate",
+            "processing_time_ms": 150,
+            "cached": false
+        }
+    },
+    "summary": {
+        "total": 1,
+        "spam": 0,
+        "legitimate": 1,
+        "inconclusive": 0,
+        "no_data": 0,
+        "error": 0
+    }
+}))]
+pub struct ContractStatusResponseV2 {
+    /// Analysis results keyed by contract address
+    #[schema(value_type = BTreeMap<String, ContractStatusResult>)]
+    pub results: BTreeMap<Address, ContractStatusResult>,
+    /// Result counts by status
+    pub summary: ContractStatusSummary,
+}
+
+/// Number of results per status in a contract status response
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ContractStatusSummary {
+    /// Total number of analyzed addresses
+    pub total: usize,
+    /// Addresses classified as spam
+    pub spam: usize,
+    /// Addresses classified as legitimate
+    pub legitimate: usize,
+    /// Addresses with an inconclusive analysis
+    pub inconclusive: usize,
+    /// Addresses without data to analyze
+    pub no_data: usize,
+    /// Addresses whose analysis failed, including metadata fetch and predictor errors
+    /// and unclassified `predictor_unavailable` results
+    pub error: usize,
+}
+
+impl ContractStatusSummary {
+    /// Count the results of a response by status
+    pub fn from_results(results: &BTreeMap<Address, ContractStatusResult>) -> Self {
+        let mut summary = Self {
+            total: results.len(),
+            ..Self::default()
+        };
+        for result in results.values() {
+            summary.count(&result.status);
+        }
+        summary
+    }
+
+    /// Add one result with the given status
+    fn count(&mut self, status: &ContractSpamStatus) {
+        match status {
+            ContractSpamStatus::Spam => self.spam += 1,
+            ContractSpamStatus::Legitimate => self.legitimate += 1,
+            ContractSpamStatus::Inconclusive => self.inconclusive += 1,
+            ContractSpamStatus::NoData => self.no_data += 1,
+            ContractSpamStatus::Error
+            | ContractSpamStatus::MetadataFetchError
+            | ContractSpamStatus::PredictorError
+            | ContractSpamStatus::PredictorUnavailable => self.error += 1,
+        }
+    }
+}
+
+/// Time limits for analyzing the addresses of one request
+#[derive(Debug, Clone, Copy)]
+struct AnalysisDeadlines {
+    /// Limit for the analysis of a single address
+    individual_timeout: Duration,
+    /// Latency budget of the whole request, if any
+    max_latency: Option<Duration>,
+    /// When the latency budget runs out
+    slo_deadline: Option<tokio::time::Instant>,
+}
+
+impl AnalysisDeadlines {
+    /// Start the clock for a request, preferring its own latency budget over the configured one
+    fn start(config: &ServerConfig, max_latency_ms: Option<u64>) -> Self {
+        let max_latency = max_latency_ms
+            .or(config.concurrency.max_latency_ms)
+            .map(Duration::from_millis);
+        Self {
+            individual_timeout: config.concurrency.per_address_timeout(),
+            max_latency,
+            slo_deadline: max_latency.map(|budget| tokio::time::Instant::now() + budget),
+        }
+    }
+
+    /// When an address whose clock started at `started` times out, and whether
+    /// the latency budget caps it before the individual timeout does
+    fn address_deadline(&self, started: tokio::time::Instant) -> (tokio::time::Instant, bool) {
+        let address_deadline = started + self.individual_timeout;
+        match self.slo_deadline {
+            Some(slo_deadline) if slo_deadline < address_deadline => (slo_deadline, true),
+            _ => (address_deadline, false),
+        }
+    }
+}
+
+/// Fetch the metadata of each chain's addresses from Pinax with one query per
+/// chain where batching applies
+///
+/// Chains whose batch fails or does not apply are absent, and their addresses
+/// are looked up one by one. The prefetch spends the budget of the addresses
+/// it fetches for, so it is abandoned at the deadline of an address whose
+/// clock started at `started`.
+async fn prefetch_pinax_batches(
+    api_registry: &ApiRegistry,
+    addresses: &[(Address, ChainId)],
+    deadlines: AnalysisDeadlines,
+    started: tokio::time::Instant,
+) -> HashMap<ChainId, PinaxBatch> {
+    let mut by_chain: HashMap<ChainId, Vec<Address>> = HashMap::new();
+    for &(address, chain_id) in addresses {
+        // Planned chains are answered without fetching metadata
+        if chain_id.implementation_status() != ChainImplementationStatus::Planned {
+            by_chain.entry(chain_id).or_default().push(address);
+        }
+    }
+
+    let (deadline, _) = deadlines.address_deadline(started);
+    let prefetches = futures::future::join_all(by_chain.iter().map(
+        |(&chain_id, chain_addresses)| async move {
+            api_registry
+                .prefetch_pinax_batch(chain_addresses, chain_id)
+                .await
+                .map(|batch| (chain_id, batch))
+        },
+    ));
+    if let Ok(batches) = timeout_at(deadline, prefetches).await {
+        batches.into_iter().flatten().collect()
+    } else {
+        warn!("Pinax batch prefetch timed out, looking up addresses one by one");
+        HashMap::new()
+    }
+}
+
+/// Analyze one address, answering with a timeout result once a deadline passes
+///
+/// The address's individual timeout runs from `started`, which precedes the
+/// call by any time already spent on its behalf.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::too_many_arguments)]
+async fn analyze_address(
+    address: Address,
+    chain_id: ChainId,
+    api_registry: &ApiRegistry,
+    pinax_batch: Option<&PinaxBatch>,
+    spam_predictor: &Arc<SpamPredictor>,
+    prediction_settings: &PredictionSettings,
+    deadlines: AnalysisDeadlines,
+    started: tokio::time::Instant,
+) -> ContractStatusResult {
+    let AnalysisDeadlines {
+        individual_timeout,
+        max_latency,
+        ..
+    } = deadlines;
+    // The latency budget caps the individual timeout when it runs out first
+    let (deadline, slo_bound) = deadlines.address_deadline(started);
+    let result = timeout_at(
+        deadline,
+        process_single_address(
+            address,
+            chain_id,
+            chain_id.implementation_status(),
+            api_registry,
+            pinax_batch,
+            spam_predictor,
+            prediction_settings,
+        ),
+    )
+    .await;
+
+    if let Ok(result) = result {
+        result
+    } else if let (true, Some(budget)) = (slo_bound, max_latency) {
+        warn!(
+            address = %address,
+            chain_id = %chain_id,
+            max_latency_ms = budget.as_millis(),
+            "address processing exceeded the latency budget"
+        );
+        slo_exceeded_result(chain_id, budget, prediction_settings.locale)
+    } else {
+        warn!(
+            address = %address,
+            chain_id = %chain_id,
+            timeout_ms = individual_timeout.as_millis(),
+            "individual address processing timed out"
+        );
+        ContractStatusResult {
+            chain_id,
+            status: ContractSpamStatus::Error,
+            message: prediction_settings
+                .locale
+                .processing_timeout(chain_id.name(), individual_timeout.as_millis()),
+            reasoning: Some("Individual address processing timeout".to_string()),
+            error_code: Some(ErrorCategory::Timeout),
+            retry_after_seconds: None,
+            distribution: None,
+            confidence: None,
+            confidence_band: None,
+            slo_exceeded: false,
+            providers_consulted: 0,
+            processing_time_ms: Some(individual_timeout.as_millis() as u64),
+            cached: false,
+            raw_model_response: None,
+            metadata: None,
+            pinax_query: None,
+        }
+    }
 }
 
 /// Process a single contract address for spam analysis
@@ -268,7 +1058,10 @@ pub struct ContractStatusResponse {
 /// - Fetching metadata from external APIs
 /// - Running spam prediction analysis
 /// - Building the appropriate result based on chain implementation status
-#[instrument(skip(api_registry, spam_predictor), fields(
+///
+/// All upstream calls are awaited inline and nothing is spawned, so dropping the
+/// returned future (e.g. on client disconnect) aborts any outstanding requests.
+#[instrument(skip(api_registry, pinax_batch, spam_predictor), fields(
     address = %address,
     chain_id = %chain_id,
     implementation_status = %implementation_status
@@ -278,7 +1071,9 @@ async fn process_single_address(
     chain_id: ChainId,
     implementation_status: ChainImplementationStatus,
     api_registry: &ApiRegistry,
+    pinax_batch: Option<&PinaxBatch>,
     spam_predictor: &Arc<SpamPredictor>,
+    prediction_settings: &PredictionSettings,
 ) -> ContractStatusResult {
     debug!(
         address = %address,
@@ -288,75 +1083,136 @@ async fn process_single_address(
 
     match implementation_status {
         ChainImplementationStatus::Full => {
-            process_with_full_implementation(address, chain_id, api_registry, spam_predictor).await
+            process_with_full_implementation(
+                address,
+                chain_id,
+                api_registry,
+                pinax_batch,
+                spam_predictor,
+                prediction_settings,
+            )
+            .await
         }
         ChainImplementationStatus::Partial => {
-            process_with_partial_implementation(address, chain_id, api_registry, spam_predictor)
-                .await
+            process_with_partial_implementation(
+                address,
+                chain_id,
+                api_registry,
+                pinax_batch,
+                spam_predictor,
+                prediction_settings,
+            )
+            .await
         }
         ChainImplementationStatus::Planned => ContractStatusResult {
             chain_id,
             status: ContractSpamStatus::NoData,
-            message: format!(
-                "contract analysis for {} is {}",
-                chain_id.name(),
-                chain_id.status_message()
-            ),
+            message: prediction_settings
+                .locale
+                .analysis_not_implemented(chain_id.name()),
             reasoning: None,
+            error_code: None,
+            retry_after_seconds: None,
+            distribution: None,
+            confidence: None,
+            confidence_band: None,
+            slo_exceeded: false,
+            providers_consulted: 0,
             processing_time_ms: None,
             cached: false,
+            raw_model_response: None,
+            metadata: None,
+            pinax_query: None,
         },
     }
 }
 
+#[allow(clippy::too_many_lines)]
 async fn process_with_full_implementation(
     address: Address,
     chain_id: ChainId,
     api_registry: &ApiRegistry,
+    pinax_batch: Option<&PinaxBatch>,
     spam_predictor: &Arc<SpamPredictor>,
+    prediction_settings: &PredictionSettings,
 ) -> ContractStatusResult {
     let start = std::time::Instant::now();
 
-    match api_registry.get_contract_metadata(address, chain_id).await {
+    let lookup = fetch_contract_metadata(
+        address,
+        chain_id,
+        api_registry,
+        pinax_batch,
+        prediction_settings,
+    )
+    .await;
+    let provider = metadata_provider_label(&lookup);
+    let providers_consulted = lookup.providers_consulted;
+    let conflict = lookup.conflict;
+    let pinax_query = lookup.pinax_query;
+    match lookup.result {
         Ok(Some(metadata)) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "found",
                 start.elapsed().as_secs_f64(),
             );
-            let analysis_result = perform_spam_analysis(&metadata, spam_predictor, address).await;
+            let analysis_result =
+                perform_spam_analysis(&metadata, spam_predictor, prediction_settings, address)
+                    .await;
 
             ContractStatusResult {
                 chain_id,
                 status: analysis_result.status.clone(),
-                message: format!(
-                    "contract metadata found on {}, {}",
-                    chain_id.name(),
-                    analysis_result.message
-                ),
-                reasoning: analysis_result.reasoning.clone(),
+                message: prediction_settings
+                    .locale
+                    .metadata_found(chain_id.name(), &analysis_result.message),
+                reasoning: with_conflict_note(analysis_result.reasoning.clone(), conflict.as_ref()),
+                error_code: analysis_result.error_code,
+                retry_after_seconds: None,
+                distribution: analysis_result.distribution,
+                confidence: analysis_result.confidence,
+                confidence_band: analysis_result.confidence_band,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: analysis_result.processing_time_ms,
                 cached: analysis_result.cached,
+                raw_model_response: analysis_result.raw_model_response,
+                metadata: analysis_result.metadata,
+                pinax_query,
             }
         }
         Ok(None) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "missing",
                 start.elapsed().as_secs_f64(),
             );
             ContractStatusResult {
                 chain_id,
                 status: ContractSpamStatus::NoData,
-                message: format!("no data found for the contract on {}", chain_id.name()),
+                message: prediction_settings.locale.no_data_found(chain_id.name()),
                 reasoning: None,
+                error_code: None,
+                retry_after_seconds: None,
+                distribution: None,
+                confidence: None,
+                confidence_band: None,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: None,
                 cached: false,
+                raw_model_response: None,
+                metadata: None,
+                pinax_query,
             }
         }
         Err(e) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "error",
                 start.elapsed().as_secs_f64(),
             );
@@ -368,72 +1224,116 @@ async fn process_with_full_implementation(
             );
             ContractStatusResult {
                 chain_id,
-                status: ContractSpamStatus::Error,
-                message: format!(
-                    "unable to retrieve contract data from external services for {}",
-                    chain_id.name()
-                ),
+                status: ContractSpamStatus::MetadataFetchError,
+                message: prediction_settings
+                    .locale
+                    .metadata_unavailable(chain_id.name()),
                 reasoning: Some(format!("External API error: {e}")),
+                error_code: Some(e.category()),
+                retry_after_seconds: e.retry_after_seconds(),
+                distribution: None,
+                confidence: None,
+                confidence_band: None,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: None,
                 cached: false,
+                raw_model_response: None,
+                metadata: None,
+                pinax_query,
             }
         }
     }
 }
 
+#[allow(clippy::too_many_lines)]
 async fn process_with_partial_implementation(
     address: Address,
     chain_id: ChainId,
     api_registry: &ApiRegistry,
+    pinax_batch: Option<&PinaxBatch>,
     spam_predictor: &Arc<SpamPredictor>,
+    prediction_settings: &PredictionSettings,
 ) -> ContractStatusResult {
     let start = std::time::Instant::now();
 
-    match api_registry.get_contract_metadata(address, chain_id).await {
+    let lookup = fetch_contract_metadata(
+        address,
+        chain_id,
+        api_registry,
+        pinax_batch,
+        prediction_settings,
+    )
+    .await;
+    let provider = metadata_provider_label(&lookup);
+    let providers_consulted = lookup.providers_consulted;
+    let conflict = lookup.conflict;
+    let pinax_query = lookup.pinax_query;
+    match lookup.result {
         Ok(Some(metadata)) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "found",
                 start.elapsed().as_secs_f64(),
             );
-            let analysis_result = perform_spam_analysis(&metadata, spam_predictor, address).await;
+            let analysis_result =
+                perform_spam_analysis(&metadata, spam_predictor, prediction_settings, address)
+                    .await;
 
             ContractStatusResult {
                 chain_id,
                 status: analysis_result.status.clone(),
-                message: format!(
-                    "contract metadata found on {} - {} - {}",
-                    chain_id.name(),
-                    chain_id.status_message(),
-                    analysis_result.message
-                ),
-                reasoning: analysis_result.reasoning.clone(),
+                message: prediction_settings
+                    .locale
+                    .partial_metadata_found(chain_id.name(), &analysis_result.message),
+                reasoning: with_conflict_note(analysis_result.reasoning.clone(), conflict.as_ref()),
+                error_code: analysis_result.error_code,
+                retry_after_seconds: None,
+                distribution: analysis_result.distribution,
+                confidence: analysis_result.confidence,
+                confidence_band: analysis_result.confidence_band,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: analysis_result.processing_time_ms,
                 cached: analysis_result.cached,
+                raw_model_response: analysis_result.raw_model_response,
+                metadata: analysis_result.metadata,
+                pinax_query,
             }
         }
         Ok(None) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "missing",
                 start.elapsed().as_secs_f64(),
             );
             ContractStatusResult {
                 chain_id,
                 status: ContractSpamStatus::NoData,
-                message: format!(
-                    "no data found for the contract on {} - {}",
-                    chain_id.name(),
-                    chain_id.status_message()
-                ),
+                message: prediction_settings
+                    .locale
+                    .partial_no_data_found(chain_id.name()),
                 reasoning: None,
+                error_code: None,
+                retry_after_seconds: None,
+                distribution: None,
+                confidence: None,
+                confidence_band: None,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: None,
                 cached: false,
+                raw_model_response: None,
+                metadata: None,
+                pinax_query,
             }
         }
         Err(e) => {
             crate::metrics::observe_metadata_api_duration(
-                "external_api",
+                provider,
+                chain_id,
                 "error",
                 start.elapsed().as_secs_f64(),
             );
@@ -445,20 +1345,106 @@ async fn process_with_partial_implementation(
             );
             ContractStatusResult {
                 chain_id,
-                status: ContractSpamStatus::Error,
-                message: format!(
-                    "unable to retrieve contract data for {} - {}",
-                    chain_id.name(),
-                    chain_id.status_message()
-                ),
+                status: ContractSpamStatus::MetadataFetchError,
+                message: prediction_settings
+                    .locale
+                    .partial_metadata_unavailable(chain_id.name()),
                 reasoning: Some(format!("External API error: {e}")),
+                error_code: Some(e.category()),
+                retry_after_seconds: e.retry_after_seconds(),
+                distribution: None,
+                confidence: None,
+                confidence_band: None,
+                slo_exceeded: false,
+                providers_consulted,
                 processing_time_ms: None,
                 cached: false,
+                raw_model_response: None,
+                metadata: None,
+                pinax_query,
             }
         }
     }
 }
 
+/// Fetch contract metadata, falling back to stale cached metadata on provider
+/// failure when `serve_stale_on_error` is enabled
+///
+/// When a token is requested its metadata is fetched instead, with the
+/// token's fields taking precedence over the collection's. Contracts queried
+/// by `pinax_batch` take its answer. Also returns the number of providers
+/// consulted.
+async fn fetch_contract_metadata(
+    address: Address,
+    chain_id: ChainId,
+    api_registry: &ApiRegistry,
+    pinax_batch: Option<&PinaxBatch>,
+    prediction_settings: &PredictionSettings,
+) -> MetadataLookup {
+    if let Some(token_id) = &prediction_settings.token_id {
+        // Only Moralis serves token metadata
+        let result = api_registry
+            .get_token_metadata(address, chain_id, token_id)
+            .await
+            .map(|token| token.map(|token| token.to_classification_metadata()));
+        let supported = api_registry.supports_token_metadata();
+        return MetadataLookup {
+            result,
+            providers_consulted: u8::from(supported),
+            provider: supported.then_some(ApiProvider::Moralis),
+            conflict: None,
+            pinax_query: None,
+        };
+    }
+
+    let mut lookup = api_registry
+        .lookup_contract_metadata_with_batch(address, chain_id, pinax_batch)
+        .await;
+    if prediction_settings.flags.serve_stale_on_error
+        && let Err(e) = &lookup.result
+        && let Some(stale) = api_registry.get_stale_contract_metadata(address, chain_id)
+    {
+        warn!(
+            %address,
+            chain = chain_id.name(),
+            error = %e,
+            "providers failed, serving stale cached metadata"
+        );
+        lookup.result = Ok(Some(stale));
+        lookup.provider = None;
+    }
+    lookup
+}
+
+/// Prefix `reasoning` with the providers' metadata conflict, if one was found
+fn with_conflict_note(
+    reasoning: Option<String>,
+    conflict: Option<&MetadataConflict>,
+) -> Option<String> {
+    let Some(conflict) = conflict else {
+        return reasoning;
+    };
+    let note = format!("Metadata conflict: {conflict}");
+    Some(match reasoning {
+        Some(reasoning) => format!("{note}. {reasoning}"),
+        None => note,
+    })
+}
+
+/// Metrics label for the source of a metadata lookup result
+fn metadata_provider_label(lookup: &MetadataLookup) -> &'static str {
+    match &lookup.provider {
+        Some(provider) => provider.as_str(),
+        None if lookup.result.is_ok() => "cache",
+        None => "none",
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
 /// Contract status analysis
 ///
 /// Analyzes blockchain contracts to determine if they are spam by:
@@ -477,119 +1463,145 @@ async fn process_with_partial_implementation(
     path = "/v1/contract/status",
     tag = "contracts",
     summary = "Analyze contract spam status",
-    description = "Analyzes one or more blockchain contract addresses on a specific chain to determine if they are spam. Supports all major blockchain networks including Ethereum (1), Polygon (137), Base (8453), Avalanche (43114), and Arbitrum (42161). Uses AI-powered classification with external blockchain data sources (Moralis API, Pinax Analytics).",
+    description = "Analyzes one or more blockchain contract addresses on a specific chain to determine if they are spam. Supports all major blockchain networks including Ethereum (1), Polygon (137), Base (8453), Avalanche (43114), and Arbitrum (42161). Uses AI-powered classification with external blockchain data sources (Moralis API, Pinax Analytics). A request may contain at most `rate_limiting.max_addresses` addresses (50 by default); entries given as `{address, chain_id}` objects are analyzed on their own chain, so one request may mix up to `rate_limiting.max_chains` chains.",
     request_body = ContractStatusRequest,
     responses(
-        (status = 200, description = "Contract analysis completed successfully", body = ContractStatusResponse),
-        (status = 400, description = "Invalid request - addresses list cannot be empty, unsupported chain, or malformed addresses", body = String),
-        (status = 429, description = "Rate limit exceeded - too many requests", body = String,
-            example = json!("Rate limit exceeded.")
+        (status = 200, description = "Contract analysis completed successfully (with `status_to_http_code`, single-contract requests use 202 for inconclusive, 404 for no data, 422 for spam and 502 for errors); the shape follows the version requested in the `Accept` header (`application/vnd.nft-api.v1+json` or `application/vnd.nft-api.v2+json`)",
+            content(
+                (ContractStatusResponse = "application/json"),
+                (ContractStatusResponse = "application/vnd.nft-api.v1+json"),
+                (ContractStatusResponseV2 = "application/vnd.nft-api.v2+json")
+            ),
+            headers(
+                ("x-spam-status" = String, description = "Status of the contract when exactly one address was analyzed"),
+                ("content-language" = String, description = "Language of the `message` fields, negotiated from `Accept-Language` (`en` or `es`, defaults to `en`)"),
+                ("retry-after" = u64, description = "Seconds to wait before retrying, when a data provider rate limited the lookup of any address"),
+                ("x-debug-query" = String, description = "Pinax SQL executed for each address, only with `debug=true` and a valid admin API key"),
+                ("x-debug-config-source" = String, description = "Per chain, whether Pinax used a chain override or the global configuration (e.g. `1=chain_override`, `137=global`), only with `debug=true` and a valid admin API key")
+            )
+        ),
+        (status = 400, description = "Invalid request - addresses list is empty or exceeds the configured maximum, unsupported chain, or malformed addresses", body = ErrorResponse),
+        (status = 403, description = "Debug output requested without a valid admin API key", body = ErrorResponse),
+        (status = 404, description = "Chain is supported but disabled in this deployment", body = ErrorResponse),
+        (status = 406, description = "The `Accept` header only names unsupported result format versions", body = ErrorResponse),
+        (status = 408, description = "Analysis exceeded the configured request timeout; the `Retry-After` header suggests when to retry", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded - too many requests", body = ErrorResponse,
+            example = json!({
+                "error": "rate_limited",
+                "message": "Rate limit exceeded, retry after 30 seconds",
+                "status": 429,
+                "details": { "retry_after_seconds": 30 }
+            })
         ),
-        (status = 500, description = "Internal server error during analysis", body = String)
+        (status = 500, description = "Internal server error during analysis", body = ErrorResponse)
     )
 )]
 #[allow(clippy::cast_precision_loss, clippy::too_many_lines)]
 #[instrument(skip(state, contract_status), fields(
-    chain_id = %contract_status.chain_id,
-    addresses_count = contract_status.addresses.len(),
-    chain_implementation = %contract_status.chain_id.implementation_status()
+    chain_id = ?contract_status.chain_id,
+    addresses_count = contract_status.addresses.len()
 ))]
 pub async fn contract_status_handler(
     State(state): State<ServerState>,
+    Query(query): Query<ContractStatusQuery>,
+    request_headers: HeaderMap,
+    AcceptResultFormat(requested_format): AcceptResultFormat,
+    AcceptLanguage(locale): AcceptLanguage,
     JsonExtractor(contract_status): JsonExtractor<ContractStatusRequest>,
-) -> Result<Json<ContractStatusResponse>, ServerError> {
+) -> Result<Response, ServerError> {
     let start_time = std::time::Instant::now();
+    let config = state.config();
     contract_status
-        .validate()
-        .map_err(|msg| ServerError::ValidationError(msg.to_string()))?;
+        .validate(
+            config.rate_limiting.max_addresses as usize,
+            config.rate_limiting.max_chains as usize,
+        )
+        .map_err(ServerError::ValidationError)?;
 
-    let chain_id = contract_status.chain_id;
-    crate::metrics::inc_requests_by_chain(chain_id);
-    let implementation_status = chain_id.implementation_status();
+    if query.debug && !is_admin_request(&state, &request_headers) {
+        warn!("rejected contract status request with debug: missing or invalid admin API key");
+        return Err(ServerError::Forbidden(
+            "debug requires a valid admin API key".to_string(),
+        ));
+    }
+
+    let chain_ids = contract_status.chains();
+    let prediction_settings = batch_prediction_settings(&state, &contract_status, &query, locale)?;
     let api_registry = state.api_registry();
 
     // Get concurrency configuration
-    let config = state.config();
     let max_concurrency = config.concurrency.max_concurrent_external_api_calls as usize;
-    let individual_timeout = config
-        .concurrency
-        .individual_address_timeout_seconds
-        .value();
+    let deadlines = AnalysisDeadlines::start(config, contract_status.max_latency_ms);
 
     info!(
-        chain_id = %chain_id,
+        chain_ids = ?chain_ids,
         addresses_count = contract_status.addresses.len(),
         max_concurrency = max_concurrency,
-        individual_timeout_seconds = individual_timeout.as_secs(),
+        individual_timeout_ms = deadlines.individual_timeout.as_millis(),
         "starting concurrent contract status analysis"
     );
 
-    // Process addresses concurrently with bounded concurrency
-    let addresses: Vec<Address> = contract_status.addresses.clone();
-    let results: HashMap<Address, ContractStatusResult> = stream::iter(addresses)
-        .map(|address| {
+    let mut cancellation_guard =
+        BatchCancellationGuard::new(chain_ids.clone(), contract_status.addresses.len());
+
+    // Fetch each chain's metadata from Pinax with one query where possible,
+    // then process addresses concurrently with bounded concurrency. The
+    // prefetch counts against each address's individual timeout.
+    let addresses = contract_status.resolved_addresses();
+    let prefetch_started = tokio::time::Instant::now();
+    let pinax_batches = if contract_status.token_id.is_none() {
+        prefetch_pinax_batches(api_registry, &addresses, deadlines, prefetch_started).await
+    } else {
+        HashMap::new()
+    };
+    let prefetch_time = prefetch_started.elapsed();
+    let pinax_batches = &pinax_batches;
+    let prediction_settings = &prediction_settings;
+    let mut results: BTreeMap<Address, ContractStatusResult> = stream::iter(addresses.clone())
+        .map(|(address, chain_id)| {
             let api_registry = api_registry.clone();
             let spam_predictor = state.spam_predictor().clone();
 
             async move {
-                let result = timeout(
-                    individual_timeout,
-                    process_single_address(
-                        address,
-                        chain_id,
-                        implementation_status,
-                        &api_registry,
-                        &spam_predictor,
-                    ),
+                let result = analyze_address(
+                    address,
+                    chain_id,
+                    &api_registry,
+                    pinax_batches.get(&chain_id),
+                    &spam_predictor,
+                    &prediction_settings[&chain_id],
+                    deadlines,
+                    tokio::time::Instant::now() - prefetch_time,
                 )
                 .await;
-
-                #[allow(clippy::cast_possible_truncation)]
-                let final_result = if let Ok(result) = result {
-                    result
-                } else {
-                    warn!(
-                        address = %address,
-                        chain_id = %chain_id,
-                        timeout_seconds = individual_timeout.as_secs(),
-                        "individual address processing timed out"
-                    );
-                    ContractStatusResult {
-                        chain_id,
-                        status: ContractSpamStatus::Error,
-                        message: format!(
-                            "processing timeout for {} after {} seconds",
-                            chain_id.name(),
-                            individual_timeout.as_secs()
-                        ),
-                        reasoning: Some("Individual address processing timeout".to_string()),
-                        processing_time_ms: Some(individual_timeout.as_millis() as u64),
-                        cached: false,
-                    }
-                };
-
-                (address, final_result)
+                (address, result)
             }
         })
         .buffer_unordered(max_concurrency)
         .collect()
         .await;
+    cancellation_guard.complete();
 
     let duration = start_time.elapsed();
     let spam_count = results.values().filter(|r| r.status.is_spam()).count();
-    let error_count = results
-        .values()
-        .filter(|r| matches!(r.status, ContractSpamStatus::Error))
-        .count();
+    let error_count = results.values().filter(|r| r.status.is_error()).count();
     let timeout_count = results
         .values()
-        .filter(|r| {
-            r.reasoning
-                .as_ref()
-                .is_some_and(|reason| reason.contains("timeout"))
-        })
+        .filter(|r| r.error_code == Some(ErrorCategory::Timeout))
         .count();
+    for &chain_id in &chain_ids {
+        if results
+            .values()
+            .any(|r| r.slo_exceeded && r.chain_id == chain_id)
+        {
+            crate::metrics::inc_slo_violations(chain_id);
+        }
+    }
     let total_addresses = results.len();
+    let chain_label = match chain_ids.as_slice() {
+        [chain_id] => chain_id.to_string(),
+        _ => "mixed".to_string(),
+    };
 
     // Record concurrent batch processing metrics
     let batch_size_category = match total_addresses {
@@ -600,13 +1612,13 @@ pub async fn contract_status_handler(
         _ => "extra_large",
     };
     crate::metrics::observe_concurrent_batch_duration(
-        &chain_id.to_string(),
+        &chain_label,
         batch_size_category,
         duration.as_secs_f64(),
     );
 
     info!(
-        chain_id = %chain_id,
+        chain_ids = ?chain_ids,
         total_addresses = total_addresses,
         spam_contracts = spam_count,
         legitimate_contracts = total_addresses - spam_count - error_count,
@@ -623,25 +1635,971 @@ pub async fn contract_status_handler(
         "detailed results summary"
     );
 
-    Ok(Json(ContractStatusResponse { results }))
+    if !config.exposes_error_details() {
+        results.values_mut().for_each(redact_error_detail);
+    }
+
+    let verdict_code = config
+        .status_to_http_code
+        .then(|| single_verdict_status_code(&results))
+        .flatten();
+    let mut response_headers = spam_status_header(&results);
+    if query.debug {
+        for &chain_id in &chain_ids {
+            append_debug_query_headers(&mut response_headers, api_registry, chain_id, &results);
+        }
+    }
+
+    insert_result_headers(&mut response_headers, &results, locale);
+    response_headers.insert(VARY, HeaderValue::from_static("accept, accept-language"));
+    let format = requested_format.unwrap_or(config.default_result_format);
+    // Unversioned requests keep the plain JSON content type
+    if requested_format.is_some() {
+        response_headers.insert(CONTENT_TYPE, HeaderValue::from_static(format.media_type()));
+    }
+
+    let mut response = match format {
+        ResultFormatVersion::V1 => {
+            (response_headers, Json(ContractStatusResponse { results })).into_response()
+        }
+        ResultFormatVersion::V2 => {
+            let summary = ContractStatusSummary::from_results(&results);
+            (
+                response_headers,
+                Json(ContractStatusResponseV2 { results, summary }),
+            )
+                .into_response()
+        }
+    };
+    if let Some(code) = verdict_code {
+        *response.status_mut() = code;
+    }
+    Ok(response)
 }
 
-/// Perform spam analysis on contract metadata
+/// Check every chain of a validated batch and resolve its prediction settings
+///
+/// Every chain of a mixed batch must pass the same checks as a single-chain
+/// request. Chain-specific model and prompt defaults make the settings per chain.
+///
+/// # Errors
+///
+/// Returns `ServerError` if a chain is disabled, lacks token metadata for a
+/// token request, or a pinned version is malformed or not registered.
+fn batch_prediction_settings(
+    state: &ServerState,
+    contract_status: &ContractStatusRequest,
+    query: &ContractStatusQuery,
+    locale: Locale,
+) -> Result<HashMap<ChainId, PredictionSettings>, ServerError> {
+    let chain_ids = contract_status.chains();
+    for &chain_id in &chain_ids {
+        ensure_chain_enabled(state, chain_id)?;
+        if contract_status.token_id.is_some() {
+            ensure_token_metadata_supported(state, chain_id)?;
+        }
+    }
+
+    for &chain_id in &chain_ids {
+        crate::metrics::inc_requests_by_chain(chain_id);
+    }
+
+    let (model_version, prompt_version) = contract_status
+        .pinned_versions()
+        .map_err(ServerError::ValidationError)?;
+    chain_ids
+        .iter()
+        .map(|&chain_id| {
+            let settings = PredictionSettings {
+                token_id: contract_status.token_id.clone(),
+                ..PredictionSettings::for_query(state, chain_id, query, locale)
+            }
+            .with_pinned_versions(
+                model_version.as_ref(),
+                prompt_version.as_ref(),
+                state.spam_predictor(),
+            )?;
+            Ok((chain_id, settings))
+        })
+        .collect()
+}
+
+/// Analyze the spam status of a single contract addressed by path
+///
+/// A `GET` counterpart of [`contract_status_handler`] for quick lookups and
+/// cacheable URLs, returning the bare result of the one address.
+///
+/// # Errors
+///
+/// Returns `ServerError` if the chain ID or address is malformed, the chain is
+/// disabled, or debug output is requested without the admin API key.
+#[utoipa::path(
+    get,
+    path = "/v1/chains/{chain_id}/contracts/{address}/status",
+    tag = "contracts",
+    summary = "Analyze the spam status of one contract",
+    description = "Analyzes a single contract address taken from the path with the same pipeline as `POST /v1/contract/status`, returning the result for that address without the surrounding map.",
+    params(
+        ("chain_id" = String, Path, description = "Chain ID (e.g. `1` for Ethereum)", example = "1"),
+        ("address" = String, Path, description = "Contract address", example = "0x1234567890123456789012345678901234567890")
+    ),
+    responses(
+        (status = 200, description = "Contract analysis completed (with `status_to_http_code`, 202 for inconclusive, 404 for no data, 422 for spam and 502 for errors)", body = ContractStatusResult,
+            headers(
+                ("x-spam-status" = String, description = "Status of the contract"),
+                ("content-language" = String, description = "Language of the `message` field, negotiated from `Accept-Language` (`en` or `es`, defaults to `en`)"),
+                ("retry-after" = u64, description = "Seconds to wait before retrying, when a data provider rate limited the lookup"),
+                ("x-debug-query" = String, description = "Pinax SQL executed for the address, only with `debug=true` and a valid admin API key"),
+                ("x-debug-config-source" = String, description = "Whether Pinax used a chain override or the global configuration for the chain (e.g. `1=chain_override`), only with `debug=true` and a valid admin API key")
+            )
+        ),
+        (status = 400, description = "Unsupported chain or malformed address", body = ErrorResponse),
+        (status = 403, description = "Debug output requested without a valid admin API key", body = ErrorResponse),
+        (status = 404, description = "Chain is supported but disabled in this deployment", body = ErrorResponse),
+        (status = 408, description = "Analysis exceeded the configured request timeout; the `Retry-After` header suggests when to retry", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded - too many requests", body = ErrorResponse),
+        (status = 500, description = "Internal server error during analysis", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, query, request_headers))]
+pub async fn contract_status_by_path_handler(
+    State(state): State<ServerState>,
+    Path((chain_id, address)): Path<(String, String)>,
+    Query(query): Query<ContractStatusQuery>,
+    request_headers: HeaderMap,
+    AcceptLanguage(locale): AcceptLanguage,
+) -> Result<Response, ServerError> {
+    let chain_id: ChainId = chain_id
+        .parse()
+        .map_err(|e| ServerError::ValidationError(format!("Invalid chain ID {chain_id}: {e}")))?;
+    let address: Address = address.parse().map_err(|e| {
+        ServerError::ValidationError(format!("Invalid contract address {address}: {e}"))
+    })?;
+
+    if query.debug && !is_admin_request(&state, &request_headers) {
+        warn!("rejected contract status request with debug: missing or invalid admin API key");
+        return Err(ServerError::Forbidden(
+            "debug requires a valid admin API key".to_string(),
+        ));
+    }
+    ensure_chain_enabled(&state, chain_id)?;
+    crate::metrics::inc_requests_by_chain(chain_id);
+
+    let config = state.config();
+    let prediction_settings = PredictionSettings::for_query(&state, chain_id, &query, locale);
+    let mut result = analyze_address(
+        address,
+        chain_id,
+        state.api_registry(),
+        None,
+        state.spam_predictor(),
+        &prediction_settings,
+        AnalysisDeadlines::start(config, None),
+        tokio::time::Instant::now(),
+    )
+    .await;
+    if !config.exposes_error_details() {
+        redact_error_detail(&mut result);
+    }
+
+    let results = BTreeMap::from([(address, result)]);
+    let verdict_code = config
+        .status_to_http_code
+        .then(|| single_verdict_status_code(&results))
+        .flatten();
+    let mut response_headers = spam_status_header(&results);
+    if query.debug {
+        append_debug_query_headers(
+            &mut response_headers,
+            state.api_registry(),
+            chain_id,
+            &results,
+        );
+    }
+    insert_result_headers(&mut response_headers, &results, locale);
+    response_headers.insert(VARY, HeaderValue::from_static("accept-language"));
+
+    let mut response = (response_headers, Json(&results[&address])).into_response();
+    if let Some(code) = verdict_code {
+        *response.status_mut() = code;
+    }
+    Ok(response)
+}
+
+/// Insert the `Retry-After` and `Content-Language` headers of a contract status response
+fn insert_result_headers(
+    headers: &mut HeaderMap,
+    results: &BTreeMap<Address, ContractStatusResult>,
+    locale: Locale,
+) {
+    // Let clients back off as long as the most restrictive rate-limited provider asked
+    if let Some(retry_after) = results.values().filter_map(|r| r.retry_after_seconds).max() {
+        headers.insert(RETRY_AFTER, HeaderValue::from(retry_after));
+    }
+    headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.code()));
+}
+
+/// Header carrying a Pinax SQL query sent while answering a debug request
+pub const DEBUG_QUERY_HEADER: &str = "x-debug-query";
+
+/// Header telling, per chain, whether Pinax used a chain override or the global configuration
+pub const DEBUG_CONFIG_SOURCE_HEADER: &str = "x-debug-config-source";
+
+/// Append one `X-Debug-Query` header per distinct Pinax query sent for the
+/// chain's `results` and the chain's `X-Debug-Config-Source` (e.g.
+/// `1=chain_override`) when Pinax is registered
+///
+/// Addresses answered by the cache or other providers without calling Pinax
+/// add no query, and addresses of one batched lookup share theirs.
+fn append_debug_query_headers(
+    headers: &mut HeaderMap,
+    api_registry: &ApiRegistry,
+    chain_id: ChainId,
+    results: &BTreeMap<Address, ContractStatusResult>,
+) {
+    if let Some(source) = api_registry.pinax_config_source(chain_id) {
+        let value = format!("{}={}", chain_id.chain_id(), source.as_str());
+        headers.append(
+            DEBUG_CONFIG_SOURCE_HEADER,
+            HeaderValue::from_str(&value).expect("chain id and source name are valid header text"),
+        );
+    }
+    let mut sent = Vec::new();
+    for result in results
+        .values()
+        .filter(|result| result.chain_id == chain_id)
+    {
+        let Some(query) = result.pinax_query.as_deref() else {
+            continue;
+        };
+        if sent.contains(&query) {
+            continue;
+        }
+        sent.push(query);
+        match HeaderValue::from_str(query) {
+            Ok(value) => {
+                headers.append(DEBUG_QUERY_HEADER, value);
+            }
+            Err(e) => warn!(error = %e, "pinax debug query is not a valid header value"),
+        }
+    }
+}
+
+/// Whether the request carries the configured admin API key
+///
+/// The comparison runs in constant time.
+fn is_admin_request(state: &ServerState, headers: &HeaderMap) -> bool {
+    state
+        .config()
+        .admin_api_key
+        .as_ref()
+        .is_some_and(|admin_key| {
+            headers.get(ADMIN_API_KEY_HEADER).is_some_and(|provided| {
+                provided
+                    .as_bytes()
+                    .ct_eq(admin_key.value().as_bytes())
+                    .into()
+            })
+        })
+}
+
+/// Result for an address still being analyzed when the latency budget ran out
+#[allow(clippy::cast_possible_truncation)]
+fn slo_exceeded_result(
+    chain_id: ChainId,
+    budget: Duration,
+    locale: Locale,
+) -> ContractStatusResult {
+    ContractStatusResult {
+        chain_id,
+        status: ContractSpamStatus::Error,
+        message: locale.latency_budget_exceeded(chain_id.name(), budget.as_millis()),
+        reasoning: Some("Latency budget exceeded".to_string()),
+        error_code: Some(ErrorCategory::Timeout),
+        retry_after_seconds: None,
+        distribution: None,
+        confidence: None,
+        confidence_band: None,
+        slo_exceeded: true,
+        providers_consulted: 0,
+        processing_time_ms: Some(budget.as_millis() as u64),
+        cached: false,
+        raw_model_response: None,
+        metadata: None,
+        pinax_query: None,
+    }
+}
+
+/// Header carrying the verdict of single-contract responses
+pub const SPAM_STATUS_HEADER: &str = "x-spam-status";
+
+/// Build the `X-Spam-Status` header when the response covers exactly one contract
+fn spam_status_header(results: &BTreeMap<Address, ContractStatusResult>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let [result] = results.values().collect::<Vec<_>>().as_slice() {
+        headers.insert(
+            SPAM_STATUS_HEADER,
+            HeaderValue::from_static(result.status.as_str()),
+        );
+    }
+    headers
+}
+
+/// HTTP status code reflecting the verdict of a single-contract response
+///
+/// Batch responses always use `200 OK`.
+fn single_verdict_status_code(
+    results: &BTreeMap<Address, ContractStatusResult>,
+) -> Option<StatusCode> {
+    let [result] = results.values().collect::<Vec<_>>()[..] else {
+        return None;
+    };
+    Some(match result.status {
+        ContractSpamStatus::Legitimate => StatusCode::OK,
+        ContractSpamStatus::Inconclusive => StatusCode::ACCEPTED,
+        ContractSpamStatus::NoData => StatusCode::NOT_FOUND,
+        ContractSpamStatus::Spam => StatusCode::UNPROCESSABLE_ENTITY,
+        ContractSpamStatus::Error
+        | ContractSpamStatus::MetadataFetchError
+        | ContractSpamStatus::PredictorError
+        | ContractSpamStatus::PredictorUnavailable => StatusCode::BAD_GATEWAY,
+    })
+}
+
+/// Generic reasoning returned for failed results when error detail is hidden
+pub const REDACTED_ERROR_REASONING: &str = "analysis failed, see error_code";
+
+/// Replace raw error detail with a generic message, keeping only the error code
+fn redact_error_detail(result: &mut ContractStatusResult) {
+    if result.error_code.is_some() {
+        result.reasoning = Some(REDACTED_ERROR_REASONING.to_string());
+    }
+}
+
+/// Frame sent by a client over the contract status WebSocket
+///
+/// Each frame is a JSON text message tagged by `type`.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schema(example = json!({
+    "type": "subscribe",
+    "chain_id": 1,
+    "addresses": ["0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d"]
+}))]
+pub enum StatusStreamRequest {
+    /// Analyze a batch of addresses, taking the same fields and limits as a
+    /// `POST /v1/contract/status` body
+    Subscribe(ContractStatusRequest),
+}
+
+/// Frame sent by the server over the contract status WebSocket
+///
+/// An accepted subscription answers with `subscribed`, then one `result` per
+/// address in order of completion, then `complete`. A rejected subscription or
+/// malformed frame answers with `error`.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schema(example = json!({
+    "type": "result",
+    "subscription_id": 1,
+    "address": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+    "result": {
+        "chain_id": 1,
+        "status": "legitimate",
+        "message": "contract metadata found on Ethereum, AI analysis classified as legitimate",
+        "processing_time_ms": 150,
+        "cached": false
+    }
+}))]
+pub enum StatusStreamMessage {
+    /// The subscription was accepted and its addresses are being analyzed
+    Subscribed {
+        /// Identifier of the subscription, counting from 1 per connection
+        subscription_id: u64,
+        /// Number of addresses that will be reported
+        addresses: usize,
+    },
+    /// Analysis of one address completed
+    Result {
+        /// Subscription the address belongs to
+        subscription_id: u64,
+        /// Analyzed contract address
+        #[schema(value_type = String)]
+        address: Address,
+        /// Analysis result, as in the contract status response
+        result: ContractStatusResult,
+    },
+    /// Every address of the subscription was reported
+    Complete {
+        /// Subscription that completed
+        subscription_id: u64,
+    },
+    /// A frame was malformed or a subscription was rejected
+    Error {
+        /// Subscription that was rejected, absent for frames that did not parse
+        #[serde(skip_serializing_if = "Option::is_none")]
+        subscription_id: Option<u64>,
+        /// Machine-readable error code, as in error responses
+        error: String,
+        /// Human-readable description of the error
+        message: String,
+        /// Seconds until the client may subscribe again, set for `rate_limited`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        retry_after_seconds: Option<u64>,
+    },
+}
+
+impl StatusStreamMessage {
+    /// Error frame reporting `error`
+    fn error(subscription_id: Option<u64>, error: &ServerError) -> Self {
+        Self::Error {
+            subscription_id,
+            error: error.code().to_string(),
+            message: error.to_string(),
+            retry_after_seconds: match error {
+                ServerError::RateLimited {
+                    retry_after_seconds,
+                } => Some(*retry_after_seconds),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Stream contract status results over a WebSocket
+///
+/// Clients send `subscribe` frames carrying a contract status request and
+/// receive each address's result as soon as its analysis completes, instead of
+/// waiting for the whole batch. Query parameters apply to every subscription
+/// of the connection. Subscriptions are processed one at a time, in order.
+/// Each one takes a token from the client IP's rate limit bucket, and a
+/// connection accepts at most `rate_limiting.max_subscriptions_per_connection`.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` if debug output is requested without a
+/// valid admin API key.
+#[utoipa::path(
+    get,
+    path = "/v1/ws/status",
+    tag = "contracts",
+    summary = "Stream contract status results",
+    description = "Upgrades to a WebSocket over which clients send `StatusStreamRequest` frames and receive `StatusStreamMessage` frames. A `subscribe` frame takes the same body and limits as `POST /v1/contract/status`; the server answers with `subscribed`, one `result` per address as its analysis completes, and `complete`. Every `subscribe` frame is rate limited like an HTTP request, limited ones being answered with a `rate_limited` error frame carrying `retry_after_seconds`, and a connection accepts at most `rate_limiting.max_subscriptions_per_connection` subscriptions. Rejected subscriptions and malformed frames are answered with an `error` frame and the connection stays open. Useful for large batches where waiting for the slowest address is undesirable.",
+    responses(
+        (status = 101, description = "Switched to the WebSocket protocol"),
+        (status = 400, description = "The request is not a WebSocket upgrade"),
+        (status = 403, description = "Debug output requested without a valid admin API key", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded - too many requests", body = ErrorResponse)
+    )
+)]
+pub async fn contract_status_ws_handler(
+    State(state): State<ServerState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Query(query): Query<ContractStatusQuery>,
+    request_headers: HeaderMap,
+    AcceptLanguage(locale): AcceptLanguage,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, ServerError> {
+    if query.debug && !is_admin_request(&state, &request_headers) {
+        warn!("rejected contract status stream with debug: missing or invalid admin API key");
+        return Err(ServerError::Forbidden(
+            "debug requires a valid admin API key".to_string(),
+        ));
+    }
+
+    let client_ip = addr.ip();
+    Ok(upgrade
+        .on_upgrade(move |socket| stream_contract_status(socket, state, client_ip, query, locale)))
+}
+
+/// Serve subscriptions of one WebSocket connection until the client leaves
+async fn stream_contract_status(
+    mut socket: WebSocket,
+    state: ServerState,
+    client_ip: IpAddr,
+    query: ContractStatusQuery,
+    locale: Locale,
+) {
+    let max_subscriptions = u64::from(
+        state
+            .config()
+            .rate_limiting
+            .max_subscriptions_per_connection,
+    );
+    let mut next_subscription_id = 1;
+    while let Some(Ok(frame)) = socket.recv().await {
+        let text = match frame {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            // Pings are answered by the WebSocket implementation
+            Message::Ping(_) | Message::Pong(_) => continue,
+            Message::Binary(_) => {
+                let error = ServerError::JsonError {
+                    message: "frames must be JSON text".to_string(),
+                };
+                if send_frame(&mut socket, &StatusStreamMessage::error(None, &error))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let sent = match serde_json::from_str::<StatusStreamRequest>(&text) {
+            Ok(StatusStreamRequest::Subscribe(request)) => {
+                let subscription_id = next_subscription_id;
+                next_subscription_id += 1;
+                let admitted = if subscription_id > max_subscriptions {
+                    Err(ServerError::ValidationError(format!(
+                        "a connection accepts at most {max_subscriptions} subscriptions"
+                    )))
+                } else {
+                    state.rate_limiter().check_client(client_ip)
+                };
+                match admitted {
+                    Ok(()) => {
+                        stream_subscription(
+                            &mut socket,
+                            &state,
+                            &query,
+                            locale,
+                            subscription_id,
+                            request,
+                        )
+                        .await
+                    }
+                    Err(e) => {
+                        send_frame(
+                            &mut socket,
+                            &StatusStreamMessage::error(Some(subscription_id), &e),
+                        )
+                        .await
+                    }
+                }
+            }
+            Err(e) => {
+                let error = ServerError::JsonError {
+                    message: e.to_string(),
+                };
+                send_frame(&mut socket, &StatusStreamMessage::error(None, &error)).await
+            }
+        };
+        if let Err(e) = sent {
+            debug!(error = %e, "contract status stream closed while sending");
+            break;
+        }
+    }
+}
+
+/// Analyze the addresses of one subscription, sending each result as it completes
+///
+/// Returning early when the client is gone drops the analysis stream, which
+/// aborts the outstanding upstream calls.
+async fn stream_subscription(
+    socket: &mut WebSocket,
+    state: &ServerState,
+    query: &ContractStatusQuery,
+    locale: Locale,
+    subscription_id: u64,
+    request: ContractStatusRequest,
+) -> Result<(), axum::Error> {
+    let config = state.config();
+    let prediction_settings = match request
+        .validate(
+            config.rate_limiting.max_addresses as usize,
+            config.rate_limiting.max_chains as usize,
+        )
+        .map_err(ServerError::ValidationError)
+        .and_then(|()| batch_prediction_settings(state, &request, query, locale))
+    {
+        Ok(settings) => settings,
+        Err(e) => {
+            return send_frame(
+                socket,
+                &StatusStreamMessage::error(Some(subscription_id), &e),
+            )
+            .await;
+        }
+    };
+
+    let addresses = request.resolved_addresses();
+    send_frame(
+        socket,
+        &StatusStreamMessage::Subscribed {
+            subscription_id,
+            addresses: addresses.len(),
+        },
+    )
+    .await?;
+
+    let max_concurrency = config.concurrency.max_concurrent_external_api_calls as usize;
+    let deadlines = AnalysisDeadlines::start(config, request.max_latency_ms);
+    let redact = !config.exposes_error_details();
+    let mut cancellation_guard = BatchCancellationGuard::new(request.chains(), addresses.len());
+    let prediction_settings = &prediction_settings;
+    let mut results = stream::iter(addresses)
+        .map(|(address, chain_id)| {
+            let api_registry = state.api_registry().clone();
+            let spam_predictor = state.spam_predictor().clone();
+
+            async move {
+                let result = analyze_address(
+                    address,
+                    chain_id,
+                    &api_registry,
+                    None,
+                    &spam_predictor,
+                    &prediction_settings[&chain_id],
+                    deadlines,
+                    tokio::time::Instant::now(),
+                )
+                .await;
+                (address, result)
+            }
+        })
+        .buffer_unordered(max_concurrency);
+
+    while let Some((address, mut result)) = results.next().await {
+        if redact {
+            redact_error_detail(&mut result);
+        }
+        send_frame(
+            socket,
+            &StatusStreamMessage::Result {
+                subscription_id,
+                address,
+                result,
+            },
+        )
+        .await?;
+    }
+    cancellation_guard.complete();
+
+    send_frame(socket, &StatusStreamMessage::Complete { subscription_id }).await
+}
+
+/// Send a frame as JSON text
+async fn send_frame(
+    socket: &mut WebSocket,
+    message: &StatusStreamMessage,
+) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text.into())).await
+}
+
+/// Collection score request payload
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CollectionScoreRequest {
+    /// Blockchain chain identifier
+    #[schema(example = 1)]
+    chain_id: ChainId,
+    /// Collection contract address
+    #[schema(value_type = String, example = "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d")]
+    address: Address,
+    /// Number of tokens to sample and classify. Defaults to the server's
+    /// `collection_score.default_sample_size` and is capped by
+    /// `collection_score.max_sample_size`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 10)]
+    sample_size: Option<u16>,
+    /// Latency budget for the whole request in milliseconds; tokens still
+    /// being classified when it runs out count as errors. Defaults to the
+    /// server's `concurrency.max_latency_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 5000)]
+    max_latency_ms: Option<u64>,
+}
+
+impl CollectionScoreRequest {
+    /// Validates the optional limits of the request
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.sample_size == Some(0) {
+            return Err("sample_size must be greater than 0");
+        }
+        if self.max_latency_ms == Some(0) {
+            return Err("max_latency_ms must be greater than 0");
+        }
+        Ok(())
+    }
+}
+
+/// Verdict for one sampled token
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TokenVerdict {
+    /// Decimal token ID
+    #[schema(example = "1234")]
+    pub token_id: String,
+    /// Spam classification of the token
+    pub status: ContractSpamStatus,
+}
+
+/// Aggregate spam score of a collection
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "chain_id": 1,
+    "address": "0xbc4ca0eda7647a8ab7c2061c2e118a18a936f13d",
+    "spam_likelihood": 0.25,
+    "distribution": {
+        "total": 4,
+        "spam": 1,
+        "legitimate": 3,
+        "inconclusive": 0,
+        "no_data": 0,
+        "error": 0
+    },
+    "tokens": [
+        { "token_id": "1", "status": "legitimate" },
+        { "token_id": "2", "status": "spam" },
+        { "token_id": "3", "status": "legitimate" },
+        { "token_id": "4", "status": "legitimate" }
+    ]
+}))]
+pub struct CollectionScoreResponse {
+    /// Blockchain chain identifier
+    #[schema(value_type = u64)]
+    pub chain_id: ChainId,
+    /// Collection contract address
+    #[schema(value_type = String)]
+    pub address: Address,
+    /// Share of classified tokens (spam, legitimate or inconclusive) that are
+    /// spam; `null` when no sampled token could be classified
+    pub spam_likelihood: Option<f64>,
+    /// Number of sampled tokens per verdict
+    pub distribution: ContractStatusSummary,
+    /// Verdict of every sampled token
+    pub tokens: Vec<TokenVerdict>,
+}
+
+impl CollectionScoreResponse {
+    /// Aggregate the verdicts of the sampled tokens
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_verdicts(chain_id: ChainId, address: Address, tokens: Vec<TokenVerdict>) -> Self {
+        let mut distribution = ContractStatusSummary {
+            total: tokens.len(),
+            ..ContractStatusSummary::default()
+        };
+        for token in &tokens {
+            distribution.count(&token.status);
+        }
+        let classified = distribution.spam + distribution.legitimate + distribution.inconclusive;
+        let spam_likelihood =
+            (classified > 0).then(|| distribution.spam as f64 / classified as f64);
+
+        Self {
+            chain_id,
+            address,
+            spam_likelihood,
+            distribution,
+            tokens,
+        }
+    }
+}
+
+/// Collection spam score
+///
+/// Samples tokens of a collection, classifies each of them and aggregates the
+/// verdicts into a collection spam likelihood.
+///
+/// # Errors
+///
+/// Returns `ServerError` if the request is invalid, the chain is disabled, no
+/// provider serves token metadata or token sampling fails.
+#[utoipa::path(
+    post,
+    path = "/v1/collection/score",
+    tag = "contracts",
+    summary = "Score a collection from sampled tokens",
+    description = "Samples up to `sample_size` tokens of a collection, classifies each token individually and returns the share of classified tokens that are spam together with the distribution of token verdicts. Requires a provider with token-level metadata (currently Moralis).",
+    request_body = CollectionScoreRequest,
+    responses(
+        (status = 200, description = "Collection scored successfully", body = CollectionScoreResponse),
+        (status = 400, description = "Invalid request - zero or too large `sample_size`, unsupported chain, or malformed address", body = ErrorResponse),
+        (status = 404, description = "Chain is supported but disabled in this deployment", body = ErrorResponse),
+        (status = 501, description = "No provider serves token-level metadata", body = ErrorResponse),
+        (status = 503, description = "Token sampling failed", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, request), fields(
+    chain_id = %request.chain_id,
+    address = %request.address
+))]
+pub async fn collection_score_handler(
+    State(state): State<ServerState>,
+    JsonExtractor(request): JsonExtractor<CollectionScoreRequest>,
+) -> Result<Json<CollectionScoreResponse>, ServerError> {
+    request
+        .validate()
+        .map_err(|msg| ServerError::ValidationError(msg.to_string()))?;
+
+    let CollectionScoreRequest {
+        chain_id, address, ..
+    } = request;
+    let config = state.config();
+    let sample_size = request
+        .sample_size
+        .unwrap_or(config.collection_score.default_sample_size);
+    if sample_size > config.collection_score.max_sample_size {
+        return Err(ServerError::ValidationError(format!(
+            "sample_size cannot exceed {}",
+            config.collection_score.max_sample_size
+        )));
+    }
+    ensure_chain_enabled(&state, chain_id)?;
+    ensure_token_metadata_supported(&state, chain_id)?;
+    crate::metrics::inc_requests_by_chain(chain_id);
+
+    let slo_deadline = request
+        .max_latency_ms
+        .or(config.concurrency.max_latency_ms)
+        .map(|budget| tokio::time::Instant::now() + Duration::from_millis(budget));
+    let tokens = state
+        .api_registry()
+        .sample_tokens(address, chain_id, u32::from(sample_size))
+        .await
+        .map_err(|e| {
+            error!(%address, chain = chain_id.name(), error = %e, "failed to sample collection tokens");
+            ServerError::Dependency {
+                message: if config.exposes_error_details() {
+                    format!("token sampling failed: {e}")
+                } else {
+                    format!("token sampling failed: {}", e.category().as_str())
+                },
+            }
+        })?;
+
+    let prediction_settings = PredictionSettings {
+        model_spec: config.spam_predictor.model_spec_for(chain_id),
+        prompt_version: config.spam_predictor.prompt_version_for(chain_id),
+        distribution: false,
+        confidence: ConfidenceFormat::default(),
+        confidence_band_thresholds: config.spam_predictor.confidence_band_thresholds,
+        locale: Locale::default(),
+        flags: state.feature_flags().current(),
+        token_id: None,
+        include_raw_response: false,
+    };
+    let individual_timeout = config.concurrency.per_address_timeout();
+    let spam_predictor = state.spam_predictor();
+    let prediction_settings = &prediction_settings;
+
+    // Order is kept so the verdicts follow the sampled tokens
+    let verdicts: Vec<TokenVerdict> = stream::iter(tokens)
+        .map(|token| async move {
+            let token_deadline = tokio::time::Instant::now() + individual_timeout;
+            let deadline = slo_deadline.map_or(token_deadline, |d| d.min(token_deadline));
+            let metadata = token.to_classification_metadata();
+            let analysis = timeout_at(
+                deadline,
+                perform_spam_analysis(&metadata, spam_predictor, prediction_settings, address),
+            )
+            .await;
+            let status = if let Ok(analysis) = analysis {
+                analysis.status
+            } else {
+                warn!(%address, token_id = token.token_id, "token classification timed out");
+                ContractSpamStatus::Error
+            };
+            TokenVerdict {
+                token_id: token.token_id,
+                status,
+            }
+        })
+        .buffered(usize::from(config.concurrency.max_concurrent_spam_analysis))
+        .collect()
+        .await;
+
+    let response = CollectionScoreResponse::from_verdicts(chain_id, address, verdicts);
+    info!(
+        chain_id = %chain_id,
+        %address,
+        sampled = response.distribution.total,
+        spam = response.distribution.spam,
+        spam_likelihood = ?response.spam_likelihood,
+        "collection score completed"
+    );
+    Ok(Json(response))
+}
+
+/// Reject requests for chains that are supported but disabled in configuration
+fn ensure_chain_enabled(state: &ServerState, chain_id: ChainId) -> Result<(), ServerError> {
+    if state
+        .config()
+        .chains
+        .get(&chain_id)
+        .is_some_and(|chain_config| chain_config.enabled)
+    {
+        Ok(())
+    } else {
+        warn!(chain_id = %chain_id, "rejected request for disabled chain");
+        Err(ChainValidationError::disabled_chain(chain_id).into())
+    }
+}
+
+/// Reject token-level requests unless both the chain and a registered
+/// provider support token metadata
+fn ensure_token_metadata_supported(
+    state: &ServerState,
+    chain_id: ChainId,
+) -> Result<(), ServerError> {
+    if chain_id.supports_capability(ChainCapability::TokenMetadata)
+        && state.api_registry().supports_token_metadata()
+    {
+        Ok(())
+    } else {
+        warn!(chain_id = %chain_id, "rejected token request without token metadata support");
+        Err(
+            ChainValidationError::unsupported_capability(chain_id, ChainCapability::TokenMetadata)
+                .into(),
+        )
+    }
+}
+
+/// Reasoning returned for contracts analyzed while spam prediction is disabled
+pub const PREDICTOR_DISABLED_REASONING: &str = "spam prediction is disabled";
+
+/// Perform spam analysis on contract metadata
 ///
 /// Returns a `SpamAnalysisResult` containing the spam classification and analysis message
+#[allow(clippy::too_many_lines)]
 #[instrument(skip(metadata, spam_predictor), fields(
     contract_address = %contract_address
 ))]
 async fn perform_spam_analysis(
-    metadata: &api_client::ContractMetadata,
+    metadata: &ContractMetadata,
     spam_predictor: &Arc<SpamPredictor>,
+    prediction_settings: &PredictionSettings,
     contract_address: Address,
 ) -> SpamAnalysisResult {
     let start_time = std::time::Instant::now();
+    if !prediction_settings.flags.predictor_enabled {
+        debug!(contract_address = %contract_address, "spam prediction disabled, skipping model");
+        return SpamAnalysisResult {
+            status: ContractSpamStatus::Inconclusive,
+            message: ContractSpamStatus::Inconclusive
+                .localized_message(prediction_settings.locale)
+                .to_owned(),
+            reasoning: Some(PREDICTOR_DISABLED_REASONING.to_string()),
+            error_code: None,
+            distribution: None,
+            confidence: None,
+            confidence_band: None,
+            processing_time_ms: None,
+            cached: false,
+            raw_model_response: None,
+            metadata: None,
+        };
+    }
     debug!(contract_address = %contract_address, "starting ai spam prediction");
 
     // Create typed prediction request
-    let request = spam_predictor::SpamPredictionRequest::spam_classification(metadata.clone());
+    let request = SpamPredictionRequest::new(
+        metadata.clone(),
+        prediction_settings.model_spec.clone(),
+        prediction_settings.prompt_version.clone(),
+    )
+    .with_distribution(prediction_settings.distribution);
 
     let result = match spam_predictor.predict_spam_typed(request).await {
         Ok(prediction_result) => {
@@ -661,13 +2619,31 @@ async fn perform_spam_analysis(
                 "ai analysis completed"
             );
 
-            let message = status.default_message().to_owned();
+            let message = status
+                .localized_message(prediction_settings.locale)
+                .to_owned();
+            let confidence = prediction_result.confidence();
+            let explain = !status.is_spam() || prediction_settings.flags.explain_on_spam;
             SpamAnalysisResult {
                 status,
                 message,
-                reasoning: prediction_result.reasoning().map(ToString::to_string),
+                reasoning: prediction_result
+                    .reasoning()
+                    .filter(|_| explain)
+                    .map(ToString::to_string),
+                error_code: None,
+                distribution: prediction_result.distribution().cloned(),
+                confidence: (prediction_settings.confidence != ConfidenceFormat::Band)
+                    .then(|| confidence.as_f64()),
+                confidence_band: (prediction_settings.confidence != ConfidenceFormat::Score)
+                    .then(|| confidence.band(&prediction_settings.confidence_band_thresholds)),
                 processing_time_ms: Some(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)),
                 cached: prediction_result.is_cached(),
+                raw_model_response: prediction_result
+                    .raw_response()
+                    .filter(|_| prediction_settings.include_raw_response)
+                    .map(ToString::to_string),
+                metadata: None,
             }
         }
         Err(e) => {
@@ -682,12 +2658,32 @@ async fn perform_spam_analysis(
                 "spam prediction failed"
             );
             crate::metrics::observe_spam_predictor_duration("error", duration_f64);
+            let return_metadata = prediction_settings
+                .flags
+                .return_metadata_on_predictor_failure;
+            let status = if return_metadata {
+                ContractSpamStatus::PredictorUnavailable
+            } else {
+                ContractSpamStatus::PredictorError
+            };
             SpamAnalysisResult {
-                status: ContractSpamStatus::Error,
-                message: "prediction failed".to_string(),
+                message: if return_metadata {
+                    status
+                        .localized_message(prediction_settings.locale)
+                        .to_owned()
+                } else {
+                    prediction_settings.locale.prediction_failed().to_string()
+                },
+                status,
                 reasoning: Some(format!("Prediction error: {e}")),
+                error_code: Some(ErrorCategory::Upstream),
+                distribution: None,
+                confidence: None,
+                confidence_band: None,
                 processing_time_ms: Some(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)),
                 cached: false,
+                raw_model_response: None,
+                metadata: return_metadata.then(|| ContractMetadataSummary::from(metadata)),
             }
         }
     };
@@ -709,23 +2705,33 @@ async fn perform_spam_analysis(
     examples(
         json!({
             "name": "Ethereum",
-            "id": 1
+            "id": 1,
+            "status": "fully_supported",
+            "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
         }),
         json!({
             "name": "Polygon",
-            "id": 137
+            "id": 137,
+            "status": "fully_supported",
+            "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
         }),
         json!({
             "name": "Base",
-            "id": 8453
+            "id": 8453,
+            "status": "fully_supported",
+            "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
         }),
         json!({
             "name": "Avalanche",
-            "id": 43114
+            "id": 43114,
+            "status": "fully_supported",
+            "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
         }),
         json!({
             "name": "Arbitrum",
-            "id": 42161
+            "id": 42161,
+            "status": "fully_supported",
+            "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
         })
     )
 )]
@@ -736,6 +2742,49 @@ pub struct ChainInfo {
     /// Numeric chain identifier
     #[schema(example = 1)]
     pub id: u64,
+    /// Support status (`fully_supported`, `partially_supported`, `planned` or `deprecated`)
+    #[schema(example = "fully_supported")]
+    pub status: String,
+    /// Capabilities available on the chain (e.g. `moralis_metadata`, `spam_prediction`)
+    #[schema(example = json!(["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]))]
+    pub capabilities: Vec<String>,
+    /// Whether the chain is enabled in configuration (admin view only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = true)]
+    pub enabled: Option<bool>,
+    /// Implementation status of the chain (admin view only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub implementation_status: Option<ChainImplementationStatus>,
+}
+
+impl ChainInfo {
+    /// Public description of `chain_id`, without the admin-only fields
+    fn new(chain_id: ChainId) -> Self {
+        Self {
+            name: chain_id.name().to_string(),
+            id: chain_id.chain_id(),
+            status: chain_id.support_status().to_string(),
+            capabilities: chain_id
+                .capabilities()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            enabled: None,
+            implementation_status: None,
+        }
+    }
+}
+
+/// Header carrying the admin API key for admin-only views
+pub const ADMIN_API_KEY_HEADER: &str = "x-admin-api-key";
+
+/// Query parameters for the chains endpoint
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ChainsQuery {
+    /// Include chains disabled in configuration (admin only, requires the `x-admin-api-key` header)
+    #[serde(default)]
+    pub include_disabled: bool,
 }
 
 /// Response from the chains endpoint
@@ -746,23 +2795,33 @@ pub struct ChainInfo {
             "chains": [
                 {
                     "name": "Ethereum",
-                    "id": 1
+                    "id": 1,
+                    "status": "fully_supported",
+                    "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                 },
                 {
                     "name": "Polygon",
-                    "id": 137
+                    "id": 137,
+                    "status": "fully_supported",
+                    "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                 },
                 {
                     "name": "Base",
-                    "id": 8453
+                    "id": 8453,
+                    "status": "fully_supported",
+                    "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                 },
                 {
                     "name": "Avalanche",
-                    "id": 43114
+                    "id": 43114,
+                    "status": "fully_supported",
+                    "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                 },
                 {
                     "name": "Arbitrum",
-                    "id": 42161
+                    "id": 42161,
+                    "status": "fully_supported",
+                    "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                 }
             ]
         })
@@ -775,50 +2834,553 @@ pub struct ChainsResponse {
 
 /// Get supported chains
 ///
-/// Returns a list of all blockchain networks supported by the API.
+/// Returns the blockchain networks enabled in configuration with their support
+/// status and capabilities. Admins can pass
+/// `include_disabled=true` to also list disabled chains, annotated with their
+/// enabled state and implementation status.
 #[utoipa::path(
     get,
     path = "/v1/chains",
     tag = "chains",
     summary = "Get supported chains",
-    description = "Returns a list of all supported blockchain networks with their names and chain IDs.",
+    description = "Returns a list of enabled blockchain networks with their names, chain IDs, support status and capabilities. \
+        Passing `include_disabled=true` together with a valid `x-admin-api-key` header also returns disabled chains, \
+        annotated with their enabled state and implementation status.",
     responses(
         (status = 200, description = "List of supported chains", body = ChainsResponse,
             example = json!({
                 "chains": [
                     {
                         "name": "Ethereum",
-                        "id": 1
+                        "id": 1,
+                        "status": "fully_supported",
+                        "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                     },
                     {
                         "name": "Polygon",
-                        "id": 137
+                        "id": 137,
+                        "status": "fully_supported",
+                        "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                     },
                     {
                         "name": "Base",
-                        "id": 8453
+                        "id": 8453,
+                        "status": "fully_supported",
+                        "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                     },
                     {
                         "name": "Avalanche",
-                        "id": 43114
+                        "id": 43114,
+                        "status": "fully_supported",
+                        "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                     },
                     {
                         "name": "Arbitrum",
-                        "id": 42161
+                        "id": 42161,
+                        "status": "fully_supported",
+                        "capabilities": ["moralis_metadata", "pinax_analytics", "spam_prediction", "realtime_updates", "token_metadata"]
                     }
                 ]
             })
-        )
+        ),
+        (status = 403, description = "Admin view requested without a valid admin API key", body = ErrorResponse)
     )
 )]
-pub async fn chains_handler() -> Result<impl IntoResponse, ServerError> {
+pub async fn chains_handler(
+    State(state): State<ServerState>,
+    Query(query): Query<ChainsQuery>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, ServerError> {
+    let config = state.config();
+
+    if query.include_disabled && !is_admin_request(&state, &headers) {
+        warn!("rejected chains request with include_disabled: missing or invalid admin API key");
+        return Err(ServerError::Forbidden(
+            "include_disabled requires a valid admin API key".to_string(),
+        ));
+    }
+
     let chains: Vec<ChainInfo> = ChainId::all()
         .iter()
-        .map(|&chain_id| ChainInfo {
-            name: chain_id.name().to_string(),
-            id: chain_id.chain_id(),
+        .filter_map(|&chain_id| {
+            let enabled = config
+                .chains
+                .get(&chain_id)
+                .is_some_and(|chain_config| chain_config.enabled);
+
+            if query.include_disabled {
+                Some(ChainInfo {
+                    enabled: Some(enabled),
+                    implementation_status: Some(chain_id.implementation_status()),
+                    ..ChainInfo::new(chain_id)
+                })
+            } else {
+                enabled.then(|| ChainInfo::new(chain_id))
+            }
         })
         .collect();
 
     Ok(Json(ChainsResponse { chains }))
 }
+
+/// Semantics of one contract spam status
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[allow(clippy::struct_excessive_bools)] // Mirrors the predicates of ContractSpamStatus
+pub struct StatusSemantics {
+    /// Status as it appears in the `status` field of contract results
+    pub status: ContractSpamStatus,
+    /// Whether the status counts as spam
+    pub is_spam: bool,
+    /// Whether the status counts as a legitimate contract
+    pub is_legitimate: bool,
+    /// Whether the analysis was inconclusive
+    pub is_inconclusive: bool,
+    /// Whether no data was found for the contract
+    pub is_no_data: bool,
+    /// Whether the status reports an error, whatever its origin
+    pub is_error: bool,
+    /// Default English message for the status
+    pub message: String,
+}
+
+/// Score range of one confidence band
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConfidenceBandRange {
+    /// Band as it appears in the `confidence_band` field of contract results
+    #[schema(value_type = String)]
+    pub band: ConfidenceBand,
+    /// Lowest score in the band (inclusive)
+    pub min_score: f64,
+    /// Score at which the next band starts (exclusive), absent for the highest band
+    pub max_score: Option<f64>,
+}
+
+/// Retry semantics of one error category
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ErrorCategorySemantics {
+    /// Category as it appears in the `error_code` field of contract results
+    #[schema(value_type = String)]
+    pub category: ErrorCategory,
+    /// Whether the failure is transient and the request worth retrying
+    pub retryable: bool,
+}
+
+/// Response from the schema endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "statuses": [
+            {
+                "status": "spam",
+                "is_spam": true,
+                "is_legitimate": false,
+                "is_inconclusive": false,
+                "is_no_data": false,
+                "is_error": false,
+                "message": "AI analysis classified as spam"
+            }
+        ],
+        "confidence_bands": [
+            {"band": "low", "min_score": 0.0, "max_score": 0.5},
+            {"band": "medium", "min_score": 0.5, "max_score": 0.8},
+            {"band": "high", "min_score": 0.8, "max_score": null}
+        ],
+        "error_categories": [
+            {"category": "network", "retryable": true},
+            {"category": "authentication", "retryable": false}
+        ]
+    })
+)]
+pub struct SchemaResponse {
+    /// Every contract spam status with its semantics
+    pub statuses: Vec<StatusSemantics>,
+    /// Confidence bands with the score ranges configured on this server
+    pub confidence_bands: Vec<ConfidenceBandRange>,
+    /// Every error category with its retry semantics
+    pub error_categories: Vec<ErrorCategorySemantics>,
+}
+
+/// Describe response semantics
+///
+/// Returns every contract spam status, the configured confidence band
+/// thresholds and the retryability of each error category, generated from the
+/// types and configuration the server uses, so clients need not hard-code them.
+#[utoipa::path(
+    get,
+    path = "/v1/schema",
+    tag = "contracts",
+    summary = "Describe response semantics",
+    description = "Returns a machine-readable description of every contract spam status and its `is_spam` semantics, \
+        the confidence band score ranges configured on this server, and whether each error category is retryable.",
+    responses(
+        (status = 200, description = "Response semantics", body = SchemaResponse)
+    )
+)]
+pub async fn schema_handler(State(state): State<ServerState>) -> Json<SchemaResponse> {
+    let thresholds = state.config().spam_predictor.confidence_band_thresholds;
+
+    let statuses = ContractSpamStatus::ALL
+        .into_iter()
+        .map(|status| StatusSemantics {
+            is_spam: status.is_spam(),
+            is_legitimate: status.is_legitimate(),
+            is_inconclusive: status.is_inconclusive(),
+            is_no_data: status.is_no_data(),
+            is_error: status.is_error(),
+            message: status.default_message().to_string(),
+            status,
+        })
+        .collect();
+
+    let confidence_bands = ConfidenceBand::ALL
+        .into_iter()
+        .map(|band| {
+            let (min_score, max_score) = thresholds.bounds(band);
+            ConfidenceBandRange {
+                band,
+                min_score,
+                max_score,
+            }
+        })
+        .collect();
+
+    let error_categories = ErrorCategory::ALL
+        .into_iter()
+        .map(|category| ErrorCategorySemantics {
+            category,
+            retryable: category.is_retryable(),
+        })
+        .collect();
+
+    Json(SchemaResponse {
+        statuses,
+        confidence_bands,
+        error_categories,
+    })
+}
+
+/// Registered versions of one model type
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ModelTypeInfo {
+    /// Model type, e.g. `spam_classification`
+    pub model_type: String,
+    /// Versions accepted as `model_version` in contract status requests, sorted
+    pub versions: Vec<String>,
+}
+
+/// Response from the models endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "model_types": [
+            {"model_type": "spam_classification", "versions": ["latest", "v0"]}
+        ],
+        "prompt_versions": ["1.0.0"],
+        "current_prompt_version": "1.0.0"
+    })
+)]
+pub struct ModelsResponse {
+    /// Model types loaded from the model registry, sorted by name
+    pub model_types: Vec<ModelTypeInfo>,
+    /// Versions accepted as `prompt_version` in contract status requests
+    pub prompt_versions: Vec<String>,
+    /// Prompt version used when a request pins none
+    pub current_prompt_version: String,
+}
+
+/// List available models and prompts
+///
+/// Returns the model types and versions, and the prompt versions, currently
+/// loaded by the spam predictor, so clients can discover valid values for the
+/// per-request `model_version` and `prompt_version` overrides.
+#[utoipa::path(
+    get,
+    path = "/v1/models",
+    tag = "contracts",
+    summary = "List available models and prompts",
+    description = "Lists the model types with their registered versions and the prompt versions currently loaded by the spam predictor, \
+        which are the values accepted by the `model_version` and `prompt_version` fields of contract status requests. \
+        Reflects registry reloads.",
+    responses(
+        (status = 200, description = "Loaded models and prompts", body = ModelsResponse)
+    )
+)]
+pub async fn models_handler(State(state): State<ServerState>) -> Json<ModelsResponse> {
+    let summary = state.spam_predictor().get_config_summary();
+
+    let model_types = summary
+        .model_versions
+        .into_iter()
+        .map(|(model_type, versions)| ModelTypeInfo {
+            model_type,
+            versions,
+        })
+        .collect();
+
+    Json(ModelsResponse {
+        model_types,
+        prompt_versions: summary.prompt_versions,
+        current_prompt_version: summary.current_prompt_version,
+    })
+}
+
+/// Per-provider request statistics collected by the API registry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(
+    example = json!({
+        "providers": [
+            {
+                "provider": "moralis",
+                "requests": 120,
+                "successes": 114,
+                "errors": 6,
+                "success_rate": 0.95,
+                "error_rate": 0.05,
+                "latency_ms": { "p50": 182.4, "p90": 410.0, "p99": 1250.7 }
+            }
+        ]
+    })
+)]
+pub struct ProviderStatsResponse {
+    /// Statistics for each registered provider
+    #[schema(value_type = Vec<Object>)]
+    pub providers: Vec<ProviderStats>,
+}
+
+/// Get per-provider request statistics
+///
+/// Returns request counts, success and error rates, and latency percentiles
+/// for each registered metadata provider. Requires the admin API key.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key.
+#[utoipa::path(
+    get,
+    path = "/admin/providers/stats",
+    tag = "admin",
+    summary = "Get per-provider request statistics",
+    description = "Returns request counts, success/error rates and latency percentiles (over the most recent requests) for each registered metadata provider since startup. Requires the `x-admin-api-key` header.",
+    responses(
+        (status = 200, description = "Provider statistics", body = ProviderStatsResponse),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse)
+    )
+)]
+pub async fn provider_stats_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<Json<ProviderStatsResponse>, ServerError> {
+    require_admin(&state, &headers, "provider stats")?;
+
+    Ok(Json(ProviderStatsResponse {
+        providers: state.api_registry().provider_stats(),
+    }))
+}
+
+/// Get spam predictor cache statistics
+///
+/// Returns entry counts, hit and miss counters, evictions and utilization of
+/// the prediction cache. Requires the admin API key since it reveals internal
+/// state.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key.
+#[utoipa::path(
+    get,
+    path = "/v1/cache/stats",
+    tag = "admin",
+    summary = "Get cache statistics",
+    description = "Returns the spam predictor cache statistics since startup: cached prediction, model and prompt counts, \
+        hits, misses, stores, evictions (in total and per reason), expirations, hit rate, utilization and capacity. \
+        Requires the `x-admin-api-key` header.",
+    responses(
+        (status = 200, description = "Cache statistics", body = Object,
+            example = json!({
+                "prediction_count": 42,
+                "model_count": 1,
+                "prompt_count": 1,
+                "cache_hits": 120,
+                "cache_misses": 45,
+                "cache_stores": 45,
+                "cache_evictions": 3,
+                "cache_expired": 3,
+                "evictions_by_reason": { "expired": 3 },
+                "hit_rate": 0.727,
+                "utilization_rate": 0.042,
+                "avg_access_count": 3.1,
+                "max_capacity": 1000
+            })
+        ),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse)
+    )
+)]
+pub async fn cache_stats_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<Json<CacheStats>, ServerError> {
+    require_admin(&state, &headers, "cache stats")?;
+
+    Ok(Json(state.spam_predictor().get_cache_stats()))
+}
+
+/// Request body of the cache clear endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({ "scope": "predictions" }))]
+pub struct CacheClearRequest {
+    /// Part of the cache to clear: `predictions` (default), `configurations` or `all`
+    #[serde(default)]
+    #[schema(value_type = String, example = "predictions")]
+    pub scope: CacheScope,
+}
+
+/// Response from the cache clear endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({ "scope": "predictions", "cleared": 42 }))]
+pub struct CacheClearResponse {
+    /// Part of the cache that was cleared
+    #[schema(value_type = String)]
+    pub scope: CacheScope,
+    /// Number of entries removed
+    pub cleared: usize,
+}
+
+/// Clear the spam predictor cache
+///
+/// Drops cached predictions, configurations or both so that subsequent
+/// requests query the model again, e.g. after a bad model version was live.
+/// Requires the admin API key.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key and
+/// `ServerError::JsonError` for a malformed body.
+#[utoipa::path(
+    post,
+    path = "/v1/cache/clear",
+    tag = "admin",
+    summary = "Clear the cache",
+    description = "Removes cached entries from the spam predictor cache and returns how many were removed. \
+        The optional body selects the `scope`: `predictions` (default), `configurations` (model IDs and prompts, \
+        reloaded from the registries on demand) or `all`. Cache statistics are kept. \
+        A shared Redis prediction cache is not cleared. Requires the `x-admin-api-key` header.",
+    request_body(content = Option<CacheClearRequest>, description = "Scope to clear, defaults to predictions"),
+    responses(
+        (status = 200, description = "Cache cleared", body = CacheClearResponse),
+        (status = 400, description = "Malformed body or unknown scope", body = ErrorResponse),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse)
+    )
+)]
+pub async fn cache_clear_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    OptionalJsonExtractor(request): OptionalJsonExtractor<CacheClearRequest>,
+) -> Result<Json<CacheClearResponse>, ServerError> {
+    require_admin(&state, &headers, "cache clear")?;
+
+    let scope = request.unwrap_or_default().scope;
+    let cleared = state.spam_predictor().clear_cache(scope);
+    Ok(Json(CacheClearResponse { scope, cleared }))
+}
+
+/// Reject requests without the admin API key
+fn require_admin(state: &ServerState, headers: &HeaderMap, what: &str) -> Result<(), ServerError> {
+    if is_admin_request(state, headers) {
+        Ok(())
+    } else {
+        warn!("rejected {what} request: missing or invalid admin API key");
+        Err(ServerError::Forbidden(format!(
+            "{what} require a valid admin API key"
+        )))
+    }
+}
+
+/// Get the current runtime feature flags
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key.
+#[utoipa::path(
+    get,
+    path = "/admin/flags",
+    tag = "admin",
+    summary = "Get runtime feature flags",
+    description = "Returns the current feature flags. Flags start from the `feature_flags` configuration section and reflect any runtime changes. Requires the `x-admin-api-key` header.",
+    responses(
+        (status = 200, description = "Current feature flags", body = FeatureFlags),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse)
+    )
+)]
+pub async fn get_feature_flags_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<Json<FeatureFlags>, ServerError> {
+    require_admin(&state, &headers, "feature flags")?;
+    Ok(Json(state.feature_flags().current()))
+}
+
+/// Update runtime feature flags
+///
+/// Only the flags present in the body change. Changes apply to requests that
+/// start afterwards and are lost on restart.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key.
+#[utoipa::path(
+    put,
+    path = "/admin/flags",
+    tag = "admin",
+    summary = "Update runtime feature flags",
+    description = "Sets the feature flags present in the body and returns the resulting flags. Changes apply to requests started afterwards and are not persisted across restarts. Requires the `x-admin-api-key` header.",
+    request_body = FeatureFlagsUpdate,
+    responses(
+        (status = 200, description = "Updated feature flags", body = FeatureFlags),
+        (status = 400, description = "Malformed body or unknown flag", body = ErrorResponse),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse)
+    )
+)]
+pub async fn update_feature_flags_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    JsonExtractor(update): JsonExtractor<FeatureFlagsUpdate>,
+) -> Result<Json<FeatureFlags>, ServerError> {
+    require_admin(&state, &headers, "feature flags")?;
+    let flags = state.feature_flags().update(update);
+    info!(?flags, "feature flags updated");
+    Ok(Json(flags))
+}
+
+/// Reload the model and prompt registries from their files
+///
+/// Only one reload runs at a time, whether requested here or by the registry
+/// file watcher. A failed reload keeps the previous registries active.
+///
+/// # Errors
+///
+/// Returns `ServerError::Forbidden` without a valid admin API key,
+/// `ServerError::ReloadInProgress` while another reload is running, and
+/// `ServerError::ValidationError` if the registry files cannot be loaded or
+/// are invalid.
+#[utoipa::path(
+    post,
+    path = "/v1/admin/reload",
+    tag = "admin",
+    summary = "Reload the model and prompt registries",
+    description = "Reloads the model and prompt registry files. Only one reload runs at a time; a request made while another reload is running, including one triggered by `spam_predictor.auto_reload`, is rejected with 409. Invalid registry files are rejected with 400 and the validation error, keeping the previous registries active. Requests already in flight finish with the registries they started with. Requires the `x-admin-api-key` header. Also served at `/admin/registries/reload`.",
+    responses(
+        (status = 204, description = "Registries reloaded"),
+        (status = 403, description = "Missing or invalid admin API key", body = ErrorResponse),
+        (status = 409, description = "Another reload is in progress", body = ErrorResponse),
+        (status = 400, description = "Registry files could not be loaded or are invalid, previous registries kept", body = ErrorResponse)
+    )
+)]
+pub async fn reload_registries_handler(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, ServerError> {
+    require_admin(&state, &headers, "registry reloads")?;
+    state.try_reload_registries().await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -8,32 +8,46 @@
 //! including server lifecycle management, router configuration, and coordinated graceful
 //! shutdown using `CancellationToken`.
 
-use std::{net::SocketAddr, sync::Arc, time::Duration};
-
-use axum::{Router, http::HeaderName, routing::get};
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use api_client::RetryPolicy;
+use axum::{
+    Router,
+    extract::connect_info::IntoMakeServiceWithConnectInfo,
+    http::{
+        HeaderName, HeaderValue, Method,
+        header::{CONTENT_LANGUAGE, RETRY_AFTER},
+    },
+    routing::get,
+};
+use axum_server::tls_rustls::RustlsConfig;
 use external_apis::{
-    ApiRegistry, MetadataCache, MoralisClient, MoralisConfig as ExternalMoralisConfig,
-    PerChainMoralisConfig, PerChainPinaxConfig, PinaxClient, PinaxConfig as ExternalPinaxConfig,
+    ApiRegistry, CreatorEnrichmentClient,
+    CreatorEnrichmentConfig as ExternalCreatorEnrichmentConfig, MetadataCache, MoralisClient,
+    MoralisConfig as ExternalMoralisConfig, NegativeLookupCache, PerChainMoralisConfig,
+    PerChainPinaxConfig, PinaxClient, PinaxConfig as ExternalPinaxConfig, SimpleHashClient,
+    SimpleHashConfig as ExternalSimpleHashConfig,
 };
 use hyper::Request;
-use spam_predictor::{SpamPredictor, SpamPredictorConfig};
+use shared_types::ChainId;
+use spam_predictor::{ModelSpec, SpamCache, SpamPredictor, SpamPredictorConfig, TrainingCapture};
 use tokio::{net::TcpListener, time::interval};
 use tokio_util::sync::CancellationToken;
 use tower::ServiceBuilder;
 use tower_http::{
-    cors::CorsLayer,
+    cors::{AllowHeaders, AllowOrigin, Any, CorsLayer},
     request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
-    timeout::TimeoutLayer,
     trace::TraceLayer,
 };
-use tracing::{error, info, info_span, warn};
+use tracing::{Span, debug, error, info, info_span, warn};
 
 use crate::{
-    config::ServerConfig,
+    config::{CorsConfig, MetricsConfig, ServerConfig},
     error::{ServerError, ServerResult},
-    metrics::metrics_handler,
-    middleware::RateLimiter,
-    routes::create_routes,
+    metrics::{metrics_handler, metrics_health_handler},
+    middleware::ApiKeyAuth,
+    routes::{create_routes, handlers::SPAM_STATUS_HEADER},
+    signing::{PayloadSigner, SIGNATURE_HEADER},
     state::ServerState,
 };
 
@@ -74,6 +88,8 @@ pub struct Server {
     cancellation_token: CancellationToken,
     /// Configuration for coordinated shutdown
     graceful_shutdown_config: ShutdownConfig,
+    /// Certificate and key for HTTPS, when TLS is configured
+    tls: Option<RustlsConfig>,
 }
 
 impl Server {
@@ -87,8 +103,57 @@ impl Server {
         Self::with_api_registry(config, shutdown_config, Arc::new(api_registry)).await
     }
 
+    /// Build chain-specific Moralis overrides from configuration
+    fn moralis_chain_overrides(config: &ServerConfig) -> HashMap<ChainId, PerChainMoralisConfig> {
+        config
+            .chains
+            .iter()
+            .filter_map(|(chain_id, chain_config)| {
+                let moralis_override = chain_config.moralis.as_ref()?;
+                Some((
+                    *chain_id,
+                    PerChainMoralisConfig {
+                        base_url: moralis_override.base_url.clone(),
+                        timeout_seconds: moralis_override
+                            .timeout_seconds
+                            .as_ref()
+                            .map(|t| t.value().as_secs()),
+                        max_retries: moralis_override.max_retries,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Build chain-specific Pinax overrides from configuration
+    fn pinax_chain_overrides(config: &ServerConfig) -> HashMap<ChainId, PerChainPinaxConfig> {
+        config
+            .chains
+            .iter()
+            .filter_map(|(chain_id, chain_config)| {
+                let pinax_override = chain_config.pinax.as_ref()?;
+                Some((
+                    *chain_id,
+                    PerChainPinaxConfig {
+                        db_name: Some(pinax_override.db_name.clone()),
+                        timeout_seconds: pinax_override
+                            .timeout_seconds
+                            .as_ref()
+                            .map(|t| t.value().as_secs()),
+                        max_retries: pinax_override.max_retries,
+                        contract_creations: pinax_override.contract_creations,
+                    },
+                ))
+            })
+            .collect()
+    }
+
     /// Create API registry from server configuration
     fn create_api_registry_from_config(config: &ServerConfig) -> ApiRegistry {
+        // Attempts are resolved per chain by each client from `max_retries`
+        let retry_policy = config.external_apis.retry.policy(1);
+        let allowlist = config.external_apis.upstream_allowlist.allowlist();
+
         // Initialize MoralisClient if enabled
         let moralis_client = if config.external_apis.moralis.enabled {
             let moralis_config = ExternalMoralisConfig {
@@ -100,6 +165,12 @@ impl Server {
                     .timeout_seconds
                     .value()
                     .as_secs(),
+                connect_timeout_seconds: config
+                    .external_apis
+                    .moralis
+                    .connect_timeout_seconds
+                    .value()
+                    .as_secs(),
                 health_check_timeout_seconds: config
                     .external_apis
                     .moralis
@@ -109,93 +180,189 @@ impl Server {
                 max_retries: config.external_apis.moralis.max_retries,
             };
 
-            // Build chain-specific Moralis overrides from configuration
-            let mut chain_overrides = std::collections::HashMap::new();
-            for (chain_id, chain_config) in &config.chains {
-                if let Some(moralis_override) = &chain_config.moralis {
-                    chain_overrides.insert(
-                        *chain_id,
-                        PerChainMoralisConfig {
-                            base_url: moralis_override.base_url.clone(),
-                            timeout_seconds: moralis_override
-                                .timeout_seconds
-                                .as_ref()
-                                .map(|t| t.value().as_secs()),
-                            max_retries: moralis_override.max_retries,
-                        },
-                    );
-                }
-            }
-
-            Some(
-                MoralisClient::with_chain_overrides(moralis_config, chain_overrides)
-                    .expect("Failed to create Moralis client"),
-            )
+            let chain_overrides = Self::moralis_chain_overrides(config);
+            let client = MoralisClient::with_chain_overrides(moralis_config, chain_overrides)
+                .expect("Failed to create Moralis client")
+                .with_retry_policy(retry_policy.clone())
+                .with_max_parsed_count(config.external_apis.moralis.max_parsed_count)
+                .with_rate_limit(config.external_apis.moralis.rate_limit());
+            Some(match &allowlist {
+                Some(allowlist) => client
+                    .with_upstream_allowlist(allowlist)
+                    .expect("Moralis URLs are validated against the upstream allowlist"),
+                None => client,
+            })
         } else {
             None
         };
 
-        // Initialize PinaxClient if enabled
-        let pinax_client = if config.external_apis.pinax.enabled {
-            let pinax_config = ExternalPinaxConfig::new(
-                config.external_apis.pinax.endpoint.as_str(),
-                config.external_apis.pinax.api_user.value(),
-                config.external_apis.pinax.api_auth.value(),
-                &config.external_apis.pinax.db_name,
-                config.external_apis.pinax.timeout_seconds.value().as_secs(),
-                config
-                    .external_apis
-                    .pinax
-                    .health_check_timeout_seconds
-                    .value()
-                    .as_secs(),
-                config.external_apis.pinax.max_retries,
-            )
-            .expect("Failed to create Pinax config");
-
-            // Build chain-specific Pinax overrides from configuration
-            let mut chain_overrides = std::collections::HashMap::new();
-            for (chain_id, chain_config) in &config.chains {
-                if let Some(pinax_override) = &chain_config.pinax {
-                    chain_overrides.insert(
-                        *chain_id,
-                        PerChainPinaxConfig {
-                            db_name: Some(pinax_override.db_name.clone()),
-                            timeout_seconds: pinax_override
-                                .timeout_seconds
-                                .as_ref()
-                                .map(|t| t.value().as_secs()),
-                            max_retries: pinax_override.max_retries,
-                        },
-                    );
-                }
-            }
+        let pinax_client = Self::create_pinax_client_from_config(config, retry_policy.clone());
+        let simplehash_client = Self::create_simplehash_client_from_config(config, retry_policy);
 
-            Some(
-                PinaxClient::with_chain_overrides(pinax_config, chain_overrides)
-                    .expect("Failed to create Pinax client"),
-            )
-        } else {
-            None
+        let (cache, negative_cache) = Self::create_caches_from_config(config);
+        let mut registry = ApiRegistry::with_clients_and_cache(None, None, cache)
+            .with_negative_cache(negative_cache);
+        if let Some(client) = moralis_client {
+            registry =
+                registry.register_with_priority(client, config.external_apis.moralis.priority);
+        }
+        if let Some(client) = pinax_client {
+            registry = registry.register_with_priority(client, config.external_apis.pinax.priority);
+        }
+        if let Some(client) = simplehash_client {
+            registry =
+                registry.register_with_priority(client, config.external_apis.simplehash.priority);
+        }
+        if let Some(breaker) = config.external_apis.circuit_breaker.breaker_config() {
+            registry = registry.with_circuit_breaker(breaker);
+        }
+        registry = registry
+            .with_cross_check(config.external_apis.metadata_cross_check)
+            .with_merge_mode(config.external_apis.metadata_merge_mode);
+
+        match Self::create_creator_enrichment_from_config(config) {
+            Some(enrichment) => registry.with_creator_enrichment(enrichment),
+            None => registry,
+        }
+    }
+
+    /// Create the Pinax client if enabled in configuration
+    fn create_pinax_client_from_config(
+        config: &ServerConfig,
+        retry_policy: RetryPolicy,
+    ) -> Option<PinaxClient> {
+        if !config.external_apis.pinax.enabled {
+            return None;
+        }
+
+        let pinax_config = ExternalPinaxConfig::new(
+            config.external_apis.pinax.endpoint.as_str(),
+            config.external_apis.pinax.api_user.value(),
+            config.external_apis.pinax.api_auth.value(),
+            &config.external_apis.pinax.db_name,
+            config.external_apis.pinax.timeout_seconds.value().as_secs(),
+            config
+                .external_apis
+                .pinax
+                .health_check_timeout_seconds
+                .value()
+                .as_secs(),
+            config.external_apis.pinax.max_retries,
+        )
+        .expect("Failed to create Pinax config")
+        .with_connect_timeout_seconds(
+            config
+                .external_apis
+                .pinax
+                .connect_timeout_seconds
+                .value()
+                .as_secs(),
+        );
+
+        let chain_overrides = Self::pinax_chain_overrides(config);
+        let client = PinaxClient::with_chain_overrides(pinax_config, chain_overrides)
+            .expect("Failed to create Pinax client")
+            .with_retry_policy(retry_policy)
+            .with_rate_limit(config.external_apis.pinax.rate_limit());
+        Some(match config.external_apis.upstream_allowlist.allowlist() {
+            Some(allowlist) => client
+                .with_upstream_allowlist(&allowlist)
+                .expect("Pinax endpoint is validated against the upstream allowlist"),
+            None => client,
+        })
+    }
+
+    /// Create the `SimpleHash` client if enabled in configuration
+    fn create_simplehash_client_from_config(
+        config: &ServerConfig,
+        retry_policy: RetryPolicy,
+    ) -> Option<SimpleHashClient> {
+        let simplehash = &config.external_apis.simplehash;
+        if !simplehash.enabled {
+            return None;
+        }
+
+        let simplehash_config = ExternalSimpleHashConfig {
+            base_url: simplehash.base_url.to_string(),
+            api_key: simplehash.api_key.value().to_string(),
+            timeout_seconds: simplehash.timeout_seconds.value().as_secs(),
+            connect_timeout_seconds: simplehash.connect_timeout_seconds.value().as_secs(),
+            health_check_timeout_seconds: simplehash.health_check_timeout_seconds.value().as_secs(),
+            max_retries: simplehash.max_retries,
         };
 
-        // Create cache with configuration
-        let cache = if config.external_apis.cache.enabled {
-            MetadataCache::with_settings(
-                Duration::from_secs(config.external_apis.cache.ttl_seconds),
-                config.external_apis.cache.max_entries,
+        let client = SimpleHashClient::new(simplehash_config)
+            .expect("Failed to create SimpleHash client")
+            .with_retry_policy(retry_policy)
+            .with_max_parsed_count(simplehash.max_parsed_count)
+            .with_rate_limit(simplehash.rate_limit());
+        Some(match config.external_apis.upstream_allowlist.allowlist() {
+            Some(allowlist) => client
+                .with_upstream_allowlist(&allowlist)
+                .expect("SimpleHash URL is validated against the upstream allowlist"),
+            None => client,
+        })
+    }
+
+    /// Create the metadata and negative lookup caches from configuration
+    fn create_caches_from_config(config: &ServerConfig) -> (MetadataCache, NegativeLookupCache) {
+        if config.external_apis.cache.enabled {
+            (
+                MetadataCache::with_settings(
+                    Duration::from_secs(config.external_apis.cache.ttl_seconds),
+                    config.external_apis.cache.max_entries,
+                ),
+                NegativeLookupCache::with_settings(
+                    Duration::from_secs(config.external_apis.cache.negative_ttl_seconds),
+                    config.external_apis.cache.max_entries,
+                ),
             )
         } else {
             // Use minimal cache settings when disabled
-            MetadataCache::with_settings(Duration::from_secs(1), 1)
+            (
+                MetadataCache::with_settings(Duration::from_secs(1), 1),
+                NegativeLookupCache::disabled(),
+            )
+        }
+    }
+
+    /// Create the creator enrichment client if enabled in configuration
+    fn create_creator_enrichment_from_config(
+        config: &ServerConfig,
+    ) -> Option<CreatorEnrichmentClient> {
+        let enrichment = &config.external_apis.creator_enrichment;
+        if !enrichment.enabled {
+            return None;
+        }
+
+        let enrichment_config = ExternalCreatorEnrichmentConfig {
+            rpc_urls: enrichment
+                .rpc_urls
+                .iter()
+                .map(|(chain_id, url)| (*chain_id, url.to_string()))
+                .collect(),
+            name_service_url: enrichment
+                .name_service_url
+                .as_ref()
+                .map(ToString::to_string),
+            timeout_seconds: enrichment.timeout_seconds.value().as_secs(),
+            fresh_address_max_transactions: enrichment.fresh_address_max_transactions,
         };
 
-        ApiRegistry::with_clients_and_cache(moralis_client, pinax_client, cache)
+        let client = CreatorEnrichmentClient::new(enrichment_config)
+            .expect("Failed to create creator enrichment client");
+        Some(match config.external_apis.upstream_allowlist.allowlist() {
+            Some(allowlist) => client
+                .with_upstream_allowlist(&allowlist)
+                .expect("Creator enrichment URLs are validated against the upstream allowlist"),
+            None => client,
+        })
     }
 
     /// Start background task to periodically update cache metrics
     fn start_cache_metrics_task(
         api_registry: Arc<ApiRegistry>,
+        spam_predictor: Arc<SpamPredictor>,
         cancellation_token: CancellationToken,
     ) {
         tokio::spawn(async move {
@@ -214,12 +381,70 @@ impl Server {
                             stats.hit_rate,
                             stats.entry_count,
                         );
+                        crate::metrics::update_spam_cache_metrics(
+                            &spam_predictor.cache().get_stats(),
+                        );
                     }
                 }
             }
         });
     }
 
+    /// Warm the prediction cache from the configured snapshot, if any
+    ///
+    /// A missing or unreadable snapshot only costs a cold cache, so failures are
+    /// logged instead of aborting startup.
+    async fn preload_prediction_cache(config: &ServerConfig, spam_predictor: &SpamPredictor) {
+        let Some(source) = &config.spam_predictor.cache_snapshot.source else {
+            return;
+        };
+        let allowlist = config.external_apis.upstream_allowlist.allowlist();
+        match spam_predictor
+            .cache()
+            .load_snapshot(source, allowlist.as_ref())
+            .await
+        {
+            Ok(restored) => info!(source, restored, "prediction cache preloaded from snapshot"),
+            Err(e) => warn!(source, error = %e, "failed to preload prediction cache snapshot"),
+        }
+    }
+
+    /// Start background task to periodically write the prediction cache snapshot
+    ///
+    /// A final snapshot is written when the task is cancelled at shutdown.
+    fn start_cache_snapshot_task(
+        config: &ServerConfig,
+        spam_predictor: Arc<SpamPredictor>,
+        cancellation_token: CancellationToken,
+    ) {
+        let snapshot_config = &config.spam_predictor.cache_snapshot;
+        let Some(path) = snapshot_config.path.clone() else {
+            return;
+        };
+        let period = Duration::from_secs(snapshot_config.interval_seconds);
+
+        tokio::spawn(async move {
+            let mut interval = interval(period);
+            // The first tick completes immediately, there is nothing new to write yet
+            interval.tick().await;
+
+            loop {
+                let cancelled = tokio::select! {
+                    () = cancellation_token.cancelled() => true,
+                    _ = interval.tick() => false,
+                };
+                match spam_predictor.cache().write_snapshot(&path).await {
+                    Ok(entries) => debug!(path, entries, "wrote prediction cache snapshot"),
+                    Err(e) => warn!(path, error = %e, "failed to write prediction cache snapshot"),
+                }
+                if cancelled {
+                    info!("cache snapshot task cancelled");
+                    break;
+                }
+            }
+        });
+    }
+
     /// Create spam predictor from server configuration
     async fn create_spam_predictor_from_config(
         config: &ServerConfig,
@@ -231,8 +456,22 @@ impl Server {
             config.spam_predictor.openai_api_key.value().to_string(),
         )
         .with_timeout(config.spam_predictor.timeout_seconds.value().as_secs())
+        .with_connect_timeout(
+            config
+                .spam_predictor
+                .connect_timeout_seconds
+                .value()
+                .as_secs(),
+        )
         .with_max_tokens(config.spam_predictor.max_tokens.unwrap_or(10))
-        .with_temperature(config.spam_predictor.temperature.unwrap_or(0.0));
+        .with_temperature(config.spam_predictor.temperature.unwrap_or(0.0))
+        .with_provider(config.spam_predictor.openai_provider.clone())
+        .with_retry_policy(
+            config
+                .external_apis
+                .retry
+                .policy(spam_predictor::openai::default_retry_policy().max_attempts),
+        );
 
         // Set base URL if configured
         let openai_config = if let Some(base_url) = &config.spam_predictor.openai_base_url {
@@ -241,6 +480,11 @@ impl Server {
             openai_config
         };
 
+        let openai_config = match config.external_apis.upstream_allowlist.allowlist() {
+            Some(allowlist) => openai_config.with_upstream_allowlist(allowlist),
+            None => openai_config,
+        };
+
         // Set organization ID if configured
         let openai_config = if let Some(org_id) = &config.spam_predictor.openai_organization_id {
             openai_config.with_organization(org_id.clone())
@@ -249,15 +493,63 @@ impl Server {
         };
 
         // Create SpamPredictorConfig
-        let predictor_config = SpamPredictorConfig::from_files(
+        let predictor_config = SpamPredictorConfig::from_files_with_tolerance(
             &config.spam_predictor.model_registry_path,
             &config.spam_predictor.prompt_registry_path,
             openai_config,
+            config.spam_predictor.registry_tolerant_load,
         )
         .await
         .map_err(|e| ServerError::Config {
             message: format!("Failed to create spam predictor configuration: {e}"),
-        })?;
+        })?
+        .with_model_input_fields(config.spam_predictor.model_input_fields.clone())
+        .with_prompt_content_hashing(config.spam_predictor.prompt_content_hashing)
+        .with_fallback_model_versions(config.spam_predictor.fallback_model_versions.clone())
+        .with_fallback_models(config.spam_predictor.fallback_models.clone())
+        .with_mock_predictions(config.spam_predictor.mock_predictions)
+        .with_confidence_threshold(config.spam_predictor.confidence_threshold)
+        .map_err(|e| ServerError::Config {
+            message: format!("Invalid spam predictor configuration: {e}"),
+        })?
+        .with_cache(
+            SpamCache::with_settings(
+                Duration::from_secs(config.spam_predictor.cache_ttl_seconds),
+                config.spam_predictor.max_cache_size,
+            )
+            .with_eviction_logging(config.spam_predictor.log_cache_evictions),
+        );
+        let predictor_config = match &config.spam_predictor.training_capture_path {
+            Some(path) if config.spam_predictor.training_capture_rate > 0.0 => {
+                let capture =
+                    TrainingCapture::new(config.spam_predictor.training_capture_rate, path)
+                        .map_err(|e| ServerError::Config {
+                            message: format!("Invalid training capture configuration: {e}"),
+                        })?;
+                info!(path, rate = capture.rate(), "training data capture enabled");
+                predictor_config.with_training_capture(capture)
+            }
+            _ => predictor_config,
+        };
+        // Configuration validation refuses `redis_url` in builds without the feature
+        #[cfg(feature = "redis")]
+        let predictor_config = match &config.spam_predictor.redis_url {
+            Some(url) => {
+                let redis_cache = spam_predictor::RedisCache::connect(
+                    url,
+                    Duration::from_secs(config.spam_predictor.cache_ttl_seconds),
+                )
+                .await
+                .map_err(|e| ServerError::Config {
+                    message: format!("Failed to connect prediction cache to Redis: {e}"),
+                })?;
+                info!("prediction cache shared through Redis");
+                predictor_config.with_prediction_backend(Arc::new(redis_cache))
+            }
+            None => predictor_config,
+        };
+
+        Self::validate_chain_prediction_defaults(config, &predictor_config)?;
 
         // Create SpamPredictor
         let predictor =
@@ -271,6 +563,47 @@ impl Server {
         Ok(predictor)
     }
 
+    /// Ensure chain-specific model and prompt defaults, and the fallback model
+    /// versions and models, exist in the loaded registries
+    fn validate_chain_prediction_defaults(
+        config: &ServerConfig,
+        predictor_config: &SpamPredictorConfig,
+    ) -> ServerResult<()> {
+        for (chain_id, spec) in &config.spam_predictor.chain_model_defaults {
+            if !predictor_config.has_model_spec(spec) {
+                return Err(ServerError::Config {
+                    message: format!("Default model {spec} for chain {chain_id} is not registered"),
+                });
+            }
+        }
+        let default_model_type = ModelSpec::spam_classification_latest().model_type().clone();
+        for version in &config.spam_predictor.fallback_model_versions {
+            let spec = ModelSpec::new(default_model_type.clone(), version.clone());
+            if !predictor_config.has_model_spec(&spec) {
+                return Err(ServerError::Config {
+                    message: format!("Fallback model {spec} is not registered"),
+                });
+            }
+        }
+        for spec in &config.spam_predictor.fallback_models {
+            if !predictor_config.has_model_spec(spec) {
+                return Err(ServerError::Config {
+                    message: format!("Fallback model {spec} is not registered"),
+                });
+            }
+        }
+        for (chain_id, version) in &config.spam_predictor.chain_prompt_defaults {
+            predictor_config
+                .get_prompt(&version.as_str())
+                .map_err(|e| ServerError::Config {
+                    message: format!(
+                        "Default prompt {version} for chain {chain_id} is invalid: {e}"
+                    ),
+                })?;
+        }
+        Ok(())
+    }
+
     /// Create server with custom API registry for dependency injection
     ///
     /// # Errors
@@ -283,22 +616,37 @@ impl Server {
     ) -> ServerResult<Self> {
         // Configuration validation is now built into the types
 
+        // Histograms are registered on first observation, so set buckets first
+        crate::metrics::configure_duration_buckets(&config.metrics.histogram_buckets);
+
         // Initialize spam predictor (always required)
         let spam_predictor = Self::create_spam_predictor_from_config(&config).await?;
         let spam_predictor = Arc::new(spam_predictor);
+        Self::preload_prediction_cache(&config, &spam_predictor).await;
 
         let cancellation_token = CancellationToken::new();
         let state = ServerState::new(
             config.clone(),
             api_registry.clone(),
-            spam_predictor,
+            spam_predictor.clone(),
             cancellation_token.child_token(),
         );
 
         // Start background task to update cache metrics
-        Self::start_cache_metrics_task(api_registry.clone(), cancellation_token.child_token());
+        Self::start_cache_metrics_task(
+            api_registry.clone(),
+            spam_predictor.clone(),
+            cancellation_token.child_token(),
+        );
+        Self::start_cache_snapshot_task(
+            &config,
+            spam_predictor.clone(),
+            cancellation_token.child_token(),
+        );
+        crate::reload::start_registry_watcher(state.clone(), cancellation_token.child_token())?;
 
-        let router = Self::create_router(state.clone());
+        let router = Self::create_router(state.clone())?;
+        let tls = Self::load_tls_config(&config).await?;
 
         Ok(Self {
             config,
@@ -306,35 +654,173 @@ impl Server {
             state,
             cancellation_token,
             graceful_shutdown_config,
+            tls,
         })
     }
 
+    /// Load the TLS certificate and key when HTTPS is configured
+    async fn load_tls_config(config: &ServerConfig) -> ServerResult<Option<RustlsConfig>> {
+        let Some(tls) = &config.tls else {
+            return Ok(None);
+        };
+
+        // Fails when a provider is already installed, which is just as good
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path)
+            .await
+            .map(Some)
+            .map_err(|e| ServerError::Config {
+                message: format!(
+                    "Failed to load TLS certificate {} and key {}: {e}",
+                    tls.cert_path.display(),
+                    tls.key_path.display()
+                ),
+            })
+    }
+
+    /// Serve `service` on `listener`, over TLS when `tls` is set, until
+    /// `cancellation_token` is cancelled
+    async fn serve(
+        listener: TcpListener,
+        service: IntoMakeServiceWithConnectInfo<Router, SocketAddr>,
+        tls: Option<RustlsConfig>,
+        cancellation_token: CancellationToken,
+        graceful_timeout: Duration,
+    ) -> std::io::Result<()> {
+        let Some(tls) = tls else {
+            return axum::serve(listener, service)
+                .with_graceful_shutdown(async move { cancellation_token.cancelled().await })
+                .await;
+        };
+
+        let handle = axum_server::Handle::new();
+        let shutdown_handle = handle.clone();
+        tokio::spawn(async move {
+            cancellation_token.cancelled().await;
+            shutdown_handle.graceful_shutdown(Some(graceful_timeout));
+        });
+        axum_server::from_tcp_rustls(listener.into_std()?, tls)
+            .handle(handle)
+            .serve(service)
+            .await
+    }
+
     /// Create application router with middleware
-    fn create_router(state: ServerState) -> Router {
+    fn create_router(state: ServerState) -> ServerResult<Router> {
         let timeout_duration = state.config().timeout_seconds.value();
 
-        // Create rate limiter from configuration
-        let rate_limiter = RateLimiter::new(state.config().rate_limiting.clone());
+        // Shared with WebSocket streams, which check it per subscription
+        let rate_limiter = state.rate_limiter().clone();
+
+        // Require client API keys when authentication is enabled
+        let auth = ApiKeyAuth::from_config(&state.config().auth);
+
+        // Sign API responses when a secret is configured
+        let signer = state
+            .config()
+            .response_signing_secret
+            .as_ref()
+            .map(|secret| PayloadSigner::new(secret.value()))
+            .transpose()?;
 
         let middleware = ServiceBuilder::new()
+            // Honors a client supplied `x-request-id`, generating a UUID otherwise
             .layer(SetRequestIdLayer::new(REQUEST_ID_HEADER, MakeRequestUuid))
-            .layer(
-                TraceLayer::new_for_http().make_span_with(|req: &Request<_>| {
-                    if let Some(request_id) = req.headers().get(REQUEST_ID_HEADER) {
-                        info_span!("http_request", ?request_id)
-                    } else {
-                        tracing::error!("failed to extract id from request");
-                        info_span!("http_request", request_id = "unknown")
-                    }
-                }),
-            )
+            .layer(TraceLayer::new_for_http().make_span_with(Self::make_request_span))
             .layer(PropagateRequestIdLayer::new(REQUEST_ID_HEADER))
-            .layer(CorsLayer::permissive())
-            .layer(TimeoutLayer::new(timeout_duration));
+            .layer(Self::cors_layer(&state.config().cors)?);
 
-        create_routes(rate_limiter)
+        Ok(create_routes(rate_limiter, auth, signer, timeout_duration)
             .layer(middleware)
-            .with_state(state)
+            .with_state(state))
+    }
+
+    /// Build the CORS layer enforcing the configured policy
+    ///
+    /// Browsers reject wildcards in credentialed responses, so with
+    /// `allow_credentials` the request's origin and headers are mirrored and the
+    /// API's own response headers are exposed by name.
+    fn cors_layer(cors: &CorsConfig) -> ServerResult<CorsLayer> {
+        let invalid = |what: &str, value: &str| ServerError::Config {
+            message: format!("invalid CORS {what}: {value}"),
+        };
+        let methods = cors
+            .allowed_methods
+            .iter()
+            .map(|method| {
+                Method::from_bytes(method.as_bytes()).map_err(|_| invalid("method", method))
+            })
+            .collect::<ServerResult<Vec<_>>>()?;
+        let layer = CorsLayer::new()
+            .allow_methods(methods)
+            .allow_credentials(cors.allow_credentials);
+
+        let origins = if cors.allows_any_origin() {
+            if cors.allow_credentials {
+                warn!("CORS allows credentials from any origin by mirroring the request origin");
+                AllowOrigin::mirror_request()
+            } else {
+                AllowOrigin::any()
+            }
+        } else {
+            AllowOrigin::list(
+                cors.allowed_origins
+                    .iter()
+                    .map(|origin| {
+                        HeaderValue::from_str(origin).map_err(|_| invalid("origin", origin))
+                    })
+                    .collect::<ServerResult<Vec<_>>>()?,
+            )
+        };
+
+        Ok(if cors.allow_credentials {
+            layer
+                .allow_origin(origins)
+                .allow_headers(AllowHeaders::mirror_request())
+                .expose_headers([
+                    REQUEST_ID_HEADER,
+                    SIGNATURE_HEADER,
+                    HeaderName::from_static(SPAM_STATUS_HEADER),
+                    RETRY_AFTER,
+                    CONTENT_LANGUAGE,
+                ])
+        } else {
+            layer
+                .allow_origin(origins)
+                .allow_headers(Any)
+                .expose_headers(Any)
+        })
+    }
+
+    /// Root span of a request, carrying its `x-request-id`
+    ///
+    /// Every span opened while handling the request is nested in it, including
+    /// the `OpenAI` calls of the spam predictor which record their own
+    /// `request_id`, so upstream calls are correlated with the request ID returned
+    /// to the client.
+    fn make_request_span<B>(req: &Request<B>) -> Span {
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .map(|request_id| request_id.to_str().unwrap_or("invalid"));
+        let request_id = request_id.unwrap_or_else(|| {
+            error!("failed to extract id from request");
+            "unknown"
+        });
+
+        info_span!(
+            "http_request",
+            request_id,
+            method = %req.method(),
+            path = req.uri().path(),
+        )
+    }
+
+    /// Create the metrics port router serving the exporter and its liveness probe
+    fn create_metrics_router(config: &MetricsConfig) -> Router {
+        Router::new()
+            .route(&config.endpoint_path, get(metrics_handler))
+            .route(&config.health_path, get(metrics_health_handler))
     }
 
     /// Run the server with coordinated graceful shutdown
@@ -364,12 +850,12 @@ impl Server {
                     address: metrics_addr,
                     source,
                 })?;
-        let metrics_router =
-            Router::new().route(&self.config.metrics.endpoint_path, get(metrics_handler));
+        let metrics_router = Self::create_metrics_router(&self.config.metrics);
 
         info!(
             address = %actual_addr,
             environment = %self.config.environment,
+            tls = self.tls.is_some(),
             "NFT API server starting",
         );
 
@@ -388,12 +874,16 @@ impl Server {
 
         let (app_result, metrics_result) = tokio::join!(
             async {
-                axum::serve(listener, app_service)
-                    .with_graceful_shutdown(async move {
-                        cancellation_token.cancelled().await;
-                        info!("NFT API server shut down gracefully");
-                    })
-                    .await
+                let result = Self::serve(
+                    listener,
+                    app_service,
+                    self.tls,
+                    cancellation_token,
+                    self.graceful_shutdown_config.graceful_timeout,
+                )
+                .await;
+                info!("NFT API server shut down gracefully");
+                result
             },
             async {
                 axum::serve(metrics_listener, metrics_service)
@@ -505,12 +995,14 @@ impl Server {
         let token = self.cancellation_token.child_token();
         let task = token.child_token();
         tokio::spawn(async move {
-            let _ = axum::serve(
+            let _ = Self::serve(
                 listener,
                 self.router
                     .into_make_service_with_connect_info::<SocketAddr>(),
+                self.tls,
+                task,
+                self.graceful_shutdown_config.graceful_timeout,
             )
-            .with_graceful_shutdown(async move { task.cancelled().await })
             .await;
         });
 
@@ -567,4 +1059,31 @@ mod tests {
             Duration::from_secs(DEFAULT_FORCE_SHUTDOWN_TIMEOUT_SECONDS)
         );
     }
+
+    #[tokio::test]
+    async fn metrics_router_serves_metrics_and_health() {
+        use axum::{body::Body, http::StatusCode};
+        use tower::ServiceExt;
+
+        let config = MetricsConfig {
+            health_path: "/healthz".to_string(),
+            ..MetricsConfig::default()
+        };
+        let router = Server::create_metrics_router(&config);
+
+        for path in ["/metrics", "/healthz"] {
+            let response = router
+                .clone()
+                .oneshot(Request::get(path).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{path}");
+        }
+
+        let response = router
+            .oneshot(Request::get("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
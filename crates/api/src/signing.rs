@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! HMAC signing of outbound payloads
+//!
+//! When a signing secret is configured, bodies sent by the server carry an
+//! `X-Signature: sha256=<hex>` header holding the HMAC-SHA256 of the raw body.
+//! Receivers recompute the HMAC with the shared secret to verify that the
+//! payload came from this deployment and was not modified in transit. The same
+//! signer is meant for API responses and asynchronous callbacks.
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::{Request, State},
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tracing::error;
+
+use crate::error::{ServerError, ServerResult};
+
+/// Header carrying the body signature
+pub const SIGNATURE_HEADER: HeaderName = HeaderName::from_static("x-signature");
+
+/// Header carrying the idempotency key of a request or callback
+pub const IDEMPOTENCY_KEY_HEADER: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Prefix naming the signature algorithm
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// Signs and verifies payloads with a shared secret
+#[derive(Clone)]
+pub struct PayloadSigner {
+    mac: Hmac<Sha256>,
+}
+
+impl std::fmt::Debug for PayloadSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PayloadSigner").finish_non_exhaustive()
+    }
+}
+
+impl PayloadSigner {
+    /// Create a signer for the given secret
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Config` if the secret is rejected as an HMAC key
+    pub fn new(secret: &str) -> ServerResult<Self> {
+        let mac = Hmac::new_from_slice(secret.as_bytes()).map_err(|e| ServerError::Config {
+            message: format!("invalid response signing secret: {e}"),
+        })?;
+        Ok(Self { mac })
+    }
+
+    /// Signature of `body`, formatted as `sha256=<hex>`
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac = self.mac.clone();
+        mac.update(body);
+        format!(
+            "{SIGNATURE_PREFIX}{}",
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
+
+    /// Whether `signature` is the valid `sha256=<hex>` signature of `body`
+    ///
+    /// The comparison runs in constant time.
+    pub fn verify(&self, body: &[u8], signature: &str) -> bool {
+        let Some(Ok(expected)) = signature.strip_prefix(SIGNATURE_PREFIX).map(hex::decode) else {
+            return false;
+        };
+        let mut mac = self.mac.clone();
+        mac.update(body);
+        mac.verify_slice(&expected).is_ok()
+    }
+}
+
+/// Sign response bodies and echo the request's idempotency key
+///
+/// Responses are buffered so the signature covers the exact bytes sent.
+pub async fn response_signing_middleware(
+    State(signer): State<PayloadSigner>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let idempotency_key = request.headers().get(IDEMPOTENCY_KEY_HEADER).cloned();
+    let response = next.run(request).await;
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            error!(error = %e, "failed to buffer response body for signing");
+            return ServerError::Runtime {
+                message: "failed to sign response".to_string(),
+            }
+            .into_response();
+        }
+    };
+
+    if let Ok(signature) = HeaderValue::from_str(&signer.sign(&bytes)) {
+        parts.headers.insert(SIGNATURE_HEADER, signature);
+    }
+    if let Some(key) = idempotency_key {
+        parts.headers.insert(IDEMPOTENCY_KEY_HEADER, key);
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_matches_known_vector() {
+        // RFC 4231 test case 2
+        let signer = PayloadSigner::new("Jefe").expect("valid secret");
+        let body = b"what do ya want for nothing?";
+        let signature = signer.sign(body);
+
+        assert_eq!(
+            signature,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(signer.verify(body, &signature));
+    }
+
+    #[test]
+    fn verify_rejects_tampering() {
+        let signer = PayloadSigner::new("secret").expect("valid secret");
+        let signature = signer.sign(b"{\"status\":\"spam\"}");
+
+        assert!(!signer.verify(b"{\"status\":\"legitimate\"}", &signature));
+        assert!(
+            !PayloadSigner::new("other")
+                .expect("valid secret")
+                .verify(b"{\"status\":\"spam\"}", &signature)
+        );
+        assert!(!signer.verify(b"{\"status\":\"spam\"}", "sha256=not-hex"));
+        assert!(!signer.verify(
+            b"{\"status\":\"spam\"}",
+            signature.trim_start_matches(SIGNATURE_PREFIX)
+        ));
+    }
+}
@@ -12,12 +12,16 @@ use std::{collections::HashMap, sync::Arc};
 use external_apis::ApiRegistry;
 use serde::{Deserialize, Serialize};
 use spam_predictor::SpamPredictor;
+use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
+use tracing::info;
 use utoipa::ToSchema;
 
 use crate::{
     config::{Environment, ServerConfig},
-    error::ServerResult,
+    error::{ServerError, ServerResult},
+    flags::FeatureFlagRegistry,
+    middleware::RateLimiter,
 };
 
 /// Shared application state with cancellation token support
@@ -29,6 +33,12 @@ pub struct ServerState {
     api_registry: Arc<ApiRegistry>,
     /// Spam predictor for contract analysis
     spam_predictor: Arc<SpamPredictor>,
+    /// Runtime feature flags, seeded from configuration
+    feature_flags: FeatureFlagRegistry,
+    /// Per-IP rate limiter, shared by the HTTP middleware and WebSocket streams
+    rate_limiter: RateLimiter,
+    /// Held while the registries are reloaded, so reloads never overlap
+    registry_reload_lock: Arc<Mutex<()>>,
     /// Cancellation token for coordinated shutdown
     pub cancellation_token: CancellationToken,
 }
@@ -49,6 +59,9 @@ impl ServerState {
         cancellation_token: CancellationToken,
     ) -> Self {
         Self {
+            feature_flags: FeatureFlagRegistry::new(config.feature_flags),
+            rate_limiter: RateLimiter::new(config.rate_limiting.clone()),
+            registry_reload_lock: Arc::new(Mutex::new(())),
             config,
             api_registry,
             spam_predictor,
@@ -71,6 +84,50 @@ impl ServerState {
         &self.spam_predictor
     }
 
+    /// Runtime feature flags
+    pub fn feature_flags(&self) -> &FeatureFlagRegistry {
+        &self.feature_flags
+    }
+
+    /// Per-IP rate limiter
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Reload the model and prompt registries, waiting for any reload in progress
+    ///
+    /// A failed reload keeps the previous registries active.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::Config` if the registry files cannot be loaded.
+    pub async fn reload_registries(&self) -> ServerResult<()> {
+        let _guard = self.registry_reload_lock.lock().await;
+        self.reload_registries_locked().await
+    }
+
+    /// Reload the model and prompt registries unless a reload is already running
+    ///
+    /// # Errors
+    ///
+    /// Returns `ServerError::ReloadInProgress` if another reload holds the lock,
+    /// or `ServerError::Config` if the registry files cannot be loaded.
+    pub async fn try_reload_registries(&self) -> ServerResult<()> {
+        let _guard = self
+            .registry_reload_lock
+            .try_lock()
+            .map_err(|_| ServerError::ReloadInProgress)?;
+        self.reload_registries_locked().await
+    }
+
+    async fn reload_registries_locked(&self) -> ServerResult<()> {
+        self.spam_predictor.reload_config().await.map_err(|e| {
+            ServerError::ValidationError(format!("Failed to reload registry files: {e}"))
+        })?;
+        info!("registry files reloaded");
+        Ok(())
+    }
+
     /// Perform health check operations
     pub async fn health_check(&self) -> ServerResult<HealthCheck> {
         let external_api_clients = self.api_registry.get_overall_health().await;
@@ -84,8 +141,26 @@ impl ServerState {
         // Add spam predictor health as a separate internal service
         api_clients.extend(spam_predictor_health);
 
+        let open_breakers = self.api_registry.open_circuit_breakers();
+        let decommissioned_models = self.spam_predictor.decommissioned_models();
+        let status = if self.feature_flags.current().degraded_mode {
+            HealthStatus::Degraded {
+                reason: "degraded mode enabled by operator".into(),
+            }
+        } else if !open_breakers.is_empty() {
+            HealthStatus::Degraded {
+                reason: format!("circuit breaker open for {}", open_breakers.join(", ")).into(),
+            }
+        } else if !decommissioned_models.is_empty() {
+            HealthStatus::Degraded {
+                reason: decommissioned_reason(&decommissioned_models).into(),
+            }
+        } else {
+            HealthStatus::Up
+        };
+
         Ok(HealthCheck {
-            status: HealthStatus::Up,
+            status,
             version: Box::from(env!("CARGO_PKG_VERSION")),
             environment: self.config.environment,
             timestamp: chrono::Utc::now().to_rfc3339(),
@@ -99,7 +174,13 @@ impl ServerState {
 
         match self.spam_predictor.health_check().await {
             Ok(health_status) => {
-                let status = if health_status.overall_healthy {
+                let status = if health_status.overall_healthy
+                    && !health_status.decommissioned_models.is_empty()
+                {
+                    HealthStatus::Degraded {
+                        reason: decommissioned_reason(&health_status.decommissioned_models).into(),
+                    }
+                } else if health_status.overall_healthy {
                     HealthStatus::Up
                 } else {
                     let reasons = vec![
@@ -233,6 +314,45 @@ pub struct HealthCheck {
     pub api_clients: HashMap<String, HealthStatus>,
 }
 
+impl HealthCheck {
+    /// Whether the service can handle traffic, i.e. no dependency is down
+    ///
+    /// Degraded dependencies still count as ready since requests are served,
+    /// if with reduced quality.
+    pub fn is_ready(&self) -> bool {
+        !matches!(self.status, HealthStatus::Down { .. })
+            && !self
+                .api_clients
+                .values()
+                .any(|status| matches!(status, HealthStatus::Down { .. }))
+    }
+}
+
+/// Liveness probe result, reported without contacting any dependency
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({"status": "Up", "version": "0.1.0"}))]
+pub struct LivenessCheck {
+    /// Always `Up` once the server answers requests
+    pub status: HealthStatus,
+    /// Service version from Cargo.toml
+    pub version: Box<str>,
+}
+
+impl LivenessCheck {
+    /// Liveness of the running process
+    pub fn current() -> Self {
+        Self {
+            status: HealthStatus::Up,
+            version: Box::from(env!("CARGO_PKG_VERSION")),
+        }
+    }
+}
+
+/// Health reason for models `OpenAI` no longer serves
+fn decommissioned_reason(models: &[String]) -> String {
+    format!("spam predictor model decommissioned: {}", models.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,4 +424,101 @@ mod tests {
         token.cancel();
         assert!(state.cancellation_token.is_cancelled());
     }
+
+    /// State whose registries are copies in `dir`, so tests can rewrite them
+    async fn state_with_registries_in(dir: &tempfile::TempDir) -> ServerState {
+        let mut config = ServerConfig::for_testing();
+        let model_path = dir.path().join("models.yaml");
+        let prompt_path = dir.path().join("prompts.json");
+        std::fs::copy(&config.spam_predictor.model_registry_path, &model_path)
+            .expect("Failed to copy model registry");
+        std::fs::copy(&config.spam_predictor.prompt_registry_path, &prompt_path)
+            .expect("Failed to copy prompt registry");
+        config.spam_predictor.model_registry_path = model_path.display().to_string();
+        config.spam_predictor.prompt_registry_path = prompt_path.display().to_string();
+
+        let spam_predictor_config = spam_predictor::SpamPredictorConfig::from_files(
+            &config.spam_predictor.model_registry_path,
+            &config.spam_predictor.prompt_registry_path,
+            spam_predictor::config::OpenAiConfig::new(
+                config.spam_predictor.openai_api_key.value().to_string(),
+            ),
+        )
+        .await
+        .expect("Failed to create spam predictor config");
+        let spam_predictor = Arc::new(
+            SpamPredictor::new(spam_predictor_config)
+                .await
+                .expect("Failed to create spam predictor"),
+        );
+
+        ServerState::new(
+            config,
+            Arc::new(ApiRegistry::new()),
+            spam_predictor,
+            CancellationToken::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn registry_reloads_are_serialized() {
+        let dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let state = state_with_registries_in(&dir).await;
+
+        let guard = state.registry_reload_lock.lock().await;
+        assert!(matches!(
+            state.try_reload_registries().await,
+            Err(ServerError::ReloadInProgress)
+        ));
+
+        let waiting = tokio::spawn({
+            let state = state.clone();
+            async move { state.reload_registries().await }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(
+            !waiting.is_finished(),
+            "reload must wait for the running one"
+        );
+
+        drop(guard);
+        waiting
+            .await
+            .expect("reload task panicked")
+            .expect("reload after the lock is released succeeds");
+        state
+            .try_reload_registries()
+            .await
+            .expect("lock is free again");
+    }
+
+    #[tokio::test]
+    async fn failed_reload_keeps_active_registries() {
+        let dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let state = state_with_registries_in(&dir).await;
+        let before = state.spam_predictor().get_config_summary();
+
+        // A valid model registry next to an invalid prompt registry: a partial
+        // swap would expose the new model type
+        std::fs::write(
+            dir.path().join("models.yaml"),
+            "model_registry:\n  nsfw_classification:\n    latest: ft:gpt-4o-2024-08-06:test::NSFW\n",
+        )
+        .expect("Failed to update model registry");
+        std::fs::write(dir.path().join("prompts.json"), "{ not json")
+            .expect("Failed to corrupt prompt registry");
+        let (first, second) = tokio::join!(state.reload_registries(), state.reload_registries());
+
+        for result in [first, second] {
+            match result {
+                Err(ServerError::ValidationError(message)) => {
+                    assert!(message.starts_with("Failed to reload registry files"));
+                }
+                other => panic!("Expected ValidationError, got: {other:?}"),
+            }
+        }
+        let after = state.spam_predictor().get_config_summary();
+        assert_eq!(after.model_types, before.model_types);
+        assert_eq!(after.prompt_versions, before.prompt_versions);
+    }
 }
@@ -0,0 +1,233 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tracing subscriber setup
+//!
+//! Logs are always written through the `tracing_subscriber` fmt layer. When the
+//! crate is built with the `otlp` feature and an OTLP endpoint is configured,
+//! spans are additionally exported to an OpenTelemetry collector over OTLP/HTTP,
+//! keeping the existing span structure (`chain_request`, `process_single_address`,
+//! ...) and their fields.
+//!
+//! Tracing is installed before [`ServerConfig`](crate::ServerConfig) is loaded so
+//! that configuration errors are logged, hence the exporter is configured through
+//! the standard OpenTelemetry environment variables rather than the `SERVER__`
+//! hierarchy.
+
+use tracing::Subscriber;
+use tracing_subscriber::{
+    EnvFilter, layer::SubscriberExt, registry::LookupSpan, util::SubscriberInitExt,
+};
+
+use crate::error::{ServerError, ServerResult};
+
+/// Environment variable holding the base URL of the OTLP/HTTP collector
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Environment variable overriding the service name reported with exported spans
+pub const SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+
+/// Service name reported with exported spans by default
+pub const DEFAULT_SERVICE_NAME: &str = "nft-api";
+
+/// Tracing export configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    /// Base URL of the OTLP/HTTP collector, e.g. `http://localhost:4318`
+    ///
+    /// Spans are only exported when set; `/v1/traces` is appended.
+    pub otlp_endpoint: Option<String>,
+    /// Service name reported with exported spans
+    pub service_name: String,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            otlp_endpoint: None,
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+        }
+    }
+}
+
+impl TelemetryConfig {
+    /// Read the configuration from [`OTLP_ENDPOINT_ENV`] and [`SERVICE_NAME_ENV`]
+    ///
+    /// Empty values are treated as unset.
+    pub fn from_env() -> Self {
+        let non_empty = |name| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        Self {
+            otlp_endpoint: non_empty(OTLP_ENDPOINT_ENV),
+            service_name: non_empty(SERVICE_NAME_ENV)
+                .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string()),
+        }
+    }
+}
+
+/// Keeps the span exporter alive, flushing pending spans when dropped
+#[derive(Debug, Default)]
+#[must_use = "dropping the guard shuts the span exporter down"]
+pub struct TelemetryGuard {
+    #[cfg(feature = "otlp")]
+    tracer_provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>,
+}
+
+impl TelemetryGuard {
+    /// Whether spans are exported over OTLP
+    pub fn is_exporting(&self) -> bool {
+        #[cfg(feature = "otlp")]
+        {
+            self.tracer_provider.is_some()
+        }
+        #[cfg(not(feature = "otlp"))]
+        {
+            false
+        }
+    }
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        #[cfg(feature = "otlp")]
+        if let Some(tracer_provider) = self.tracer_provider.take()
+            && let Err(e) = tracer_provider.shutdown()
+        {
+            eprintln!("Failed to flush OTLP spans: {e}");
+        }
+    }
+}
+
+/// Build the tracing subscriber without installing it
+///
+/// The level filter comes from `RUST_LOG`, defaulting to `info`.
+///
+/// # Errors
+///
+/// Returns a configuration error if the OTLP exporter cannot be built
+pub fn build_subscriber(
+    config: &TelemetryConfig,
+) -> ServerResult<(
+    impl Subscriber + Send + Sync + for<'span> LookupSpan<'span>,
+    TelemetryGuard,
+)> {
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let registry = tracing_subscriber::registry()
+        .with(env_filter)
+        .with(tracing_subscriber::fmt::layer());
+
+    #[cfg(feature = "otlp")]
+    {
+        use opentelemetry::trace::TracerProvider;
+
+        let tracer_provider = config
+            .otlp_endpoint
+            .as_deref()
+            .map(|endpoint| otlp_tracer_provider(endpoint, &config.service_name))
+            .transpose()?;
+        let otel_layer = tracer_provider.as_ref().map(|tracer_provider| {
+            tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(DEFAULT_SERVICE_NAME))
+        });
+
+        Ok((
+            registry.with(otel_layer),
+            TelemetryGuard { tracer_provider },
+        ))
+    }
+
+    #[cfg(not(feature = "otlp"))]
+    {
+        let _ = config;
+        Ok((registry, TelemetryGuard::default()))
+    }
+}
+
+/// Build and install the global tracing subscriber
+///
+/// # Errors
+///
+/// Returns an error if the OTLP exporter cannot be built or a global
+/// subscriber is already installed
+pub fn init(config: &TelemetryConfig) -> ServerResult<TelemetryGuard> {
+    let (subscriber, guard) = build_subscriber(config)?;
+    subscriber.try_init().map_err(|e| ServerError::Runtime {
+        message: format!("Failed to install tracing subscriber: {e}"),
+    })?;
+
+    if let Some(endpoint) = &config.otlp_endpoint {
+        if guard.is_exporting() {
+            tracing::info!(endpoint, "exporting spans over OTLP");
+        } else {
+            tracing::warn!(
+                endpoint,
+                "{OTLP_ENDPOINT_ENV} is set but the server was built without the `otlp` feature"
+            );
+        }
+    }
+
+    Ok(guard)
+}
+
+/// Tracer provider batching spans to the OTLP/HTTP collector at `endpoint`
+#[cfg(feature = "otlp")]
+fn otlp_tracer_provider(
+    endpoint: &str,
+    service_name: &str,
+) -> ServerResult<opentelemetry_sdk::trace::SdkTracerProvider> {
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| ServerError::Config {
+            message: format!("Invalid OTLP exporter configuration: {e}"),
+        })?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(service_name.to_string())
+                .build(),
+        )
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_does_not_export() {
+        let (_subscriber, guard) =
+            build_subscriber(&TelemetryConfig::default()).expect("fmt subscriber");
+        assert!(!guard.is_exporting());
+    }
+
+    #[tokio::test]
+    async fn subscriber_builds_with_otlp_endpoint() {
+        let config = TelemetryConfig {
+            otlp_endpoint: Some("http://127.0.0.1:4318/".to_string()),
+            ..TelemetryConfig::default()
+        };
+
+        let (subscriber, guard) = build_subscriber(&config).expect("OTLP subscriber");
+        assert_eq!(guard.is_exporting(), cfg!(feature = "otlp"));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("chain_request", chain_id = 1);
+            let _entered = span.enter();
+            tracing::info!("inside exported span");
+        });
+        // Nothing listens on the endpoint; shutting down must not panic either
+        drop(guard);
+    }
+}
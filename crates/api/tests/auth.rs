@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for client API key authentication of `/v1` routes
+
+use std::net::SocketAddr;
+
+use api::{
+    Server, ServerConfig, ShutdownConfig,
+    config::{ApiKey, AuthConfig},
+};
+use axum::http::StatusCode;
+
+const CLIENT_KEY: &str = "client-key-1";
+
+async fn start_server() -> SocketAddr {
+    let mut config = ServerConfig::for_testing();
+    config.auth = AuthConfig {
+        enabled: Some(true),
+        api_keys: vec![
+            ApiKey::new(CLIENT_KEY.to_string()).expect("valid key"),
+            ApiKey::new("client-key-2".to_string()).expect("valid key"),
+        ],
+    };
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+#[tokio::test]
+async fn valid_key_is_accepted_in_either_header() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+    let url = format!("http://{addr}/v1/chains");
+
+    let response = client
+        .get(&url)
+        .bearer_auth(CLIENT_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(&url)
+        .header("x-api-key", CLIENT_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn missing_or_invalid_key_is_rejected_with_json_error() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+    let url = format!("http://{addr}/v1/chains");
+
+    for request in [
+        client.get(&url),
+        client.get(&url).bearer_auth("not-a-client-key"),
+        client.get(&url).header("x-api-key", "not-a-client-key"),
+        // Sharing a prefix with a valid key is not enough
+        client.get(&url).bearer_auth("client-key-"),
+        client.get(&url).header("x-api-key", "client-key-10"),
+    ] {
+        let response = request.send().await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()["www-authenticate"], "Bearer");
+        let body: serde_json::Value = response.json().await.expect("JSON body");
+        assert_eq!(body["status"], 401);
+        assert_eq!(body["error"], "unauthorized");
+        assert!(
+            body["message"]
+                .as_str()
+                .is_some_and(|error| error.contains("API key")),
+            "unexpected body: {body}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn health_and_docs_are_exempt() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+
+    for path in ["/health", "/api-doc/openapi.json"] {
+        let response = client
+            .get(format!("http://{addr}{path}"))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_ne!(response.status(), StatusCode::UNAUTHORIZED, "{path}");
+    }
+}
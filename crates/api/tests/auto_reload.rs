@@ -0,0 +1,257 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for automatic and admin-triggered reloads of the registry files
+
+use std::{fs, net::SocketAddr, time::Duration};
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use tempfile::TempDir;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+mod fixtures;
+use fixtures::chat_completion;
+
+const ADMIN_KEY: &str = "admin-test-key";
+const MODELS: &str =
+    "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o-2024-08-06:test::BASE\n";
+
+/// Server whose registries live in `dir`, reloaded automatically on change
+async fn server_with_registries_in(dir: &TempDir) -> Server {
+    let model_path = dir.path().join("models.yaml");
+    let prompt_path = dir.path().join("prompts.json");
+    fs::write(&model_path, MODELS).expect("Failed to write model registry");
+    fs::copy("../../assets/prompts/ft_prompt.json", &prompt_path)
+        .expect("Failed to copy prompt registry");
+
+    let mut config = ServerConfig::for_testing();
+    config.spam_predictor.model_registry_path = model_path.display().to_string();
+    config.spam_predictor.prompt_registry_path = prompt_path.display().to_string();
+    config.spam_predictor.auto_reload.enabled = true;
+    config.spam_predictor.auto_reload.debounce_ms = 100;
+
+    Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+}
+
+fn model_types(server: &Server) -> Vec<String> {
+    let mut model_types = server
+        .state()
+        .spam_predictor()
+        .get_config_summary()
+        .model_types;
+    model_types.sort();
+    model_types
+}
+
+#[tokio::test]
+async fn registry_change_is_reloaded_after_debounce() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let server = server_with_registries_in(&dir).await;
+    assert_eq!(model_types(&server), vec!["spam_classification"]);
+
+    fs::write(
+        dir.path().join("models.yaml"),
+        format!("{MODELS}  nsfw_classification:\n    latest: ft:gpt-4o-2024-08-06:test::NSFW\n"),
+    )
+    .expect("Failed to update model registry");
+
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while model_types(&server).len() < 2 {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "registry change was not picked up"
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(
+        model_types(&server),
+        vec!["nsfw_classification", "spam_classification"]
+    );
+}
+
+#[tokio::test]
+async fn invalid_registry_change_keeps_previous_configuration() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let server = server_with_registries_in(&dir).await;
+
+    fs::write(
+        dir.path().join("models.yaml"),
+        "model_registry: [not, a, map]\n",
+    )
+    .expect("Failed to update model registry");
+    // Well past the debounce window, so the reload has been attempted
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    assert_eq!(model_types(&server), vec!["spam_classification"]);
+}
+
+#[tokio::test]
+async fn admin_reload_applies_registry_changes() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let mut config = ServerConfig::for_testing();
+    let model_path = dir.path().join("models.yaml");
+    fs::write(&model_path, MODELS).expect("Failed to write model registry");
+    config.spam_predictor.model_registry_path = model_path.display().to_string();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let url = format!("http://{addr}/v1/admin/reload");
+    let response = client
+        .post(&url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .post(&url)
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    fs::write(&model_path, "model_registry: [not, a, map]\n")
+        .expect("Failed to update model registry");
+    let response = client
+        .post(&url)
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.expect("JSON body");
+    assert_eq!(body["error"], "validation_error");
+    assert!(
+        body["message"]
+            .as_str()
+            .is_some_and(|error| error.contains("Failed to reload registry files")),
+        "unexpected body: {body}"
+    );
+}
+
+/// Mock servers backing the metadata provider and the spam predictor
+struct Upstreams {
+    moralis: MockServer,
+    openai: MockServer,
+}
+
+async fn start_upstreams() -> Upstreams {
+    let moralis = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1111111111111111111111111111111111111111",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis)
+        .await;
+
+    let openai = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai)
+        .await;
+
+    Upstreams { moralis, openai }
+}
+
+/// Classify `address` and return the model ID of the completion it triggered
+async fn classify_model_id(
+    client: &reqwest::Client,
+    addr: SocketAddr,
+    upstreams: &Upstreams,
+    address: &str,
+) -> String {
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let requests = upstreams
+        .openai
+        .received_requests()
+        .await
+        .expect("request recording enabled");
+    let body: Value = requests
+        .last()
+        .expect("a completion was requested")
+        .body_json()
+        .expect("JSON completion request");
+    body["model"].as_str().expect("model ID").to_string()
+}
+
+#[tokio::test]
+async fn admin_reload_serves_new_model_id() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let upstreams = start_upstreams().await;
+    let model_path = dir.path().join("models.yaml");
+    fs::write(&model_path, MODELS).expect("Failed to write model registry");
+
+    let mut config = ServerConfig::for_testing();
+    config.spam_predictor.model_registry_path = model_path.display().to_string();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = upstreams.moralis.uri().parse().expect("valid URL");
+    config.spam_predictor.openai_base_url =
+        Some(upstreams.openai.uri().parse().expect("valid mock URL"));
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let model_id = classify_model_id(
+        &client,
+        addr,
+        &upstreams,
+        "0x1111111111111111111111111111111111111111",
+    )
+    .await;
+    assert_eq!(model_id, "ft:gpt-4o-2024-08-06:test::BASE");
+
+    fs::write(&model_path, MODELS.replace("test::BASE", "test::RELOADED"))
+        .expect("Failed to update model registry");
+    // The original path stays available
+    let response = client
+        .post(format!("http://{addr}/admin/registries/reload"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let model_id = classify_model_id(
+        &client,
+        addr,
+        &upstreams,
+        "0x2222222222222222222222222222222222222222",
+    )
+    .await;
+    assert_eq!(model_id, "ft:gpt-4o-2024-08-06:test::RELOADED");
+}
@@ -0,0 +1,219 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the prediction cache endpoints
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+mod fixtures;
+use fixtures::chat_completion;
+
+const ADMIN_KEY: &str = "admin-test-key";
+const ADDRESS: &str = "0x1111111111111111111111111111111111111111";
+
+/// Mock servers backing the metadata provider and the spam predictor
+struct Upstreams {
+    _moralis: MockServer,
+    openai: MockServer,
+}
+
+/// Start a server whose metadata and predictions come from mocks that classify everything as spam
+async fn start_server() -> (SocketAddr, Upstreams) {
+    let moralis = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": ADDRESS,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis)
+        .await;
+
+    let openai = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url = Some(openai.uri().parse().expect("valid mock URL"));
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (
+        addr,
+        Upstreams {
+            _moralis: moralis,
+            openai,
+        },
+    )
+}
+
+/// Classify `ADDRESS` and return its result
+async fn classify(client: &reqwest::Client, addr: SocketAddr) -> Value {
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [ADDRESS] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body: Value = response.json().await.expect("valid JSON");
+    body[ADDRESS].take()
+}
+
+/// Number of chat completions the predictor requested, including health probes
+async fn completion_requests(upstreams: &Upstreams) -> usize {
+    upstreams
+        .openai
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .len()
+}
+
+async fn cache_stats(client: &reqwest::Client, addr: SocketAddr) -> Value {
+    let response = client
+        .get(format!("http://{addr}/v1/cache/stats"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.expect("valid JSON")
+}
+
+#[tokio::test]
+async fn cache_stats_reflect_cache_activity() {
+    let (addr, upstreams) = start_server().await;
+    let client = reqwest::Client::new();
+
+    let stats = cache_stats(&client, addr).await;
+    assert_eq!(stats["prediction_count"], 0);
+    assert_eq!(stats["cache_hits"], 0);
+
+    let completions_before = completion_requests(&upstreams).await;
+    assert_eq!(classify(&client, addr).await["status"], "spam");
+    let result = classify(&client, addr).await;
+    assert_eq!(result["status"], "spam");
+    assert_eq!(result["cached"], true);
+    assert_eq!(
+        completion_requests(&upstreams).await,
+        completions_before + 1
+    );
+
+    let stats = cache_stats(&client, addr).await;
+    assert_eq!(stats["prediction_count"], 1);
+    assert_eq!(stats["cache_stores"], 1);
+    assert!(stats["cache_hits"].as_u64().expect("hit count") >= 1);
+    assert!(stats["cache_misses"].as_u64().expect("miss count") >= 1);
+    assert!(stats["hit_rate"].as_f64().expect("hit rate") > 0.0);
+    assert!(stats["utilization_rate"].as_f64().expect("utilization") > 0.0);
+    assert!(stats["max_capacity"].as_u64().expect("capacity") > 0);
+    for field in [
+        "model_count",
+        "prompt_count",
+        "cache_evictions",
+        "cache_expired",
+        "evictions_by_reason",
+        "avg_access_count",
+    ] {
+        assert!(stats.get(field).is_some(), "missing {field}: {stats}");
+    }
+}
+
+async fn clear_cache(client: &reqwest::Client, addr: SocketAddr, body: Option<Value>) -> Value {
+    let mut request = client
+        .post(format!("http://{addr}/v1/cache/clear"))
+        .header("x-admin-api-key", ADMIN_KEY);
+    if let Some(body) = body {
+        request = request.json(&body);
+    }
+    let response = request.send().await.expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    response.json().await.expect("valid JSON")
+}
+
+#[tokio::test]
+async fn cleared_predictions_are_requested_again() {
+    let (addr, upstreams) = start_server().await;
+    let client = reqwest::Client::new();
+
+    classify(&client, addr).await;
+    let completions = completion_requests(&upstreams).await;
+
+    // Clearing configurations keeps the cached prediction
+    let cleared = clear_cache(&client, addr, Some(json!({ "scope": "configurations" }))).await;
+    assert_eq!(cleared["scope"], "configurations");
+    assert_eq!(classify(&client, addr).await["cached"], true);
+    assert_eq!(completion_requests(&upstreams).await, completions);
+
+    // Without a body only predictions are cleared
+    let cleared = clear_cache(&client, addr, None).await;
+    assert_eq!(cleared, json!({ "scope": "predictions", "cleared": 1 }));
+
+    let result = classify(&client, addr).await;
+    assert_eq!(result["status"], "spam");
+    assert_ne!(result["cached"], true);
+    assert_eq!(completion_requests(&upstreams).await, completions + 1);
+
+    let cleared = clear_cache(&client, addr, Some(json!({ "scope": "all" }))).await;
+    assert_eq!(cleared["scope"], "all");
+    assert!(cleared["cleared"].as_u64().expect("cleared count") >= 1);
+}
+
+#[tokio::test]
+async fn cache_clear_rejects_unknown_scope() {
+    let (addr, _upstreams) = start_server().await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/cache/clear"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&json!({ "scope": "everything" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn cache_endpoints_require_admin_key() {
+    let (addr, _upstreams) = start_server().await;
+    let client = reqwest::Client::new();
+
+    for request in [
+        client.get(format!("http://{addr}/v1/cache/stats")),
+        client
+            .get(format!("http://{addr}/v1/cache/stats"))
+            .header("x-admin-api-key", "wrong-key"),
+        client.post(format!("http://{addr}/v1/cache/clear")),
+        client
+            .post(format!("http://{addr}/v1/cache/clear"))
+            .header("x-admin-api-key", "wrong-key"),
+    ] {
+        let response = request.send().await.expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the chains endpoint
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey};
+use axum::http::StatusCode;
+use serde_json::Value;
+use shared_types::ChainId;
+
+const ADMIN_KEY: &str = "admin-test-key";
+
+/// Start a server with Base disabled and an admin key configured
+async fn start_server() -> SocketAddr {
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    for (chain_id, chain_config) in &mut config.chains {
+        chain_config.enabled = *chain_id != ChainId::Base;
+    }
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+fn chain_ids(body: &Value) -> Vec<u64> {
+    body["chains"]
+        .as_array()
+        .expect("chains array")
+        .iter()
+        .map(|chain| chain["id"].as_u64().expect("numeric chain id"))
+        .collect()
+}
+
+#[tokio::test]
+async fn chains_public_response_excludes_disabled_chains() {
+    let addr = start_server().await;
+
+    let response = reqwest::get(format!("http://{addr}/v1/chains"))
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("valid JSON");
+    let ids = chain_ids(&body);
+    assert_eq!(ids.len(), ChainId::all().len() - 1);
+    assert!(!ids.contains(&ChainId::Base.chain_id()));
+
+    // Public entries are not annotated with admin-only fields
+    for chain in body["chains"].as_array().expect("chains array") {
+        assert!(chain.get("enabled").is_none());
+        assert!(chain.get("implementation_status").is_none());
+    }
+}
+
+#[tokio::test]
+async fn chains_admin_response_includes_disabled_chains() {
+    let addr = start_server().await;
+
+    let response = reqwest::Client::new()
+        .get(format!("http://{addr}/v1/chains?include_disabled=true"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("valid JSON");
+    assert_eq!(chain_ids(&body).len(), ChainId::all().len());
+
+    for chain in body["chains"].as_array().expect("chains array") {
+        let expected_enabled = chain["id"] != ChainId::Base.chain_id();
+        assert_eq!(chain["enabled"], expected_enabled);
+        assert_eq!(chain["implementation_status"], "Full");
+    }
+}
+
+#[tokio::test]
+async fn chains_include_disabled_requires_admin_key() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{addr}/v1/chains?include_disabled=true"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .get(format!("http://{addr}/v1/chains?include_disabled=true"))
+        .header("x-admin-api-key", "wrong-key")
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn chains_response_describes_support_status_and_capabilities() {
+    let addr = start_server().await;
+
+    let response = reqwest::get(format!("http://{addr}/v1/chains"))
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("valid JSON");
+    let ethereum = body["chains"]
+        .as_array()
+        .expect("chains array")
+        .iter()
+        .find(|chain| chain["id"] == ChainId::Ethereum.chain_id())
+        .expect("Ethereum listed");
+    assert_eq!(ethereum["name"], "Ethereum");
+    assert_eq!(ethereum["status"], "fully_supported");
+    assert_eq!(
+        ethereum["capabilities"],
+        serde_json::json!([
+            "moralis_metadata",
+            "pinax_analytics",
+            "spam_prediction",
+            "realtime_updates",
+            "token_metadata"
+        ])
+    );
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for circuit breaking of failing metadata providers
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+#[tokio::test]
+async fn health_reports_degraded_while_circuit_breaker_is_open() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.external_apis.circuit_breaker.failure_threshold = 2;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let health = || async {
+        client
+            .get(format!("http://{addr}/health"))
+            .send()
+            .await
+            .expect("Failed to send request")
+            .json::<Value>()
+            .await
+            .expect("valid JSON")
+    };
+    assert_eq!(health().await["status"], "Up");
+
+    let addresses = [
+        "0x1111111111111111111111111111111111111111",
+        "0x2222222222222222222222222222222222222222",
+    ];
+    for address in addresses {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&json!({ "chain_id": 1, "addresses": [address] }))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    // The breaker is open, so the next lookup does not reach Moralis
+    let requests_before = moralis_server
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .len();
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": ["0x3333333333333333333333333333333333333333"]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: Value = response.json().await.expect("valid JSON");
+    let result = &body["0x3333333333333333333333333333333333333333"];
+    assert_eq!(result["status"], "metadata_fetch_error");
+    assert_eq!(result["error_code"], "unavailable");
+    assert_eq!(
+        moralis_server
+            .received_requests()
+            .await
+            .expect("request recording enabled")
+            .len(),
+        requests_before
+    );
+
+    let health = health().await;
+    assert_eq!(
+        health["status"],
+        json!({ "Degraded": { "reason": "circuit breaker open for moralis" } })
+    );
+}
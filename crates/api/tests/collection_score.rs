@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the collection score endpoint
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_string_contains, method, path_regex, query_param},
+};
+
+mod fixtures;
+use fixtures::chat_completion;
+
+const COLLECTION: &str = "0x4444444444444444444444444444444444444444";
+
+fn token(token_id: &str, name: &str) -> Value {
+    json!({
+        "token_address": COLLECTION,
+        "token_id": token_id,
+        "contract_type": "ERC721",
+        "name": "Mixed Collection",
+        "symbol": "MIX",
+        "metadata": json!({ "name": name }).to_string()
+    })
+}
+
+/// Start a server whose Moralis mock returns four tokens, one of them spam
+async fn start_server_with_sampled_tokens() -> (SocketAddr, MockServer) {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{COLLECTION}$")))
+        .and(query_param("limit", "4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [
+                token("1", "Mixed #1"),
+                token("2", "FreeAirdropSpam claim"),
+                token("3", "Mixed #3"),
+                token("4", "Mixed #4"),
+            ]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_string_contains("FreeAirdropSpam"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.collection_score.max_sample_size = 4;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, moralis_server)
+}
+
+async fn post_collection_score(addr: SocketAddr, body: Value) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{addr}/v1/collection/score"))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn collection_score_aggregates_sampled_token_verdicts() {
+    let (addr, _moralis_server) = start_server_with_sampled_tokens().await;
+
+    let response = post_collection_score(
+        addr,
+        json!({ "chain_id": 1, "address": COLLECTION, "sample_size": 4 }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["spam_likelihood"], 0.25);
+    assert_eq!(
+        body["distribution"],
+        json!({
+            "total": 4,
+            "spam": 1,
+            "legitimate": 3,
+            "inconclusive": 0,
+            "no_data": 0,
+            "error": 0
+        })
+    );
+    assert_eq!(
+        body["tokens"],
+        json!([
+            { "token_id": "1", "status": "legitimate" },
+            { "token_id": "2", "status": "spam" },
+            { "token_id": "3", "status": "legitimate" },
+            { "token_id": "4", "status": "legitimate" }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn collection_score_rejects_sample_size_above_maximum() {
+    let (addr, _moralis_server) = start_server_with_sampled_tokens().await;
+
+    for sample_size in [0, 5] {
+        let response = post_collection_score(
+            addr,
+            json!({ "chain_id": 1, "address": COLLECTION, "sample_size": sample_size }),
+        )
+        .await;
+        assert_eq!(
+            response.status(),
+            StatusCode::BAD_REQUEST,
+            "sample_size {sample_size}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn collection_score_requires_token_metadata_provider() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response =
+        post_collection_score(addr, json!({ "chain_id": 1, "address": COLLECTION })).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    let body: Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["details"]["requested_capability"], "token_metadata");
+}
@@ -4,10 +4,19 @@
 
 //! Integration tests for the contract status endpoint
 
-use api::{Server, ServerConfig, ShutdownConfig};
+use api::{
+    Server, ServerConfig, ShutdownConfig,
+    config::{ApiKey, ChainPinaxConfig, TimeoutSeconds, UpstreamAllowlistConfig},
+};
 use axum::http::StatusCode;
 use serde_json::json;
 use shared_types::ChainId;
+use spam_predictor::ConfidenceBandThresholds;
+use std::time::Duration;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, body_string_contains, method, path_regex},
+};
 
 mod fixtures;
 use fixtures::*;
@@ -43,6 +52,45 @@ async fn contract_status_valid_addresses() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn contract_status_accepts_legacy_field_aliases() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let address = "0x1234567890123456789012345678901234567890";
+
+    for request in [
+        json!({ "chain_id": 137, "contracts": [address] }),
+        json!({ "chain_id": "Polygon", "addresses": [address] }),
+        json!({ "chain_id": "137", "addresses": [address] }),
+        json!({ "chain_id": "matic", "contracts": [address] }),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(
+            response.status(),
+            StatusCode::OK,
+            "request {request} rejected"
+        );
+
+        let body: serde_json::Value = response.json().await.expect("valid JSON");
+        assert_eq!(
+            body[address]["chain_id"],
+            ChainId::Polygon.chain_id(),
+            "request {request} analyzed on the wrong chain"
+        );
+    }
+}
+
 #[tokio::test]
 async fn contract_status_invalid_addresses() {
     let config = ServerConfig::for_testing();
@@ -103,7 +151,48 @@ async fn contract_status_empty_addresses() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 
     let response_text = response.text().await.expect("Failed to read response");
-    assert!(response_text.contains("addresses list cannot be empty"));
+    assert!(response_text.contains("list cannot be empty"));
+}
+
+#[tokio::test]
+async fn contract_status_enforces_max_addresses() {
+    let mut config = ServerConfig::for_testing();
+    config.rate_limiting.max_addresses = 3;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let addresses =
+        |count: usize| -> Vec<String> { (1..=count).map(|i| format!("0x{i:040}")).collect() };
+
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses(3) }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(
+        response.status(),
+        StatusCode::OK,
+        "a request exactly at the limit should be accepted"
+    );
+
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses(4) }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response_text = response.text().await.expect("Failed to read response");
+    assert!(
+        response_text.contains("request exceeds maximum of 3 addresses"),
+        "unexpected body: {response_text}"
+    );
 }
 
 #[tokio::test]
@@ -134,6 +223,46 @@ async fn contract_status_invalid_chain_id() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+#[tokio::test]
+async fn contract_status_refuses_chain_disabled_in_config() {
+    let mut config = ServerConfig::for_testing();
+    config
+        .chains
+        .get_mut(&ChainId::Ethereum)
+        .expect("Ethereum is configured")
+        .enabled = false;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let addresses = ["0x1234567890123456789012345678901234567890"];
+
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["error"], "chain_disabled");
+    assert_eq!(body["details"]["chain_id"], 1);
+    assert_eq!(body["status"], 404);
+
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 137, "addresses": addresses }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn contract_status_chain_id_string() {
     let config = ServerConfig::for_testing();
@@ -162,6 +291,71 @@ async fn contract_status_chain_id_string() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn contract_status_accepts_every_chain_id_form() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let address = "0x1234567890123456789012345678901234567890";
+
+    for &chain in ChainId::all() {
+        let forms = [
+            json!(chain.chain_id()),
+            json!(chain.chain_id().to_string()),
+            json!(chain.name().to_lowercase()),
+        ];
+        for form in forms {
+            for request in [
+                json!({ "chain_id": form, "addresses": [address] }),
+                json!({ "addresses": [{ "address": address, "chain_id": form }] }),
+            ] {
+                let response = client
+                    .post(format!("http://{addr}/v1/contract/status"))
+                    .json(&request)
+                    .send()
+                    .await
+                    .expect("Failed to send request");
+                assert_eq!(
+                    response.status(),
+                    StatusCode::OK,
+                    "request {request} rejected"
+                );
+
+                let body: serde_json::Value = response.json().await.expect("valid JSON");
+                assert_eq!(
+                    body[address]["chain_id"],
+                    chain.chain_id(),
+                    "request {request} analyzed on the wrong chain"
+                );
+            }
+        }
+    }
+
+    // The OpenAPI schema documents both the numeric and the string forms
+    let spec: serde_json::Value = client
+        .get(format!("http://{addr}/api-doc/openapi.json"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("valid JSON");
+    let chain_id =
+        &spec["components"]["schemas"]["ContractStatusRequest"]["properties"]["chain_id"];
+    let types: Vec<&str> = chain_id["oneOf"]
+        .as_array()
+        .expect("chain_id documented as oneOf")
+        .iter()
+        .filter_map(|schema| schema["type"].as_str())
+        .collect();
+    assert_eq!(types, ["integer", "string"]);
+}
+
 #[tokio::test]
 async fn contract_status_full_implementation_chain() {
     let config = ServerConfig::for_testing();
@@ -511,7 +705,7 @@ async fn contract_status_chain_specific_error_messages() {
     for fixture in ChainTestFixture::all_chains() {
         let request = json!({
             "chain_id": fixture.chain_id.chain_id(),
-            "addresses": []  // Empty addresses should trigger validation error
+            "addresses": []  // Empty addresses are rejected while parsing
         });
 
         let response = client
@@ -525,7 +719,7 @@ async fn contract_status_chain_specific_error_messages() {
 
         let error_text = response.text().await.expect("failed to read response");
         assert!(
-            error_text.contains("addresses list cannot be empty"),
+            error_text.contains("list cannot be empty"),
             "error message should be descriptive for chain {}",
             fixture.chain_id.name()
         );
@@ -604,3 +798,2316 @@ async fn contract_status_performance_all_chains() {
         );
     }
 }
+
+#[tokio::test]
+async fn contract_status_client_disconnect_aborts_upstream_calls() {
+    const MORALIS_DELAY: Duration = Duration::from_millis(300);
+
+    // Moralis responds slowly so the request is still in flight when the client gives up
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(MORALIS_DELAY),
+        )
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    // Process addresses one at a time so the remaining ones are still pending on disconnect
+    config.concurrency.max_concurrent_external_api_calls = 1;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let openai_requests_before = openai_server
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .len();
+
+    let client = reqwest::Client::builder()
+        .timeout(MORALIS_DELAY / 3)
+        .build()
+        .expect("Failed to build client");
+
+    let request = json!({
+        "chain_id": 1,
+        "addresses": [
+            "0x1234567890123456789012345678901234567890",
+            "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd",
+            "0x9876543210987654321098765432109876543210"
+        ]
+    });
+
+    let result = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&request)
+        .send()
+        .await;
+    assert!(
+        result.is_err_and(|e| e.is_timeout()),
+        "client should give up before the server responds"
+    );
+
+    // Watch the upstreams for as long as every address would have taken to
+    // complete had the work not been aborted
+    let deadline = tokio::time::Instant::now() + MORALIS_DELAY * 5;
+    loop {
+        let moralis_requests = moralis_server
+            .received_requests()
+            .await
+            .expect("request recording enabled")
+            .len();
+        assert!(
+            moralis_requests <= 1,
+            "only the in-flight upstream call should have been issued"
+        );
+        let openai_requests_after = openai_server
+            .received_requests()
+            .await
+            .expect("request recording enabled")
+            .len();
+        assert_eq!(
+            openai_requests_after, openai_requests_before,
+            "no spam predictions should run after the client disconnected"
+        );
+
+        if tokio::time::Instant::now() >= deadline {
+            assert_eq!(
+                moralis_requests, 1,
+                "the first address should have been fetched"
+            );
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Start a server whose Moralis upstream fails with a detailed error body
+async fn start_server_with_failing_upstream(
+    expose_error_details: bool,
+) -> (std::net::SocketAddr, MockServer) {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("db-replica-7.internal exploded"))
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.expose_error_details = Some(expose_error_details);
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, moralis_server)
+}
+
+async fn failing_upstream_result(addr: std::net::SocketAddr) -> serde_json::Value {
+    let address = "0x1234567890123456789012345678901234567890";
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    let result = body[address].clone();
+    assert_eq!(result["status"], "metadata_fetch_error");
+    result
+}
+
+#[tokio::test]
+async fn contract_status_hides_upstream_error_detail_when_disabled() {
+    let (addr, _moralis) = start_server_with_failing_upstream(false).await;
+    let result = failing_upstream_result(addr).await;
+
+    assert_eq!(result["error_code"], "upstream");
+    assert_eq!(result["reasoning"], "analysis failed, see error_code");
+    assert!(!result.to_string().contains("db-replica-7.internal"));
+}
+
+#[tokio::test]
+async fn contract_status_exposes_upstream_error_detail_when_enabled() {
+    let (addr, _moralis) = start_server_with_failing_upstream(true).await;
+    let result = failing_upstream_result(addr).await;
+
+    assert_eq!(result["error_code"], "upstream");
+    let reasoning = result["reasoning"].as_str().expect("reasoning present");
+    assert!(reasoning.starts_with("External API error:"));
+    assert!(reasoning.contains("db-replica-7.internal exploded"));
+}
+
+/// Analyze a contract whose metadata is found but whose classification fails
+async fn failing_predictor_result(return_metadata_on_predictor_failure: bool) -> serde_json::Value {
+    let address = "0x1234567890123456789012345678901234567890";
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{address}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": address,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Cool Cats",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": { "message": "model unavailable", "type": "invalid_request_error" }
+        })))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.expose_error_details = Some(true);
+    config.feature_flags.return_metadata_on_predictor_failure =
+        return_metadata_on_predictor_failure;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let mut body: serde_json::Value = response.json().await.expect("valid JSON");
+    body[address].take()
+}
+
+#[tokio::test]
+async fn contract_status_distinguishes_predictor_error_from_metadata_fetch_error() {
+    let result = failing_predictor_result(false).await;
+
+    assert_eq!(result["status"], "predictor_error");
+    assert_eq!(result["error_code"], "upstream");
+    let reasoning = result["reasoning"].as_str().expect("reasoning present");
+    assert!(reasoning.starts_with("Prediction error:"));
+    assert!(result.get("metadata").is_none());
+}
+
+#[tokio::test]
+async fn contract_status_returns_metadata_when_predictor_fails_if_enabled() {
+    let result = failing_predictor_result(true).await;
+
+    assert_eq!(result["status"], "predictor_unavailable");
+    assert_eq!(
+        result["message"],
+        "contract metadata found on Ethereum, AI analysis unavailable, returning contract metadata only"
+    );
+    assert_eq!(result["error_code"], "upstream");
+    assert_eq!(
+        result["metadata"],
+        json!({ "name": "Cool Cats", "symbol": "TST", "contract_type": "ERC721" })
+    );
+}
+
+/// Start a server whose Moralis upstream rate limits every lookup
+async fn start_server_with_rate_limited_upstream(
+    upstream_response: ResponseTemplate,
+) -> (std::net::SocketAddr, MockServer) {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(upstream_response)
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.external_apis.moralis.retry_after_seconds = 25;
+    // Surface the rate limit instead of waiting out the upstream delay
+    config.external_apis.moralis.max_retries = 0;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, moralis_server)
+}
+
+async fn rate_limited_retry_after(addr: std::net::SocketAddr) -> u64 {
+    let address = "0x1234567890123456789012345678901234567890";
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let header: u64 = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .expect("rate-limited lookup carries Retry-After")
+        .to_str()
+        .expect("ASCII header")
+        .parse()
+        .expect("delay in seconds");
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["error_code"], "rate_limited");
+    assert_eq!(body[address]["retry_after_seconds"], header);
+    header
+}
+
+#[tokio::test]
+async fn contract_status_surfaces_provider_retry_after() {
+    let (addr, _moralis) = start_server_with_rate_limited_upstream(
+        ResponseTemplate::new(429).insert_header("Retry-After", "7"),
+    )
+    .await;
+
+    assert_eq!(rate_limited_retry_after(addr).await, 7);
+}
+
+#[tokio::test]
+async fn contract_status_surfaces_configured_retry_after() {
+    let (addr, _moralis) =
+        start_server_with_rate_limited_upstream(ResponseTemplate::new(429)).await;
+
+    assert_eq!(rate_limited_retry_after(addr).await, 25);
+}
+
+#[tokio::test]
+async fn contract_status_uses_chain_specific_model_default() {
+    let models = tempfile::NamedTempFile::new().expect("temp model registry");
+    std::fs::write(
+        models.path(),
+        "model_registry:\n  spam_classification:\n    latest: ft:generic-model\n    v1: ft:ethereum-model\n",
+    )
+    .expect("write model registry");
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1234567890123456789012345678901234567890",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Chain Default NFT",
+                "symbol": "CDN"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.model_registry_path = models.path().display().to_string();
+    config.spam_predictor.chain_model_defaults.insert(
+        ChainId::Ethereum,
+        spam_predictor::ModelSpec::new(
+            spam_predictor::ModelType::spam_classification(),
+            spam_predictor::ModelVersion::new("v1").expect("valid version"),
+        ),
+    );
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let mut models_used = Vec::new();
+    for chain_id in [ChainId::Ethereum, ChainId::Polygon] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&json!({
+                "chain_id": chain_id.chain_id(),
+                "addresses": ["0x1234567890123456789012345678901234567890"]
+            }))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let requests = openai_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        let body: serde_json::Value = requests
+            .last()
+            .expect("prediction request sent")
+            .body_json()
+            .expect("JSON body");
+        models_used.push(body["model"].as_str().expect("model field").to_string());
+    }
+
+    assert_eq!(models_used, ["ft:ethereum-model", "ft:generic-model"]);
+}
+
+#[tokio::test]
+async fn contract_status_analyzes_each_entry_on_its_own_chain() {
+    let models = tempfile::NamedTempFile::new().expect("temp model registry");
+    std::fs::write(
+        models.path(),
+        "model_registry:\n  spam_classification:\n    latest: ft:generic-model\n    v1: ft:ethereum-model\n",
+    )
+    .expect("write model registry");
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1234567890123456789012345678901234567890",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Portfolio NFT",
+                "symbol": "PNFT"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.model_registry_path = models.path().display().to_string();
+    config.spam_predictor.chain_model_defaults.insert(
+        ChainId::Ethereum,
+        spam_predictor::ModelSpec::new(
+            spam_predictor::ModelType::spam_classification(),
+            spam_predictor::ModelVersion::new("v1").expect("valid version"),
+        ),
+    );
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let ethereum_address = "0x1111111111111111111111111111111111111111";
+    let polygon_address = "0x2222222222222222222222222222222222222222";
+    let client = reqwest::Client::new();
+    let post = |body: serde_json::Value| {
+        client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&body)
+            .send()
+    };
+
+    // The top-level chain is only the default for plain address strings
+    let response = post(json!({
+        "chain_id": "polygon",
+        "addresses": [
+            { "address": ethereum_address, "chain_id": 1 },
+            polygon_address
+        ]
+    }))
+    .await
+    .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[ethereum_address]["chain_id"], 1);
+    assert_eq!(body[polygon_address]["chain_id"], 137);
+
+    // Each address was looked up on its own chain and classified with its
+    // chain's model
+    let moralis_chains: Vec<(String, String)> = moralis_server
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .iter()
+        .map(|request| {
+            let chain = request
+                .url
+                .query_pairs()
+                .find(|(key, _)| key == "chain")
+                .map(|(_, value)| value.into_owned())
+                .expect("chain query parameter");
+            let address = request.url.path().rsplit('/').next().unwrap_or_default();
+            (address.to_lowercase(), chain)
+        })
+        .collect();
+    assert!(moralis_chains.contains(&(ethereum_address.to_string(), "eth".to_string())));
+    assert!(moralis_chains.contains(&(polygon_address.to_string(), "polygon".to_string())));
+    let mut models_used: Vec<String> = openai_server
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .iter()
+        .filter_map(|request| {
+            let body: serde_json::Value = request.body_json().ok()?;
+            Some(body["model"].as_str()?.to_string())
+        })
+        .filter(|model| model.starts_with("ft:"))
+        .collect();
+    models_used.sort();
+    assert_eq!(models_used, ["ft:ethereum-model", "ft:generic-model"]);
+}
+
+#[tokio::test]
+async fn contract_status_checks_each_entrys_chain() {
+    let mut config = ServerConfig::for_testing();
+    config
+        .chains
+        .get_mut(&ChainId::Base)
+        .expect("Base is configured")
+        .enabled = false;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let ethereum_address = "0x1111111111111111111111111111111111111111";
+    let polygon_address = "0x2222222222222222222222222222222222222222";
+    let client = reqwest::Client::new();
+    let post = |body: serde_json::Value| {
+        client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&body)
+            .send()
+    };
+
+    // Every entry's chain must be enabled
+    let response = post(json!({
+        "addresses": [
+            { "address": ethereum_address, "chain_id": 1 },
+            { "address": polygon_address, "chain_id": 8453 }
+        ]
+    }))
+    .await
+    .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["details"]["chain_id"], 8453);
+
+    // Results are keyed by address, so an address may only name one chain
+    let response = post(json!({
+        "addresses": [
+            { "address": ethereum_address, "chain_id": 1 },
+            { "address": ethereum_address, "chain_id": 137 }
+        ]
+    }))
+    .await
+    .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // Plain addresses need a top-level chain
+    let response = post(json!({
+        "addresses": [{ "address": ethereum_address, "chain_id": 1 }, polygon_address]
+    }))
+    .await
+    .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn contract_status_enforces_max_chains() {
+    let mut config = ServerConfig::for_testing();
+    config.rate_limiting.max_chains = 1;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "addresses": [
+                { "address": "0x1111111111111111111111111111111111111111", "chain_id": 1 },
+                { "address": "0x2222222222222222222222222222222222222222", "chain_id": 137 }
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.text().await.expect("response body");
+    assert!(
+        body.contains("request spans 2 distinct chains, exceeding the maximum of 1 chains"),
+        "unexpected body: {body}"
+    );
+
+    // Repeating a chain does not count it twice
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": [
+                { "address": "0x1111111111111111111111111111111111111111", "chain_id": 1 },
+                "0x2222222222222222222222222222222222222222"
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_ne!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn contract_status_single_contract_spam_status_header() {
+    let spam_address = "0x1111111111111111111111111111111111111111";
+    let legitimate_address = "0x2222222222222222222222222222222222222222";
+    let unknown_address = "0x3333333333333333333333333333333333333333";
+
+    let moralis_server = MockServer::start().await;
+    for (address, name) in [
+        (spam_address, "FreeAirdropSpam"),
+        (legitimate_address, "Cool Cats"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path_regex(format!("(?i)/nft/{address}$")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": [{
+                    "token_address": address,
+                    "token_id": "1",
+                    "contract_type": "ERC721",
+                    "name": name,
+                    "symbol": "TST"
+                }]
+            })))
+            .mount(&moralis_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{unknown_address}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_string_contains("FreeAirdropSpam"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    for (address, expected) in [
+        (spam_address, "spam"),
+        (legitimate_address, "legitimate"),
+        (unknown_address, "no_data"),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&json!({ "chain_id": 1, "addresses": [address] }))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let header = response
+            .headers()
+            .get("x-spam-status")
+            .expect("single-contract response carries the header")
+            .to_str()
+            .expect("ASCII header")
+            .to_string();
+        let body: serde_json::Value = response.json().await.expect("valid JSON");
+        assert_eq!(header, expected);
+        assert_eq!(body[address]["status"], expected);
+    }
+
+    // Batch responses carry per-address statuses only in the body
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [spam_address, legitimate_address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert!(response.headers().get("x-spam-status").is_none());
+}
+
+#[tokio::test]
+async fn contract_status_classifies_single_token() {
+    let address = "0x4444444444444444444444444444444444444444";
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{address}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": address,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Cool Cats",
+                "symbol": "COOL"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{address}/7$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "token_address": address,
+            "token_id": "7",
+            "contract_type": "ERC721",
+            "name": "Cool Cats",
+            "symbol": "COOL",
+            "metadata": "{\"name\":\"FreeAirdropSpam claim\",\"description\":\"Visit to claim\"}"
+        })))
+        .mount(&moralis_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{address}/8$")))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_string_contains("FreeAirdropSpam"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    for (request, expected) in [
+        (
+            json!({ "chain_id": 1, "addresses": [address] }),
+            "legitimate",
+        ),
+        (
+            json!({ "chain_id": 1, "addresses": [address], "token_id": "7" }),
+            "spam",
+        ),
+        (
+            json!({ "chain_id": 1, "addresses": [address], "token_id": "8" }),
+            "no_data",
+        ),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: serde_json::Value = response.json().await.expect("valid JSON");
+        assert_eq!(body[address]["status"], expected, "request {request}");
+    }
+
+    for request in [
+        json!({ "chain_id": 1, "addresses": [address], "token_id": "0x07" }),
+        json!({
+            "chain_id": 1,
+            "addresses": [address, "0x5555555555555555555555555555555555555555"],
+            "token_id": "7"
+        }),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&request)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(
+            response.status(),
+            StatusCode::BAD_REQUEST,
+            "request {request}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn contract_status_rejects_token_without_token_metadata_provider() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": ["0x4444444444444444444444444444444444444444"],
+            "token_id": "7"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["error"], "capability_not_supported");
+    assert_eq!(body["details"]["requested_capability"], "token_metadata");
+}
+
+#[tokio::test]
+async fn contract_status_returns_distribution_when_requested() {
+    let prompts = tempfile::NamedTempFile::new().expect("temp prompt registry");
+    std::fs::write(
+        prompts.path(),
+        json!({
+            "versions": [{
+                "version": "1.0.0",
+                "date": "2025-06-01",
+                "description": "Distribution prompt",
+                "system_message": "Answer with a JSON object of spam, legitimate and inconclusive probabilities.",
+                "output_format": "distribution"
+            }],
+            "current_version": "1.0.0"
+        })
+        .to_string(),
+    )
+    .expect("write prompt registry");
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1234567890123456789012345678901234567890",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_partial_json(
+            json!({ "response_format": { "type": "json_object" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion(
+            r#"{"spam": 0.8, "legitimate": 0.15, "inconclusive": 0.05}"#,
+        )))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.prompt_registry_path = prompts.path().display().to_string();
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let address = "0x1234567890123456789012345678901234567890";
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!(
+            "http://{addr}/v1/contract/status?distribution=true"
+        ))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    let result = &body[address];
+    assert_eq!(result["status"], "spam");
+    assert_eq!(result["distribution"]["spam"], 0.8);
+    assert_eq!(result["distribution"]["legitimate"], 0.15);
+    assert_eq!(result["distribution"]["inconclusive"], 0.05);
+
+    // Without the query parameter the cached top category is returned without probabilities
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["status"], "spam");
+    assert_eq!(body[address]["cached"], true);
+    assert!(body[address].get("distribution").is_none());
+}
+
+/// Start a server whose distribution prompt always answers with a spam probability of 0.8
+async fn start_server_with_spam_probability(
+    thresholds: ConfidenceBandThresholds,
+) -> (
+    std::net::SocketAddr,
+    tempfile::NamedTempFile,
+    MockServer,
+    MockServer,
+) {
+    let prompts = tempfile::NamedTempFile::new().expect("temp prompt registry");
+    std::fs::write(
+        prompts.path(),
+        json!({
+            "versions": [{
+                "version": "1.0.0",
+                "date": "2025-06-01",
+                "description": "Distribution prompt",
+                "system_message": "Answer with a JSON object of spam, legitimate and inconclusive probabilities.",
+                "output_format": "distribution"
+            }],
+            "current_version": "1.0.0"
+        })
+        .to_string(),
+    )
+    .expect("write prompt registry");
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1234567890123456789012345678901234567890",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion(
+            r#"{"spam": 0.8, "legitimate": 0.15, "inconclusive": 0.05}"#,
+        )))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.prompt_registry_path = prompts.path().display().to_string();
+    config.spam_predictor.confidence_band_thresholds = thresholds;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, prompts, moralis_server, openai_server)
+}
+
+async fn analyzed_result(addr: std::net::SocketAddr, query: &str) -> serde_json::Value {
+    let address = "0x1234567890123456789012345678901234567890";
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status?{query}"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body: serde_json::Value = response.json().await.expect("valid JSON");
+    body[address].take()
+}
+
+#[tokio::test]
+async fn contract_status_returns_confidence_band() {
+    let (addr, _prompts, _moralis, _openai) =
+        start_server_with_spam_probability(ConfidenceBandThresholds::default()).await;
+
+    // A score on the high threshold falls into the high band
+    let result = analyzed_result(addr, "distribution=true").await;
+    assert_eq!(result["status"], "spam");
+    assert_eq!(result["confidence"], 0.8);
+    assert_eq!(result["confidence_band"], "high");
+
+    let result = analyzed_result(addr, "distribution=true&confidence=band").await;
+    assert!(result.get("confidence").is_none());
+    assert_eq!(result["confidence_band"], "high");
+
+    let result = analyzed_result(addr, "distribution=true&confidence=score").await;
+    assert_eq!(result["confidence"], 0.8);
+    assert!(result.get("confidence_band").is_none());
+}
+
+#[tokio::test]
+async fn contract_status_confidence_band_uses_configured_thresholds() {
+    let (addr, _prompts, _moralis, _openai) =
+        start_server_with_spam_probability(ConfidenceBandThresholds {
+            medium: 0.8,
+            high: 0.81,
+        })
+        .await;
+
+    let result = analyzed_result(addr, "distribution=true").await;
+    assert_eq!(result["confidence"], 0.8);
+    assert_eq!(result["confidence_band"], "medium");
+}
+
+#[tokio::test]
+async fn contract_status_reports_confidence_from_logprobs() {
+    let address = "0x1234567890123456789012345678901234567890";
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": address,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Cool Cats",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+    let openai_server = MockServer::start().await;
+    let mut completion = chat_completion("False");
+    completion["choices"][0]["logprobs"] = json!({
+        "content": [{ "token": "False", "logprob": 0.6_f64.ln(), "top_logprobs": [] }]
+    });
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_partial_json(json!({ "logprobs": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let result = analyzed_result(addr, "").await;
+    assert_eq!(result["status"], "legitimate");
+    let confidence = result["confidence"].as_f64().expect("numeric confidence");
+    assert!((confidence - 0.6).abs() < 1e-9, "confidence {confidence}");
+    assert_eq!(result["confidence_band"], "medium");
+}
+
+#[tokio::test]
+async fn contract_status_debug_query_requires_admin_flag() {
+    const ADMIN_KEY: &str = "admin-test-key";
+    let address = "0xABCDEF0123456789ABCDEF0123456789ABCDEF01";
+
+    let pinax_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{ "name": "Debug NFT", "symbol": "DBG", "description": null }]
+        })))
+        .mount(&pinax_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.pinax.enabled = true;
+    config.external_apis.pinax.endpoint = pinax_server.uri().parse().expect("valid mock URL");
+    config.external_apis.pinax.api_user =
+        ApiKey::new("pinax-user".to_string()).expect("valid Pinax user");
+    config.external_apis.pinax.api_auth =
+        ApiKey::new("pinax-auth".to_string()).expect("valid Pinax auth");
+    config
+        .chains
+        .get_mut(&ChainId::Ethereum)
+        .expect("Ethereum configured")
+        .pinax = Some(ChainPinaxConfig {
+        db_name: "eth-debug-db".to_string(),
+        timeout_seconds: None,
+        max_retries: None,
+        contract_creations: false,
+    });
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    let client = reqwest::Client::new();
+    let body = json!({ "chain_id": 1, "addresses": [address] });
+
+    // Normal responses never carry the query
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("x-debug-query").is_none());
+
+    // The debug flag is refused without the admin key
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status?debug=true"))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // Metadata served from cache sent no query to report
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status?debug=true"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("x-debug-query").is_none());
+
+    let address = "0x0123456789ABCDEF0123456789ABCDEF01234567";
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status?debug=true"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let query = response
+        .headers()
+        .get("x-debug-query")
+        .expect("debug response carries the query")
+        .to_str()
+        .expect("ASCII header")
+        .to_string();
+    assert_eq!(
+        response
+            .headers()
+            .get("x-debug-config-source")
+            .expect("debug response carries the config source"),
+        "1=chain_override"
+    );
+    assert!(query.contains("`eth-debug-db`.erc721_metadata_by_contract"));
+    assert!(query.contains(&format!("contract = '{}'", address.to_lowercase())));
+    assert!(!query.contains(address));
+
+    let requests = pinax_server
+        .received_requests()
+        .await
+        .expect("request recording enabled");
+    assert_eq!(requests.len(), 2);
+    let executed = String::from_utf8(requests[1].body.clone()).expect("UTF-8 query");
+    assert_eq!(query, executed);
+}
+
+#[tokio::test]
+async fn contract_status_debug_query_reports_the_batched_pinax_query() {
+    const ADMIN_KEY: &str = "admin-test-key";
+    let first = "0x1111111111111111111111111111111111111111";
+    let second = "0x2222222222222222222222222222222222222222";
+
+    let pinax_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [
+                { "name": "First NFT", "symbol": "ONE", "contract": first },
+                { "name": "Second NFT", "symbol": "TWO", "contract": second }
+            ]
+        })))
+        .mount(&pinax_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.pinax.enabled = true;
+    config.external_apis.pinax.endpoint = pinax_server.uri().parse().expect("valid mock URL");
+    config.external_apis.pinax.api_user =
+        ApiKey::new("pinax-user".to_string()).expect("valid Pinax user");
+    config.external_apis.pinax.api_auth =
+        ApiKey::new("pinax-auth".to_string()).expect("valid Pinax auth");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status?debug=true"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&json!({ "chain_id": 1, "addresses": [first, second] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let queries: Vec<_> = response.headers().get_all("x-debug-query").iter().collect();
+    assert_eq!(queries.len(), 1, "one query answered both addresses");
+    let query = queries[0].to_str().expect("ASCII header").to_string();
+    assert!(query.contains(&format!("IN ('{first}', '{second}')")));
+
+    // Both addresses were answered by the single batched request
+    let requests = pinax_server
+        .received_requests()
+        .await
+        .expect("request recording enabled");
+    assert_eq!(requests.len(), 1);
+    let executed = String::from_utf8(requests[0].body.clone()).expect("UTF-8 query");
+    assert_eq!(query, executed);
+}
+
+#[tokio::test]
+async fn contract_status_debug_reports_pinax_config_source_per_chain() {
+    const ADMIN_KEY: &str = "admin-secret";
+
+    let pinax_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "data": [] })))
+        .mount(&pinax_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.pinax.enabled = true;
+    config.external_apis.pinax.endpoint = pinax_server.uri().parse().expect("valid mock URL");
+    config
+        .chains
+        .get_mut(&ChainId::Ethereum)
+        .expect("Ethereum configured")
+        .pinax = Some(ChainPinaxConfig {
+        db_name: "eth-override-db".to_string(),
+        timeout_seconds: None,
+        max_retries: None,
+        contract_creations: false,
+    });
+    config
+        .chains
+        .get_mut(&ChainId::Polygon)
+        .expect("Polygon configured")
+        .pinax = None;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    let client = reqwest::Client::new();
+
+    for (chain_id, expected) in [
+        (ChainId::Ethereum, "1=chain_override"),
+        (ChainId::Polygon, "137=global"),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status?debug=true"))
+            .header("x-admin-api-key", ADMIN_KEY)
+            .json(&json!({
+                "chain_id": chain_id.chain_id(),
+                "addresses": ["0x1234567890123456789012345678901234567890"]
+            }))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get("x-debug-config-source")
+                .expect("debug response carries the config source"),
+            expected
+        );
+    }
+}
+
+#[tokio::test]
+async fn contract_status_returns_raw_model_response_only_to_admin_debug_requests() {
+    const ADMIN_KEY: &str = "admin-test-key";
+    let address = "0x1111111111111111111111111111111111111111";
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": address,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion(" True\n")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let body = json!({ "chain_id": 1, "addresses": [address] });
+
+    // Admin debug requests get the answer exactly as the model returned it;
+    // the request runs first because cached verdicts carry no model output
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status?debug=true"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let result: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(result[address]["status"], "spam");
+    assert_eq!(result[address]["raw_model_response"], " True\n");
+
+    // Requests without the debug flag never see the model output, even from admins
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to send request");
+    let result: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(result[address]["status"], "spam");
+    assert!(result[address].get("raw_model_response").is_none());
+}
+
+#[tokio::test]
+async fn contract_status_fails_fast_when_latency_budget_exceeded() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(Duration::from_secs(3)),
+        )
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let chain_label = ChainId::Polygon.to_string();
+    let violations_before = api::metrics::SLO_VIOLATIONS
+        .with_label_values(&[&chain_label])
+        .get();
+
+    let address = "0x1234567890123456789012345678901234567890";
+    let started = std::time::Instant::now();
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": ChainId::Polygon.chain_id(),
+            "addresses": [address],
+            "max_latency_ms": 200
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        started.elapsed() < Duration::from_secs(2),
+        "request should fail fast instead of waiting for the slow upstream"
+    );
+
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    let result = &body[address];
+    assert_eq!(result["status"], "error");
+    assert_eq!(result["error_code"], "timeout");
+    assert_eq!(result["slo_exceeded"], true);
+
+    let violations_after = api::metrics::SLO_VIOLATIONS
+        .with_label_values(&[&chain_label])
+        .get();
+    assert_eq!(violations_after, violations_before + 1);
+}
+
+#[tokio::test]
+async fn contract_status_records_metadata_latency_by_provider_and_chain() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .expect(1)
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let labels = ["moralis", ChainId::Base.name(), "missing"];
+    let observed_before = api::metrics::METADATA_API_REQUEST_DURATION
+        .with_label_values(&labels)
+        .get_sample_count();
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": ChainId::Base.chain_id(),
+            "addresses": ["0x2222222222222222222222222222222222222222"]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let observed_after = api::metrics::METADATA_API_REQUEST_DURATION
+        .with_label_values(&labels)
+        .get_sample_count();
+    // The histogram is process-wide, so other tests may add to it concurrently
+    assert!(observed_after > observed_before);
+}
+
+async fn post_with_accept(addr: std::net::SocketAddr, accept: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .header("accept", accept)
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": [
+                "0x1234567890123456789012345678901234567890",
+                "0xabcdefabcdefabcdefabcdefabcdefabcdefabcd"
+            ]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn contract_status_result_format_follows_accept_header() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    for accept in ["application/json", "application/vnd.nft-api.v1+json"] {
+        let response = post_with_accept(addr, accept).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value = response.json().await.expect("valid JSON");
+        assert!(body.get("results").is_none(), "v1 is a flat map: {body}");
+        assert!(
+            body.get("0x1234567890123456789012345678901234567890")
+                .is_some()
+        );
+    }
+
+    let response = post_with_accept(addr, "application/vnd.nft-api.v2+json").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/vnd.nft-api.v2+json"
+    );
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["results"].as_object().expect("results map").len(), 2);
+    assert_eq!(body["summary"]["total"], 2);
+    let counted: u64 = ["spam", "legitimate", "inconclusive", "no_data", "error"]
+        .iter()
+        .map(|status| body["summary"][status].as_u64().expect("count"))
+        .sum();
+    assert_eq!(counted, 2);
+}
+
+#[tokio::test]
+async fn contract_status_rejects_unknown_result_format_version() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = post_with_accept(addr, "application/vnd.nft-api.v3+json").await;
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["status"], 406);
+}
+
+#[tokio::test]
+async fn contract_status_uses_configured_default_result_format() {
+    let mut config = ServerConfig::for_testing();
+    config.default_result_format = api::config::ResultFormatVersion::V2;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = post_with_accept(addr, "*/*").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["summary"]["total"], 2);
+}
+
+async fn analyzed_result_in(
+    addr: std::net::SocketAddr,
+    accept_language: &str,
+) -> (String, serde_json::Value) {
+    let address = "0x1234567890123456789012345678901234567890";
+    let response = reqwest::Client::new()
+        .post(format!(
+            "http://{addr}/v1/contract/status?distribution=true"
+        ))
+        .header("accept-language", accept_language)
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_language = response.headers()["content-language"]
+        .to_str()
+        .expect("ASCII header")
+        .to_string();
+    let mut body: serde_json::Value = response.json().await.expect("valid JSON");
+    (content_language, body[address].take())
+}
+
+#[tokio::test]
+async fn contract_status_localizes_messages_from_accept_language() {
+    let (addr, _prompts, _moralis, _openai) =
+        start_server_with_spam_probability(ConfidenceBandThresholds::default()).await;
+
+    let (language, result) = analyzed_result_in(addr, "es-ES,es;q=0.9").await;
+    assert_eq!(language, "es");
+    assert_eq!(result["status"], "spam");
+    assert_eq!(
+        result["message"],
+        "metadatos del contrato encontrados en Ethereum, el análisis de IA lo clasificó como spam"
+    );
+
+    // Unsupported locales fall back to English
+    let (language, result) = analyzed_result_in(addr, "fr-FR").await;
+    assert_eq!(language, "en");
+    assert_eq!(
+        result["message"],
+        "contract metadata found on Ethereum, AI analysis classified as spam"
+    );
+}
+
+#[tokio::test]
+async fn contract_status_timeout_returns_retry_after() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(Duration::from_secs(3)),
+        )
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.timeout_seconds = TimeoutSeconds::new(1).expect("valid timeout");
+    config.concurrency.individual_address_timeout_seconds =
+        TimeoutSeconds::new(10).expect("valid timeout");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": ["0x1234567890123456789012345678901234567890"]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    assert_eq!(
+        response
+            .headers()
+            .get("retry-after")
+            .expect("timeout carries Retry-After"),
+        "1"
+    );
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["status"], 408);
+    assert_eq!(body["error"], "request_timeout");
+    assert_eq!(body["details"]["retry_after_seconds"], 1);
+    assert!(
+        body["message"]
+            .as_str()
+            .expect("error message")
+            .contains("configured request timeout of 1 seconds")
+    );
+}
+
+#[tokio::test]
+async fn contract_status_times_out_slow_address_without_delaying_batch() {
+    let slow_address = "0x5555555555555555555555555555555555555555";
+    let fast_addresses = [
+        "0x1111111111111111111111111111111111111111",
+        "0x2222222222222222222222222222222222222222",
+        "0x3333333333333333333333333333333333333333",
+    ];
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{slow_address}$")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(&moralis_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.concurrency.per_address_timeout_ms = Some(300);
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let mut addresses = vec![slow_address];
+    addresses.extend(fast_addresses);
+    let started = std::time::Instant::now();
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        started.elapsed() < Duration::from_secs(2),
+        "the slow address must not hold up the batch"
+    );
+
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    let slow = &body[slow_address];
+    assert_eq!(slow["status"], "error");
+    assert_eq!(slow["error_code"], "timeout");
+    assert_eq!(
+        slow["message"],
+        "processing timeout for Ethereum after 300 ms"
+    );
+    for address in fast_addresses {
+        assert_eq!(body[address]["status"], "no_data", "{address}");
+    }
+}
+
+#[tokio::test]
+async fn contract_status_pinax_prefetch_counts_against_the_address_timeout() {
+    let found = "0x1111111111111111111111111111111111111111";
+    let missing = "0x2222222222222222222222222222222222222222";
+
+    let pinax_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({
+                    "data": [{ "name": "Found NFT", "symbol": "FND", "contract": found }]
+                }))
+                .set_delay(Duration::from_millis(600)),
+        )
+        .mount(&pinax_server)
+        .await;
+    // The address missing from the batch falls back to Moralis
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(Duration::from_millis(600)),
+        )
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.pinax.enabled = true;
+    config.external_apis.pinax.priority = 10;
+    config.external_apis.pinax.endpoint = pinax_server.uri().parse().expect("valid mock URL");
+    config.external_apis.pinax.api_user =
+        ApiKey::new("pinax-user".to_string()).expect("valid Pinax user");
+    config.external_apis.pinax.api_auth =
+        ApiKey::new("pinax-auth".to_string()).expect("valid Pinax auth");
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.concurrency.per_address_timeout_ms = Some(1000);
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [found, missing] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Prefetch and fallback each fit the timeout, but not together
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[missing]["status"], "error");
+    assert_eq!(body[missing]["error_code"], "timeout");
+    let requests = pinax_server
+        .received_requests()
+        .await
+        .expect("request recording enabled");
+    assert_eq!(requests.len(), 1, "both addresses share the batched query");
+}
+
+/// Start a server whose upstreams yield a spam, a legitimate and an unknown contract
+async fn start_server_with_verdicts(
+    status_to_http_code: bool,
+) -> (std::net::SocketAddr, MockServer, MockServer) {
+    let moralis_server = MockServer::start().await;
+    for (address, name) in [
+        (
+            "0x1111111111111111111111111111111111111111",
+            "FreeAirdropSpam",
+        ),
+        ("0x2222222222222222222222222222222222222222", "Cool Cats"),
+    ] {
+        Mock::given(method("GET"))
+            .and(path_regex(format!("(?i)/nft/{address}$")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": [{
+                    "token_address": address,
+                    "token_id": "1",
+                    "contract_type": "ERC721",
+                    "name": name,
+                    "symbol": "TST"
+                }]
+            })))
+            .mount(&moralis_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_string_contains("FreeAirdropSpam"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.status_to_http_code = status_to_http_code;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, moralis_server, openai_server)
+}
+
+async fn status_code_for(addr: std::net::SocketAddr, addresses: &[&str]) -> StatusCode {
+    reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses }))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .status()
+}
+
+#[tokio::test]
+async fn contract_status_maps_single_verdict_to_http_code_when_enabled() {
+    let (addr, _moralis, _openai) = start_server_with_verdicts(true).await;
+
+    for (address, expected) in [
+        (
+            "0x1111111111111111111111111111111111111111",
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        ("0x2222222222222222222222222222222222222222", StatusCode::OK),
+        (
+            "0x3333333333333333333333333333333333333333",
+            StatusCode::NOT_FOUND,
+        ),
+    ] {
+        assert_eq!(status_code_for(addr, &[address]).await, expected);
+    }
+
+    // Batches always answer 200 regardless of the verdicts
+    let batch = [
+        "0x1111111111111111111111111111111111111111",
+        "0x3333333333333333333333333333333333333333",
+    ];
+    assert_eq!(status_code_for(addr, &batch).await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn contract_status_keeps_200_for_verdicts_when_disabled() {
+    let (addr, _moralis, _openai) = start_server_with_verdicts(false).await;
+
+    for address in [
+        "0x1111111111111111111111111111111111111111",
+        "0x2222222222222222222222222222222222222222",
+        "0x3333333333333333333333333333333333333333",
+    ] {
+        assert_eq!(status_code_for(addr, &[address]).await, StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn contract_status_analyzes_addresses_concurrently() {
+    // Each Moralis lookup takes 500ms, so ten serial lookups would take at least 5s
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "result": [] }))
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    // Submitted in reverse order to check the response is ordered by address
+    let addresses: Vec<String> = (0..10).rev().map(|i| format!("0x{i:040}")).collect();
+
+    let started = std::time::Instant::now();
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": addresses }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let elapsed = started.elapsed();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        elapsed < Duration::from_millis(2500),
+        "ten addresses took {elapsed:?}, expected concurrent processing"
+    );
+
+    let body = response.text().await.expect("Failed to read body");
+    let positions: Vec<usize> = (0..10)
+        .map(|i| {
+            body.find(&format!("\"0x{i:040}\""))
+                .unwrap_or_else(|| panic!("address {i} missing from response"))
+        })
+        .collect();
+    assert!(
+        positions.is_sorted(),
+        "response should be ordered by address"
+    );
+}
+
+#[tokio::test]
+async fn contract_status_reports_providers_consulted_on_failover() {
+    let address = "0x1234567890123456789012345678901234567890";
+
+    // Moralis rejects the lookup, so the registry fails over to Pinax
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&moralis_server)
+        .await;
+
+    let pinax_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{ "name": "Failover NFT", "symbol": "FAIL", "description": null }]
+        })))
+        .mount(&pinax_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.external_apis.pinax.enabled = true;
+    config.external_apis.pinax.endpoint = pinax_server.uri().parse().expect("valid mock URL");
+    config.external_apis.pinax.api_user =
+        ApiKey::new("pinax-user".to_string()).expect("valid Pinax user");
+    config.external_apis.pinax.api_auth =
+        ApiKey::new("pinax-auth".to_string()).expect("valid Pinax auth");
+    config
+        .chains
+        .get_mut(&ChainId::Ethereum)
+        .expect("Ethereum configured")
+        .pinax = Some(ChainPinaxConfig {
+        db_name: "eth-failover-db".to_string(),
+        timeout_seconds: None,
+        max_retries: None,
+        contract_creations: false,
+    });
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["providers_consulted"], 2);
+    assert_eq!(
+        moralis_server
+            .received_requests()
+            .await
+            .expect("request recording enabled")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn contract_status_get_route_returns_single_result() {
+    let (addr, _moralis, _openai) = start_server_with_verdicts(false).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!(
+            "http://{addr}/v1/chains/1/contracts/0x1111111111111111111111111111111111111111/status"
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-spam-status"], "spam");
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["chain_id"], 1);
+    assert_eq!(body["status"], "spam");
+
+    let response = client
+        .get(format!(
+            "http://{addr}/v1/chains/ethereum/contracts/0x2222222222222222222222222222222222222222/status"
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body["status"], "legitimate");
+
+    for path in [
+        "/v1/chains/1/contracts/0x1234/status",
+        "/v1/chains/1/contracts/not-an-address/status",
+        "/v1/chains/999999/contracts/0x1111111111111111111111111111111111111111/status",
+    ] {
+        let response = client
+            .get(format!("http://{addr}{path}"))
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{path}");
+    }
+
+    let spec: serde_json::Value = client
+        .get(format!("http://{addr}/api-doc/openapi.json"))
+        .send()
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("valid JSON");
+    let operation = &spec["paths"]["/v1/chains/{chain_id}/contracts/{address}/status"]["get"];
+    let parameters: Vec<&str> = operation["parameters"]
+        .as_array()
+        .expect("documented parameters")
+        .iter()
+        .filter_map(|parameter| parameter["name"].as_str())
+        .collect();
+    assert_eq!(parameters, ["chain_id", "address"]);
+}
+
+const DEFAULT_MODEL: &str = "ft:gpt-4o-2024-08-06:semiotic-labs::DEFAULT";
+const PINNED_MODEL: &str = "ft:gpt-4o-2024-08-06:semiotic-labs::PINNED";
+const PINNED_PROMPT: &str = "Pinned prompt: label spams as True.";
+
+/// Start a server whose registries hold two models and two prompts; the model
+/// registered as `v0` calls everything spam, the default model nothing
+async fn start_server_with_pinnable_versions() -> (
+    std::net::SocketAddr,
+    MockServer,
+    MockServer,
+    [tempfile::NamedTempFile; 2],
+) {
+    let models = tempfile::NamedTempFile::new().expect("temp model registry");
+    std::fs::write(
+        models.path(),
+        format!(
+            "model_registry:\n  spam_classification:\n    latest: {DEFAULT_MODEL}\n    v0: {PINNED_MODEL}\n"
+        ),
+    )
+    .expect("write model registry");
+    let prompts = tempfile::NamedTempFile::new().expect("temp prompt registry");
+    std::fs::write(
+        prompts.path(),
+        json!({
+            "versions": [
+                {
+                    "version": "1.0.0",
+                    "date": "2025-06-01",
+                    "description": "Default prompt",
+                    "system_message": "Default prompt: label spams as True."
+                },
+                {
+                    "version": "2.0.0",
+                    "date": "2025-07-01",
+                    "description": "Pinned prompt",
+                    "system_message": PINNED_PROMPT
+                }
+            ],
+            "current_version": "1.0.0"
+        })
+        .to_string(),
+    )
+    .expect("write prompt registry");
+
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1234567890123456789012345678901234567890",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Pinned NFT",
+                "symbol": "PIN"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_partial_json(json!({ "model": PINNED_MODEL })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("False")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.model_registry_path = models.path().display().to_string();
+    config.spam_predictor.prompt_registry_path = prompts.path().display().to_string();
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    (addr, moralis_server, openai_server, [models, prompts])
+}
+
+#[tokio::test]
+async fn contract_status_pins_model_and_prompt_versions() {
+    let (addr, _moralis_server, openai_server, _registries) =
+        start_server_with_pinnable_versions().await;
+    let address = "0x1234567890123456789012345678901234567890";
+    let client = reqwest::Client::new();
+
+    // Without pinned versions the chain defaults apply
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["status"], "legitimate");
+
+    // The pinned versions are used and do not hit the default's cache entry
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": [address],
+            "model_version": "v0",
+            "prompt_version": "2.0.0"
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["status"], "spam");
+    assert_ne!(body[address]["cached"], true);
+
+    let pinned_request = openai_server
+        .received_requests()
+        .await
+        .expect("request recording enabled")
+        .into_iter()
+        .filter_map(|request| serde_json::from_slice::<serde_json::Value>(&request.body).ok())
+        .find(|request| request["model"] == PINNED_MODEL)
+        .expect("pinned model requested");
+    assert_eq!(pinned_request["messages"][0]["content"], PINNED_PROMPT);
+}
+
+#[tokio::test]
+async fn contract_status_rejects_unregistered_pinned_versions() {
+    let (addr, _moralis_server, _openai_server, _registries) =
+        start_server_with_pinnable_versions().await;
+    let address = "0x1234567890123456789012345678901234567890";
+    let client = reqwest::Client::new();
+
+    for (body, expected) in [
+        (
+            json!({ "chain_id": 1, "addresses": [address], "model_version": "v9" }),
+            "model_version v9 is not registered",
+        ),
+        (
+            json!({ "chain_id": 1, "addresses": [address], "prompt_version": "9.9.9" }),
+            "prompt_version 9.9.9 is not registered",
+        ),
+        (
+            json!({ "chain_id": 1, "addresses": [address], "prompt_version": "newest" }),
+            "invalid prompt_version",
+        ),
+    ] {
+        let response = client
+            .post(format!("http://{addr}/v1/contract/status"))
+            .json(&body)
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{body}");
+        let error = response.text().await.expect("error body");
+        assert!(error.contains(expected), "{body}: {error}");
+    }
+}
+
+#[tokio::test]
+async fn contract_status_blocks_private_upstreams_by_default() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .mount(&moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.upstream_allowlist = UpstreamAllowlistConfig::default();
+    config.external_apis.moralis.enabled = true;
+
+    // Private IP literals are refused by validation, host names resolving to
+    // private addresses when the request is made
+    let port = moralis_server.address().port();
+    config.external_apis.moralis.base_url = format!("http://localhost:{port}")
+        .parse()
+        .expect("valid mock URL");
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": ["0x1234567890123456789012345678901234567890"]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        moralis_server
+            .received_requests()
+            .await
+            .expect("request recording")
+            .is_empty()
+    );
+}
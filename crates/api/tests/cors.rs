@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the configurable CORS policy
+
+use std::net::SocketAddr;
+
+use api::{CorsConfig, Server, ServerConfig, ShutdownConfig};
+use reqwest::{
+    Method,
+    header::{
+        ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_METHODS,
+        ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_REQUEST_METHOD, ORIGIN,
+    },
+};
+
+const ALLOWED_ORIGIN: &str = "https://app.example.com";
+
+async fn start_server(cors: CorsConfig) -> SocketAddr {
+    let mut config = ServerConfig::for_testing();
+    config.cors = cors;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+async fn chains_from(addr: SocketAddr, origin: &str) -> reqwest::Response {
+    reqwest::Client::new()
+        .get(format!("http://{addr}/v1/chains"))
+        .header(ORIGIN, origin)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+#[tokio::test]
+async fn listed_origins_receive_cors_headers() {
+    let addr = start_server(CorsConfig {
+        allowed_origins: vec![ALLOWED_ORIGIN.to_string()],
+        allowed_methods: vec!["GET".to_string(), "POST".to_string()],
+        allow_credentials: true,
+    })
+    .await;
+
+    let response = chains_from(addr, ALLOWED_ORIGIN).await;
+    assert_eq!(
+        response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN],
+        ALLOWED_ORIGIN
+    );
+    assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+
+    // Without an allowed origin header browsers withhold the response
+    let response = chains_from(addr, "https://evil.example.com").await;
+    assert!(
+        response
+            .headers()
+            .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+            .is_none()
+    );
+
+    let preflight = reqwest::Client::new()
+        .request(Method::OPTIONS, format!("http://{addr}/v1/contract/status"))
+        .header(ORIGIN, ALLOWED_ORIGIN)
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(
+        preflight.headers()[ACCESS_CONTROL_ALLOW_ORIGIN],
+        ALLOWED_ORIGIN
+    );
+    assert_eq!(
+        preflight.headers()[ACCESS_CONTROL_ALLOW_METHODS],
+        "GET,POST"
+    );
+}
+
+#[tokio::test]
+async fn wildcard_origin_allows_any_origin_by_default() {
+    let addr = start_server(CorsConfig::default()).await;
+
+    let response = chains_from(addr, "https://anywhere.example.com").await;
+    assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    assert!(
+        response
+            .headers()
+            .get(ACCESS_CONTROL_ALLOW_CREDENTIALS)
+            .is_none()
+    );
+}
@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the runtime feature flags endpoint
+
+use std::net::SocketAddr;
+
+use api::{
+    Server, ServerConfig, ShutdownConfig, config::ApiKey,
+    routes::handlers::PREDICTOR_DISABLED_REASONING,
+};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+mod fixtures;
+use fixtures::chat_completion;
+
+const ADMIN_KEY: &str = "admin-test-key";
+const ADDRESS: &str = "0x1111111111111111111111111111111111111111";
+
+/// Start a server whose model classifies every contract as spam, with the
+/// predictor disabled in configuration
+async fn start_server() -> (SocketAddr, MockServer, MockServer) {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{ADDRESS}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": ADDRESS,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.feature_flags.predictor_enabled = false;
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    (addr, moralis_server, openai_server)
+}
+
+async fn put_flags(addr: SocketAddr, key: &str, update: &Value) -> reqwest::Response {
+    reqwest::Client::new()
+        .put(format!("http://{addr}/admin/flags"))
+        .header("x-admin-api-key", key)
+        .json(update)
+        .send()
+        .await
+        .expect("Failed to send request")
+}
+
+async fn contract_result(addr: SocketAddr) -> Value {
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [ADDRESS] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let mut body: Value = response.json().await.expect("valid JSON");
+    body[ADDRESS].take()
+}
+
+#[tokio::test]
+async fn feature_flags_default_from_config() {
+    let (addr, _moralis, _openai) = start_server().await;
+
+    let response = reqwest::Client::new()
+        .get(format!("http://{addr}/admin/flags"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let flags: Value = response.json().await.expect("valid JSON");
+    assert_eq!(
+        flags,
+        json!({
+            "degraded_mode": false,
+            "predictor_enabled": false,
+            "serve_stale_on_error": false,
+            "explain_on_spam": true,
+            "return_metadata_on_predictor_failure": false
+        })
+    );
+
+    let result = contract_result(addr).await;
+    assert_eq!(result["status"], "inconclusive");
+    assert_eq!(result["reasoning"], PREDICTOR_DISABLED_REASONING);
+}
+
+#[tokio::test]
+async fn feature_flags_toggle_handler_behavior_at_runtime() {
+    let (addr, _moralis, _openai) = start_server().await;
+
+    let response = put_flags(
+        addr,
+        ADMIN_KEY,
+        &json!({ "predictor_enabled": true, "explain_on_spam": false }),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let flags: Value = response.json().await.expect("valid JSON");
+    assert_eq!(flags["predictor_enabled"], true);
+    assert_eq!(flags["explain_on_spam"], false);
+    // Flags absent from the update keep their value
+    assert_eq!(flags["degraded_mode"], false);
+
+    let result = contract_result(addr).await;
+    assert_eq!(result["status"], "spam");
+    assert!(result.get("reasoning").is_none());
+
+    put_flags(addr, ADMIN_KEY, &json!({ "explain_on_spam": true })).await;
+    let result = contract_result(addr).await;
+    assert_eq!(result["status"], "spam");
+    assert!(result["reasoning"].is_string());
+
+    put_flags(addr, ADMIN_KEY, &json!({ "degraded_mode": true })).await;
+    let health: Value = reqwest::get(format!("http://{addr}/health"))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("valid JSON");
+    assert!(health["status"].get("Degraded").is_some());
+}
+
+#[tokio::test]
+async fn feature_flags_require_admin_key_and_known_flags() {
+    let (addr, _moralis, _openai) = start_server().await;
+
+    let response = put_flags(addr, "wrong-key", &json!({ "predictor_enabled": true })).await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = put_flags(addr, ADMIN_KEY, &json!({ "unknown_flag": true })).await;
+    assert!(response.status().is_client_error());
+
+    // Rejected updates leave the flags untouched
+    let result = contract_result(addr).await;
+    assert_eq!(result["status"], "inconclusive");
+}
@@ -71,6 +71,9 @@ impl ChainTestFixture {
                 creator_address: Some(Address::from([0xc0; 20])),
                 is_verified: Some(true),
                 contract_type: Some(ContractType::Erc721),
+                external_url: None,
+                twitter: None,
+                discord: None,
                 additional_data: HashMap::new(),
             },
             expected_capabilities: vec![
@@ -115,6 +118,9 @@ impl ChainTestFixture {
                 creator_address: Some(Address::from([0xc1; 20])),
                 is_verified: Some(true),
                 contract_type: Some(ContractType::Erc1155),
+                external_url: None,
+                twitter: None,
+                discord: None,
                 additional_data: HashMap::new(),
             },
             expected_capabilities: vec![
@@ -158,6 +164,9 @@ impl ChainTestFixture {
                 creator_address: Some(Address::from([0xc2; 20])),
                 is_verified: Some(true),
                 contract_type: Some(ContractType::Erc721),
+                external_url: None,
+                twitter: None,
+                discord: None,
                 additional_data: HashMap::new(),
             },
             expected_capabilities: vec![
@@ -201,6 +210,9 @@ impl ChainTestFixture {
                 creator_address: Some(Address::from([0xc3; 20])),
                 is_verified: Some(true),
                 contract_type: Some(ContractType::Erc721),
+                external_url: None,
+                twitter: None,
+                discord: None,
                 additional_data: HashMap::new(),
             },
             expected_capabilities: vec![
@@ -244,6 +256,9 @@ impl ChainTestFixture {
                 creator_address: Some(Address::from([0xc4; 20])),
                 is_verified: Some(true),
                 contract_type: Some(ContractType::Erc1155),
+                external_url: None,
+                twitter: None,
+                discord: None,
                 additional_data: HashMap::new(),
             },
             expected_capabilities: vec![
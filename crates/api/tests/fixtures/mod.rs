@@ -1,12 +1,32 @@
 // SPDX-FileCopyrightText: 2025 Semiotic Labs
 //
 // SPDX-License-Identifier: Apache-2.0
+#![allow(dead_code)]
 
 //! Test fixtures for multi-chain testing
 //!
 //! This module provides reusable test data and fixtures for testing
-//! blockchain contract analysis across all supported chains.
+//! blockchain contract analysis across all supported chains, and the
+//! upstream responses shared by the integration tests' mocks.
 
 pub mod chains;
 
+// Not every test binary uses the chain fixtures
+#[allow(unused_imports)]
 pub use chains::*;
+use serde_json::{Value, json};
+
+/// `OpenAI` chat completion answering with `content`
+pub fn chat_completion(content: &str) -> Value {
+    json!({
+        "id": "chatcmpl-test",
+        "object": "chat.completion",
+        "created": 0,
+        "model": "gpt-3.5-turbo",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": content },
+            "finish_reason": "stop"
+        }]
+    })
+}
@@ -0,0 +1,86 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the liveness and readiness probes
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+#[tokio::test]
+async fn liveness_stays_up_while_readiness_follows_providers() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/info/endpointWeights$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&openai_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let probe = |path: &'static str| {
+        let client = client.clone();
+        async move {
+            let response = client
+                .get(format!("http://{addr}{path}"))
+                .send()
+                .await
+                .expect("Failed to send request");
+            let status = response.status();
+            let body: Value = response.json().await.expect("valid JSON");
+            (status, body)
+        }
+    };
+
+    let (status, body) = probe("/health/live").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "Up");
+    let (status, body) = probe("/health/ready").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["api_clients"]["moralis"], "Up");
+
+    // Moralis now rejects our credentials, so it reports itself down
+    moralis_server.reset().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/info/endpointWeights$"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&moralis_server)
+        .await;
+
+    let (status, body) = probe("/health/live").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "Up");
+    let (status, body) = probe("/health/ready").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(
+        body["api_clients"]["moralis"],
+        json!({ "Down": { "reason": "Authentication failed" } })
+    );
+
+    // The deep check keeps answering 200 for existing monitoring
+    let (status, _) = probe("/health").await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the configurable duration histogram buckets
+
+use std::{net::TcpListener, time::Duration};
+
+use api::{Server, ServerConfig, ShutdownConfig, metrics::observe_spam_predictor_duration};
+use axum::http::StatusCode;
+use reqwest::header::CONTENT_TYPE;
+
+/// Reserve a free local port for the metrics server
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("Failed to reserve a port")
+        .port()
+}
+
+#[tokio::test]
+async fn metrics_endpoint_exposes_configured_buckets() {
+    let mut config = ServerConfig::for_testing();
+    config.metrics.port = free_port();
+    config.metrics.histogram_buckets = vec![0.005, 0.05, 0.5, 5.0, 60.0];
+    let metrics_url = format!(
+        "http://127.0.0.1:{}{}",
+        config.metrics.port, config.metrics.endpoint_path
+    );
+
+    let server = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server");
+    let cancellation_token = server.cancellation_token();
+    let handle = tokio::spawn(server.run());
+
+    observe_spam_predictor_duration("success", 0.02);
+
+    let mut response = None;
+    for _ in 0..50 {
+        if let Ok(scraped) = reqwest::get(&metrics_url).await {
+            response = Some(scraped);
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    let response = response.expect("metrics server should start");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response.headers()[CONTENT_TYPE]
+            .to_str()
+            .expect("ASCII content type")
+            .starts_with("text/plain")
+    );
+
+    let body = response.text().await.expect("text body");
+    for (bound, count) in [("0.005", 0), ("0.05", 1), ("0.5", 1), ("5", 1), ("60", 1)] {
+        let line = format!(
+            "nft_api_spam_predictor_request_duration_bucket{{result=\"success\",le=\"{bound}\"}} {count}"
+        );
+        assert!(body.contains(&line), "missing `{line}` in:\n{body}");
+    }
+    assert!(body.contains(
+        "nft_api_spam_predictor_request_duration_bucket{result=\"success\",le=\"+Inf\"} 1"
+    ));
+    // The default boundaries are gone
+    assert!(!body.contains("le=\"0.025\""));
+
+    cancellation_token.cancel();
+    handle
+        .await
+        .expect("server task should not panic")
+        .expect("server should shut down cleanly");
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for falling back from decommissioned `OpenAI` models
+
+use std::fs;
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use spam_predictor::ModelVersion;
+use tempfile::TempDir;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, method, path_regex},
+};
+
+mod fixtures;
+use fixtures::chat_completion;
+
+const RETIRED: &str = "ft:gpt-4o-2024-08-06:semiotic-labs::RETIRED";
+const FALLBACK: &str = "ft:gpt-4o-2024-08-06:semiotic-labs::FALLBACK";
+
+#[tokio::test]
+async fn decommissioned_model_falls_back_and_degrades_health() {
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1111111111111111111111111111111111111111",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "FreeAirdropSpam",
+                "symbol": "TST"
+            }]
+        })))
+        .mount(&moralis_server)
+        .await;
+
+    let openai_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .and(body_partial_json(json!({ "model": RETIRED })))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": {
+                "message": format!("The model `{RETIRED}` does not exist"),
+                "type": "invalid_request_error",
+                "code": "model_not_found"
+            }
+        })))
+        .mount(&openai_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path_regex(r"/chat/completions$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_completion("True")))
+        .mount(&openai_server)
+        .await;
+
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let model_path = dir.path().join("models.yaml");
+    fs::write(
+        &model_path,
+        format!(
+            "model_registry:\n  spam_classification:\n    latest: {RETIRED}\n    v0: {FALLBACK}\n"
+        ),
+    )
+    .expect("Failed to write model registry");
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.openai_base_url =
+        Some(openai_server.uri().parse().expect("valid mock URL"));
+    config.spam_predictor.model_registry_path = model_path.display().to_string();
+    config.spam_predictor.fallback_model_versions =
+        vec![ModelVersion::new("v0").expect("valid version")];
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let health = || async {
+        client
+            .get(format!("http://{addr}/health"))
+            .send()
+            .await
+            .expect("Failed to send request")
+            .json::<Value>()
+            .await
+            .expect("valid JSON")
+    };
+    assert_eq!(health().await["status"], "Up");
+
+    let address = "0x1111111111111111111111111111111111111111";
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({ "chain_id": 1, "addresses": [address] }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: Value = response.json().await.expect("valid JSON");
+    assert_eq!(body[address]["status"], "spam");
+
+    let reason = format!("spam predictor model decommissioned: {RETIRED}");
+    let health = health().await;
+    assert_eq!(
+        health["status"],
+        json!({ "Degraded": { "reason": reason } })
+    );
+    assert_eq!(
+        health["api_clients"]["spam-predictor"],
+        json!({ "Degraded": { "reason": reason } })
+    );
+}
+
+#[tokio::test]
+async fn unregistered_fallback_model_fails_startup() {
+    let mut config = ServerConfig::for_testing();
+    config.spam_predictor.fallback_model_versions =
+        vec![ModelVersion::new("v9").expect("valid version")];
+
+    let error = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect_err("unregistered fallback must fail startup");
+    assert!(
+        error
+            .to_string()
+            .contains("Fallback model spam_classification:v9"),
+        "unexpected error: {error}"
+    );
+}
@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the models endpoint
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+use serde_json::json;
+
+#[tokio::test]
+async fn models_lists_the_loaded_registries() {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::get(format!("http://{addr}/v1/models"))
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+
+    // Entries of assets/configs/models.yaml and assets/prompts/ft_prompt.json
+    assert_eq!(
+        body["model_types"],
+        json!([{"model_type": "spam_classification", "versions": ["latest", "v0"]}])
+    );
+    assert_eq!(body["prompt_versions"], json!(["1.0.0"]));
+    assert_eq!(body["current_prompt_version"], "1.0.0");
+
+    let spec: serde_json::Value = reqwest::get(format!("http://{addr}/api-doc/openapi.json"))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("JSON body");
+    assert!(spec["paths"]["/v1/models"]["get"].is_object());
+    assert!(spec["components"]["schemas"]["ModelsResponse"]["example"].is_object());
+}
@@ -0,0 +1,123 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the provider statistics endpoint
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey};
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+const ADMIN_KEY: &str = "admin-test-key";
+const FOUND_ADDRESS: &str = "0x1111111111111111111111111111111111111111";
+const EMPTY_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+const FAILING_ADDRESS: &str = "0x3333333333333333333333333333333333333333";
+
+/// Start a server with Moralis backed by a mock that succeeds for two addresses and fails for one
+async fn start_server(moralis_server: &MockServer) -> SocketAddr {
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{FOUND_ADDRESS}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": FOUND_ADDRESS,
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Stats NFT",
+                "symbol": "STAT"
+            }]
+        })))
+        .mount(moralis_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{EMPTY_ADDRESS}$")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .mount(moralis_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(format!("(?i)/nft/{FAILING_ADDRESS}$")))
+        .respond_with(ResponseTemplate::new(401).set_body_string("invalid key"))
+        .mount(moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new(ADMIN_KEY.to_string()).expect("valid admin key"));
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+#[tokio::test]
+async fn provider_stats_reflect_request_outcomes() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server(&moralis_server).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .json(&json!({
+            "chain_id": 1,
+            "addresses": [FOUND_ADDRESS, EMPTY_ADDRESS, FAILING_ADDRESS]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(format!("http://{addr}/admin/providers/stats"))
+        .header("x-admin-api-key", ADMIN_KEY)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Value = response.json().await.expect("valid JSON");
+    let providers = body["providers"].as_array().expect("providers array");
+    assert_eq!(providers.len(), 1);
+
+    let moralis = &providers[0];
+    assert_eq!(moralis["provider"], "moralis");
+    assert_eq!(moralis["requests"], 3);
+    assert_eq!(moralis["successes"], 2);
+    assert_eq!(moralis["errors"], 1);
+    let error_rate = moralis["error_rate"].as_f64().expect("numeric error rate");
+    assert!((error_rate - 1.0 / 3.0).abs() < 1e-9);
+    for percentile in ["p50", "p90", "p99"] {
+        assert!(moralis["latency_ms"][percentile].as_f64().is_some());
+    }
+}
+
+#[tokio::test]
+async fn provider_stats_require_admin_key() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server(&moralis_server).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{addr}/admin/providers/stats"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = client
+        .get(format!("http://{addr}/admin/providers/stats"))
+        .header("x-admin-api-key", "wrong-key")
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for per-IP rate limiting of API routes
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey};
+use axum::http::StatusCode;
+
+#[tokio::test]
+async fn rate_limited_request_gets_retry_after_and_json_body() {
+    let mut config = ServerConfig::for_testing();
+    config.rate_limiting.enabled = true;
+    config.rate_limiting.requests_per_minute = 1;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let url = format!("http://{addr}/v1/chains");
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    // One token per minute, so the next one is nearly a minute away
+    let retry_after: u64 = response.headers()["retry-after"]
+        .to_str()
+        .expect("ASCII header")
+        .parse()
+        .expect("whole seconds");
+    assert!(
+        (59..=60).contains(&retry_after),
+        "retry-after: {retry_after}"
+    );
+
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+    assert_eq!(body["error"], "rate_limited");
+    assert_eq!(body["status"], 429);
+    assert_eq!(body["details"]["retry_after_seconds"], retry_after);
+
+    // Unlimited routes stay reachable for monitoring
+    let response = client
+        .get(format!("http://{addr}/health"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn admin_key_guesses_are_rate_limited() {
+    let mut config = ServerConfig::for_testing();
+    config.admin_api_key = Some(ApiKey::new("admin-test-key".to_string()).expect("valid key"));
+    config.rate_limiting.enabled = true;
+    config.rate_limiting.requests_per_minute = 1;
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::new();
+    let guess = || {
+        client
+            .get(format!("http://{addr}/admin/flags"))
+            .header("x-admin-api-key", "admin-test-guess")
+            .send()
+    };
+    let response = guess().await.expect("Failed to send request");
+    assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(response.status().is_client_error());
+
+    let response = guess().await.expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+}
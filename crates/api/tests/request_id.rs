@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for `x-request-id` propagation to responses
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use axum::http::StatusCode;
+
+async fn start_server() -> SocketAddr {
+    let (addr, _) = Server::new(ServerConfig::for_testing(), ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+fn request_id(response: &reqwest::Response) -> String {
+    response.headers()["x-request-id"]
+        .to_str()
+        .expect("ASCII header")
+        .to_string()
+}
+
+#[tokio::test]
+async fn request_id_is_returned_on_success_and_error() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+
+    let success = client
+        .get(format!("http://{addr}/v1/chains"))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(success.status(), StatusCode::OK);
+
+    let error = client
+        .post(format!("http://{addr}/v1/contract/status"))
+        .header("content-type", "application/json")
+        .body("{not json")
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(error.status(), StatusCode::BAD_REQUEST);
+
+    let success_id = request_id(&success);
+    let error_id = request_id(&error);
+    for id in [&success_id, &error_id] {
+        assert_eq!(id.len(), 36, "expected a generated UUID, got {id}");
+    }
+    assert_ne!(success_id, error_id);
+}
+
+#[tokio::test]
+async fn supplied_request_id_is_echoed() {
+    let addr = start_server().await;
+    let client = reqwest::Client::new();
+
+    for (method, path) in [
+        (reqwest::Method::GET, "/health"),
+        (reqwest::Method::GET, "/v1/unknown"),
+    ] {
+        let response = client
+            .request(method, format!("http://{addr}{path}"))
+            .header("x-request-id", "support-ticket-42")
+            .send()
+            .await
+            .expect("Failed to send request");
+        assert_eq!(request_id(&response), "support-ticket-42", "{path}");
+    }
+}
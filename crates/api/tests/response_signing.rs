@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for HMAC signing of API responses
+
+use std::net::SocketAddr;
+
+use api::{Server, ServerConfig, ShutdownConfig, config::ApiKey, signing::PayloadSigner};
+use axum::http::StatusCode;
+use serde_json::json;
+
+const SIGNING_SECRET: &str = "callback-signing-secret";
+
+async fn start_server(signing_secret: Option<&str>) -> SocketAddr {
+    let mut config = ServerConfig::for_testing();
+    config.response_signing_secret =
+        signing_secret.map(|secret| ApiKey::new(secret.to_string()).expect("valid secret"));
+
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+#[tokio::test]
+async fn responses_are_signed_with_configured_secret() {
+    let addr = start_server(Some(SIGNING_SECRET)).await;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/v1/contract/status"))
+        .header("idempotency-key", "batch-42")
+        .json(&json!({
+            "chain_id": 137,
+            "addresses": ["0x1234567890123456789012345678901234567890"]
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["idempotency-key"], "batch-42");
+
+    let signature = response.headers()["x-signature"]
+        .to_str()
+        .expect("ASCII signature")
+        .to_string();
+    assert!(signature.starts_with("sha256="));
+
+    let body = response.bytes().await.expect("response body");
+    let signer = PayloadSigner::new(SIGNING_SECRET).expect("valid secret");
+    assert!(signer.verify(&body, &signature));
+    assert_eq!(signature, signer.sign(&body));
+
+    let other = PayloadSigner::new("another-secret").expect("valid secret");
+    assert!(!other.verify(&body, &signature));
+}
+
+#[tokio::test]
+async fn responses_are_unsigned_without_secret() {
+    let addr = start_server(None).await;
+
+    let response = reqwest::get(format!("http://{addr}/v1/chains"))
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("x-signature").is_none());
+}
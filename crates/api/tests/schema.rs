@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for the response semantics endpoint
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use api_client::ErrorCategory;
+use axum::http::StatusCode;
+use shared_types::ContractSpamStatus;
+use spam_predictor::ConfidenceBandThresholds;
+
+#[tokio::test]
+async fn schema_describes_every_status_band_and_error_category() {
+    let mut config = ServerConfig::for_testing();
+    config.spam_predictor.confidence_band_thresholds = ConfidenceBandThresholds {
+        medium: 0.4,
+        high: 0.9,
+    };
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let response = reqwest::get(format!("http://{addr}/v1/schema"))
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = response.json().await.expect("JSON body");
+
+    let statuses = body["statuses"].as_array().expect("statuses array");
+    assert_eq!(statuses.len(), ContractSpamStatus::ALL.len());
+    for status in ContractSpamStatus::ALL {
+        let entry = statuses
+            .iter()
+            .find(|entry| entry["status"] == status.as_str())
+            .unwrap_or_else(|| panic!("missing status {}", status.as_str()));
+        assert_eq!(entry["is_spam"], status.is_spam(), "{}", status.as_str());
+        assert_eq!(entry["is_legitimate"], status.is_legitimate());
+        assert_eq!(entry["is_inconclusive"], status.is_inconclusive());
+        assert_eq!(entry["is_no_data"], status.is_no_data());
+        assert_eq!(entry["is_error"], status.is_error());
+        assert_eq!(entry["message"], status.default_message());
+    }
+    let spam: Vec<_> = statuses
+        .iter()
+        .filter(|entry| entry["is_spam"] == true)
+        .map(|entry| entry["status"].clone())
+        .collect();
+    assert_eq!(spam, ["spam"]);
+
+    // Bands follow the configured thresholds rather than the defaults
+    assert_eq!(
+        body["confidence_bands"],
+        serde_json::json!([
+            {"band": "low", "min_score": 0.0, "max_score": 0.4},
+            {"band": "medium", "min_score": 0.4, "max_score": 0.9},
+            {"band": "high", "min_score": 0.9, "max_score": null}
+        ])
+    );
+
+    let categories = body["error_categories"]
+        .as_array()
+        .expect("error_categories array");
+    assert_eq!(categories.len(), ErrorCategory::ALL.len());
+    for category in ErrorCategory::ALL {
+        let entry = categories
+            .iter()
+            .find(|entry| entry["category"] == category.as_str())
+            .unwrap_or_else(|| panic!("missing category {category}"));
+        assert_eq!(entry["retryable"], category.is_retryable(), "{category}");
+    }
+    assert!(ErrorCategory::Timeout.is_retryable());
+    assert!(!ErrorCategory::Authentication.is_retryable());
+}
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for native TLS termination
+
+use std::fs;
+
+use api::{Server, ServerConfig, ServerError, ShutdownConfig, TlsConfig};
+use axum::http::StatusCode;
+use tempfile::TempDir;
+
+/// Write a self-signed certificate for `localhost` and return it with the TLS config
+fn self_signed_tls(dir: &TempDir) -> (String, TlsConfig) {
+    let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])
+        .expect("Failed to generate certificate");
+    let cert_pem = certified.cert.pem();
+
+    let tls = TlsConfig {
+        cert_path: dir.path().join("cert.pem"),
+        key_path: dir.path().join("key.pem"),
+    };
+    fs::write(&tls.cert_path, &cert_pem).expect("Failed to write certificate");
+    fs::write(&tls.key_path, certified.key_pair.serialize_pem()).expect("Failed to write key");
+    (cert_pem, tls)
+}
+
+#[tokio::test]
+async fn server_serves_https_with_configured_certificate() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let (cert_pem, tls) = self_signed_tls(&dir);
+
+    let mut config = ServerConfig::for_testing();
+    config.tls = Some(tls);
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+
+    let client = reqwest::Client::builder()
+        .add_root_certificate(
+            reqwest::Certificate::from_pem(cert_pem.as_bytes()).expect("valid certificate"),
+        )
+        .resolve("localhost", addr)
+        .build()
+        .expect("Failed to build client");
+    let response = client
+        .get(format!("https://localhost:{}/health", addr.port()))
+        .send()
+        .await
+        .expect("HTTPS request failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn server_rejects_unparseable_certificate() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let tls = TlsConfig {
+        cert_path: dir.path().join("cert.pem"),
+        key_path: dir.path().join("key.pem"),
+    };
+    fs::write(&tls.cert_path, "not a certificate").expect("Failed to write certificate");
+    fs::write(&tls.key_path, "not a key").expect("Failed to write key");
+
+    let mut config = ServerConfig::for_testing();
+    config.tls = Some(tls);
+    let error = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect_err("invalid certificate must fail startup");
+
+    match error {
+        ServerError::Config { message } => {
+            assert!(
+                message.starts_with("Failed to load TLS certificate"),
+                "unexpected message: {message}"
+            );
+            assert!(message.contains("cert.pem"));
+        }
+        other => panic!("Expected Config error, got: {other:?}"),
+    }
+}
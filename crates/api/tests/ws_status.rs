@@ -0,0 +1,262 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for streaming contract status results over a WebSocket
+
+use std::{collections::BTreeSet, net::SocketAddr};
+
+use api::{Server, ServerConfig, ShutdownConfig};
+use futures::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Start a server whose Moralis lookups succeed and whose predictions are mocked
+async fn start_server(moralis_server: &MockServer) -> SocketAddr {
+    start_server_with(moralis_server, |_| {}).await
+}
+
+/// Like [`start_server`], adjusting the configuration first
+async fn start_server_with(
+    moralis_server: &MockServer,
+    configure: impl FnOnce(&mut ServerConfig),
+) -> SocketAddr {
+    Mock::given(method("GET"))
+        .and(path_regex(r"/nft/0x[0-9a-fA-F]{40}$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": "0x1111111111111111111111111111111111111111",
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "Cool Cats",
+                "symbol": "COOL"
+            }]
+        })))
+        .mount(moralis_server)
+        .await;
+
+    let mut config = ServerConfig::for_testing();
+    config.external_apis.moralis.enabled = true;
+    config.external_apis.moralis.base_url = moralis_server.uri().parse().expect("valid mock URL");
+    config.spam_predictor.mock_predictions = true;
+    configure(&mut config);
+    let (addr, _) = Server::new(config, ShutdownConfig::default())
+        .await
+        .expect("Failed to create server")
+        .run_for_testing()
+        .await
+        .expect("Failed to start test server");
+    addr
+}
+
+async fn connect(addr: SocketAddr) -> Socket {
+    let (socket, _) = connect_async(format!("ws://{addr}/v1/ws/status"))
+        .await
+        .expect("Failed to connect");
+    socket
+}
+
+async fn send(socket: &mut Socket, frame: &Value) {
+    socket
+        .send(Message::Text(frame.to_string().into()))
+        .await
+        .expect("Failed to send frame");
+}
+
+async fn receive(socket: &mut Socket) -> Value {
+    loop {
+        let frame = socket
+            .next()
+            .await
+            .expect("Connection closed")
+            .expect("valid frame");
+        if let Message::Text(text) = frame {
+            return serde_json::from_str(&text).expect("valid JSON");
+        }
+        assert!(
+            matches!(frame, Message::Ping(_) | Message::Pong(_)),
+            "unexpected frame: {frame:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn subscription_streams_each_result_then_completes() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server(&moralis_server).await;
+    let mut socket = connect(addr).await;
+
+    let addresses = [
+        "0x1111111111111111111111111111111111111111",
+        "0x2222222222222222222222222222222222222222",
+    ];
+    send(
+        &mut socket,
+        &json!({ "type": "subscribe", "chain_id": 1, "addresses": addresses }),
+    )
+    .await;
+
+    assert_eq!(
+        receive(&mut socket).await,
+        json!({ "type": "subscribed", "subscription_id": 1, "addresses": 2 })
+    );
+    let mut reported = BTreeSet::new();
+    for _ in addresses {
+        let frame = receive(&mut socket).await;
+        assert_eq!(frame["type"], "result");
+        assert_eq!(frame["subscription_id"], 1);
+        assert_eq!(frame["result"]["chain_id"], 1);
+        assert_eq!(frame["result"]["status"], "legitimate");
+        reported.insert(frame["address"].as_str().expect("address").to_string());
+    }
+    assert_eq!(reported, addresses.map(String::from).into());
+    assert_eq!(
+        receive(&mut socket).await,
+        json!({ "type": "complete", "subscription_id": 1 })
+    );
+
+    // The connection stays open for further subscriptions
+    send(
+        &mut socket,
+        &json!({ "type": "subscribe", "chain_id": 1, "addresses": [addresses[0]] }),
+    )
+    .await;
+    assert_eq!(receive(&mut socket).await["subscription_id"], 2);
+}
+
+#[tokio::test]
+async fn invalid_frames_are_answered_with_error_frames() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server(&moralis_server).await;
+    let mut socket = connect(addr).await;
+
+    // Empty address lists fail to parse, so no subscription is created
+    send(
+        &mut socket,
+        &json!({ "type": "subscribe", "chain_id": 1, "addresses": [] }),
+    )
+    .await;
+    let frame = receive(&mut socket).await;
+    assert_eq!(frame["type"], "error");
+    assert_eq!(frame["error"], "invalid_json");
+    assert!(frame.get("subscription_id").is_none());
+
+    // Parsed subscriptions failing validation are rejected by ID
+    send(
+        &mut socket,
+        &json!({
+            "type": "subscribe",
+            "addresses": ["0x1111111111111111111111111111111111111111"]
+        }),
+    )
+    .await;
+    let frame = receive(&mut socket).await;
+    assert_eq!(frame["type"], "error");
+    assert_eq!(frame["subscription_id"], 1);
+    assert_eq!(frame["error"], "validation_error");
+    assert!(
+        frame["message"]
+            .as_str()
+            .expect("message")
+            .contains("chain_id is required")
+    );
+
+    send(&mut socket, &json!({ "type": "unsubscribe" })).await;
+    assert_eq!(receive(&mut socket).await["error"], "invalid_json");
+}
+
+/// Subscribe to a single address and read frames up to the one ending it
+async fn subscribe_once(socket: &mut Socket) -> Value {
+    send(
+        socket,
+        &json!({
+            "type": "subscribe",
+            "chain_id": 1,
+            "addresses": ["0x1111111111111111111111111111111111111111"]
+        }),
+    )
+    .await;
+    loop {
+        let frame = receive(socket).await;
+        if frame["type"] == "complete" || frame["type"] == "error" {
+            return frame;
+        }
+    }
+}
+
+#[tokio::test]
+async fn subscriptions_beyond_the_connection_limit_are_rejected() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server_with(&moralis_server, |config| {
+        config.rate_limiting.max_subscriptions_per_connection = 2;
+    })
+    .await;
+    let mut socket = connect(addr).await;
+
+    for subscription_id in 1..=2 {
+        assert_eq!(
+            subscribe_once(&mut socket).await,
+            json!({ "type": "complete", "subscription_id": subscription_id })
+        );
+    }
+
+    let frame = subscribe_once(&mut socket).await;
+    assert_eq!(frame["type"], "error");
+    assert_eq!(frame["subscription_id"], 3);
+    assert_eq!(frame["error"], "validation_error");
+    assert!(
+        frame["message"]
+            .as_str()
+            .expect("message")
+            .contains("at most 2 subscriptions")
+    );
+
+    // A new connection starts over
+    let mut socket = connect(addr).await;
+    assert_eq!(subscribe_once(&mut socket).await["type"], "complete");
+}
+
+#[tokio::test]
+async fn each_subscription_is_rate_limited() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server_with(&moralis_server, |config| {
+        config.rate_limiting.enabled = true;
+        config.rate_limiting.requests_per_minute = 1;
+        // One token for the upgrade and one for the first subscription
+        config.rate_limiting.burst_capacity = Some(2);
+    })
+    .await;
+    let mut socket = connect(addr).await;
+
+    assert_eq!(subscribe_once(&mut socket).await["type"], "complete");
+
+    let frame = subscribe_once(&mut socket).await;
+    assert_eq!(frame["type"], "error");
+    assert_eq!(frame["subscription_id"], 2);
+    assert_eq!(frame["error"], "rate_limited");
+    let retry_after = frame["retry_after_seconds"].as_u64().expect("retry delay");
+    assert!((1..=60).contains(&retry_after), "{retry_after}");
+}
+
+#[tokio::test]
+async fn openapi_documents_the_stream() {
+    let moralis_server = MockServer::start().await;
+    let addr = start_server(&moralis_server).await;
+
+    let spec: Value = reqwest::get(format!("http://{addr}/api-doc/openapi.json"))
+        .await
+        .expect("Failed to send request")
+        .json()
+        .await
+        .expect("JSON body");
+    assert!(spec["paths"]["/v1/ws/status"]["get"].is_object());
+    assert!(spec["components"]["schemas"]["StatusStreamRequest"].is_object());
+    assert!(spec["components"]["schemas"]["StatusStreamMessage"].is_object());
+}
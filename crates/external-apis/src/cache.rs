@@ -5,11 +5,12 @@
 //! Caching layer for external API operations
 //!
 //! This module provides high-performance in-memory caching for contract metadata
-//! from external APIs (Moralis, Pinax) to minimize API calls and improve response times.
+//! from external APIs (Moralis, Pinax, `SimpleHash`) to minimize API calls and improve response times.
 
 use std::{
     fmt::Display,
     hash::Hash,
+    sync::atomic::{AtomicU64, Ordering},
     time::{Duration, Instant},
 };
 
@@ -27,17 +28,27 @@ pub enum ApiProvider {
     Moralis,
     /// Pinax Analytics API
     Pinax,
+    /// `SimpleHash` NFT API
+    SimpleHash,
 }
 
-impl Display for ApiProvider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ApiProvider {
+    /// Lowercase provider name, as used in statistics and health reports
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Moralis => write!(f, "moralis"),
-            Self::Pinax => write!(f, "pinax"),
+            Self::Moralis => "moralis",
+            Self::Pinax => "pinax",
+            Self::SimpleHash => "simplehash",
         }
     }
 }
 
+impl Display for ApiProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Cache key for contract metadata lookups
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MetadataCacheKey {
@@ -152,15 +163,15 @@ impl MetadataCache {
 
                 return Some(cached.metadata.clone());
             }
-            // Remove expired entry
+            // Keep the expired entry as a stale fallback until it is refreshed
+            // or cleaned up
             let expired_provider = cached.provider.clone();
             drop(cached);
-            self.metadata.remove(key);
             self.increment_stat("cache_expired");
             self.increment_stat(&format!("cache_expired_{expired_provider}"));
 
             debug!(
-                "expired cache entry removed for address={} chain_id={}",
+                "expired cache entry for address={} chain_id={}",
                 key.address,
                 key.chain_id.name()
             );
@@ -170,6 +181,16 @@ impl MetadataCache {
         None
     }
 
+    /// Get cached contract metadata regardless of its age
+    ///
+    /// Used to serve stale metadata when every provider fails. Expired entries
+    /// remain available until they are refreshed or cleaned up.
+    pub fn get_stale_metadata(&self, key: &MetadataCacheKey) -> Option<ContractMetadata> {
+        let metadata = self.metadata.get(key)?.metadata.clone()?;
+        self.increment_stat("cache_stale_hits");
+        Some(metadata)
+    }
+
     /// Store contract metadata in the cache
     pub fn store_metadata(
         &self,
@@ -416,6 +437,120 @@ impl MetadataCache {
     }
 }
 
+/// Default TTL for "not found across all providers" lookups (5 minutes)
+pub const DEFAULT_NEGATIVE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Cache of addresses no provider returned metadata for
+///
+/// Kept apart from [`MetadataCache`] so that misses, which may turn into hits
+/// once an indexer catches up, expire much sooner than found metadata. A zero
+/// TTL disables negative caching. When full, new misses are not cached rather
+/// than evicting live entries.
+#[derive(Debug)]
+pub struct NegativeLookupCache {
+    /// Time each missing address was recorded
+    entries: DashMap<MetadataCacheKey, Instant>,
+    /// How long a miss is remembered
+    ttl: Duration,
+    /// Maximum number of remembered misses
+    max_entries: usize,
+    /// Lookups answered from the cache
+    hits: AtomicU64,
+    /// Misses recorded
+    stores: AtomicU64,
+}
+
+impl Default for NegativeLookupCache {
+    fn default() -> Self {
+        Self::with_settings(DEFAULT_NEGATIVE_CACHE_TTL, 50000)
+    }
+}
+
+impl NegativeLookupCache {
+    /// Create a negative lookup cache with custom settings
+    pub fn with_settings(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            ttl,
+            max_entries,
+            hits: AtomicU64::new(0),
+            stores: AtomicU64::new(0),
+        }
+    }
+
+    /// Create a cache that never remembers misses
+    pub fn disabled() -> Self {
+        Self::with_settings(Duration::ZERO, 0)
+    }
+
+    /// Whether misses are cached at all
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.max_entries > 0
+    }
+
+    /// Whether `key` is a known miss within the TTL
+    pub fn contains(&self, key: &MetadataCacheKey) -> bool {
+        let Some(recorded_at) = self.entries.get(key).map(|entry| *entry) else {
+            return false;
+        };
+        if recorded_at.elapsed() < self.ttl {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return true;
+        }
+        self.entries.remove(key);
+        false
+    }
+
+    /// Remember that no provider had metadata for `key`
+    pub fn store(&self, key: &MetadataCacheKey) {
+        if !self.is_enabled() {
+            return;
+        }
+        if self.entries.len() >= self.max_entries {
+            self.entries
+                .retain(|_, recorded_at| recorded_at.elapsed() < self.ttl);
+            if self.entries.len() >= self.max_entries {
+                debug!(
+                    address = %key.address,
+                    chain_id = %key.chain_id,
+                    "negative lookup cache full, not caching miss"
+                );
+                return;
+            }
+        }
+        self.entries.insert(key.clone(), Instant::now());
+        self.stores.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Forget all remembered misses
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    /// Get negative cache statistics
+    pub fn get_stats(&self) -> NegativeLookupCacheStats {
+        NegativeLookupCacheStats {
+            entry_count: self.entries.len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            stores: self.stores.load(Ordering::Relaxed),
+            ttl_seconds: self.ttl.as_secs(),
+        }
+    }
+}
+
+/// Negative lookup cache statistics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegativeLookupCacheStats {
+    /// Number of remembered misses, including expired ones not yet purged
+    pub entry_count: usize,
+    /// Lookups that skipped the provider fan-out
+    pub hits: u64,
+    /// Misses recorded
+    pub stores: u64,
+    /// TTL in seconds
+    pub ttl_seconds: u64,
+}
+
 /// Cache statistics and metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetadataCacheStats {
@@ -469,6 +604,9 @@ mod tests {
             creator_address: None,
             is_verified: Some(true),
             contract_type: Some(api_client::ContractType::Erc721),
+            external_url: None,
+            twitter: None,
+            discord: None,
             additional_data: std::collections::HashMap::new(),
         }
     }
@@ -484,6 +622,7 @@ mod tests {
     fn api_provider_display() {
         assert_eq!(ApiProvider::Moralis.to_string(), "moralis");
         assert_eq!(ApiProvider::Pinax.to_string(), "pinax");
+        assert_eq!(ApiProvider::SimpleHash.to_string(), "simplehash");
     }
 
     #[test]
@@ -527,6 +666,22 @@ mod tests {
         assert_eq!(cache.get_metadata(&key), None);
     }
 
+    #[test]
+    fn stale_metadata_survives_expiration() {
+        let cache = MetadataCache::with_settings(Duration::from_millis(10), 1000);
+        let key = MetadataCacheKey::new(Address::ZERO, ChainId::Ethereum);
+        let metadata = create_test_metadata();
+
+        cache.store_metadata(&key, Some(&metadata), &ApiProvider::Moralis);
+        thread::sleep(Duration::from_millis(15));
+
+        assert_eq!(cache.get_metadata(&key), None);
+        assert_eq!(cache.get_stale_metadata(&key), Some(metadata));
+
+        cache.cleanup_expired().unwrap();
+        assert_eq!(cache.get_stale_metadata(&key), None);
+    }
+
     #[test]
     fn cache_statistics() {
         let cache = MetadataCache::new();
@@ -611,4 +766,36 @@ mod tests {
         assert_eq!(stats.cache_evictions, 1);
         assert!(stats.utilization_rate > 0.8); // Should be high utilization
     }
+
+    #[test]
+    fn negative_lookup_cache_expires_and_can_be_disabled() {
+        let cache = NegativeLookupCache::with_settings(Duration::from_millis(10), 1000);
+        let key = MetadataCacheKey::new(Address::ZERO, ChainId::Ethereum);
+
+        assert!(!cache.contains(&key));
+        cache.store(&key);
+        assert!(cache.contains(&key));
+        assert_eq!(cache.get_stats().hits, 1);
+
+        thread::sleep(Duration::from_millis(15));
+        assert!(!cache.contains(&key));
+
+        let disabled = NegativeLookupCache::disabled();
+        disabled.store(&key);
+        assert!(!disabled.contains(&key));
+        assert_eq!(disabled.get_stats().stores, 0);
+    }
+
+    #[test]
+    fn negative_lookup_cache_does_not_grow_past_capacity() {
+        let cache = NegativeLookupCache::with_settings(Duration::from_secs(3600), 1);
+        let key1 = MetadataCacheKey::new(Address::from([1u8; 20]), ChainId::Ethereum);
+        let key2 = MetadataCacheKey::new(Address::from([2u8; 20]), ChainId::Ethereum);
+
+        cache.store(&key1);
+        cache.store(&key2);
+        assert!(cache.contains(&key1));
+        assert!(!cache.contains(&key2));
+        assert_eq!(cache.get_stats().entry_count, 1);
+    }
 }
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-provider circuit breaker for metadata lookups
+//!
+//! After `failure_threshold` consecutive failures within `failure_window`, a
+//! provider's breaker opens and the registry skips the provider for
+//! `cooldown`. Once the cooldown has elapsed a single half-open probe is let
+//! through: success closes the breaker, failure opens it for another cooldown.
+
+use std::{
+    sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Default number of consecutive failures that opens a breaker
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+/// Default window the consecutive failures must fall within
+pub const DEFAULT_FAILURE_WINDOW: Duration = Duration::from_secs(60);
+/// Default time an open breaker skips its provider before probing it again
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Circuit breaker thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// Window the consecutive failures must fall within, measured from the first
+    pub failure_window: Duration,
+    /// Time the provider is skipped once the breaker opens
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            failure_window: DEFAULT_FAILURE_WINDOW,
+            cooldown: DEFAULT_COOLDOWN,
+        }
+    }
+}
+
+/// State of a provider's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerState {
+    /// Requests flow normally
+    Closed,
+    /// The provider is skipped until the cooldown elapses
+    Open,
+    /// The cooldown elapsed and a probe request may test the provider
+    HalfOpen,
+}
+
+/// Lock-free circuit breaker for a single provider
+///
+/// Timestamps are milliseconds since the breaker was created, offset by one
+/// so that `0` means "unset".
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    created: Instant,
+    consecutive_failures: AtomicU32,
+    first_failure_at: AtomicU64,
+    opened_at: AtomicU64,
+    probe_in_flight: AtomicBool,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            created: Instant::now(),
+            consecutive_failures: AtomicU32::new(0),
+            first_failure_at: AtomicU64::new(0),
+            opened_at: AtomicU64::new(0),
+            probe_in_flight: AtomicBool::new(false),
+        }
+    }
+
+    /// Current state of the breaker
+    pub fn state(&self) -> BreakerState {
+        let opened_at = self.opened_at.load(Ordering::Acquire);
+        if opened_at == 0 {
+            BreakerState::Closed
+        } else if self.elapsed_since(opened_at) < self.config.cooldown {
+            BreakerState::Open
+        } else {
+            BreakerState::HalfOpen
+        }
+    }
+
+    /// Whether a request may be sent to the provider
+    ///
+    /// In the half-open state only the first caller gets to probe the provider.
+    pub fn allow_request(&self) -> bool {
+        match self.state() {
+            BreakerState::Closed => true,
+            BreakerState::Open => false,
+            BreakerState::HalfOpen => self
+                .probe_in_flight
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok(),
+        }
+    }
+
+    /// Record a successful request, closing the breaker
+    pub fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Release);
+        self.first_failure_at.store(0, Ordering::Release);
+        self.opened_at.store(0, Ordering::Release);
+        self.probe_in_flight.store(false, Ordering::Release);
+    }
+
+    /// Record a failed request, opening the breaker once the threshold is reached
+    pub fn record_failure(&self) {
+        let now = self.now();
+        if self.opened_at.load(Ordering::Acquire) != 0 {
+            // A failed probe, or a request that started before the breaker
+            // opened: restart the cooldown
+            self.opened_at.store(now, Ordering::Release);
+            self.probe_in_flight.store(false, Ordering::Release);
+            return;
+        }
+
+        let first_failure_at = self.first_failure_at.load(Ordering::Acquire);
+        let failures = if first_failure_at == 0
+            || self.elapsed_since(first_failure_at) > self.config.failure_window
+        {
+            self.first_failure_at.store(now, Ordering::Release);
+            self.consecutive_failures.store(1, Ordering::Release);
+            1
+        } else {
+            self.consecutive_failures.fetch_add(1, Ordering::AcqRel) + 1
+        };
+        if failures >= self.config.failure_threshold {
+            self.opened_at.store(now, Ordering::Release);
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn now(&self) -> u64 {
+        self.created.elapsed().as_millis() as u64 + 1
+    }
+
+    fn elapsed_since(&self, timestamp: u64) -> Duration {
+        Duration::from_millis(self.now().saturating_sub(timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            failure_window: Duration::from_secs(60),
+            cooldown,
+        })
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = breaker(Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        assert!(breaker.allow_request());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Open);
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn success_resets_the_failure_count() {
+        let breaker = breaker(Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn failures_outside_the_window_do_not_accumulate() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            failure_window: Duration::from_millis(20),
+            cooldown: Duration::from_secs(60),
+        });
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(40));
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn half_open_allows_a_single_probe() {
+        let breaker = breaker(Duration::from_millis(20));
+        for _ in 0..3 {
+            breaker.record_failure();
+        }
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(breaker.state(), BreakerState::HalfOpen);
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+
+        // A failed probe opens the breaker for another cooldown
+        breaker.record_failure();
+        assert_eq!(breaker.state(), BreakerState::Open);
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(breaker.allow_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), BreakerState::Closed);
+        assert!(breaker.allow_request());
+    }
+}
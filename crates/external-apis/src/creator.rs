@@ -0,0 +1,517 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Creator address enrichment via name services and JSON-RPC
+//!
+//! This module looks up the deployer of a contract to derive a coarse reputation
+//! signal for spam classification. Serial spammers tend to deploy many contracts
+//! from freshly funded addresses, while established creators usually have a
+//! reverse-resolved name or a long transaction history.
+
+use std::{collections::HashMap, fmt, time::Duration};
+
+use alloy_primitives::Address;
+use api_client::{ContractMetadata, UpstreamAllowlist};
+use reqwest::{Client, ClientBuilder, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+use shared_types::ChainId;
+use thiserror::Error;
+use tokio::time::timeout;
+use tracing::{debug, warn};
+
+/// Default request timeout for creator lookups in seconds
+pub const DEFAULT_CREATOR_ENRICHMENT_TIMEOUT_SECONDS: u64 = 5;
+
+/// Default transaction count at or below which a creator is considered fresh
+pub const DEFAULT_FRESH_ADDRESS_MAX_TRANSACTIONS: u64 = 5;
+
+/// Configuration for the creator enrichment client
+#[derive(Debug, Clone)]
+pub struct CreatorEnrichmentConfig {
+    /// JSON-RPC endpoints used to query creator transaction counts, per chain
+    pub rpc_urls: HashMap<ChainId, String>,
+    /// Base URL of a reverse-resolution name service (`GET {url}/{address}`)
+    pub name_service_url: Option<String>,
+    /// Request timeout in seconds
+    pub timeout_seconds: u64,
+    /// Transaction count at or below which a creator is considered fresh
+    pub fresh_address_max_transactions: u64,
+}
+
+impl Default for CreatorEnrichmentConfig {
+    fn default() -> Self {
+        Self {
+            rpc_urls: HashMap::new(),
+            name_service_url: None,
+            timeout_seconds: DEFAULT_CREATOR_ENRICHMENT_TIMEOUT_SECONDS,
+            fresh_address_max_transactions: DEFAULT_FRESH_ADDRESS_MAX_TRANSACTIONS,
+        }
+    }
+}
+
+/// Coarse reputation of a contract creator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreatorReputation {
+    /// Creator has a reverse-resolved name
+    Known,
+    /// Creator has a transaction history beyond the fresh threshold
+    Established,
+    /// Creator has little to no prior activity
+    Fresh,
+    /// Creator activity could not be determined
+    Unknown,
+}
+
+impl CreatorReputation {
+    /// Get the string representation used in model input
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreatorReputation::Known => "known",
+            CreatorReputation::Established => "established",
+            CreatorReputation::Fresh => "fresh",
+            CreatorReputation::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for CreatorReputation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Enrichment result for a single creator address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatorProfile {
+    /// Creator address
+    pub address: Address,
+    /// Reverse-resolved name (e.g. an ENS name), if any
+    pub name: Option<String>,
+    /// Number of transactions sent by the creator, if known
+    pub transaction_count: Option<u64>,
+    /// Derived reputation
+    pub reputation: CreatorReputation,
+}
+
+/// Errors specific to creator enrichment
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum CreatorEnrichmentError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Upstream returned an unexpected response
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    /// JSON-RPC endpoint returned an error object
+    #[error("RPC error {code}: {message}")]
+    Rpc { code: i64, message: String },
+
+    /// Configuration error
+    #[error("Configuration error: {0}")]
+    Config(String),
+
+    /// Timeout error
+    #[error("Request timeout after {seconds} seconds")]
+    Timeout { seconds: u64 },
+}
+
+/// JSON-RPC response envelope
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    result: Option<String>,
+    error: Option<RpcErrorObject>,
+}
+
+/// JSON-RPC error object
+#[derive(Debug, Deserialize)]
+struct RpcErrorObject {
+    code: i64,
+    message: String,
+}
+
+/// Name service reverse-resolution response
+#[derive(Debug, Deserialize)]
+struct NameServiceResponse {
+    name: Option<String>,
+}
+
+/// Client that resolves creator names and activity for reputation signals
+#[derive(Debug)]
+pub struct CreatorEnrichmentClient {
+    client: Client,
+    config: CreatorEnrichmentConfig,
+}
+
+impl CreatorEnrichmentClient {
+    /// Create a new creator enrichment client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no lookup source is configured or the HTTP client cannot be created
+    pub fn new(config: CreatorEnrichmentConfig) -> Result<Self, CreatorEnrichmentError> {
+        if config.rpc_urls.is_empty() && config.name_service_url.is_none() {
+            return Err(CreatorEnrichmentError::Config(
+                "At least one RPC URL or a name service URL is required".to_string(),
+            ));
+        }
+
+        let client = Self::client_builder(&config)
+            .build()
+            .map_err(CreatorEnrichmentError::Http)?;
+
+        Ok(Self { client, config })
+    }
+
+    fn client_builder(config: &CreatorEnrichmentConfig) -> ClientBuilder {
+        Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .user_agent("nft-api/0.1.0")
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a configured RPC or name service URL is not permitted
+    /// or the HTTP client cannot be rebuilt
+    pub fn with_upstream_allowlist(
+        mut self,
+        allowlist: &UpstreamAllowlist,
+    ) -> Result<Self, CreatorEnrichmentError> {
+        let urls = self
+            .config
+            .rpc_urls
+            .values()
+            .chain(self.config.name_service_url.as_ref());
+        for url in urls {
+            allowlist
+                .check_url_str(url)
+                .map_err(|e| CreatorEnrichmentError::Config(e.to_string()))?;
+        }
+
+        self.client = allowlist
+            .apply(Self::client_builder(&self.config))
+            .build()
+            .map_err(CreatorEnrichmentError::Http)?;
+        Ok(self)
+    }
+
+    /// Look up the creator's name and activity and derive a reputation
+    ///
+    /// Sources that are not configured for the chain are skipped. Individual
+    /// source failures are logged and treated as missing data.
+    pub async fn lookup_creator(&self, creator: Address, chain_id: ChainId) -> CreatorProfile {
+        let (name, transaction_count) = tokio::join!(
+            self.resolve_name(creator),
+            self.get_transaction_count(creator, chain_id)
+        );
+
+        let name = name.unwrap_or_else(|e| {
+            warn!("Name service lookup failed for creator {}: {}", creator, e);
+            None
+        });
+        let transaction_count = transaction_count.unwrap_or_else(|e| {
+            warn!(
+                "Transaction count lookup failed for creator {} on chain {}: {}",
+                creator,
+                chain_id.name(),
+                e
+            );
+            None
+        });
+
+        let reputation = match (&name, transaction_count) {
+            (Some(_), _) => CreatorReputation::Known,
+            (None, Some(count)) if count <= self.config.fresh_address_max_transactions => {
+                CreatorReputation::Fresh
+            }
+            (None, Some(_)) => CreatorReputation::Established,
+            (None, None) => CreatorReputation::Unknown,
+        };
+
+        CreatorProfile {
+            address: creator,
+            name,
+            transaction_count,
+            reputation,
+        }
+    }
+
+    /// Attach creator reputation fields to the metadata's additional data
+    ///
+    /// Does nothing when the metadata has no creator address.
+    pub async fn enrich(&self, metadata: &mut ContractMetadata, chain_id: ChainId) {
+        let Some(creator) = metadata.creator_address else {
+            return;
+        };
+
+        let profile = self.lookup_creator(creator, chain_id).await;
+        debug!(
+            "Creator {} of contract {} has reputation {}",
+            creator, metadata.address, profile.reputation
+        );
+
+        metadata.additional_data.insert(
+            "creator_reputation".to_string(),
+            serde_json::Value::String(profile.reputation.to_string()),
+        );
+        if let Some(name) = profile.name {
+            metadata
+                .additional_data
+                .insert("creator_name".to_string(), serde_json::Value::String(name));
+        }
+        if let Some(count) = profile.transaction_count {
+            metadata
+                .additional_data
+                .insert("creator_transaction_count".to_string(), count.into());
+        }
+    }
+
+    /// Reverse-resolve a name for the address via the configured name service
+    async fn resolve_name(
+        &self,
+        address: Address,
+    ) -> Result<Option<String>, CreatorEnrichmentError> {
+        let Some(base_url) = &self.config.name_service_url else {
+            return Ok(None);
+        };
+
+        let url = format!("{}/{}", base_url.trim_end_matches('/'), address);
+        let response = self.send(self.client.get(&url)).await?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let body: NameServiceResponse = response.json().await?;
+                Ok(body.name.filter(|name| !name.trim().is_empty()))
+            }
+            StatusCode::NOT_FOUND => Ok(None),
+            status => Err(CreatorEnrichmentError::InvalidResponse(format!(
+                "name service returned status {}",
+                status.as_u16()
+            ))),
+        }
+    }
+
+    /// Query the number of transactions sent by the address via JSON-RPC
+    async fn get_transaction_count(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Result<Option<u64>, CreatorEnrichmentError> {
+        let Some(rpc_url) = self.config.rpc_urls.get(&chain_id) else {
+            return Ok(None);
+        };
+
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_getTransactionCount",
+            "params": [address.to_string(), "latest"],
+        });
+        let response = self.send(self.client.post(rpc_url).json(&payload)).await?;
+
+        if response.status() != StatusCode::OK {
+            return Err(CreatorEnrichmentError::InvalidResponse(format!(
+                "RPC endpoint returned status {}",
+                response.status().as_u16()
+            )));
+        }
+
+        let body: RpcResponse = response.json().await?;
+        if let Some(error) = body.error {
+            return Err(CreatorEnrichmentError::Rpc {
+                code: error.code,
+                message: error.message,
+            });
+        }
+
+        let result = body.result.ok_or_else(|| {
+            CreatorEnrichmentError::InvalidResponse("RPC response has no result".to_string())
+        })?;
+        let count = u64::from_str_radix(result.trim_start_matches("0x"), 16).map_err(|_| {
+            CreatorEnrichmentError::InvalidResponse(format!("invalid transaction count: {result}"))
+        })?;
+
+        Ok(Some(count))
+    }
+
+    /// Send a request, bounding it with the configured timeout
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, CreatorEnrichmentError> {
+        timeout(
+            Duration::from_secs(self.config.timeout_seconds),
+            request.send(),
+        )
+        .await
+        .map_err(|_| CreatorEnrichmentError::Timeout {
+            seconds: self.config.timeout_seconds,
+        })?
+        .map_err(CreatorEnrichmentError::Http)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        Mock, MockServer, ResponseTemplate,
+        matchers::{body_string_contains, method, path},
+    };
+
+    use super::*;
+
+    fn creator_address() -> Address {
+        "0xc0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0"
+            .parse()
+            .unwrap()
+    }
+
+    async fn mock_rpc(transaction_count: &str) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("eth_getTransactionCount"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": transaction_count,
+            })))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn config_with_rpc(server: &MockServer) -> CreatorEnrichmentConfig {
+        CreatorEnrichmentConfig {
+            rpc_urls: HashMap::from([(ChainId::Ethereum, server.uri())]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn client_requires_a_lookup_source() {
+        let result = CreatorEnrichmentClient::new(CreatorEnrichmentConfig::default());
+        assert!(matches!(result, Err(CreatorEnrichmentError::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn fresh_creator_detected_from_rpc() {
+        let server = mock_rpc("0x1").await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let profile = client
+            .lookup_creator(creator_address(), ChainId::Ethereum)
+            .await;
+
+        assert_eq!(profile.transaction_count, Some(1));
+        assert_eq!(profile.reputation, CreatorReputation::Fresh);
+    }
+
+    #[tokio::test]
+    async fn established_creator_detected_from_rpc() {
+        let server = mock_rpc("0x3e8").await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let profile = client
+            .lookup_creator(creator_address(), ChainId::Ethereum)
+            .await;
+
+        assert_eq!(profile.transaction_count, Some(1000));
+        assert_eq!(profile.reputation, CreatorReputation::Established);
+    }
+
+    #[tokio::test]
+    async fn named_creator_is_known() {
+        let rpc = mock_rpc("0x0").await;
+        let name_service = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/{}", creator_address())))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "name": "creator.eth",
+            })))
+            .mount(&name_service)
+            .await;
+
+        let config = CreatorEnrichmentConfig {
+            name_service_url: Some(name_service.uri()),
+            ..config_with_rpc(&rpc)
+        };
+        let client = CreatorEnrichmentClient::new(config).unwrap();
+
+        let profile = client
+            .lookup_creator(creator_address(), ChainId::Ethereum)
+            .await;
+
+        assert_eq!(profile.name.as_deref(), Some("creator.eth"));
+        assert_eq!(profile.reputation, CreatorReputation::Known);
+    }
+
+    #[tokio::test]
+    async fn unconfigured_chain_is_unknown() {
+        let server = mock_rpc("0x1").await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let profile = client
+            .lookup_creator(creator_address(), ChainId::Polygon)
+            .await;
+
+        assert_eq!(profile.transaction_count, None);
+        assert_eq!(profile.reputation, CreatorReputation::Unknown);
+    }
+
+    #[tokio::test]
+    async fn rpc_error_is_treated_as_missing_data() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": { "code": -32000, "message": "header not found" },
+            })))
+            .mount(&server)
+            .await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let profile = client
+            .lookup_creator(creator_address(), ChainId::Ethereum)
+            .await;
+
+        assert_eq!(profile.reputation, CreatorReputation::Unknown);
+    }
+
+    #[tokio::test]
+    async fn enrich_populates_creator_reputation() {
+        let server = mock_rpc("0x0").await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let mut metadata = ContractMetadata::minimal(Address::repeat_byte(0x12));
+        metadata.creator_address = Some(creator_address());
+        client.enrich(&mut metadata, ChainId::Ethereum).await;
+
+        assert_eq!(
+            metadata.additional_data.get("creator_reputation"),
+            Some(&serde_json::Value::String("fresh".to_string()))
+        );
+        assert_eq!(
+            metadata.additional_data.get("creator_transaction_count"),
+            Some(&serde_json::Value::from(0))
+        );
+    }
+
+    #[tokio::test]
+    async fn enrich_skips_metadata_without_creator() {
+        let server = mock_rpc("0x0").await;
+        let client = CreatorEnrichmentClient::new(config_with_rpc(&server)).unwrap();
+
+        let mut metadata = ContractMetadata::minimal(Address::repeat_byte(0x12));
+        client.enrich(&mut metadata, ChainId::Ethereum).await;
+
+        assert!(metadata.additional_data.is_empty());
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
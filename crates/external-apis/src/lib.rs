@@ -10,14 +10,18 @@
 //!
 //! # Architecture
 //!
-//! - **Client Implementations**: [`moralis`], [`pinax`] - specific API integrations
+//! - **Client Implementations**: [`moralis`], [`pinax`], [`simplehash`] - specific API integrations
+//! - **Creator Enrichment**: [`creator`] - reputation signals for contract deployers
 //! - **Registry Pattern**: [`registry::ApiRegistry`] - orchestrates multiple clients with failover
+//! - **Circuit Breaking**: [`circuit_breaker`] - stops calling a provider that keeps failing
 //! - **Caching Layer**: [`cache`] - high-performance in-memory caching with TTL and LRU eviction
-//! - **Validation Utilities**: [`non_empty_string::NonEmptyString`] - ensures non-empty string constraints
+//! - **Validation Utilities**: [`non_empty_string::NonEmptyString`] and
+//!   [`non_empty_vec::NonEmptyVec`] - ensure non-empty string and list constraints
 //!
 //! # Features
 //!
-//! - **Automatic Failover**: Registry tries clients in order, skipping unhealthy ones
+//! - **Automatic Failover**: Registry tries clients from the highest configured priority down,
+//!   skipping those whose circuit breaker is open
 //! - **Intelligent Caching**: Contract metadata cached with configurable TTL and capacity limits
 //! - **Concurrent Health Checks**: Uses `tokio::join!` for efficient health monitoring
 //! - **Robust Error Handling**: Comprehensive error types for different failure scenarios
@@ -25,13 +29,23 @@
 //! - **Testing Support**: Comprehensive test coverage using wiremock for HTTP simulation
 
 pub mod cache;
+pub mod circuit_breaker;
+pub mod creator;
 pub mod moralis;
 pub mod non_empty_string;
+pub mod non_empty_vec;
 pub mod pinax;
 pub mod registry;
+pub mod simplehash;
+pub mod stats;
 
 pub use cache::*;
+pub use circuit_breaker::{BreakerState, CircuitBreakerConfig};
+pub use creator::*;
 pub use moralis::*;
 pub use non_empty_string::NonEmptyString;
+pub use non_empty_vec::NonEmptyVec;
 pub use pinax::*;
 pub use registry::*;
+pub use simplehash::*;
+pub use stats::{LatencyPercentiles, ProviderStats};
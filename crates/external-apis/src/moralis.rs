@@ -10,9 +10,13 @@
 use std::{collections::HashMap, time::Duration};
 
 use alloy_primitives::Address;
-use api_client::{ApiClient, ApiError, ContractMetadata, ContractType, HealthStatus};
-use reqwest::{Client, StatusCode};
-use serde::Deserialize;
+use api_client::{
+    ApiClient, ApiError, ContractMetadata, ContractType, DEFAULT_MAX_PARSED_COUNT, HealthStatus,
+    RateLimitConfig, RetryPolicy, TokenMetadata, UpstreamAllowlist, parse_count,
+    retry_after_header,
+};
+use reqwest::{Client, ClientBuilder, RequestBuilder, StatusCode};
+use serde::{Deserialize, de::DeserializeOwned};
 use shared_types::ChainId;
 use thiserror::Error;
 use tokio::time::timeout;
@@ -28,6 +32,8 @@ pub struct MoralisConfig {
     pub api_key: String,
     /// Request timeout in seconds
     pub timeout_seconds: u64,
+    /// Connection establishment timeout in seconds
+    pub connect_timeout_seconds: u64,
     /// Health check timeout in seconds
     pub health_check_timeout_seconds: u64,
     /// Maximum number of retry attempts
@@ -40,6 +46,7 @@ impl Default for MoralisConfig {
             base_url: "https://deep-index.moralis.io/api/v2".to_string(),
             api_key: "test-api-key".to_string(),
             timeout_seconds: 30,
+            connect_timeout_seconds: 1,
             health_check_timeout_seconds: 5,
             max_retries: 3,
         }
@@ -65,8 +72,6 @@ struct ChainEffectiveConfig {
     /// Effective timeout (base config or chain override)
     timeout_seconds: u64,
     /// Effective max retries (base config or chain override)
-    /// Note: Currently unused but prepared for future retry logic implementation
-    #[allow(dead_code)]
     max_retries: u32,
 }
 
@@ -75,8 +80,14 @@ struct ChainEffectiveConfig {
 pub struct MoralisClient {
     client: Client,
     config: MoralisConfig,
+    /// Backoff between retries; attempts come from the effective `max_retries`
+    retry_policy: RetryPolicy,
     /// Chain-specific configuration overrides
     chain_overrides: HashMap<ChainId, PerChainMoralisConfig>,
+    /// Upper bound for counts parsed from responses
+    max_parsed_count: u64,
+    /// Fallback back-off reported when a rate-limited response has no `Retry-After`
+    rate_limit: RateLimitConfig,
 }
 
 /// Errors specific to the Moralis API client
@@ -95,9 +106,13 @@ pub enum MoralisError {
     #[error("API error: {status} - {message}")]
     ApiError { status: u16, message: String },
 
-    /// Rate limit exceeded
-    #[error("Rate limit exceeded")]
-    RateLimited,
+    /// Rate limit exceeded; `from_header` tells whether Moralis sent the delay
+    /// in a `Retry-After` header or the configured fallback was used
+    #[error("Rate limit exceeded, retry after {retry_after_seconds} seconds")]
+    RateLimited {
+        retry_after_seconds: u64,
+        from_header: bool,
+    },
 
     /// Authentication failed
     #[error("Authentication failed")]
@@ -118,6 +133,29 @@ pub enum MoralisError {
     UnsupportedChain { chain_id: u64, chain_name: String },
 }
 
+impl MoralisError {
+    /// Whether the error is likely transient and the request worth retrying
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(error) => error.is_timeout() || error.is_connect(),
+            Self::Timeout { .. } | Self::RateLimited { .. } => true,
+            Self::ApiError { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Delay Moralis asked to wait before retrying, from its `Retry-After` header
+    pub fn requested_delay(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited {
+                retry_after_seconds,
+                from_header: true,
+            } => Some(Duration::from_secs(*retry_after_seconds)),
+            _ => None,
+        }
+    }
+}
+
 impl From<MoralisError> for ApiError {
     fn from(value: MoralisError) -> Self {
         match value {
@@ -130,8 +168,11 @@ impl From<MoralisError> for ApiError {
             MoralisError::ApiError { status, message } => ApiError::Custom {
                 error: anyhow::Error::msg(format!("{status}: {message}")),
             },
-            MoralisError::RateLimited => ApiError::RateLimitExceeded {
-                retry_after_seconds: 3,
+            MoralisError::RateLimited {
+                retry_after_seconds,
+                ..
+            } => ApiError::RateLimitExceeded {
+                retry_after_seconds,
             },
             MoralisError::Unauthorized => ApiError::Authentication {
                 message: value.to_string(),
@@ -155,6 +196,9 @@ impl From<MoralisError> for ApiError {
 /// Response structure for Moralis contract NFTs endpoint
 #[derive(Debug, Deserialize)]
 pub struct MoralisContractNftsResponse {
+    /// Total number of NFTs in the contract, as reported by Moralis
+    #[serde(default)]
+    pub total: Option<serde_json::Value>,
     /// List of NFT items returned from the API
     pub result: Vec<MoralisNftItem>,
 }
@@ -172,6 +216,9 @@ pub struct MoralisNftItem {
     pub token_hash: Option<String>,
     /// Metadata associated with the NFT
     pub metadata: Option<serde_json::Value>,
+    /// Metadata normalized by Moralis into a common schema
+    #[serde(default)]
+    pub normalized_metadata: Option<serde_json::Value>,
     /// Name of the NFT collection
     pub name: Option<String>,
     /// Symbol of the NFT collection
@@ -214,19 +261,86 @@ impl MoralisClient {
             return Err(MoralisError::Config("Base URL cannot be empty".to_string()));
         }
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_seconds))
-            .user_agent("nft-api/0.1.0")
+        let client = Self::client_builder(&config)
             .build()
             .map_err(MoralisError::Http)?;
 
         Ok(Self {
             client,
             config,
+            retry_policy: RetryPolicy::default(),
             chain_overrides,
+            max_parsed_count: DEFAULT_MAX_PARSED_COUNT,
+            rate_limit: RateLimitConfig::default(),
         })
     }
 
+    fn client_builder(config: &MoralisConfig) -> ClientBuilder {
+        Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .connect_timeout(Duration::from_secs(config.connect_timeout_seconds))
+            .user_agent("nft-api/0.1.0")
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a configured base URL is not permitted or the HTTP
+    /// client cannot be rebuilt
+    pub fn with_upstream_allowlist(
+        mut self,
+        allowlist: &UpstreamAllowlist,
+    ) -> Result<Self, MoralisError> {
+        let override_urls = self
+            .chain_overrides
+            .values()
+            .filter_map(|chain_override| chain_override.base_url.as_ref().map(Url::as_str));
+        for base_url in std::iter::once(self.config.base_url.as_str()).chain(override_urls) {
+            allowlist
+                .check_url_str(base_url)
+                .map_err(|e| MoralisError::Config(e.to_string()))?;
+        }
+
+        self.client = allowlist
+            .apply(Self::client_builder(&self.config))
+            .build()
+            .map_err(MoralisError::Http)?;
+        Ok(self)
+    }
+
+    /// Configure the backoff used between retries of transient failures
+    ///
+    /// The number of attempts is always derived from the effective per-chain
+    /// `max_retries`, so only the delay and jitter settings of `retry_policy` apply.
+    /// A `429` carrying a `Retry-After` header waits that long instead of the
+    /// backoff, and is returned right away when it asks for more than
+    /// `retry_policy.max_delay_ms`.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the upper bound for the total supply parsed from responses
+    ///
+    /// Larger or overflowing values saturate to `max_parsed_count`.
+    #[must_use]
+    pub fn with_max_parsed_count(mut self, max_parsed_count: u64) -> Self {
+        self.max_parsed_count = max_parsed_count;
+        self
+    }
+
+    /// Configure the back-off reported for rate-limited responses
+    ///
+    /// A `Retry-After` header from Moralis takes precedence over
+    /// `rate_limit.retry_after_seconds`.
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
     /// Get the Moralis chain identifier for a given `ChainId`
     ///
     /// Moralis uses specific string identifiers for each chain
@@ -331,28 +445,165 @@ impl MoralisClient {
             .header("X-API-Key", &self.config.api_key)
             .header("accept", "application/json");
 
-        let response = timeout(
-            Duration::from_secs(chain_config.timeout_seconds),
-            request.send(),
-        )
-        .await
-        .map_err(|_| MoralisError::Timeout {
-            seconds: chain_config.timeout_seconds,
-        })?
-        .map_err(MoralisError::Http)?;
+        let retry_policy = self
+            .retry_policy
+            .clone()
+            .with_max_attempts(chain_config.max_retries.saturating_add(1));
+        let request = &request;
+        let timeout_seconds = chain_config.timeout_seconds;
+
+        retry_policy
+            .retry_with_hint(
+                move || async move {
+                    let request = request.try_clone().ok_or_else(|| {
+                        MoralisError::Config("Moralis request cannot be retried".to_string())
+                    })?;
+                    self.send_contract_nfts_request(request, timeout_seconds, address)
+                        .await
+                },
+                MoralisError::is_transient,
+                MoralisError::requested_delay,
+            )
+            .await
+    }
 
-        match response.status() {
-            StatusCode::OK => {
-                let nfts_response: MoralisContractNftsResponse =
-                    response.json().await.map_err(MoralisError::Http)?;
-                Ok(nfts_response)
-            }
-            StatusCode::NOT_FOUND => {
-                debug!("Contract NFTs not found for address: {}", address);
-                Ok(MoralisContractNftsResponse { result: vec![] })
+    /// Get the metadata of up to `sample_size` tokens of a collection
+    ///
+    /// Tokens are taken from the first page Moralis returns for the contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the chain is unsupported, or the response cannot be parsed
+    pub async fn sample_tokens(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        sample_size: u32,
+    ) -> Result<Vec<TokenMetadata>, MoralisError> {
+        let response = self
+            .get_contract_nfts(address, chain_id, None, Some(sample_size), Some(true))
+            .await?;
+        let sample_size = usize::try_from(sample_size).unwrap_or(usize::MAX);
+        response
+            .result
+            .iter()
+            .take(sample_size)
+            .map(|item| self.convert_nft_item_to_token_metadata(item))
+            .collect()
+    }
+
+    /// Get the metadata of a single token from Moralis
+    ///
+    /// Returns `Ok(None)` if Moralis does not know the token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token ID is not a decimal integer, the request
+    /// fails, the chain is unsupported, or the response cannot be parsed
+    pub async fn get_token_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        token_id: &str,
+    ) -> Result<Option<TokenMetadata>, MoralisError> {
+        if token_id.is_empty() || !token_id.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(MoralisError::Config(format!(
+                "Invalid token ID provided: {token_id}"
+            )));
+        }
+        self.validate_chain_support(chain_id)?;
+
+        let chain_config = self.get_chain_config(chain_id);
+        let moralis_chain = self.get_moralis_chain_identifier(chain_id);
+        let url = format!("{}/nft/{}/{}", chain_config.base_url, address, token_id);
+
+        debug!(
+            url,
+            chain = moralis_chain,
+            chain_id = %chain_id,
+            "fetching token metadata from Moralis"
+        );
+
+        let request = self
+            .client
+            .get(&url)
+            .query(&[
+                ("chain", moralis_chain),
+                ("format", "decimal"),
+                ("normalizeMetadata", "true"),
+            ])
+            .header("X-API-Key", &self.config.api_key)
+            .header("accept", "application/json");
+
+        let retry_policy = self
+            .retry_policy
+            .clone()
+            .with_max_attempts(chain_config.max_retries.saturating_add(1));
+        let request = &request;
+        let timeout_seconds = chain_config.timeout_seconds;
+
+        let item: Option<MoralisNftItem> = retry_policy
+            .retry_with_hint(
+                move || async move {
+                    let request = request.try_clone().ok_or_else(|| {
+                        MoralisError::Config("Moralis request cannot be retried".to_string())
+                    })?;
+                    self.send_request(request, timeout_seconds).await
+                },
+                MoralisError::is_transient,
+                MoralisError::requested_delay,
+            )
+            .await?;
+
+        let Some(item) = item else {
+            debug!(%address, token_id, "token not found in Moralis");
+            return Ok(None);
+        };
+        self.convert_nft_item_to_token_metadata(&item).map(Some)
+    }
+
+    /// Send a single contract NFTs request and map the response status
+    async fn send_contract_nfts_request(
+        &self,
+        request: RequestBuilder,
+        timeout_seconds: u64,
+        address: Address,
+    ) -> Result<MoralisContractNftsResponse, MoralisError> {
+        let response = self.send_request(request, timeout_seconds).await?;
+        Ok(response.unwrap_or_else(|| {
+            debug!("Contract NFTs not found for address: {}", address);
+            MoralisContractNftsResponse {
+                total: None,
+                result: vec![],
             }
+        }))
+    }
+
+    /// Send a single request and map the response status, `None` meaning not found
+    async fn send_request<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        timeout_seconds: u64,
+    ) -> Result<Option<T>, MoralisError> {
+        let response = timeout(Duration::from_secs(timeout_seconds), request.send())
+            .await
+            .map_err(|_| MoralisError::Timeout {
+                seconds: timeout_seconds,
+            })?
+            .map_err(MoralisError::Http)?;
+
+        match response.status() {
+            StatusCode::OK => response.json().await.map(Some).map_err(MoralisError::Http),
+            StatusCode::NOT_FOUND => Ok(None),
             StatusCode::UNAUTHORIZED => Err(MoralisError::Unauthorized),
-            StatusCode::TOO_MANY_REQUESTS => Err(MoralisError::RateLimited),
+            StatusCode::TOO_MANY_REQUESTS => {
+                let requested = retry_after_header(response.headers());
+                debug!(retry_after_seconds = ?requested, "rate limited by Moralis");
+                Err(MoralisError::RateLimited {
+                    retry_after_seconds: requested.unwrap_or(self.rate_limit.retry_after_seconds),
+                    from_header: requested.is_some(),
+                })
+            }
             status => {
                 let error_text = response
                     .text()
@@ -367,6 +618,16 @@ impl MoralisClient {
         }
     }
 
+    /// Parse the reported NFT total, dropping values that are not a count
+    fn parse_total_supply(&self, total: Option<&serde_json::Value>) -> Option<String> {
+        let total = total?;
+        let supply = parse_count(total, self.max_parsed_count);
+        if supply.is_none() && !total.is_null() {
+            debug!(total = %total, "ignoring unparseable Moralis total supply");
+        }
+        supply.map(|supply| supply.to_string())
+    }
+
     /// Convert a Moralis NFT item to contract metadata
     fn convert_nft_item_to_metadata(
         &self,
@@ -402,6 +663,15 @@ impl MoralisClient {
             additional_data.insert("metadata".to_string(), metadata.clone());
         }
 
+        let metadata = nft_item.metadata.as_ref().and_then(parse_token_metadata);
+        let metadata_link = |key: &str| metadata.as_ref().and_then(|m| string_field(m, key));
+        let external_url = metadata_link("external_url").or_else(|| {
+            nft_item
+                .normalized_metadata
+                .as_ref()
+                .and_then(|m| string_field(m, "external_link"))
+        });
+
         Ok(ContractMetadata {
             address,
             name: nft_item.name.clone(),
@@ -414,9 +684,62 @@ impl MoralisClient {
             creator_address: None,
             is_verified: None,
             contract_type,
+            external_url,
+            twitter: metadata_link("twitter"),
+            discord: metadata_link("discord"),
             additional_data,
         })
     }
+
+    /// Convert a Moralis NFT item to token metadata
+    fn convert_nft_item_to_token_metadata(
+        &self,
+        nft_item: &MoralisNftItem,
+    ) -> Result<TokenMetadata, MoralisError> {
+        let contract = self.convert_nft_item_to_metadata(nft_item)?;
+        let metadata = nft_item.metadata.as_ref().and_then(parse_token_metadata);
+        let token_field = |key: &str| {
+            metadata
+                .as_ref()
+                .and_then(|m| string_field(m, key))
+                .or_else(|| {
+                    nft_item
+                        .normalized_metadata
+                        .as_ref()
+                        .and_then(|m| string_field(m, key))
+                })
+        };
+
+        Ok(TokenMetadata {
+            token_id: nft_item.token_id.clone(),
+            name: token_field("name"),
+            description: token_field("description"),
+            image: token_field("image"),
+            external_url: token_field("external_url"),
+            contract,
+        })
+    }
+}
+
+/// Token metadata as a JSON object
+///
+/// Moralis returns `metadata` as a JSON-encoded string, so decode it when needed.
+fn parse_token_metadata(metadata: &serde_json::Value) -> Option<serde_json::Value> {
+    match metadata {
+        serde_json::Value::String(raw) => serde_json::from_str(raw).ok(),
+        serde_json::Value::Object(_) => Some(metadata.clone()),
+        _ => None,
+    }
+}
+
+/// Non-empty string field of a JSON object
+fn string_field(value: &serde_json::Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(str::to_string)
 }
 
 impl ApiClient for MoralisClient {
@@ -498,13 +821,14 @@ impl ApiClient for MoralisClient {
 
         if let Some(first_nft) = nfts_response.result.first() {
             debug!("Found NFT metadata for address: {}", address);
-            let metadata = self.convert_nft_item_to_metadata(first_nft).map_err(|e| {
+            let mut metadata = self.convert_nft_item_to_metadata(first_nft).map_err(|e| {
                 error!(
                     "Failed to convert NFT item to metadata for address {}: {}",
                     address, e
                 );
                 e
             })?;
+            metadata.total_supply = self.parse_total_supply(nfts_response.total.as_ref());
             return Ok(Some(metadata));
         }
 
@@ -555,6 +879,7 @@ mod tests {
             contract_type: Some("ERC721".to_string()),
             token_hash: Some("abc123".to_string()),
             metadata: Some(serde_json::json!({"name": "Test NFT"})),
+            normalized_metadata: None,
             name: Some("Test NFT Collection".to_string()),
             symbol: Some("TNFT".to_string()),
         };
@@ -566,6 +891,59 @@ mod tests {
         assert!(metadata.additional_data.contains_key("contract_type"));
         assert!(metadata.additional_data.contains_key("token_hash"));
         assert!(metadata.additional_data.contains_key("metadata"));
+        assert!(!metadata.has_social_presence());
+    }
+
+    #[test]
+    fn parse_total_supply_is_robust() {
+        let client = MoralisClient::new(MoralisConfig::default())
+            .unwrap()
+            .with_max_parsed_count(1_000_000);
+
+        let total = |value: serde_json::Value| client.parse_total_supply(Some(&value));
+        assert_eq!(total(serde_json::json!(5000)), Some("5000".to_string()));
+        assert_eq!(total(serde_json::json!("5000")), Some("5000".to_string()));
+        assert_eq!(
+            total(serde_json::json!("340282366920938463463374607431768211456")),
+            Some("1000000".to_string())
+        );
+        assert_eq!(total(serde_json::json!("many")), None);
+        assert_eq!(total(serde_json::json!(-1)), None);
+        assert_eq!(client.parse_total_supply(None), None);
+    }
+
+    #[test]
+    fn convert_nft_item_to_metadata_social_links() {
+        let client = MoralisClient::new(MoralisConfig::default()).unwrap();
+
+        let metadata_string = serde_json::json!({
+            "name": "Test NFT",
+            "twitter": "https://twitter.com/test",
+            "discord": " ",
+        })
+        .to_string();
+        let nft_item = MoralisNftItem {
+            token_address: "0x1234567890123456789012345678901234567890".to_string(),
+            token_id: "1".to_string(),
+            contract_type: Some("ERC721".to_string()),
+            token_hash: None,
+            metadata: Some(serde_json::Value::String(metadata_string)),
+            normalized_metadata: Some(serde_json::json!({"external_link": "https://example.com"})),
+            name: Some("Test NFT Collection".to_string()),
+            symbol: Some("TNFT".to_string()),
+        };
+
+        let metadata = client.convert_nft_item_to_metadata(&nft_item).unwrap();
+        assert_eq!(
+            metadata.external_url.as_deref(),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            metadata.twitter.as_deref(),
+            Some("https://twitter.com/test")
+        );
+        assert_eq!(metadata.discord, None);
+        assert!(metadata.has_social_presence());
     }
 
     #[tokio::test]
@@ -594,6 +972,7 @@ mod tests {
             contract_type: Some("ERC721".to_string()),
             token_hash: Some("abc123".to_string()),
             metadata: None,
+            normalized_metadata: None,
             name: Some("Test NFT".to_string()),
             symbol: Some("TNFT".to_string()),
         };
@@ -0,0 +1,200 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Non-empty list validation utilities
+//!
+//! This module provides [`NonEmptyVec`], the list counterpart of
+//! [`NonEmptyString`](crate::NonEmptyString): a wrapper that holds at least one
+//! element by construction. Its `Deserialize` implementation rejects empty
+//! arrays, so request types using it need no post-hoc emptiness check.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use external_apis::NonEmptyVec;
+//!
+//! let addresses = NonEmptyVec::new(vec!["0x01", "0x02"]).expect("Valid list");
+//! assert_eq!(addresses.len(), 2);
+//! assert_eq!(addresses.first(), &"0x01");
+//!
+//! assert!(NonEmptyVec::<u8>::new(Vec::new()).is_err());
+//!
+//! // Empty arrays fail at parse time
+//! assert!(serde_json::from_str::<NonEmptyVec<u8>>("[]").is_err());
+//! ```
+
+use core::{fmt, ops::Deref};
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+/// Error message for an empty list
+const EMPTY_LIST: &str = "list cannot be empty";
+
+/// A vector guaranteed to hold at least one element
+///
+/// Dereferences to a slice, so the usual read-only slice methods are
+/// available. Serializes as a plain array.
+///
+/// # Examples
+///
+/// ```rust
+/// use external_apis::NonEmptyVec;
+///
+/// let values = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+/// assert_eq!(values.iter().sum::<i32>(), 6);
+/// assert_eq!(values.into_vec(), vec![1, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    /// Create a new `NonEmptyVec`
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive error message if `values` is empty.
+    pub fn new(values: Vec<T>) -> Result<Self, String> {
+        if values.is_empty() {
+            Err(EMPTY_LIST.to_string())
+        } else {
+            Ok(Self(values))
+        }
+    }
+
+    /// First element, which always exists
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Get a slice of the contained elements
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Unwrap the contained elements
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = String;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        Self::new(values)
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(values: NonEmptyVec<T>) -> Self {
+        values.0
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyVec<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for NonEmptyVec<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        Self::new(values).map_err(de::Error::custom)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for NonEmptyVec<T> {
+    /// Formats the elements separated by commas
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, value) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        let values = NonEmptyVec::new(vec![3, 1, 2]).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.first(), &3);
+        assert_eq!(values.as_slice(), &[3, 1, 2]);
+        assert_eq!(values.iter().max(), Some(&3));
+        assert_eq!(values.to_string(), "3, 1, 2");
+        assert_eq!(Vec::from(values), vec![3, 1, 2]);
+
+        let single = NonEmptyVec::try_from(vec!["only"]).unwrap();
+        assert_eq!(single.into_iter().collect::<Vec<_>>(), vec!["only"]);
+    }
+
+    #[test]
+    fn empty_is_rejected() {
+        assert_eq!(
+            NonEmptyVec::<u8>::new(Vec::new()).unwrap_err(),
+            "list cannot be empty"
+        );
+        assert!(NonEmptyVec::<u8>::try_from(Vec::new()).is_err());
+
+        let error = serde_json::from_str::<NonEmptyVec<u8>>("[]").unwrap_err();
+        assert!(error.is_data());
+        assert!(error.to_string().contains("list cannot be empty"));
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let values: NonEmptyVec<String> = serde_json::from_str(r#"["a","b"]"#).unwrap();
+        assert_eq!(values.as_slice(), ["a", "b"]);
+
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, r#"["a","b"]"#);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyVec<String>>(&json).unwrap(),
+            values
+        );
+
+        // Element errors still surface
+        assert!(serde_json::from_str::<NonEmptyVec<u8>>("[256]").is_err());
+    }
+}
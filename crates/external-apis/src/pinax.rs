@@ -10,8 +10,12 @@
 use std::{collections::HashMap, time::Duration};
 
 use alloy_primitives::Address;
-use api_client::{ApiClient, ApiError, ContractMetadata, ContractType, HealthStatus};
-use reqwest::{Client, StatusCode};
+use api_client::{
+    ApiClient, ApiError, ContractMetadata, ContractType, HealthStatus, RateLimitConfig,
+    RetryPolicy, UpstreamAllowlist, parse_count,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use reqwest::{Client, ClientBuilder, RequestBuilder, StatusCode};
 use serde::Deserialize;
 use shared_types::ChainId;
 use thiserror::Error;
@@ -22,6 +26,7 @@ use crate::non_empty_string::NonEmptyString;
 
 // Pinax API constants
 const DEFAULT_PINAX_TIMEOUT_SECONDS: u64 = 20;
+const DEFAULT_PINAX_CONNECT_TIMEOUT_SECONDS: u64 = 1;
 const DEFAULT_PINAX_HEALTH_CHECK_TIMEOUT_SECONDS: u64 = 5;
 const DEFAULT_PINAX_MAX_RETRIES: u32 = 3;
 
@@ -39,6 +44,8 @@ pub struct PinaxConfig {
     pub db_name: NonEmptyString,
     /// Request timeout in seconds
     pub timeout_seconds: u64,
+    /// Connection establishment timeout in seconds
+    pub connect_timeout_seconds: u64,
     /// Health check timeout in seconds
     pub health_check_timeout_seconds: u64,
     /// Maximum number of retry attempts
@@ -62,11 +69,19 @@ impl PinaxConfig {
             api_auth: NonEmptyString::new(api_auth)?,
             db_name: NonEmptyString::new(db_name)?,
             timeout_seconds,
+            connect_timeout_seconds: DEFAULT_PINAX_CONNECT_TIMEOUT_SECONDS,
             health_check_timeout_seconds,
             max_retries,
         })
     }
 
+    /// Give up on establishing a connection after `connect_timeout_seconds`
+    #[must_use]
+    pub fn with_connect_timeout_seconds(mut self, connect_timeout_seconds: u64) -> Self {
+        self.connect_timeout_seconds = connect_timeout_seconds;
+        self
+    }
+
     /// Create default configuration for testing
     #[allow(clippy::missing_panics_doc)]
     pub fn default_test() -> Self {
@@ -78,6 +93,7 @@ impl PinaxConfig {
             db_name: NonEmptyString::new("mainnet:evm-nft-tokens@v0.6.2")
                 .expect("known to be non-empty"),
             timeout_seconds: DEFAULT_PINAX_TIMEOUT_SECONDS,
+            connect_timeout_seconds: DEFAULT_PINAX_CONNECT_TIMEOUT_SECONDS,
             health_check_timeout_seconds: DEFAULT_PINAX_HEALTH_CHECK_TIMEOUT_SECONDS,
             max_retries: DEFAULT_PINAX_MAX_RETRIES,
         }
@@ -93,6 +109,28 @@ pub struct PerChainPinaxConfig {
     pub timeout_seconds: Option<u64>,
     /// Chain-specific max retries override
     pub max_retries: Option<u32>,
+    /// Whether the chain database has a `contract_creations` table to read
+    /// deployment block, time and creator from
+    pub contract_creations: bool,
+}
+
+/// Where the effective Pinax settings of a chain come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainConfigSource {
+    /// Every setting comes from the global Pinax configuration
+    Global,
+    /// At least one setting comes from a per-chain override
+    ChainOverride,
+}
+
+impl ChainConfigSource {
+    /// Lowercase name, as used in debug responses
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::ChainOverride => "chain_override",
+        }
+    }
 }
 
 /// Resolved effective configuration for a specific chain
@@ -103,9 +141,11 @@ struct ChainPinaxEffectiveConfig {
     /// Effective timeout (base config or chain override)
     timeout_seconds: u64,
     /// Effective max retries (base config or chain override)
-    /// Note: Currently unused but prepared for future retry logic implementation
-    #[allow(dead_code)]
     max_retries: u32,
+    /// Whether creation fields are joined from `contract_creations`
+    contract_creations: bool,
+    /// Whether any of the settings above came from a chain override
+    source: ChainConfigSource,
 }
 
 /// Pinax API client implementation with chain-specific support
@@ -113,8 +153,12 @@ struct ChainPinaxEffectiveConfig {
 pub struct PinaxClient {
     client: Client,
     config: PinaxConfig,
+    /// Backoff between retries; attempts come from the effective `max_retries`
+    retry_policy: RetryPolicy,
     /// Chain-specific configuration overrides
     chain_overrides: HashMap<ChainId, PerChainPinaxConfig>,
+    /// Fallback back-off reported when a rate-limited response has no `Retry-After`
+    rate_limit: RateLimitConfig,
 }
 
 /// Errors specific to the Pinax API client
@@ -133,6 +177,10 @@ pub enum PinaxError {
     #[error("API error: {status} - {message}")]
     ApiError { status: u16, message: String },
 
+    /// Rate limit exceeded
+    #[error("Rate limit exceeded, retry after {retry_after_seconds} seconds")]
+    RateLimited { retry_after_seconds: u64 },
+
     /// Authentication failed
     #[error("Authentication failed")]
     Unauthorized,
@@ -156,6 +204,18 @@ pub enum PinaxError {
     UnsupportedChain { chain_id: u64, chain_name: String },
 }
 
+impl PinaxError {
+    /// Whether the error is likely transient and the query worth retrying
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(error) => error.is_timeout() || error.is_connect(),
+            Self::Timeout { .. } | Self::RateLimited { .. } => true,
+            Self::ApiError { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
 impl From<PinaxError> for ApiError {
     fn from(value: PinaxError) -> Self {
         match value {
@@ -168,6 +228,11 @@ impl From<PinaxError> for ApiError {
             PinaxError::ApiError { status, message } => ApiError::Custom {
                 error: anyhow::Error::msg(format!("{status}: {message}")),
             },
+            PinaxError::RateLimited {
+                retry_after_seconds,
+            } => ApiError::RateLimitExceeded {
+                retry_after_seconds,
+            },
             PinaxError::Unauthorized => ApiError::Authentication {
                 message: value.to_string(),
             },
@@ -191,9 +256,60 @@ impl From<PinaxError> for ApiError {
 /// Response structure for Pinax NFT metadata query
 #[derive(Debug, Deserialize)]
 struct PinaxNftMetadata {
+    /// Lowercased contract address
+    #[serde(default)]
+    contract: Option<String>,
     symbol: Option<String>,
     name: Option<String>,
     description: Option<String>,
+    /// Token standard of the table the row came from, `erc721` or `erc1155`
+    #[serde(default)]
+    token_standard: Option<String>,
+    /// Deployment block, a number or a quoted 64-bit integer
+    #[serde(default)]
+    creation_block: Option<serde_json::Value>,
+    /// Deployment time in UTC, as `YYYY-MM-DD hh:mm:ss`, RFC 3339 or Unix seconds
+    #[serde(default)]
+    creation_timestamp: Option<serde_json::Value>,
+    /// Lowercased deployer address
+    #[serde(default)]
+    creator_address: Option<String>,
+}
+
+/// Parse a deployment block, treating the join default `0` as missing
+fn parse_creation_block(value: Option<&serde_json::Value>) -> Option<u64> {
+    value
+        .and_then(|value| parse_count(value, u64::MAX))
+        .filter(|block| *block > 0)
+}
+
+/// Parse a deployment time, treating the join default (the Unix epoch) as missing
+fn parse_creation_timestamp(value: Option<&serde_json::Value>) -> Option<DateTime<Utc>> {
+    let timestamp = match value? {
+        serde_json::Value::String(text) => {
+            let text = text.trim();
+            DateTime::parse_from_rfc3339(text)
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .ok()
+                .or_else(|| {
+                    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+                        .ok()
+                        .map(|timestamp| timestamp.and_utc())
+                })
+        }
+        serde_json::Value::Number(seconds) => seconds
+            .as_i64()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0)),
+        _ => None,
+    };
+    timestamp.filter(|timestamp| timestamp.timestamp() > 0)
+}
+
+/// Parse a deployer address, treating the join defaults (empty or zero) as missing
+fn parse_creator_address(value: Option<&str>) -> Option<Address> {
+    value
+        .and_then(|address| address.trim().parse::<Address>().ok())
+        .filter(|address| *address != Address::ZERO)
 }
 
 /// Response structure for Pinax API
@@ -231,19 +347,67 @@ impl PinaxClient {
         config: PinaxConfig,
         chain_overrides: HashMap<ChainId, PerChainPinaxConfig>,
     ) -> Result<Self, PinaxError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_seconds))
-            .user_agent("nft-api/0.1.0")
+        let client = Self::client_builder(&config)
             .build()
             .map_err(PinaxError::Http)?;
 
         Ok(Self {
             client,
             config,
+            retry_policy: RetryPolicy::default(),
             chain_overrides,
+            rate_limit: RateLimitConfig::default(),
         })
     }
 
+    fn client_builder(config: &PinaxConfig) -> ClientBuilder {
+        Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .connect_timeout(Duration::from_secs(config.connect_timeout_seconds))
+            .user_agent("nft-api/0.1.0")
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured endpoint is not permitted or the HTTP
+    /// client cannot be rebuilt
+    pub fn with_upstream_allowlist(
+        mut self,
+        allowlist: &UpstreamAllowlist,
+    ) -> Result<Self, PinaxError> {
+        allowlist
+            .check_url_str(self.config.endpoint.as_str())
+            .map_err(|e| PinaxError::Config(e.to_string()))?;
+
+        self.client = allowlist
+            .apply(Self::client_builder(&self.config))
+            .build()
+            .map_err(PinaxError::Http)?;
+        Ok(self)
+    }
+
+    /// Configure the backoff used between retries of transient failures
+    ///
+    /// The number of attempts is always derived from the effective per-chain
+    /// `max_retries`, so only the delay and jitter settings of `retry_policy` apply.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Configure the back-off reported for rate-limited responses
+    ///
+    /// A `Retry-After` header from Pinax takes precedence over
+    /// `rate_limit.retry_after_seconds`.
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
     /// Get effective configuration for a specific chain, applying overrides
     fn get_chain_config(&self, chain_id: ChainId) -> ChainPinaxEffectiveConfig {
         let override_config = self.chain_overrides.get(&chain_id);
@@ -258,9 +422,25 @@ impl PinaxClient {
             max_retries: override_config
                 .and_then(|o| o.max_retries)
                 .unwrap_or(self.config.max_retries),
+            contract_creations: override_config.is_some_and(|o| o.contract_creations),
+            source: if override_config.is_some_and(|o| {
+                o.db_name.is_some()
+                    || o.timeout_seconds.is_some()
+                    || o.max_retries.is_some()
+                    || o.contract_creations
+            }) {
+                ChainConfigSource::ChainOverride
+            } else {
+                ChainConfigSource::Global
+            },
         }
     }
 
+    /// Whether a chain uses per-chain overrides or only the global configuration
+    pub fn chain_config_source(&self, chain_id: ChainId) -> ChainConfigSource {
+        self.get_chain_config(chain_id).source
+    }
+
     /// Validate that a chain is supported for Pinax operations
     fn validate_chain_support(&self, chain_id: ChainId) -> Result<(), PinaxError> {
         // Check if chain is fully implemented
@@ -273,51 +453,164 @@ impl PinaxClient {
         Ok(())
     }
 
-    /// Get NFT contract metadata from Pinax using SQL query with chain-specific configuration
-    async fn get_nft_metadata(
-        &self,
-        address: Address,
-        chain_id: ChainId,
-    ) -> Result<Option<ContractMetadata>, PinaxError> {
-        if address == Address::ZERO {
-            return Err(PinaxError::Config("Invalid address provided".to_string()));
-        }
+    /// SQL query executed to fetch metadata for an address on a chain
+    ///
+    /// The chain's `db_name` and the lowercased address are interpolated, and
+    /// whitespace is collapsed so the query fits on a single line (e.g. in a
+    /// debug header).
+    pub fn metadata_query(&self, address: Address, chain_id: ChainId) -> String {
+        let address_lower = format!("{address:#x}").to_lowercase();
+        self.build_metadata_query(chain_id, &format!("= '{address_lower}'"), "LIMIT 1")
+    }
 
-        // Validate chain support
-        self.validate_chain_support(chain_id)?;
+    /// SQL query executed to fetch metadata for several addresses on a chain
+    ///
+    /// Like [`Self::metadata_query`], with an `IN (...)` clause over the
+    /// addresses and at most one row per contract.
+    pub fn metadata_batch_query(&self, addresses: &[Address], chain_id: ChainId) -> String {
+        let addresses_lower = addresses
+            .iter()
+            .map(|address| format!("'{}'", format!("{address:#x}").to_lowercase()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.build_metadata_query(
+            chain_id,
+            &format!("IN ({addresses_lower})"),
+            "LIMIT 1 BY cm.contract",
+        )
+    }
 
-        // Get chain-specific configuration
+    /// Metadata query selecting contracts matching `contract_filter`
+    ///
+    /// Creation fields are only selected on chains configured with a
+    /// `contract_creations` table; a missing table would fail the whole query.
+    fn build_metadata_query(
+        &self,
+        chain_id: ChainId,
+        contract_filter: &str,
+        limit: &str,
+    ) -> String {
         let chain_config = self.get_chain_config(chain_id);
-
-        let address_lower = format!("{address:#x}").to_lowercase();
+        let db_name = chain_config.db_name;
+        let (creation_columns, creation_join) = if chain_config.contract_creations {
+            (
+                ", cc.block_num AS creation_block, cc.timestamp AS creation_timestamp, \
+                 cc.creator AS creator_address",
+                format!("LEFT JOIN `{db_name}`.contract_creations cc ON cm.contract = cc.contract"),
+            )
+        } else {
+            ("", String::new())
+        };
 
         let query = format!(
             r"
             WITH contract_metadata AS (
-                SELECT symbol, name, contract FROM `{}`.erc1155_metadata_by_contract
-                WHERE contract = '{}'
+                SELECT symbol, name, contract, 'erc1155' AS token_standard
+                FROM `{db_name}`.erc1155_metadata_by_contract
+                WHERE contract {contract_filter}
 
                 UNION ALL
 
-                SELECT symbol, name, contract FROM `{}`.erc721_metadata_by_contract
-                WHERE contract = '{}'
+                SELECT symbol, name, contract, 'erc721' AS token_standard
+                FROM `{db_name}`.erc721_metadata_by_contract
+                WHERE contract {contract_filter}
             )
             SELECT
+                cm.contract,
                 cm.symbol,
                 cm.name,
-                nm.description
+                nm.description,
+                cm.token_standard{creation_columns}
             FROM contract_metadata cm
-            LEFT JOIN `{}`.nft_metadata nm
+            LEFT JOIN `{db_name}`.nft_metadata nm
             ON cm.contract = nm.contract
-            LIMIT 1
+            {creation_join}
+            {limit}
             FORMAT JSON
-            ",
-            chain_config.db_name,
-            address_lower,
-            chain_config.db_name,
-            address_lower,
-            chain_config.db_name
+            "
+        );
+        query.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Get NFT contract metadata from Pinax using SQL query with chain-specific configuration
+    async fn get_nft_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Result<Option<ContractMetadata>, PinaxError> {
+        if address == Address::ZERO {
+            return Err(PinaxError::Config("Invalid address provided".to_string()));
+        }
+
+        let query = self.metadata_query(address, chain_id);
+        let rows = self.execute_metadata_query(query, chain_id).await?;
+
+        if let Some(metadata) = rows.into_iter().next() {
+            Ok(Some(self.convert_metadata(address, metadata)))
+        } else {
+            debug!("No NFT metadata found for address: {}", address);
+            Ok(None)
+        }
+    }
+
+    /// Get NFT contract metadata for several addresses on a chain in a single query
+    ///
+    /// Addresses Pinax has no metadata for are absent from the returned map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an address is the zero address, the chain is not
+    /// supported, or the query fails
+    pub async fn get_nft_metadata_batch(
+        &self,
+        addresses: &[Address],
+        chain_id: ChainId,
+    ) -> Result<HashMap<Address, ContractMetadata>, PinaxError> {
+        if addresses.contains(&Address::ZERO) {
+            return Err(PinaxError::Config("Invalid address provided".to_string()));
+        }
+        if addresses.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let query = self.metadata_batch_query(addresses, chain_id);
+        let rows = self.execute_metadata_query(query, chain_id).await?;
+
+        let mut metadata = HashMap::with_capacity(rows.len());
+        for row in rows {
+            // Rows are matched back to the requested addresses by their contract
+            let Some(address) = row
+                .contract
+                .as_deref()
+                .and_then(|contract| contract.parse::<Address>().ok())
+                .filter(|address| addresses.contains(address))
+            else {
+                warn!(contract = ?row.contract, "ignoring Pinax row for an unrequested contract");
+                continue;
+            };
+            metadata
+                .entry(address)
+                .or_insert_with(|| self.convert_metadata(address, row));
+        }
+        debug!(
+            requested = addresses.len(),
+            found = metadata.len(),
+            "received Pinax batch metadata"
         );
+        Ok(metadata)
+    }
+
+    /// Run a metadata query against the chain's database, retrying transient failures
+    async fn execute_metadata_query(
+        &self,
+        query: String,
+        chain_id: ChainId,
+    ) -> Result<Vec<PinaxNftMetadata>, PinaxError> {
+        // Validate chain support
+        self.validate_chain_support(chain_id)?;
+
+        // Get chain-specific configuration
+        let chain_config = self.get_chain_config(chain_id);
 
         debug!(
             query,
@@ -336,15 +629,38 @@ impl PinaxClient {
             )
             .header("Content-Type", "text/plain");
 
-        let response = timeout(
-            Duration::from_secs(chain_config.timeout_seconds),
-            request.send(),
-        )
-        .await
-        .map_err(|_| PinaxError::Timeout {
-            seconds: chain_config.timeout_seconds,
-        })?
-        .map_err(PinaxError::Http)?;
+        let retry_policy = self
+            .retry_policy
+            .clone()
+            .with_max_attempts(chain_config.max_retries.saturating_add(1));
+        let request = &request;
+        let timeout_seconds = chain_config.timeout_seconds;
+
+        retry_policy
+            .retry(
+                move || async move {
+                    let request = request.try_clone().ok_or_else(|| {
+                        PinaxError::Config("Pinax request cannot be retried".to_string())
+                    })?;
+                    self.send_metadata_query(request, timeout_seconds).await
+                },
+                PinaxError::is_transient,
+            )
+            .await
+    }
+
+    /// Send a single metadata query and map the response status to its rows
+    async fn send_metadata_query(
+        &self,
+        request: RequestBuilder,
+        timeout_seconds: u64,
+    ) -> Result<Vec<PinaxNftMetadata>, PinaxError> {
+        let response = timeout(Duration::from_secs(timeout_seconds), request.send())
+            .await
+            .map_err(|_| PinaxError::Timeout {
+                seconds: timeout_seconds,
+            })?
+            .map_err(PinaxError::Http)?;
 
         match response.status() {
             StatusCode::OK => {
@@ -358,19 +674,15 @@ impl PinaxClient {
                     return Err(PinaxError::SqlError(error));
                 }
 
-                if let Some(data) = pinax_response.data {
-                    if let Some(metadata) = data.into_iter().next() {
-                        Ok(Some(self.convert_metadata(address, metadata)))
-                    } else {
-                        debug!("No NFT metadata found for address: {}", address);
-                        Ok(None)
-                    }
-                } else {
-                    debug!("No data returned for address: {}", address);
-                    Ok(None)
+                if pinax_response.data.is_none() {
+                    debug!("No data returned by Pinax");
                 }
+                Ok(pinax_response.data.unwrap_or_default())
             }
             StatusCode::UNAUTHORIZED => Err(PinaxError::Unauthorized),
+            StatusCode::TOO_MANY_REQUESTS => Err(PinaxError::RateLimited {
+                retry_after_seconds: self.rate_limit.retry_after(response.headers()),
+            }),
             status => {
                 let error_text = response
                     .text()
@@ -389,6 +701,29 @@ impl PinaxClient {
         }
     }
 
+    /// Send a single health check query, failing unless Pinax answers `200 OK`
+    async fn send_health_query(&self, request: RequestBuilder) -> Result<(), PinaxError> {
+        let timeout_seconds = self.config.health_check_timeout_seconds;
+        let response = timeout(Duration::from_secs(timeout_seconds), request.send())
+            .await
+            .map_err(|_| PinaxError::Timeout {
+                seconds: timeout_seconds,
+            })?
+            .map_err(PinaxError::Http)?;
+
+        match response.status() {
+            StatusCode::OK => Ok(()),
+            StatusCode::UNAUTHORIZED => Err(PinaxError::Unauthorized),
+            StatusCode::TOO_MANY_REQUESTS => Err(PinaxError::RateLimited {
+                retry_after_seconds: self.rate_limit.retry_after(response.headers()),
+            }),
+            status => Err(PinaxError::ApiError {
+                status: status.as_u16(),
+                message: status.to_string(),
+            }),
+        }
+    }
+
     /// Convert Pinax metadata to our standard format
     fn convert_metadata(&self, address: Address, metadata: PinaxNftMetadata) -> ContractMetadata {
         let mut additional_data = HashMap::new();
@@ -407,11 +742,18 @@ impl PinaxClient {
             total_supply: None,
             holder_count: None,
             transaction_count: None,
-            creation_block: None,
-            creation_timestamp: None,
-            creator_address: None,
+            creation_block: parse_creation_block(metadata.creation_block.as_ref()),
+            creation_timestamp: parse_creation_timestamp(metadata.creation_timestamp.as_ref()),
+            creator_address: parse_creator_address(metadata.creator_address.as_deref()),
             is_verified: None,
-            contract_type: Some(ContractType::Unknown), // Pinax doesn't specify exact type in this query
+            contract_type: Some(match metadata.token_standard.as_deref() {
+                Some("erc721") => ContractType::Erc721,
+                Some("erc1155") => ContractType::Erc1155,
+                _ => ContractType::Unknown,
+            }),
+            external_url: None,
+            twitter: None,
+            discord: None,
             additional_data,
         }
     }
@@ -433,36 +775,52 @@ impl ApiClient for PinaxClient {
             )
             .header("Content-Type", "text/plain");
 
-        let start_time = std::time::Instant::now();
-        let response = timeout(
-            Duration::from_secs(self.config.health_check_timeout_seconds),
-            request.send(),
-        )
-        .await
-        .map_err(|_| PinaxError::Timeout {
-            seconds: start_time.elapsed().as_secs(),
-        })?
-        .map_err(PinaxError::Http)?;
+        // Transient failures are retried like metadata queries, so that a single
+        // dropped request does not mark Pinax as degraded
+        let retry_policy = self
+            .retry_policy
+            .clone()
+            .with_max_attempts(self.config.max_retries.saturating_add(1));
+        let request = &request;
 
+        let start_time = std::time::Instant::now();
+        let result = retry_policy
+            .retry(
+                move || async move {
+                    let request = request.try_clone().ok_or_else(|| {
+                        PinaxError::Config("Pinax request cannot be retried".to_string())
+                    })?;
+                    self.send_health_query(request).await
+                },
+                PinaxError::is_transient,
+            )
+            .await;
         let response_time = start_time.elapsed();
 
-        match response.status() {
-            StatusCode::OK => {
+        match result {
+            Ok(()) => {
                 info!("Pinax API health check passed in {:?}", response_time);
                 Ok(HealthStatus::Up)
             }
-            StatusCode::UNAUTHORIZED => {
+            Err(PinaxError::Unauthorized) => {
                 warn!("Pinax API health check failed: unauthorized");
                 Ok(HealthStatus::Down {
                     reason: "Authentication failed".to_string(),
                 })
             }
-            status => {
+            Err(PinaxError::ApiError { status, .. }) => {
                 warn!("Pinax API health check failed with status: {}", status);
                 Ok(HealthStatus::Degraded {
-                    reason: format!("API returned status {}", status.as_u16()),
+                    reason: format!("API returned status {status}"),
+                })
+            }
+            Err(PinaxError::RateLimited { .. }) => {
+                warn!("Pinax API health check failed: rate limited");
+                Ok(HealthStatus::Degraded {
+                    reason: "Rate limited".to_string(),
                 })
             }
+            Err(e) => Err(e.into()),
         }
     }
 
@@ -542,6 +900,52 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[test]
+    fn chain_config_source_reflects_overrides() {
+        let chain_overrides = HashMap::from([(
+            ChainId::Ethereum,
+            PerChainPinaxConfig {
+                db_name: Some("eth-override-db".to_string()),
+                timeout_seconds: None,
+                max_retries: None,
+                contract_creations: false,
+            },
+        )]);
+        let client =
+            PinaxClient::with_chain_overrides(PinaxConfig::default_test(), chain_overrides)
+                .unwrap();
+
+        assert_eq!(
+            client.chain_config_source(ChainId::Ethereum),
+            ChainConfigSource::ChainOverride
+        );
+        assert_eq!(
+            client.chain_config_source(ChainId::Polygon),
+            ChainConfigSource::Global
+        );
+    }
+
+    #[tokio::test]
+    async fn unreachable_endpoint_fails_within_connect_timeout() {
+        // Non-routable address: packets are dropped, so only the connect timeout ends the attempt
+        let config = PinaxConfig::new(
+            "http://10.255.255.1:81/sql",
+            "test-user",
+            "test-auth",
+            "mainnet:evm-nft-tokens@v0.6.2",
+            30,
+            30,
+            DEFAULT_PINAX_MAX_RETRIES,
+        )
+        .unwrap()
+        .with_connect_timeout_seconds(1);
+        let client = PinaxClient::new(config).unwrap();
+
+        let start = std::time::Instant::now();
+        assert!(client.health_check().await.is_err());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
     #[test]
     fn pinax_client_creation_invalid_config() {
         // Try to create config with empty user - should fail at config creation time
@@ -573,6 +977,11 @@ mod tests {
             symbol: Some("PNFT".to_string()),
             name: Some("Test Pinax NFT".to_string()),
             description: Some("A test NFT from Pinax".to_string()),
+            token_standard: None,
+            contract: None,
+            creation_block: None,
+            creation_timestamp: None,
+            creator_address: None,
         };
 
         let metadata = client.convert_metadata(address, pinax_metadata);
@@ -580,6 +989,10 @@ mod tests {
         assert_eq!(metadata.symbol, Some("PNFT".to_string()));
         assert_eq!(metadata.address, address);
         assert!(metadata.additional_data.contains_key("description"));
+        assert_eq!(metadata.contract_type, Some(ContractType::Unknown));
+        assert_eq!(metadata.creation_block, None);
+        assert_eq!(metadata.creation_timestamp, None);
+        assert_eq!(metadata.creator_address, None);
     }
 
     #[test]
@@ -626,6 +1039,231 @@ mod tests {
         assert_eq!(metadata.address, test_address());
     }
 
+    #[tokio::test]
+    async fn get_contract_metadata_contract_type() {
+        for (token_standard, contract_type) in [
+            ("erc721", ContractType::Erc721),
+            ("erc1155", ContractType::Erc1155),
+        ] {
+            let mock_server = setup_mock_server().await;
+            let config = create_test_config(&mock_server.uri());
+            let client = PinaxClient::new(config).unwrap();
+
+            Mock::given(method("POST"))
+                .and(body_string_contains(
+                    "'erc1155' AS token_standard FROM `mainnet:evm-nft-tokens@v0.6.2`.erc1155_metadata_by_contract",
+                ))
+                .and(body_string_contains(
+                    "'erc721' AS token_standard FROM `mainnet:evm-nft-tokens@v0.6.2`.erc721_metadata_by_contract",
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": [{"name": "TestNFT", "token_standard": token_standard}]
+                })))
+                .mount(&mock_server)
+                .await;
+
+            let metadata = client
+                .get_contract_metadata(test_address(), ChainId::Ethereum)
+                .await
+                .unwrap()
+                .expect("metadata");
+            assert_eq!(
+                metadata.contract_type,
+                Some(contract_type),
+                "{token_standard}"
+            );
+        }
+    }
+
+    #[test]
+    fn contract_creations_join_is_opt_in_per_chain() {
+        let chain_overrides = HashMap::from([(
+            ChainId::Ethereum,
+            PerChainPinaxConfig {
+                db_name: None,
+                timeout_seconds: None,
+                max_retries: None,
+                contract_creations: true,
+            },
+        )]);
+        let client =
+            PinaxClient::with_chain_overrides(PinaxConfig::default_test(), chain_overrides)
+                .unwrap();
+
+        let query = client.metadata_query(test_address(), ChainId::Ethereum);
+        assert!(query.contains(".contract_creations cc ON cm.contract = cc.contract"));
+        assert!(query.contains("cc.creator AS creator_address"));
+
+        for query in [
+            client.metadata_query(test_address(), ChainId::Polygon),
+            client.metadata_batch_query(&[test_address()], ChainId::Polygon),
+        ] {
+            assert!(!query.contains("contract_creations"), "{query}");
+            assert!(!query.contains("creation_block"), "{query}");
+        }
+    }
+
+    #[tokio::test]
+    async fn get_contract_metadata_creation_data() {
+        let mock_server = setup_mock_server().await;
+        let config = create_test_config(&mock_server.uri());
+        let chain_overrides = HashMap::from([(
+            ChainId::Ethereum,
+            PerChainPinaxConfig {
+                db_name: None,
+                timeout_seconds: None,
+                max_retries: None,
+                contract_creations: true,
+            },
+        )]);
+        let client = PinaxClient::with_chain_overrides(config, chain_overrides).unwrap();
+        let creator = Address::from([0xc0; 20]);
+
+        Mock::given(method("POST"))
+            .and(body_string_contains(
+                "LEFT JOIN `mainnet:evm-nft-tokens@v0.6.2`.contract_creations cc ON cm.contract = cc.contract",
+            ))
+            .and(body_string_contains("cc.creator AS creator_address"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "name": "TestNFT",
+                    "token_standard": "erc721",
+                    // ClickHouse quotes 64-bit integers in JSON output
+                    "creation_block": "12287507",
+                    "creation_timestamp": "2021-04-22 18:27:52",
+                    "creator_address": format!("{creator:#x}")
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let metadata = client
+            .get_contract_metadata(test_address(), ChainId::Ethereum)
+            .await
+            .unwrap()
+            .expect("metadata");
+        assert_eq!(metadata.creation_block, Some(12_287_507));
+        assert_eq!(
+            metadata.creation_timestamp,
+            DateTime::from_timestamp(1_619_116_072, 0)
+        );
+        assert_eq!(metadata.creator_address, Some(creator));
+    }
+
+    #[tokio::test]
+    async fn get_contract_metadata_missing_creation_data() {
+        let mock_server = setup_mock_server().await;
+        let config = create_test_config(&mock_server.uri());
+        let client = PinaxClient::new(config).unwrap();
+
+        // Nulls, the defaults of an unmatched join and malformed values
+        for creation in [
+            serde_json::json!({
+                "creation_block": null,
+                "creation_timestamp": null,
+                "creator_address": null
+            }),
+            serde_json::json!({
+                "creation_block": 0,
+                "creation_timestamp": "1970-01-01 00:00:00",
+                "creator_address": ""
+            }),
+            serde_json::json!({
+                "creation_block": "unknown",
+                "creation_timestamp": "yesterday",
+                "creator_address": "0x0000000000000000000000000000000000000000"
+            }),
+        ] {
+            let mut row = serde_json::json!({ "name": "TestNFT" });
+            row.as_object_mut()
+                .unwrap()
+                .extend(creation.as_object().unwrap().clone());
+            let _mock = Mock::given(method("POST"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": [row] })),
+                )
+                .mount_as_scoped(&mock_server)
+                .await;
+
+            let metadata = client
+                .get_contract_metadata(test_address(), ChainId::Ethereum)
+                .await
+                .unwrap()
+                .expect("metadata");
+            assert_eq!(metadata.name.as_deref(), Some("TestNFT"));
+            assert_eq!(metadata.creation_block, None, "{creation}");
+            assert_eq!(metadata.creation_timestamp, None, "{creation}");
+            assert_eq!(metadata.creator_address, None, "{creation}");
+        }
+    }
+
+    #[test]
+    fn creation_timestamp_formats() {
+        let expected = DateTime::from_timestamp(1_619_116_072, 0);
+        for value in [
+            serde_json::json!("2021-04-22 18:27:52"),
+            serde_json::json!("2021-04-22 18:27:52.000"),
+            serde_json::json!("2021-04-22T18:27:52Z"),
+            serde_json::json!(1_619_116_072),
+        ] {
+            assert_eq!(parse_creation_timestamp(Some(&value)), expected, "{value}");
+        }
+    }
+
+    #[tokio::test]
+    async fn get_nft_metadata_batch_single_query() {
+        let mock_server = setup_mock_server().await;
+        let config = create_test_config(&mock_server.uri());
+        let client = PinaxClient::new(config).unwrap();
+        let addresses = [
+            Address::from([0x11; 20]),
+            Address::from([0x22; 20]),
+            Address::from([0x33; 20]),
+        ];
+
+        Mock::given(method("POST"))
+            .and(body_string_contains(format!(
+                "contract IN ('{:#x}', '{:#x}', '{:#x}')",
+                addresses[0], addresses[1], addresses[2]
+            )))
+            .and(body_string_contains("LIMIT 1 BY cm.contract"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"contract": format!("{:#x}", addresses[0]), "name": "One", "token_standard": "erc721"},
+                    {"contract": format!("{:#x}", addresses[1]), "name": "Two", "token_standard": "erc1155"},
+                    {"contract": format!("{:#x}", addresses[2]), "name": "Three", "token_standard": "erc721"},
+                    {"contract": format!("{:#x}", Address::from([0x44; 20])), "name": "Unrequested"}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let metadata = client
+            .get_nft_metadata_batch(&addresses, ChainId::Ethereum)
+            .await
+            .unwrap();
+        assert_eq!(metadata.len(), 3);
+        for (address, name) in addresses.iter().zip(["One", "Two", "Three"]) {
+            assert_eq!(metadata[address].address, *address);
+            assert_eq!(metadata[address].name.as_deref(), Some(name));
+        }
+        assert_eq!(
+            metadata[&addresses[1]].contract_type,
+            Some(ContractType::Erc1155)
+        );
+
+        // Nothing to query
+        assert!(
+            client
+                .get_nft_metadata_batch(&[], ChainId::Ethereum)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[tokio::test]
     async fn get_contract_metadata_invalid_address() {
         let config = PinaxConfig::default_test();
@@ -659,6 +1297,93 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn get_contract_metadata_retries_server_error() {
+        let mock_server = setup_mock_server().await;
+        let config = create_test_config(&mock_server.uri());
+        let client = PinaxClient::new(config)
+            .unwrap()
+            .with_retry_policy(RetryPolicy::default().with_initial_delay_ms(10));
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"symbol": "RNFT", "name": "RetriedNFT", "description": null}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let metadata = client
+            .get_contract_metadata(test_address(), ChainId::Ethereum)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.name, Some("RetriedNFT".to_string()));
+    }
+
+    #[tokio::test]
+    async fn get_contract_metadata_fails_fast_on_client_error() {
+        for status in [400, 401] {
+            let mock_server = setup_mock_server().await;
+            let config = create_test_config(&mock_server.uri());
+            let client = PinaxClient::new(config)
+                .unwrap()
+                .with_retry_policy(RetryPolicy::default().with_initial_delay_ms(10));
+
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(status))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let error = client
+                .get_contract_metadata(test_address(), ChainId::Ethereum)
+                .await
+                .unwrap_err();
+            if status == 401 {
+                assert!(
+                    matches!(error, ApiError::Authentication { .. }),
+                    "{error:?}"
+                );
+            } else {
+                assert!(matches!(error, ApiError::Custom { .. }), "{error:?}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn health_check_retries_unavailable() {
+        let mock_server = setup_mock_server().await;
+        let config = create_test_config(&mock_server.uri());
+        let client = PinaxClient::new(config)
+            .unwrap()
+            .with_retry_policy(RetryPolicy::default().with_initial_delay_ms(10));
+
+        Mock::given(method("POST"))
+            .and(body_string_contains("SELECT 1"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SELECT 1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"1": 1}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(client.health_check().await.unwrap(), HealthStatus::Up);
+    }
+
     #[tokio::test]
     async fn get_contract_metadata_invalid_json() {
         let mock_server = setup_mock_server().await;
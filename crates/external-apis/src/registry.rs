@@ -7,16 +7,24 @@
 //! This module provides orchestration and fallback logic for multiple API clients,
 //! enabling resilient data retrieval with automatic failover.
 
-use std::collections::HashMap;
+use std::{cmp::Reverse, collections::HashMap, fmt, time::Instant};
 
 use alloy_primitives::Address;
-use api_client::{ApiClient, ContractMetadata, HealthStatus};
+use api_client::{
+    ApiClient, ApiError, ContractMetadata, ContractType, ErrorCategory, HealthStatus, TokenMetadata,
+};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
 use shared_types::ChainId;
 use tracing::{debug, info, warn};
 
 use crate::{
-    MoralisClient, PinaxClient,
-    cache::{ApiProvider, MetadataCache, MetadataCacheKey},
+    ChainConfigSource, CreatorEnrichmentClient, MoralisClient, PinaxClient, SimpleHashClient,
+    cache::{
+        ApiProvider, MetadataCache, MetadataCacheKey, NegativeLookupCache, NegativeLookupCacheStats,
+    },
+    circuit_breaker::{BreakerState, CircuitBreaker, CircuitBreakerConfig},
+    stats::{ProviderStats, ProviderStatsRecorder, RequestOutcome},
 };
 
 /// Registry for managing API clients with fallback logic and caching
@@ -24,7 +32,20 @@ use crate::{
 pub struct ApiRegistry {
     moralis_client: Option<MoralisClient>,
     pinax_client: Option<PinaxClient>,
+    simplehash_client: Option<SimpleHashClient>,
+    /// Failover priority per provider, highest tried first; unset means `0`
+    priorities: HashMap<ApiProvider, u8>,
+    /// Circuit breaker per provider, empty when circuit breaking is disabled
+    breakers: HashMap<ApiProvider, CircuitBreaker>,
+    creator_enrichment: Option<CreatorEnrichmentClient>,
+    /// Also consult the next provider after a successful lookup and resolve
+    /// conflicting answers, see [`MetadataConflict`]
+    cross_check: bool,
+    /// Whether the first answer wins or every provider's answers are merged
+    merge_mode: MetadataMergeMode,
     cache: MetadataCache,
+    negative_cache: NegativeLookupCache,
+    stats: ProviderStatsRecorder,
 }
 
 /// Error type for registry operations
@@ -37,13 +58,362 @@ pub enum RegistryError {
 
     /// All API clients failed
     #[error("All API clients failed: {details}")]
-    AllClientsFailed { details: String },
+    AllClientsFailed {
+        details: String,
+        /// Category of the first client failure
+        category: ErrorCategory,
+        /// Longest back-off requested by a rate-limited client, in seconds
+        retry_after_seconds: Option<u64>,
+    },
 
     /// No clients registered
     #[error("No API clients registered")]
     NoClients,
 }
 
+impl RegistryError {
+    /// Coarse category of the error, safe to expose without upstream detail
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::NoHealthyClients => ErrorCategory::Unavailable,
+            Self::AllClientsFailed { category, .. } => *category,
+            Self::NoClients => ErrorCategory::Configuration,
+        }
+    }
+
+    /// Seconds to wait before retrying, when a provider was rate limited
+    pub fn retry_after_seconds(&self) -> Option<u64> {
+        match self {
+            Self::AllClientsFailed {
+                retry_after_seconds,
+                ..
+            } => *retry_after_seconds,
+            Self::NoHealthyClients | Self::NoClients => None,
+        }
+    }
+}
+
+/// A contract metadata client that can be registered with [`ApiRegistry`]
+#[derive(Debug)]
+pub enum MetadataClient {
+    /// Moralis Web3 API client
+    Moralis(MoralisClient),
+    /// Pinax Analytics API client
+    Pinax(PinaxClient),
+    /// `SimpleHash` NFT API client
+    SimpleHash(SimpleHashClient),
+}
+
+impl From<MoralisClient> for MetadataClient {
+    fn from(client: MoralisClient) -> Self {
+        Self::Moralis(client)
+    }
+}
+
+impl From<PinaxClient> for MetadataClient {
+    fn from(client: PinaxClient) -> Self {
+        Self::Pinax(client)
+    }
+}
+
+impl From<SimpleHashClient> for MetadataClient {
+    fn from(client: SimpleHashClient) -> Self {
+        Self::SimpleHash(client)
+    }
+}
+
+/// How the answers of several providers combine into one contract's metadata
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataMergeMode {
+    /// Use the answer of the first provider that has metadata
+    #[default]
+    FirstWins,
+    /// Also query the remaining providers and fill the fields missing from
+    /// higher-priority answers with those of lower-priority ones
+    Merge,
+}
+
+/// Outcome of a contract metadata lookup and the providers it took
+#[derive(Debug)]
+pub struct MetadataLookup {
+    /// Metadata, absence of metadata or the failure of every provider
+    pub result: Result<Option<ContractMetadata>, RegistryError>,
+    /// Number of providers called, counting failed ones; `0` when the lookup
+    /// was served from cache
+    pub providers_consulted: u8,
+    /// Provider whose answer became the result (the last one consulted, or
+    /// the preferred one of a conflict); `None` when the lookup was served
+    /// from cache or no provider was called
+    pub provider: Option<ApiProvider>,
+    /// Disagreement found by cross-checking providers; `None` when cross-checking
+    /// is disabled, the providers agreed or the lookup was served from cache
+    pub conflict: Option<MetadataConflict>,
+    /// SQL query sent to Pinax, the shared `IN (...)` query for batched
+    /// lookups; `None` when Pinax was not called
+    ///
+    /// Intended for admin debugging only; never include it in regular responses.
+    pub pinax_query: Option<String>,
+}
+
+/// Metadata of several contracts on one chain fetched with a single Pinax query
+///
+/// Created by [`ApiRegistry::prefetch_pinax_batch`].
+#[derive(Debug, Clone)]
+pub struct PinaxBatch {
+    /// Chain the contracts were queried on
+    pub chain_id: ChainId,
+    /// SQL query sent to Pinax
+    pub query: String,
+    /// Contracts the query asked for, sorted
+    pub queried: Vec<Address>,
+    /// Metadata of the queried contracts Pinax knows
+    pub found: HashMap<Address, ContractMetadata>,
+}
+
+/// Providers called while looking up one contract
+#[derive(Debug, Default)]
+struct ConsultedProviders {
+    /// Number of providers called, counting failed ones
+    count: u8,
+    /// SQL query sent to Pinax, if it was called
+    pinax_query: Option<String>,
+}
+
+/// Materially different metadata returned by two providers for the same contract
+///
+/// Found when cross-checking is enabled (see [`ApiRegistry::with_cross_check`]).
+/// Names compare case-insensitively and an unknown contract type never conflicts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataConflict {
+    /// Provider whose metadata became the result
+    pub chosen: ApiProvider,
+    /// Provider whose metadata was discarded
+    pub rejected: ApiProvider,
+    /// Fields the providers disagreed on, `name` and/or `contract_type`
+    pub fields: Vec<&'static str>,
+    /// Why the chosen provider was preferred
+    pub resolution: ConflictResolution,
+}
+
+/// Rule that picked the metadata kept from a [`MetadataConflict`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Only the chosen provider reported the contract as verified
+    Verified,
+    /// The chosen provider populated more metadata fields
+    MoreComplete,
+    /// Neither rule applied, so the higher-priority provider won
+    Priority,
+}
+
+impl ConflictResolution {
+    /// Human-readable reason the chosen metadata was preferred
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::MoreComplete => "more complete",
+            Self::Priority => "higher priority",
+        }
+    }
+}
+
+impl MetadataConflict {
+    /// Compare the `primary` provider's answer with the `secondary` one's,
+    /// returning the conflict if they disagree on name or contract type
+    fn detect(
+        primary: (&ApiProvider, &ContractMetadata),
+        secondary: (&ApiProvider, &ContractMetadata),
+    ) -> Option<Self> {
+        let (primary_provider, primary) = primary;
+        let (secondary_provider, secondary) = secondary;
+
+        let mut fields = Vec::new();
+        if let (Some(a), Some(b)) = (primary.name.as_deref(), secondary.name.as_deref())
+            && !a.trim().eq_ignore_ascii_case(b.trim())
+        {
+            fields.push("name");
+        }
+        if let (Some(a), Some(b)) = (known_contract_type(primary), known_contract_type(secondary))
+            && a != b
+        {
+            fields.push("contract_type");
+        }
+        if fields.is_empty() {
+            return None;
+        }
+
+        let verified = |metadata: &ContractMetadata| metadata.is_verified == Some(true);
+        let (secondary_wins, resolution) = match (verified(primary), verified(secondary)) {
+            (true, false) => (false, ConflictResolution::Verified),
+            (false, true) => (true, ConflictResolution::Verified),
+            _ => match completeness(primary).cmp(&completeness(secondary)) {
+                std::cmp::Ordering::Greater => (false, ConflictResolution::MoreComplete),
+                std::cmp::Ordering::Less => (true, ConflictResolution::MoreComplete),
+                std::cmp::Ordering::Equal => (false, ConflictResolution::Priority),
+            },
+        };
+        let (chosen, rejected) = if secondary_wins {
+            (secondary_provider, primary_provider)
+        } else {
+            (primary_provider, secondary_provider)
+        };
+        Some(Self {
+            chosen: chosen.clone(),
+            rejected: rejected.clone(),
+            fields,
+            resolution,
+        })
+    }
+}
+
+impl fmt::Display for MetadataConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} and {} disagree on {}; using {} metadata ({})",
+            self.chosen,
+            self.rejected,
+            self.fields.join(", "),
+            self.chosen,
+            self.resolution.as_str()
+        )
+    }
+}
+
+/// Contract type of `metadata`, unless the provider could not identify it
+fn known_contract_type(metadata: &ContractMetadata) -> Option<&ContractType> {
+    metadata
+        .contract_type
+        .as_ref()
+        .filter(|contract_type| **contract_type != ContractType::Unknown)
+}
+
+/// Number of populated metadata fields, used to prefer the richer of two answers
+fn completeness(metadata: &ContractMetadata) -> usize {
+    [
+        metadata.name.is_some(),
+        metadata.symbol.is_some(),
+        metadata.total_supply.is_some(),
+        metadata.holder_count.is_some(),
+        metadata.transaction_count.is_some(),
+        metadata.creation_block.is_some(),
+        metadata.creation_timestamp.is_some(),
+        metadata.creator_address.is_some(),
+        metadata.is_verified.is_some(),
+        known_contract_type(metadata).is_some(),
+        metadata.external_url.is_some(),
+        metadata.twitter.is_some(),
+        metadata.discord.is_some(),
+    ]
+    .into_iter()
+    .filter(|populated| *populated)
+    .count()
+}
+
+/// Fill the fields missing from `metadata` with those of `other`, returning
+/// the names of the filled fields
+///
+/// An unknown contract type counts as missing, and `additional_data` keys
+/// already present are kept.
+fn fill_missing(metadata: &mut ContractMetadata, other: ContractMetadata) -> Vec<&'static str> {
+    fn fill<T>(
+        field: &mut Option<T>,
+        value: Option<T>,
+        name: &'static str,
+        filled: &mut Vec<&'static str>,
+    ) {
+        if field.is_none() && value.is_some() {
+            *field = value;
+            filled.push(name);
+        }
+    }
+
+    let ContractMetadata {
+        address: _,
+        name,
+        symbol,
+        total_supply,
+        holder_count,
+        transaction_count,
+        creation_block,
+        creation_timestamp,
+        creator_address,
+        is_verified,
+        contract_type,
+        external_url,
+        twitter,
+        discord,
+        additional_data,
+    } = other;
+
+    let mut filled = Vec::new();
+    fill(&mut metadata.name, name, "name", &mut filled);
+    fill(&mut metadata.symbol, symbol, "symbol", &mut filled);
+    fill(
+        &mut metadata.total_supply,
+        total_supply,
+        "total_supply",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.holder_count,
+        holder_count,
+        "holder_count",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.transaction_count,
+        transaction_count,
+        "transaction_count",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.creation_block,
+        creation_block,
+        "creation_block",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.creation_timestamp,
+        creation_timestamp,
+        "creation_timestamp",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.creator_address,
+        creator_address,
+        "creator_address",
+        &mut filled,
+    );
+    fill(
+        &mut metadata.is_verified,
+        is_verified,
+        "is_verified",
+        &mut filled,
+    );
+    if known_contract_type(metadata).is_none()
+        && contract_type
+            .as_ref()
+            .is_some_and(|contract_type| *contract_type != ContractType::Unknown)
+    {
+        metadata.contract_type = contract_type;
+        filled.push("contract_type");
+    }
+    fill(
+        &mut metadata.external_url,
+        external_url,
+        "external_url",
+        &mut filled,
+    );
+    fill(&mut metadata.twitter, twitter, "twitter", &mut filled);
+    fill(&mut metadata.discord, discord, "discord", &mut filled);
+    for (key, value) in additional_data {
+        metadata.additional_data.entry(key).or_insert(value);
+    }
+    filled
+}
+
 impl Default for ApiRegistry {
     fn default() -> Self {
         Self::new()
@@ -56,7 +426,15 @@ impl ApiRegistry {
         Self {
             moralis_client: None,
             pinax_client: None,
+            simplehash_client: None,
+            priorities: HashMap::new(),
+            breakers: HashMap::new(),
+            creator_enrichment: None,
+            cross_check: false,
+            merge_mode: MetadataMergeMode::default(),
             cache: MetadataCache::new(),
+            negative_cache: NegativeLookupCache::default(),
+            stats: ProviderStatsRecorder::new(),
         }
     }
 
@@ -68,7 +446,15 @@ impl ApiRegistry {
         Self {
             moralis_client,
             pinax_client,
+            simplehash_client: None,
+            priorities: HashMap::new(),
+            breakers: HashMap::new(),
+            creator_enrichment: None,
+            cross_check: false,
+            merge_mode: MetadataMergeMode::default(),
             cache: MetadataCache::new(),
+            negative_cache: NegativeLookupCache::default(),
+            stats: ProviderStatsRecorder::new(),
         }
     }
 
@@ -81,10 +467,130 @@ impl ApiRegistry {
         Self {
             moralis_client,
             pinax_client,
+            simplehash_client: None,
+            priorities: HashMap::new(),
+            breakers: HashMap::new(),
+            creator_enrichment: None,
+            cross_check: false,
+            merge_mode: MetadataMergeMode::default(),
             cache,
+            negative_cache: NegativeLookupCache::default(),
+            stats: ProviderStatsRecorder::new(),
         }
     }
 
+    /// Register `client` with a failover priority, replacing any client of the same provider
+    ///
+    /// Lookups try providers from the highest priority to the lowest. Providers
+    /// with equal priority keep the default order: Moralis, Pinax, then `SimpleHash`.
+    #[must_use]
+    pub fn register_with_priority(
+        mut self,
+        client: impl Into<MetadataClient>,
+        priority: u8,
+    ) -> Self {
+        let provider = match client.into() {
+            MetadataClient::Moralis(client) => {
+                self.moralis_client = Some(client);
+                ApiProvider::Moralis
+            }
+            MetadataClient::Pinax(client) => {
+                self.pinax_client = Some(client);
+                ApiProvider::Pinax
+            }
+            MetadataClient::SimpleHash(client) => {
+                self.simplehash_client = Some(client);
+                ApiProvider::SimpleHash
+            }
+        };
+        self.priorities.insert(provider, priority);
+        self
+    }
+
+    /// Skip providers that keep failing, see [`crate::circuit_breaker`]
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.breakers = [
+            ApiProvider::Moralis,
+            ApiProvider::Pinax,
+            ApiProvider::SimpleHash,
+        ]
+        .into_iter()
+        .map(|provider| (provider, CircuitBreaker::new(config)))
+        .collect();
+        self
+    }
+
+    /// State of the circuit breaker of `provider`, if circuit breaking is enabled
+    pub fn circuit_breaker_state(&self, provider: &ApiProvider) -> Option<BreakerState> {
+        self.breakers.get(provider).map(CircuitBreaker::state)
+    }
+
+    /// Names of registered providers whose circuit breaker is not closed
+    pub fn open_circuit_breakers(&self) -> Vec<&'static str> {
+        self.providers_by_priority()
+            .into_iter()
+            .filter(|provider| {
+                self.circuit_breaker_state(provider)
+                    .is_some_and(|state| state != BreakerState::Closed)
+            })
+            .map(|provider| provider.as_str())
+            .collect()
+    }
+
+    /// Registered metadata providers in the order lookups try them
+    pub fn providers_by_priority(&self) -> Vec<ApiProvider> {
+        let mut providers: Vec<ApiProvider> = [
+            (ApiProvider::Moralis, self.moralis_client.is_some()),
+            (ApiProvider::Pinax, self.pinax_client.is_some()),
+            (ApiProvider::SimpleHash, self.simplehash_client.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(provider, registered)| registered.then_some(provider))
+        .collect();
+        // Stable, so equal priorities keep the default order
+        providers
+            .sort_by_key(|provider| Reverse(self.priorities.get(provider).copied().unwrap_or(0)));
+        providers
+    }
+
+    /// Replace the cache of addresses no provider has metadata for
+    #[must_use]
+    pub fn with_negative_cache(mut self, negative_cache: NegativeLookupCache) -> Self {
+        self.negative_cache = negative_cache;
+        self
+    }
+
+    /// Enrich fetched metadata with creator reputation before it is cached
+    #[must_use]
+    pub fn with_creator_enrichment(mut self, client: CreatorEnrichmentClient) -> Self {
+        self.creator_enrichment = Some(client);
+        self
+    }
+
+    /// Cross-check every fetched answer against the next available provider
+    ///
+    /// On a conflict the verified, then the more complete, then the
+    /// higher-priority answer is kept and reported in
+    /// [`MetadataLookup::conflict`]. Doubles provider calls on cache misses.
+    #[must_use]
+    pub fn with_cross_check(mut self, enabled: bool) -> Self {
+        self.cross_check = enabled;
+        self
+    }
+
+    /// Choose whether the first provider's answer wins or the answers of every
+    /// available provider are merged
+    ///
+    /// Merging fills the fields a higher-priority provider left empty (e.g.
+    /// `holder_count`) from lower-priority ones, at the cost of calling every
+    /// provider on cache misses. It takes precedence over cross-checking.
+    #[must_use]
+    pub fn with_merge_mode(mut self, mode: MetadataMergeMode) -> Self {
+        self.merge_mode = mode;
+        self
+    }
+
     /// Get contract metadata using cache first, then fallback to available clients
     ///
     /// # Arguments
@@ -102,7 +608,233 @@ impl ApiRegistry {
         address: Address,
         chain_id: ChainId,
     ) -> Result<Option<ContractMetadata>, RegistryError> {
-        // Check cache first
+        self.lookup_contract_metadata(address, chain_id)
+            .await
+            .result
+    }
+
+    /// Like [`Self::get_contract_metadata`], also reporting how many providers
+    /// were consulted (more than one when failover kicked in)
+    pub async fn lookup_contract_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> MetadataLookup {
+        self.lookup_with_providers(address, chain_id, &self.providers_by_priority(), None)
+            .await
+    }
+
+    /// Like [`Self::lookup_contract_metadata`] for several contracts on one chain
+    ///
+    /// When Pinax is the first provider to consult, the contracts missing from
+    /// the cache are fetched with a single [`PinaxClient::get_nft_metadata_batch`]
+    /// query, and only those Pinax has no metadata for are looked up one by one
+    /// on the remaining providers. Otherwise, or when the batch query fails,
+    /// every contract is looked up one by one.
+    pub async fn lookup_contract_metadata_batch(
+        &self,
+        addresses: &[Address],
+        chain_id: ChainId,
+    ) -> HashMap<Address, MetadataLookup> {
+        let mut unique = addresses.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let batch = self.prefetch_pinax_batch(&unique, chain_id).await;
+        let lookups = join_all(unique.iter().map(|&address| {
+            self.lookup_contract_metadata_with_batch(address, chain_id, batch.as_ref())
+        }))
+        .await;
+        unique.into_iter().zip(lookups).collect()
+    }
+
+    /// Fetch the metadata of the uncached `addresses` from Pinax with one query
+    ///
+    /// Returns `None` when batching does not apply (Pinax is not the first
+    /// provider to consult, cross-checking or merging is enabled or fewer than
+    /// two addresses are uncached) or the query failed. Pass the batch to
+    /// [`Self::lookup_contract_metadata_with_batch`] to finish each lookup.
+    pub async fn prefetch_pinax_batch(
+        &self,
+        addresses: &[Address],
+        chain_id: ChainId,
+    ) -> Option<PinaxBatch> {
+        let mut misses: Vec<Address> = addresses
+            .iter()
+            .copied()
+            .filter(|&address| self.cached_metadata(address, chain_id).is_none())
+            .collect();
+        misses.sort_unstable();
+        misses.dedup();
+
+        let (query, found) = self
+            .fetch_pinax_batch(&misses, chain_id, &self.providers_by_priority())
+            .await?;
+        Some(PinaxBatch {
+            chain_id,
+            query,
+            queried: misses,
+            found,
+        })
+    }
+
+    /// Like [`Self::lookup_contract_metadata`], taking the answer from `batch`
+    /// for the contracts it queried
+    ///
+    /// Contracts the batch found metadata for are answered by it; the others
+    /// it queried are looked up on the remaining providers, with Pinax counted
+    /// as consulted.
+    pub async fn lookup_contract_metadata_with_batch(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        batch: Option<&PinaxBatch>,
+    ) -> MetadataLookup {
+        let Some(batch) = batch.filter(|batch| {
+            batch.chain_id == chain_id && batch.queried.binary_search(&address).is_ok()
+        }) else {
+            return self.lookup_contract_metadata(address, chain_id).await;
+        };
+
+        if let Some(metadata) = batch.found.get(&address) {
+            return MetadataLookup {
+                result: Ok(Some(metadata.clone())),
+                providers_consulted: 1,
+                provider: Some(ApiProvider::Pinax),
+                conflict: None,
+                pinax_query: Some(batch.query.clone()),
+            };
+        }
+        let mut providers = self.providers_by_priority();
+        providers.retain(|provider| *provider != ApiProvider::Pinax);
+        self.lookup_with_providers(address, chain_id, &providers, Some(&batch.query))
+            .await
+    }
+
+    /// Fetch metadata for `addresses` with one Pinax query, caching what is
+    /// found, and return it along with the query
+    ///
+    /// Returns `None` when batching does not apply (Pinax is not the first of
+    /// `providers` to consult, cross-checking or merging is enabled or there
+    /// are fewer than two addresses) or the query failed.
+    async fn fetch_pinax_batch(
+        &self,
+        addresses: &[Address],
+        chain_id: ChainId,
+        providers: &[ApiProvider],
+    ) -> Option<(String, HashMap<Address, ContractMetadata>)> {
+        let pinax_client = self.pinax_client.as_ref()?;
+        if addresses.len() < 2
+            || self.cross_check
+            || self.merge_mode == MetadataMergeMode::Merge
+            || providers.first() != Some(&ApiProvider::Pinax)
+            || !self.breaker_allows_request(&ApiProvider::Pinax)
+        {
+            return None;
+        }
+
+        debug!(
+            count = addresses.len(),
+            "Trying Pinax batch query for chain {}",
+            chain_id.name()
+        );
+        let start = Instant::now();
+        let result = pinax_client
+            .get_nft_metadata_batch(addresses, chain_id)
+            .await
+            .map_err(ApiError::from);
+        self.record_outcome("pinax", &result, start);
+        self.record_breaker_outcome(&ApiProvider::Pinax, &result);
+        let mut batch = match result {
+            Ok(batch) => batch,
+            Err(e) => {
+                warn!(
+                    "Pinax batch query failed, looking up addresses one by one: {}",
+                    e
+                );
+                return None;
+            }
+        };
+
+        join_all(
+            batch
+                .values_mut()
+                .map(|metadata| self.enrich_creator(Some(metadata), chain_id)),
+        )
+        .await;
+        for (address, metadata) in &batch {
+            self.cache.store_metadata(
+                &MetadataCacheKey::new(*address, chain_id),
+                Some(metadata),
+                &ApiProvider::Pinax,
+            );
+        }
+        Some((
+            pinax_client.metadata_batch_query(addresses, chain_id),
+            batch,
+        ))
+    }
+
+    /// Lookup behind [`Self::lookup_contract_metadata`] trying `providers` in
+    /// order, counting Pinax as already consulted without metadata when it was
+    /// sent `pinax_batch_query`
+    async fn lookup_with_providers(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        providers: &[ApiProvider],
+        pinax_batch_query: Option<&str>,
+    ) -> MetadataLookup {
+        let mut consulted = ConsultedProviders {
+            count: u8::from(pinax_batch_query.is_some()),
+            pinax_query: pinax_batch_query.map(str::to_string),
+        };
+        let mut provider = pinax_batch_query.map(|_| ApiProvider::Pinax);
+        let mut conflict = None;
+        let result = self
+            .fetch_contract_metadata(
+                address,
+                chain_id,
+                providers,
+                &mut consulted,
+                &mut provider,
+                &mut conflict,
+            )
+            .await;
+        MetadataLookup {
+            result,
+            providers_consulted: consulted.count,
+            provider,
+            conflict,
+            pinax_query: consulted.pinax_query,
+        }
+    }
+
+    /// Count a call to `provider`, remembering the SQL query sent when it is Pinax
+    fn record_consulted(
+        &self,
+        provider: &ApiProvider,
+        address: Address,
+        chain_id: ChainId,
+        consulted: &mut ConsultedProviders,
+    ) {
+        consulted.count += 1;
+        if *provider == ApiProvider::Pinax
+            && let Some(client) = &self.pinax_client
+        {
+            consulted.pinax_query = Some(client.metadata_query(address, chain_id));
+        }
+    }
+
+    /// Cached metadata, or cached absence of metadata, of `address`
+    ///
+    /// Like [`MetadataCache::get_metadata`], `Some(None)` is a cached miss.
+    #[allow(clippy::option_option)]
+    fn cached_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Option<Option<ContractMetadata>> {
         let cache_key = MetadataCacheKey::new(address, chain_id);
         if let Some(cached_result) = self.cache.get_metadata(&cache_key) {
             debug!(
@@ -110,75 +842,364 @@ impl ApiRegistry {
                 address,
                 chain_id.name()
             );
+            return Some(cached_result);
+        }
+        if self.negative_cache.contains(&cache_key) {
+            debug!(
+                "negative cache hit for address {} on chain {}",
+                address,
+                chain_id.name()
+            );
+            return Some(None);
+        }
+        None
+    }
+
+    /// Cache-first lookup behind [`Self::lookup_with_providers`]
+    async fn fetch_contract_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        providers: &[ApiProvider],
+        consulted: &mut ConsultedProviders,
+        last_provider: &mut Option<ApiProvider>,
+        conflict: &mut Option<MetadataConflict>,
+    ) -> Result<Option<ContractMetadata>, RegistryError> {
+        // Check cache first
+        if let Some(cached_result) = self.cached_metadata(address, chain_id) {
             return Ok(cached_result);
         }
+        let cache_key = MetadataCacheKey::new(address, chain_id);
 
         // Cache miss - try external APIs
-        if self.moralis_client.is_none() && self.pinax_client.is_none() {
+        if self.client_count() == 0 {
             return Err(RegistryError::NoClients);
         }
 
         let mut errors = Vec::new();
+        let mut skipped = false;
 
-        if let Some(result) = self
-            .try_moralis_metadata(address, chain_id, &mut errors)
-            .await
-        {
-            // Cache the successful result
-            self.cache
-                .store_metadata(&cache_key, result.as_ref(), &ApiProvider::Moralis);
-            return Ok(result);
-        }
-
-        if let Some(result) = self
-            .try_pinax_metadata(address, chain_id, &mut errors)
-            .await
-        {
-            // Cache the successful result
-            self.cache
-                .store_metadata(&cache_key, result.as_ref(), &ApiProvider::Pinax);
-            return Ok(result);
+        for (index, provider) in providers.iter().enumerate() {
+            if !self.breaker_allows_request(provider) {
+                debug!(%provider, "circuit breaker open, skipping provider");
+                skipped = true;
+                continue;
+            }
+            self.record_consulted(provider, address, chain_id, consulted);
+            *last_provider = Some(provider.clone());
+            let mut provider = provider.clone();
+            let result = self
+                .try_provider_metadata(&provider, address, chain_id, &mut errors)
+                .await;
+            if let Some(mut result) = result {
+                if self.merge_mode == MetadataMergeMode::Merge
+                    && let Some(metadata) = result.as_mut()
+                {
+                    self.merge_metadata(
+                        address,
+                        chain_id,
+                        metadata,
+                        &providers[index + 1..],
+                        consulted,
+                    )
+                    .await;
+                } else if self.cross_check
+                    && let Some(metadata) = result.take()
+                {
+                    let (chosen, metadata, found) = self
+                        .cross_check_metadata(
+                            address,
+                            chain_id,
+                            provider,
+                            metadata,
+                            &providers[index + 1..],
+                            consulted,
+                        )
+                        .await;
+                    *last_provider = Some(chosen.clone());
+                    *conflict = found;
+                    provider = chosen;
+                    result = Some(metadata);
+                }
+                self.enrich_creator(result.as_mut(), chain_id).await;
+                // Cache the successful result
+                self.cache
+                    .store_metadata(&cache_key, result.as_ref(), &provider);
+                return Ok(result);
+            }
         }
 
-        if errors.is_empty() {
+        if errors.is_empty() && consulted.count == 0 {
+            Err(RegistryError::NoHealthyClients)
+        } else if errors.is_empty() {
             debug!(
                 "no metadata found for address {} on chain {} in any client",
                 address,
                 chain_id.name()
             );
-            // Cache the "no data" result to avoid repeated API calls
-            self.cache.store_metadata(
-                &cache_key,
-                None,
-                // Use Moralis as default provider for "no data" entries
-                if self.moralis_client.is_some() {
-                    &ApiProvider::Moralis
-                } else {
-                    &ApiProvider::Pinax
-                },
-            );
+            // Remember the miss briefly to skip the provider fan-out on repeats,
+            // unless a skipped provider might still have the metadata
+            if !skipped {
+                self.negative_cache.store(&cache_key);
+            }
             Ok(None)
         } else {
             Err(RegistryError::AllClientsFailed {
-                details: errors.join("; "),
+                details: errors
+                    .iter()
+                    .map(|(provider, error)| format!("{provider}: {error}"))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                category: errors[0].1.category(),
+                retry_after_seconds: errors
+                    .iter()
+                    .filter_map(|(_, error)| error.retry_after_seconds())
+                    .max(),
             })
         }
     }
 
+    /// Cached contract metadata regardless of its age, if any was ever found
+    ///
+    /// Intended as a fallback when [`Self::get_contract_metadata`] fails.
+    pub fn get_stale_contract_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Option<ContractMetadata> {
+        self.cache
+            .get_stale_metadata(&MetadataCacheKey::new(address, chain_id))
+    }
+
+    /// Whether a registered client can retrieve token-level metadata
+    pub fn supports_token_metadata(&self) -> bool {
+        self.moralis_client.is_some()
+    }
+
+    /// Get the metadata of a single token
+    ///
+    /// Only Moralis provides token-level metadata. Token lookups are not cached,
+    /// since each token of a collection would otherwise get its own entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(metadata))` if the token metadata was retrieved successfully
+    /// * `Ok(None)` if the provider does not know the token
+    /// * `Err(error)` if the provider failed or no client supports token metadata
+    pub async fn get_token_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        token_id: &str,
+    ) -> Result<Option<TokenMetadata>, RegistryError> {
+        let Some(moralis_client) = self.moralis_client.as_ref() else {
+            return Err(RegistryError::NoClients);
+        };
+
+        debug!(
+            "fetching token {} of {} on chain {} from Moralis",
+            token_id,
+            address,
+            chain_id.name()
+        );
+        let start = Instant::now();
+        let result = moralis_client
+            .get_token_metadata(address, chain_id, token_id)
+            .await
+            .map_err(ApiError::from);
+        self.record_outcome("moralis", &result, start);
+        match result {
+            Ok(mut token) => {
+                if let Some(token) = token.as_mut() {
+                    self.enrich_creator(Some(&mut token.contract), chain_id)
+                        .await;
+                }
+                Ok(token)
+            }
+            Err(e) => {
+                warn!("Moralis token metadata request failed: {}", e);
+                Err(RegistryError::AllClientsFailed {
+                    category: e.category(),
+                    retry_after_seconds: e.retry_after_seconds(),
+                    details: format!("moralis: {e}"),
+                })
+            }
+        }
+    }
+
+    /// Get the metadata of up to `sample_size` tokens of a collection
+    ///
+    /// Like [`Self::get_token_metadata`] this needs Moralis and is not cached.
+    /// An empty list means the provider knows no tokens of the contract.
+    pub async fn sample_tokens(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        sample_size: u32,
+    ) -> Result<Vec<TokenMetadata>, RegistryError> {
+        let Some(moralis_client) = self.moralis_client.as_ref() else {
+            return Err(RegistryError::NoClients);
+        };
+
+        debug!(
+            "sampling {} tokens of {} on chain {} from Moralis",
+            sample_size,
+            address,
+            chain_id.name()
+        );
+        let start = Instant::now();
+        let result = moralis_client
+            .sample_tokens(address, chain_id, sample_size)
+            .await
+            .map_err(ApiError::from);
+        self.record_outcome("moralis", &result, start);
+        match result {
+            Ok(mut tokens) => {
+                for token in &mut tokens {
+                    self.enrich_creator(Some(&mut token.contract), chain_id)
+                        .await;
+                }
+                Ok(tokens)
+            }
+            Err(e) => {
+                warn!("Moralis token sampling failed: {}", e);
+                Err(RegistryError::AllClientsFailed {
+                    category: e.category(),
+                    retry_after_seconds: e.retry_after_seconds(),
+                    details: format!("moralis: {e}"),
+                })
+            }
+        }
+    }
+
+    /// Attach creator reputation to the metadata when enrichment is configured
+    async fn enrich_creator(&self, metadata: Option<&mut ContractMetadata>, chain_id: ChainId) {
+        if let (Some(enrichment), Some(metadata)) = (&self.creator_enrichment, metadata) {
+            enrichment.enrich(metadata, chain_id).await;
+        }
+    }
+
+    /// Try to get contract metadata from `provider`'s client
+    async fn try_provider_metadata(
+        &self,
+        provider: &ApiProvider,
+        address: Address,
+        chain_id: ChainId,
+        errors: &mut Vec<(&'static str, ApiError)>,
+    ) -> Option<Option<ContractMetadata>> {
+        match provider {
+            ApiProvider::Moralis => self.try_moralis_metadata(address, chain_id, errors).await,
+            ApiProvider::Pinax => self.try_pinax_metadata(address, chain_id, errors).await,
+            ApiProvider::SimpleHash => {
+                self.try_simplehash_metadata(address, chain_id, errors)
+                    .await
+            }
+        }
+    }
+
+    /// Compare `metadata` from `primary` with the first of the `remaining`
+    /// providers that also has metadata, returning the preferred provider, its
+    /// metadata and the conflict if they disagree
+    ///
+    /// Failures of the remaining providers are logged and otherwise ignored,
+    /// since the primary answer is already usable.
+    async fn cross_check_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        primary: ApiProvider,
+        metadata: ContractMetadata,
+        remaining: &[ApiProvider],
+        consulted: &mut ConsultedProviders,
+    ) -> (ApiProvider, ContractMetadata, Option<MetadataConflict>) {
+        for secondary in remaining {
+            if !self.breaker_allows_request(secondary) {
+                continue;
+            }
+            self.record_consulted(secondary, address, chain_id, consulted);
+            let mut errors = Vec::new();
+            let Some(Some(other)) = self
+                .try_provider_metadata(secondary, address, chain_id, &mut errors)
+                .await
+            else {
+                debug!(%secondary, "cross-check provider has no metadata");
+                continue;
+            };
+            let Some(conflict) =
+                MetadataConflict::detect((&primary, &metadata), (secondary, &other))
+            else {
+                return (primary, metadata, None);
+            };
+            warn!(
+                %address,
+                chain = chain_id.name(),
+                %conflict,
+                "providers returned conflicting contract metadata"
+            );
+            let chosen = if conflict.chosen == primary {
+                metadata
+            } else {
+                other
+            };
+            return (conflict.chosen.clone(), chosen, Some(conflict));
+        }
+        (primary, metadata, None)
+    }
+
+    /// Fill the fields missing from `metadata` with the answers of the
+    /// `remaining` providers, in priority order
+    ///
+    /// Failures of the remaining providers are logged and otherwise ignored,
+    /// since the primary answer is already usable.
+    async fn merge_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        metadata: &mut ContractMetadata,
+        remaining: &[ApiProvider],
+        consulted: &mut ConsultedProviders,
+    ) {
+        for provider in remaining {
+            if !self.breaker_allows_request(provider) {
+                continue;
+            }
+            self.record_consulted(provider, address, chain_id, consulted);
+            let mut errors = Vec::new();
+            let Some(Some(other)) = self
+                .try_provider_metadata(provider, address, chain_id, &mut errors)
+                .await
+            else {
+                debug!(%provider, "merge provider has no metadata");
+                continue;
+            };
+            let filled = fill_missing(metadata, other);
+            debug!(
+                %address,
+                chain = chain_id.name(),
+                %provider,
+                ?filled,
+                "merged contract metadata"
+            );
+        }
+    }
+
     /// Try to get contract metadata from Moralis client
     async fn try_moralis_metadata(
         &self,
         address: Address,
         chain_id: ChainId,
-        errors: &mut Vec<String>,
+        errors: &mut Vec<(&'static str, ApiError)>,
     ) -> Option<Option<ContractMetadata>> {
         let moralis_client = self.moralis_client.as_ref()?;
 
         debug!("Trying Moralis client for chain {}", chain_id.name());
-        match moralis_client
+        let start = Instant::now();
+        let result = moralis_client
             .get_contract_metadata(address, chain_id)
-            .await
-        {
+            .await;
+        self.record_outcome("moralis", &result, start);
+        self.record_breaker_outcome(&ApiProvider::Moralis, &result);
+        match result {
             Ok(Some(metadata)) => {
                 info!("Successfully retrieved metadata from Moralis client");
                 Some(Some(metadata))
@@ -189,7 +1210,7 @@ impl ApiRegistry {
             }
             Err(e) => {
                 warn!("Moralis client failed: {}", e);
-                errors.push(format!("moralis: {e}"));
+                errors.push(("moralis", e));
                 None
             }
         }
@@ -200,12 +1221,16 @@ impl ApiRegistry {
         &self,
         address: Address,
         chain_id: ChainId,
-        errors: &mut Vec<String>,
+        errors: &mut Vec<(&'static str, ApiError)>,
     ) -> Option<Option<ContractMetadata>> {
         let pinax_client = self.pinax_client.as_ref()?;
 
         debug!("Trying Pinax client for chain {}", chain_id.name());
-        match pinax_client.get_contract_metadata(address, chain_id).await {
+        let start = Instant::now();
+        let result = pinax_client.get_contract_metadata(address, chain_id).await;
+        self.record_outcome("pinax", &result, start);
+        self.record_breaker_outcome(&ApiProvider::Pinax, &result);
+        match result {
             Ok(Some(metadata)) => {
                 info!("Successfully retrieved metadata from Pinax client");
                 Some(Some(metadata))
@@ -216,15 +1241,94 @@ impl ApiRegistry {
             }
             Err(e) => {
                 warn!("Pinax client failed: {}", e);
-                errors.push(format!("pinax: {e}"));
+                errors.push(("pinax", e));
+                None
+            }
+        }
+    }
+
+    /// Try to get contract metadata from `SimpleHash` client
+    async fn try_simplehash_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+        errors: &mut Vec<(&'static str, ApiError)>,
+    ) -> Option<Option<ContractMetadata>> {
+        let simplehash_client = self.simplehash_client.as_ref()?;
+
+        debug!("Trying SimpleHash client for chain {}", chain_id.name());
+        let start = Instant::now();
+        let result = simplehash_client
+            .get_contract_metadata(address, chain_id)
+            .await;
+        self.record_outcome("simplehash", &result, start);
+        self.record_breaker_outcome(&ApiProvider::SimpleHash, &result);
+        match result {
+            Ok(Some(metadata)) => {
+                info!("Successfully retrieved metadata from SimpleHash client");
+                Some(Some(metadata))
+            }
+            Ok(None) => {
+                debug!("No metadata found in SimpleHash client");
+                None
+            }
+            Err(e) => {
+                warn!("SimpleHash client failed: {}", e);
+                errors.push(("simplehash", e));
                 None
             }
         }
     }
 
+    /// Record the outcome and latency of a provider call started at `start`
+    fn record_outcome<T>(
+        &self,
+        provider: &'static str,
+        result: &Result<T, ApiError>,
+        start: Instant,
+    ) {
+        let outcome = if result.is_ok() {
+            RequestOutcome::Success
+        } else {
+            RequestOutcome::Error
+        };
+        self.stats.record(provider, outcome, start.elapsed());
+    }
+
+    /// Whether the circuit breaker of `provider`, if any, lets a request through
+    fn breaker_allows_request(&self, provider: &ApiProvider) -> bool {
+        self.breakers
+            .get(provider)
+            .is_none_or(CircuitBreaker::allow_request)
+    }
+
+    /// Feed the outcome of a metadata lookup to the circuit breaker of `provider`
+    fn record_breaker_outcome<T>(&self, provider: &ApiProvider, result: &Result<T, ApiError>) {
+        if let Some(breaker) = self.breakers.get(provider) {
+            if result.is_ok() {
+                breaker.record_success();
+            } else {
+                let was_closed = breaker.state() == BreakerState::Closed;
+                breaker.record_failure();
+                if was_closed && breaker.state() == BreakerState::Open {
+                    warn!(%provider, "circuit breaker opened after repeated failures");
+                }
+            }
+        }
+    }
+
+    /// Request outcome and latency statistics for each registered client
+    pub fn provider_stats(&self) -> Vec<ProviderStats> {
+        self.client_names()
+            .into_iter()
+            .map(|provider| self.stats.snapshot(provider))
+            .collect()
+    }
+
     /// Get the overall health status of all registered clients
     ///
-    /// Health checks are performed concurrently for better performance.
+    /// Health checks are performed concurrently for better performance. A
+    /// client whose circuit breaker is open is reported as degraded.
     pub async fn get_overall_health(&self) -> HashMap<String, HealthStatus> {
         let mut health_status = HashMap::new();
 
@@ -260,14 +1364,39 @@ impl ApiRegistry {
             }
         };
 
-        let (moralis_result, pinax_result) = tokio::join!(moralis_future, pinax_future);
+        let simplehash_future = async {
+            if let Some(simplehash_client) = &self.simplehash_client {
+                match simplehash_client.health_check().await {
+                    Ok(status) => Some(("simplehash".to_string(), status)),
+                    Err(e) => {
+                        let status = HealthStatus::Down {
+                            reason: format!("Health check failed: {e}"),
+                        };
+                        Some(("simplehash".to_string(), status))
+                    }
+                }
+            } else {
+                None
+            }
+        };
 
-        if let Some((name, status)) = moralis_result {
+        let (moralis_result, pinax_result, simplehash_result) =
+            tokio::join!(moralis_future, pinax_future, simplehash_future);
+
+        for (name, status) in [moralis_result, pinax_result, simplehash_result]
+            .into_iter()
+            .flatten()
+        {
             health_status.insert(name, status);
         }
 
-        if let Some((name, status)) = pinax_result {
-            health_status.insert(name, status);
+        // A provider answering health checks is still degraded while lookups skip it
+        for name in self.open_circuit_breakers() {
+            if let Some(status @ HealthStatus::Up) = health_status.get_mut(name) {
+                *status = HealthStatus::Degraded {
+                    reason: "circuit breaker open after repeated failures".to_string(),
+                };
+            }
         }
 
         health_status
@@ -282,19 +1411,27 @@ impl ApiRegistry {
         if self.pinax_client.is_some() {
             count += 1;
         }
+        if self.simplehash_client.is_some() {
+            count += 1;
+        }
         count
     }
 
-    /// Get the names of all registered clients
+    /// Get the names of all registered clients, in the order lookups try them
     pub fn client_names(&self) -> Vec<&'static str> {
-        let mut names = Vec::new();
-        if self.moralis_client.is_some() {
-            names.push("moralis");
-        }
-        if self.pinax_client.is_some() {
-            names.push("pinax");
-        }
-        names
+        self.providers_by_priority()
+            .into_iter()
+            .map(|provider| provider.as_str())
+            .collect()
+    }
+
+    /// Whether Pinax uses per-chain overrides for the chain, if Pinax is registered
+    ///
+    /// Intended for admin debugging of chain-specific provider issues.
+    pub fn pinax_config_source(&self, chain_id: ChainId) -> Option<ChainConfigSource> {
+        self.pinax_client
+            .as_ref()
+            .map(|client| client.chain_config_source(chain_id))
     }
 
     /// Get cache statistics
@@ -302,9 +1439,15 @@ impl ApiRegistry {
         self.cache.get_stats()
     }
 
-    /// Clear all cached metadata (useful for testing or cache invalidation)
+    /// Get statistics of the "not found across all providers" cache
+    pub fn negative_cache_stats(&self) -> NegativeLookupCacheStats {
+        self.negative_cache.get_stats()
+    }
+
+    /// Clear all cached metadata and misses (useful for testing or cache invalidation)
     pub fn clear_cache(&self) {
         self.cache.clear_metadata();
+        self.negative_cache.clear();
     }
 
     /// Clean up expired cache entries and return the number of entries removed
@@ -346,13 +1489,100 @@ mod tests {
 
         let error = RegistryError::AllClientsFailed {
             details: "all failed".to_string(),
+            category: ErrorCategory::Timeout,
+            retry_after_seconds: None,
         };
         assert_eq!(error.to_string(), "All API clients failed: all failed");
+        assert_eq!(error.category(), ErrorCategory::Timeout);
+        assert_eq!(error.retry_after_seconds(), None);
 
         let error = RegistryError::NoHealthyClients;
         assert_eq!(error.to_string(), "No healthy API clients available");
     }
 
+    fn metadata(name: &str, contract_type: ContractType) -> ContractMetadata {
+        ContractMetadata {
+            address: Address::ZERO,
+            name: Some(name.to_string()),
+            symbol: None,
+            total_supply: None,
+            holder_count: None,
+            transaction_count: None,
+            creation_block: None,
+            creation_timestamp: None,
+            creator_address: None,
+            is_verified: None,
+            contract_type: Some(contract_type),
+            external_url: None,
+            twitter: None,
+            discord: None,
+            additional_data: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn fill_missing_keeps_present_fields() {
+        let mut primary = metadata("Primary", ContractType::Unknown);
+        primary
+            .additional_data
+            .insert("description".to_string(), "primary".into());
+        let mut secondary = metadata("Secondary", ContractType::Erc1155);
+        secondary.holder_count = Some(1200);
+        secondary.additional_data.extend([
+            ("description".to_string(), "secondary".into()),
+            ("floor_price".to_string(), 0.5.into()),
+        ]);
+
+        let filled = fill_missing(&mut primary, secondary);
+        assert_eq!(filled, vec!["holder_count", "contract_type"]);
+        assert_eq!(primary.name.as_deref(), Some("Primary"));
+        assert_eq!(primary.holder_count, Some(1200));
+        assert_eq!(primary.contract_type, Some(ContractType::Erc1155));
+        assert_eq!(primary.additional_data["description"], "primary");
+        assert_eq!(primary.additional_data["floor_price"], 0.5);
+
+        // An unknown contract type never replaces a known one
+        let filled = fill_missing(&mut primary, metadata("Other", ContractType::Unknown));
+        assert!(filled.is_empty());
+        assert_eq!(primary.contract_type, Some(ContractType::Erc1155));
+    }
+
+    #[test]
+    fn metadata_conflict_resolution() {
+        let moralis = ApiProvider::Moralis;
+        let pinax = ApiProvider::Pinax;
+
+        // An unknown contract type never conflicts
+        let primary = metadata("Punks", ContractType::Erc721);
+        let secondary = metadata("PUNKS ", ContractType::Unknown);
+        assert!(MetadataConflict::detect((&moralis, &primary), (&pinax, &secondary)).is_none());
+
+        // Equally complete answers keep the primary provider
+        let secondary = metadata("Punks", ContractType::Erc1155);
+        let conflict =
+            MetadataConflict::detect((&moralis, &primary), (&pinax, &secondary)).unwrap();
+        assert_eq!(conflict.chosen, moralis);
+        assert_eq!(conflict.fields, vec!["contract_type"]);
+        assert_eq!(conflict.resolution, ConflictResolution::Priority);
+
+        // A verified answer wins over a more complete one
+        let primary = ContractMetadata {
+            symbol: Some("PNK".to_string()),
+            holder_count: Some(10),
+            ..metadata("Punks", ContractType::Erc721)
+        };
+        let secondary = ContractMetadata {
+            is_verified: Some(true),
+            ..metadata("Fake Punks", ContractType::Erc721)
+        };
+        let conflict =
+            MetadataConflict::detect((&moralis, &primary), (&pinax, &secondary)).unwrap();
+        assert_eq!(conflict.chosen, pinax);
+        assert_eq!(conflict.rejected, moralis);
+        assert_eq!(conflict.fields, vec!["name"]);
+        assert_eq!(conflict.resolution, ConflictResolution::Verified);
+    }
+
     #[test]
     fn cache_operations() {
         let registry = ApiRegistry::new();
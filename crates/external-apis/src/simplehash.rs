@@ -0,0 +1,564 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! `SimpleHash` NFT API integration
+//!
+//! This module provides an implementation of the `ApiClient` trait for the `SimpleHash` API.
+//! `SimpleHash` aggregates NFT collection metadata across many chains, which makes it a
+//! useful fallback when the other providers have no data for a contract.
+
+use std::{collections::HashMap, time::Duration};
+
+use alloy_primitives::Address;
+use api_client::{
+    ApiClient, ApiError, ContractMetadata, DEFAULT_MAX_PARSED_COUNT, HealthStatus, RateLimitConfig,
+    RetryPolicy, UpstreamAllowlist, parse_count,
+};
+use reqwest::{Client, ClientBuilder, RequestBuilder, StatusCode};
+use serde::Deserialize;
+use shared_types::ChainId;
+use thiserror::Error;
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+
+/// Configuration for the `SimpleHash` API client
+#[derive(Debug, Clone)]
+pub struct SimpleHashConfig {
+    /// Base URL for the `SimpleHash` API
+    pub base_url: String,
+    /// API key for authentication
+    pub api_key: String,
+    /// Request timeout in seconds
+    pub timeout_seconds: u64,
+    /// Connection establishment timeout in seconds
+    pub connect_timeout_seconds: u64,
+    /// Health check timeout in seconds
+    pub health_check_timeout_seconds: u64,
+    /// Maximum number of retry attempts
+    pub max_retries: u32,
+}
+
+impl Default for SimpleHashConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.simplehash.com".to_string(),
+            api_key: "test-api-key".to_string(),
+            timeout_seconds: 30,
+            connect_timeout_seconds: 1,
+            health_check_timeout_seconds: 5,
+            max_retries: 3,
+        }
+    }
+}
+
+/// `SimpleHash` API client implementation
+#[derive(Debug)]
+pub struct SimpleHashClient {
+    client: Client,
+    config: SimpleHashConfig,
+    /// Backoff between retries; attempts come from `max_retries`
+    retry_policy: RetryPolicy,
+    /// Upper bound for counts parsed from responses
+    max_parsed_count: u64,
+    /// Fallback back-off reported when a rate-limited response has no `Retry-After`
+    rate_limit: RateLimitConfig,
+}
+
+/// Errors specific to the `SimpleHash` API client
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum SimpleHashError {
+    /// HTTP request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// API returned an error response
+    #[error("API error: {status} - {message}")]
+    ApiError { status: u16, message: String },
+
+    /// Rate limit exceeded
+    #[error("Rate limit exceeded, retry after {retry_after_seconds} seconds")]
+    RateLimited { retry_after_seconds: u64 },
+
+    /// Authentication failed
+    #[error("Authentication failed")]
+    Unauthorized,
+
+    /// Configuration error
+    #[error("Configuration error: {0}")]
+    Config(String),
+
+    /// Timeout error
+    #[error("Request timeout")]
+    Timeout { seconds: u64 },
+}
+
+impl SimpleHashError {
+    /// Whether the error is likely transient and the request worth retrying
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(error) => error.is_timeout() || error.is_connect(),
+            Self::Timeout { .. } | Self::RateLimited { .. } => true,
+            Self::ApiError { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+impl From<SimpleHashError> for ApiError {
+    fn from(value: SimpleHashError) -> Self {
+        match value {
+            SimpleHashError::Http(error) => ApiError::Http {
+                message: error.to_string(),
+            },
+            SimpleHashError::ApiError { status, message } => ApiError::Custom {
+                error: anyhow::Error::msg(format!("{status}: {message}")),
+            },
+            SimpleHashError::RateLimited {
+                retry_after_seconds,
+            } => ApiError::RateLimitExceeded {
+                retry_after_seconds,
+            },
+            SimpleHashError::Unauthorized => ApiError::Authentication {
+                message: value.to_string(),
+            },
+            SimpleHashError::Config(message) => ApiError::Configuration { message },
+            SimpleHashError::Timeout { seconds } => ApiError::Timeout {
+                timeout_seconds: seconds,
+            },
+        }
+    }
+}
+
+/// Response structure for the `SimpleHash` collections by contract endpoint
+#[derive(Debug, Deserialize)]
+pub struct SimpleHashCollectionsResponse {
+    /// Collections the contract belongs to, empty if `SimpleHash` does not know it
+    #[serde(default)]
+    pub collections: Vec<SimpleHashCollection>,
+}
+
+/// Individual collection from the collections by contract endpoint
+#[derive(Debug, Deserialize)]
+pub struct SimpleHashCollection {
+    /// `SimpleHash` identifier of the collection
+    pub collection_id: Option<String>,
+    /// Name of the collection
+    pub name: Option<String>,
+    /// Description of the collection
+    pub description: Option<String>,
+    /// Collection image
+    pub image_url: Option<String>,
+    /// Project website
+    pub external_url: Option<String>,
+    /// Twitter handle, without the leading `@`
+    pub twitter_username: Option<String>,
+    /// Discord invite link
+    pub discord_url: Option<String>,
+    /// Number of distinct tokens in the collection
+    #[serde(default)]
+    pub distinct_nft_count: Option<serde_json::Value>,
+    /// Number of distinct owners holding tokens of the collection
+    #[serde(default)]
+    pub distinct_owner_count: Option<serde_json::Value>,
+    /// `SimpleHash`'s own spam score, from 0 (not spam) to 100
+    #[serde(default)]
+    pub spam_score: Option<serde_json::Value>,
+}
+
+/// `SimpleHash` chain slug of `chain_id`
+pub fn simplehash_chain(chain_id: ChainId) -> &'static str {
+    match chain_id {
+        ChainId::Ethereum => "ethereum",
+        ChainId::Polygon => "polygon",
+        ChainId::Base => "base",
+        ChainId::Avalanche => "avalanche",
+        ChainId::Arbitrum => "arbitrum",
+    }
+}
+
+impl SimpleHashClient {
+    /// Create a new `SimpleHash` API client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be created or configuration is invalid
+    pub fn new(config: SimpleHashConfig) -> Result<Self, SimpleHashError> {
+        if config.api_key.trim().is_empty() {
+            return Err(SimpleHashError::Config(
+                "API key cannot be empty".to_string(),
+            ));
+        }
+
+        if config.base_url.trim().is_empty() {
+            return Err(SimpleHashError::Config(
+                "Base URL cannot be empty".to_string(),
+            ));
+        }
+
+        let client = Self::client_builder(&config)
+            .build()
+            .map_err(SimpleHashError::Http)?;
+
+        Ok(Self {
+            client,
+            config,
+            retry_policy: RetryPolicy::default(),
+            max_parsed_count: DEFAULT_MAX_PARSED_COUNT,
+            rate_limit: RateLimitConfig::default(),
+        })
+    }
+
+    fn client_builder(config: &SimpleHashConfig) -> ClientBuilder {
+        Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .connect_timeout(Duration::from_secs(config.connect_timeout_seconds))
+            .user_agent("nft-api/0.1.0")
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the base URL is not permitted or the HTTP client
+    /// cannot be rebuilt
+    pub fn with_upstream_allowlist(
+        mut self,
+        allowlist: &UpstreamAllowlist,
+    ) -> Result<Self, SimpleHashError> {
+        allowlist
+            .check_url_str(&self.config.base_url)
+            .map_err(|e| SimpleHashError::Config(e.to_string()))?;
+
+        self.client = allowlist
+            .apply(Self::client_builder(&self.config))
+            .build()
+            .map_err(SimpleHashError::Http)?;
+        Ok(self)
+    }
+
+    /// Configure the backoff used between retries of transient failures
+    ///
+    /// The number of attempts is always derived from `max_retries`, so only
+    /// the delay and jitter settings of `retry_policy` apply.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the upper bound for the counts parsed from responses
+    ///
+    /// Larger or overflowing values saturate to `max_parsed_count`.
+    #[must_use]
+    pub fn with_max_parsed_count(mut self, max_parsed_count: u64) -> Self {
+        self.max_parsed_count = max_parsed_count;
+        self
+    }
+
+    /// Configure the back-off reported for rate-limited responses
+    ///
+    /// A `Retry-After` header from `SimpleHash` takes precedence over
+    /// `rate_limit.retry_after_seconds`.
+    #[must_use]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+
+    /// Get the collections a contract belongs to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn get_collections(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Result<SimpleHashCollectionsResponse, SimpleHashError> {
+        if address == Address::ZERO {
+            return Err(SimpleHashError::Config(
+                "Invalid contract address provided".to_string(),
+            ));
+        }
+
+        let chain = simplehash_chain(chain_id);
+        let url = format!(
+            "{}/api/v0/nfts/collections/{chain}/{address}",
+            self.config.base_url.trim_end_matches('/')
+        );
+
+        debug!(url, chain, chain_id = %chain_id, "fetching collections from SimpleHash");
+
+        let request = self
+            .client
+            .get(&url)
+            .header("X-API-KEY", &self.config.api_key)
+            .header("accept", "application/json");
+
+        let retry_policy = self
+            .retry_policy
+            .clone()
+            .with_max_attempts(self.config.max_retries.saturating_add(1));
+        let request = &request;
+
+        let response = retry_policy
+            .retry(
+                move || async move {
+                    let request = request.try_clone().ok_or_else(|| {
+                        SimpleHashError::Config("SimpleHash request cannot be retried".to_string())
+                    })?;
+                    self.send_request(request).await
+                },
+                SimpleHashError::is_transient,
+            )
+            .await?;
+
+        Ok(response.unwrap_or_else(|| {
+            debug!(%address, "collections not found in SimpleHash");
+            SimpleHashCollectionsResponse {
+                collections: vec![],
+            }
+        }))
+    }
+
+    /// Send a single request and map the response status, `None` meaning not found
+    async fn send_request(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<Option<SimpleHashCollectionsResponse>, SimpleHashError> {
+        let timeout_seconds = self.config.timeout_seconds;
+        let response = timeout(Duration::from_secs(timeout_seconds), request.send())
+            .await
+            .map_err(|_| SimpleHashError::Timeout {
+                seconds: timeout_seconds,
+            })?
+            .map_err(SimpleHashError::Http)?;
+
+        match response.status() {
+            StatusCode::OK => response
+                .json()
+                .await
+                .map(Some)
+                .map_err(SimpleHashError::Http),
+            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Err(SimpleHashError::Unauthorized),
+            StatusCode::TOO_MANY_REQUESTS => Err(SimpleHashError::RateLimited {
+                retry_after_seconds: self.rate_limit.retry_after(response.headers()),
+            }),
+            status => {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                warn!("SimpleHash API error: {} - {}", status.as_u16(), error_text);
+                Err(SimpleHashError::ApiError {
+                    status: status.as_u16(),
+                    message: error_text,
+                })
+            }
+        }
+    }
+
+    /// Parse a count reported by `SimpleHash`, dropping values that are not a count
+    fn parse_count(&self, count: Option<&serde_json::Value>) -> Option<u64> {
+        count.and_then(|count| parse_count(count, self.max_parsed_count))
+    }
+
+    /// Convert a `SimpleHash` collection to contract metadata
+    fn convert_collection_to_metadata(
+        &self,
+        address: Address,
+        collection: &SimpleHashCollection,
+    ) -> ContractMetadata {
+        let non_empty = |field: &Option<String>| {
+            field
+                .as_deref()
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .map(str::to_string)
+        };
+
+        let mut additional_data = HashMap::new();
+        for (key, value) in [
+            ("collection_id", &collection.collection_id),
+            ("description", &collection.description),
+            ("image_url", &collection.image_url),
+        ] {
+            if let Some(value) = non_empty(value) {
+                additional_data.insert(key.to_string(), serde_json::Value::String(value));
+            }
+        }
+        if let Some(spam_score) = collection.spam_score.as_ref().filter(|s| !s.is_null()) {
+            additional_data.insert("spam_score".to_string(), spam_score.clone());
+        }
+
+        ContractMetadata {
+            address,
+            name: non_empty(&collection.name),
+            symbol: None,
+            total_supply: self
+                .parse_count(collection.distinct_nft_count.as_ref())
+                .map(|count| count.to_string()),
+            holder_count: self.parse_count(collection.distinct_owner_count.as_ref()),
+            transaction_count: None,
+            creation_block: None,
+            creation_timestamp: None,
+            creator_address: None,
+            is_verified: None,
+            // SimpleHash reports collections, not token standards
+            contract_type: None,
+            external_url: non_empty(&collection.external_url),
+            twitter: non_empty(&collection.twitter_username)
+                .map(|username| format!("@{}", username.trim_start_matches('@'))),
+            discord: non_empty(&collection.discord_url),
+            additional_data,
+        }
+    }
+}
+
+impl ApiClient for SimpleHashClient {
+    async fn health_check(&self) -> Result<HealthStatus, ApiError> {
+        let url = format!(
+            "{}/api/v0/chains",
+            self.config.base_url.trim_end_matches('/')
+        );
+
+        debug!(url, "performing health check on SimpleHash API");
+
+        let request = self
+            .client
+            .get(&url)
+            .header("X-API-KEY", &self.config.api_key)
+            .header("accept", "application/json");
+
+        let start_time = std::time::Instant::now();
+        let response = timeout(
+            Duration::from_secs(self.config.health_check_timeout_seconds),
+            request.send(),
+        )
+        .await
+        .map_err(|_| SimpleHashError::Timeout {
+            seconds: start_time.elapsed().as_secs(),
+        })?
+        .map_err(SimpleHashError::Http)?;
+
+        let response_time = start_time.elapsed();
+
+        match response.status() {
+            StatusCode::OK => {
+                info!("SimpleHash API health check passed in {:?}", response_time);
+                Ok(HealthStatus::Up)
+            }
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                warn!("SimpleHash API health check failed: unauthorized");
+                Ok(HealthStatus::Down {
+                    reason: "Authentication failed".to_string(),
+                })
+            }
+            StatusCode::TOO_MANY_REQUESTS => {
+                warn!("SimpleHash API health check failed: rate limited");
+                Ok(HealthStatus::Degraded {
+                    reason: "Rate limited".to_string(),
+                })
+            }
+            status => {
+                warn!("SimpleHash API health check failed with status: {}", status);
+                Ok(HealthStatus::Degraded {
+                    reason: format!("API returned status {}", status.as_u16()),
+                })
+            }
+        }
+    }
+
+    async fn get_contract_metadata(
+        &self,
+        address: Address,
+        chain_id: ChainId,
+    ) -> Result<Option<ContractMetadata>, ApiError> {
+        info!(
+            "Fetching contract metadata for address: {} on chain: {}",
+            address,
+            chain_id.name()
+        );
+
+        let response = self.get_collections(address, chain_id).await.map_err(|e| {
+            error!(
+                "Failed to fetch collections from SimpleHash for address {} on chain {}: {}",
+                address,
+                chain_id.name(),
+                e
+            );
+            e
+        })?;
+
+        let Some(collection) = response.collections.first() else {
+            debug!("No collection found for address: {}", address);
+            return Ok(None);
+        };
+        Ok(Some(
+            self.convert_collection_to_metadata(address, collection),
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "simplehash"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simplehash_client_creation_invalid_config() {
+        let config = SimpleHashConfig {
+            api_key: " ".to_string(),
+            ..Default::default()
+        };
+
+        let client = SimpleHashClient::new(config);
+        assert!(matches!(client.unwrap_err(), SimpleHashError::Config(_)));
+    }
+
+    #[test]
+    fn chain_slugs() {
+        let slugs: Vec<_> = ChainId::all()
+            .iter()
+            .map(|&chain_id| simplehash_chain(chain_id))
+            .collect();
+        assert_eq!(
+            slugs,
+            vec!["polygon", "ethereum", "base", "avalanche", "arbitrum"]
+        );
+    }
+
+    #[test]
+    fn convert_collection_to_metadata() {
+        let client = SimpleHashClient::new(SimpleHashConfig::default()).unwrap();
+        let collection: SimpleHashCollection = serde_json::from_value(serde_json::json!({
+            "collection_id": "abc123",
+            "name": "Cool Cats",
+            "description": " ",
+            "external_url": "https://coolcats.example",
+            "twitter_username": "coolcats",
+            "discord_url": null,
+            "distinct_nft_count": 9999,
+            "distinct_owner_count": "4321",
+            "spam_score": 0
+        }))
+        .unwrap();
+
+        let metadata = client.convert_collection_to_metadata(Address::ZERO, &collection);
+        assert_eq!(metadata.name.as_deref(), Some("Cool Cats"));
+        assert_eq!(metadata.total_supply.as_deref(), Some("9999"));
+        assert_eq!(metadata.holder_count, Some(4321));
+        assert_eq!(metadata.twitter.as_deref(), Some("@coolcats"));
+        assert_eq!(metadata.discord, None);
+        assert_eq!(metadata.contract_type, None);
+        assert_eq!(metadata.additional_data["collection_id"], "abc123");
+        assert_eq!(metadata.additional_data["spam_score"], 0);
+        assert!(!metadata.additional_data.contains_key("description"));
+    }
+}
@@ -0,0 +1,200 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-provider request outcome and latency statistics
+//!
+//! The registry records every upstream metadata call here so operators can
+//! inspect success rates and latency percentiles per provider without a
+//! Prometheus query. Latency percentiles are computed over a bounded window of
+//! the most recent requests.
+
+use std::{collections::VecDeque, time::Duration};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+/// Number of most recent latency samples kept per provider
+pub const LATENCY_SAMPLE_WINDOW: usize = 1024;
+
+/// Outcome of a single upstream request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestOutcome {
+    /// The provider answered, with or without metadata
+    Success,
+    /// The provider failed
+    Error,
+}
+
+/// Running counters and recent latencies for one provider
+#[derive(Debug, Default)]
+struct ProviderRecord {
+    requests: u64,
+    successes: u64,
+    errors: u64,
+    latencies: VecDeque<Duration>,
+}
+
+/// Thread-safe recorder of per-provider outcomes
+#[derive(Debug, Default)]
+pub struct ProviderStatsRecorder {
+    records: DashMap<&'static str, ProviderRecord>,
+}
+
+impl ProviderStatsRecorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the outcome and latency of a request to `provider`
+    pub fn record(&self, provider: &'static str, outcome: RequestOutcome, latency: Duration) {
+        let mut record = self.records.entry(provider).or_default();
+        record.requests += 1;
+        match outcome {
+            RequestOutcome::Success => record.successes += 1,
+            RequestOutcome::Error => record.errors += 1,
+        }
+        if record.latencies.len() == LATENCY_SAMPLE_WINDOW {
+            record.latencies.pop_front();
+        }
+        record.latencies.push_back(latency);
+    }
+
+    /// Snapshot the statistics of `provider`, empty if it has not been called yet
+    pub fn snapshot(&self, provider: &'static str) -> ProviderStats {
+        let Some(record) = self.records.get(provider) else {
+            return ProviderStats::empty(provider);
+        };
+
+        let mut latencies: Vec<Duration> = record.latencies.iter().copied().collect();
+        latencies.sort_unstable();
+
+        ProviderStats {
+            provider: provider.to_string(),
+            requests: record.requests,
+            successes: record.successes,
+            errors: record.errors,
+            success_rate: ratio(record.successes, record.requests),
+            error_rate: ratio(record.errors, record.requests),
+            latency_ms: LatencyPercentiles {
+                p50: percentile_ms(&latencies, 50),
+                p90: percentile_ms(&latencies, 90),
+                p99: percentile_ms(&latencies, 99),
+            },
+        }
+    }
+}
+
+/// Request statistics for a single provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProviderStats {
+    /// Provider name (e.g. `moralis`)
+    pub provider: String,
+    /// Total number of requests sent
+    pub requests: u64,
+    /// Requests the provider answered, including "not found"
+    pub successes: u64,
+    /// Requests that failed
+    pub errors: u64,
+    /// Fraction of requests that succeeded (0.0 to 1.0)
+    pub success_rate: f64,
+    /// Fraction of requests that failed (0.0 to 1.0)
+    pub error_rate: f64,
+    /// Latency percentiles over the most recent requests
+    pub latency_ms: LatencyPercentiles,
+}
+
+impl ProviderStats {
+    fn empty(provider: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+            requests: 0,
+            successes: 0,
+            errors: 0,
+            success_rate: 0.0,
+            error_rate: 0.0,
+            latency_ms: LatencyPercentiles::default(),
+        }
+    }
+}
+
+/// Latency percentiles in milliseconds, `None` until a request was recorded
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    /// Median latency
+    pub p50: Option<f64>,
+    /// 90th percentile latency
+    pub p90: Option<f64>,
+    /// 99th percentile latency
+    pub p99: Option<f64>,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ratio(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile_ms(sorted: &[Duration], percentile: usize) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (percentile * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank - 1].as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_reports_rates_and_percentiles() {
+        let recorder = ProviderStatsRecorder::new();
+        for ms in 1..=100 {
+            let outcome = if ms % 4 == 0 {
+                RequestOutcome::Error
+            } else {
+                RequestOutcome::Success
+            };
+            recorder.record("moralis", outcome, Duration::from_millis(ms));
+        }
+
+        let stats = recorder.snapshot("moralis");
+        assert_eq!(stats.requests, 100);
+        assert_eq!(stats.successes, 75);
+        assert_eq!(stats.errors, 25);
+        assert!((stats.success_rate - 0.75).abs() < f64::EPSILON);
+        assert!((stats.error_rate - 0.25).abs() < f64::EPSILON);
+        assert_eq!(stats.latency_ms.p50, Some(50.0));
+        assert_eq!(stats.latency_ms.p90, Some(90.0));
+        assert_eq!(stats.latency_ms.p99, Some(99.0));
+    }
+
+    #[test]
+    fn snapshot_of_unused_provider_is_empty() {
+        let stats = ProviderStatsRecorder::new().snapshot("pinax");
+        assert_eq!(stats.provider, "pinax");
+        assert_eq!(stats.requests, 0);
+        assert_eq!(stats.latency_ms, LatencyPercentiles::default());
+    }
+
+    #[test]
+    fn latency_window_keeps_most_recent_samples() {
+        let recorder = ProviderStatsRecorder::new();
+        for _ in 0..LATENCY_SAMPLE_WINDOW {
+            recorder.record("pinax", RequestOutcome::Success, Duration::from_secs(10));
+        }
+        for _ in 0..LATENCY_SAMPLE_WINDOW {
+            recorder.record("pinax", RequestOutcome::Success, Duration::from_millis(5));
+        }
+
+        let stats = recorder.snapshot("pinax");
+        assert_eq!(stats.requests, 2 * LATENCY_SAMPLE_WINDOW as u64);
+        assert_eq!(stats.latency_ms.p99, Some(5.0));
+    }
+}
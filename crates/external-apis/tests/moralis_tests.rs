@@ -8,7 +8,10 @@
 //! in various scenarios, similar to the Python test patterns.
 
 use alloy_primitives::Address;
-use api_client::{ApiClient, ApiError, ContractType, HealthStatus};
+use api_client::{
+    ApiClient, ApiError, ContractType, HealthStatus, RateLimitConfig, RetryPolicy,
+    UpstreamAllowlist,
+};
 use external_apis::{MoralisClient, MoralisConfig, MoralisError};
 use serde_json::json;
 use shared_types::ChainId;
@@ -30,6 +33,7 @@ fn create_test_config(base_url: String) -> MoralisConfig {
         base_url,
         api_key: "test-api-key".to_string(),
         timeout_seconds: TEST_TIMEOUT_SECONDS,
+        connect_timeout_seconds: 1,
         health_check_timeout_seconds: TEST_HEALTH_CHECK_TIMEOUT_SECONDS,
         max_retries: TEST_MAX_RETRIES,
     }
@@ -76,6 +80,54 @@ async fn get_nft_metadata_success() {
     assert_eq!(metadata.address, test_address);
 }
 
+/// Test that collection social links are parsed from the token metadata
+#[tokio::test]
+async fn get_nft_metadata_parses_social_links() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config).unwrap();
+
+    let test_address = Address::from([0x13; 20]);
+    let token_metadata = json!({
+        "name": "Cool NFT Item",
+        "external_url": "https://coolnft.example",
+        "twitter": "@coolnft",
+        "discord": "https://discord.gg/coolnft"
+    });
+    let mock_response = json!({
+        "result": [{
+            "token_address": test_address.to_string(),
+            "token_id": "1",
+            "contract_type": "ERC721",
+            "metadata": token_metadata.to_string(),
+            "name": "CoolNFT",
+            "symbol": "CNFT"
+        }]
+    });
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response))
+        .mount(&mock_server)
+        .await;
+
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap()
+        .expect("metadata");
+
+    assert_eq!(
+        metadata.external_url.as_deref(),
+        Some("https://coolnft.example")
+    );
+    assert_eq!(metadata.twitter.as_deref(), Some("@coolnft"));
+    assert_eq!(
+        metadata.discord.as_deref(),
+        Some("https://discord.gg/coolnft")
+    );
+}
+
 /// Test contract not found
 #[tokio::test]
 async fn get_contract_metadata_not_found() {
@@ -139,7 +191,7 @@ async fn get_contract_metadata_rate_limited() {
 
     Mock::given(method("GET"))
         .and(path(format!("/nft/{test_address}")))
-        .respond_with(ResponseTemplate::new(429))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "17"))
         .mount(&mock_server)
         .await;
 
@@ -149,11 +201,172 @@ async fn get_contract_metadata_rate_limited() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        ApiError::RateLimitExceeded { .. } => {}
+        ApiError::RateLimitExceeded {
+            retry_after_seconds,
+        } => assert_eq!(retry_after_seconds, 17),
         other => panic!("Expected RateLimitExceeded error, got: {other:?}"),
     }
 }
 
+/// Test rate limiting without a `Retry-After` header falls back to the configured back-off
+#[tokio::test]
+async fn get_contract_metadata_rate_limited_uses_configured_retry_after() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config)
+        .unwrap()
+        .with_rate_limit(RateLimitConfig {
+            retry_after_seconds: 42,
+            ..RateLimitConfig::default()
+        });
+
+    let test_address = Address::from([0x9b; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(429))
+        .mount(&mock_server)
+        .await;
+
+    let error = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.retry_after_seconds(), Some(42));
+}
+
+/// Mount a `429` answer carrying `retry_after` for the first request, then a success
+async fn rate_limited_once(mock_server: &MockServer, test_address: Address, retry_after: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", retry_after))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": test_address.to_string(),
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "CoolNFT",
+                "symbol": "CNFT"
+            }]
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+/// Test a numeric `Retry-After` is waited out before retrying
+#[tokio::test]
+async fn get_contract_metadata_rate_limited_honors_retry_after_seconds() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config).unwrap();
+    let test_address = Address::from([0x9c; 20]);
+    rate_limited_once(&mock_server, test_address, "1").await;
+
+    let start = std::time::Instant::now();
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap()
+        .expect("metadata after the retry");
+
+    assert_eq!(metadata.name.as_deref(), Some("CoolNFT"));
+    // The default backoff before the first retry is at most 100ms
+    assert!(start.elapsed() >= std::time::Duration::from_secs(1));
+}
+
+/// Test an HTTP-date `Retry-After` is waited out before retrying
+#[tokio::test]
+async fn get_contract_metadata_rate_limited_honors_retry_after_date() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config).unwrap();
+    let test_address = Address::from([0x9d; 20]);
+    let retry_at = chrono::Utc::now() + chrono::Duration::seconds(2);
+    rate_limited_once(
+        &mock_server,
+        test_address,
+        &retry_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+    )
+    .await;
+
+    let start = std::time::Instant::now();
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap()
+        .expect("metadata after the retry");
+
+    assert_eq!(metadata.name.as_deref(), Some("CoolNFT"));
+    // Whole-second dates land one to two seconds out
+    assert!(start.elapsed() >= std::time::Duration::from_secs(1));
+}
+
+/// Test the rate limit surfaces with the upstream delay once retries run out
+#[tokio::test]
+async fn get_contract_metadata_rate_limited_exhausts_retries() {
+    let mock_server = MockServer::start().await;
+    let config = MoralisConfig {
+        max_retries: 2,
+        ..create_test_config(mock_server.uri())
+    };
+    let client = MoralisClient::new(config).unwrap();
+    let test_address = Address::from([0x9e; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let start = std::time::Instant::now();
+    let error = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        ApiError::RateLimitExceeded {
+            retry_after_seconds: 1
+        }
+    ));
+    assert!(start.elapsed() >= std::time::Duration::from_secs(2));
+}
+
+/// Test a `Retry-After` beyond the maximum retry delay is surfaced without waiting
+#[tokio::test]
+async fn get_contract_metadata_rate_limited_does_not_wait_beyond_max_delay() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config)
+        .unwrap()
+        .with_retry_policy(RetryPolicy::default().with_max_delay_ms(5_000));
+    let test_address = Address::from([0x9f; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let error = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.retry_after_seconds(), Some(30));
+}
+
 /// Test API server error
 #[tokio::test]
 async fn get_contract_metadata_server_error() {
@@ -180,6 +393,73 @@ async fn get_contract_metadata_server_error() {
     }
 }
 
+/// Test that transient server errors are retried with the shared retry policy
+#[tokio::test]
+async fn get_contract_metadata_retries_server_error() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config)
+        .unwrap()
+        .with_retry_policy(RetryPolicy::default().with_initial_delay_ms(10));
+
+    let test_address = Address::from([0xbd; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": test_address.to_string(),
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "RetriedNFT",
+                "symbol": "RNFT"
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(metadata.name, Some("RetriedNFT".to_string()));
+}
+
+/// Test that client errors are not retried
+#[tokio::test]
+async fn get_contract_metadata_does_not_retry_unauthorized() {
+    let mock_server = MockServer::start().await;
+    let config = create_test_config(mock_server.uri());
+    let client = MoralisClient::new(config)
+        .unwrap()
+        .with_retry_policy(RetryPolicy::default().with_initial_delay_ms(10));
+
+    let test_address = Address::from([0xbe; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let result = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await;
+
+    assert!(matches!(result, Err(ApiError::Authentication { .. })));
+}
+
 /// Test health check success
 #[tokio::test]
 async fn health_check_success() {
@@ -255,6 +535,7 @@ fn client_creation_invalid_api_key() {
         api_key: String::new(),
         base_url: "https://api.example.com".to_string(),
         timeout_seconds: 30,
+        connect_timeout_seconds: 1,
         health_check_timeout_seconds: 5,
         max_retries: 3,
     };
@@ -277,6 +558,7 @@ fn client_creation_invalid_base_url() {
         api_key: "valid-key".to_string(),
         base_url: String::new(),
         timeout_seconds: 30,
+        connect_timeout_seconds: 1,
         health_check_timeout_seconds: 5,
         max_retries: 3,
     };
@@ -580,3 +862,162 @@ async fn moralis_chain_parameter_validation() {
         "should fail or return None for mismatched chain parameter"
     );
 }
+
+/// Mount a successful metadata response for `address` and return the mock server
+async fn mock_metadata_server(address: Address) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{address}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "result": [{
+                "token_address": address.to_string(),
+                "token_id": "1",
+                "contract_type": "ERC721",
+                "name": "CoolNFT",
+                "symbol": "CNFT"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+/// Test that private network upstreams are blocked by default once an allowlist is installed
+#[tokio::test]
+async fn upstream_allowlist_blocks_private_ip_by_default() {
+    let test_address = Address::from([0x61; 20]);
+    let mock_server = mock_metadata_server(test_address).await;
+
+    // IP literal base URLs are rejected when the allowlist is installed
+    let result = MoralisClient::new(create_test_config(mock_server.uri()))
+        .unwrap()
+        .with_upstream_allowlist(&UpstreamAllowlist::default());
+    assert!(matches!(result, Err(MoralisError::Config(_))));
+
+    // Host names resolving to a private address are rejected at request time
+    let port = mock_server.address().port();
+    let client = MoralisClient::new(create_test_config(format!("http://localhost:{port}")))
+        .unwrap()
+        .with_upstream_allowlist(&UpstreamAllowlist::default())
+        .unwrap();
+    let result = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await;
+    assert!(result.is_err());
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+/// Test that explicitly allowlisted hosts are reachable even on private networks
+#[tokio::test]
+async fn upstream_allowlist_permits_listed_host() {
+    let test_address = Address::from([0x62; 20]);
+    let mock_server = mock_metadata_server(test_address).await;
+    let port = mock_server.address().port();
+
+    let client = MoralisClient::new(create_test_config(format!("http://localhost:{port}")))
+        .unwrap()
+        .with_upstream_allowlist(&UpstreamAllowlist::new(["localhost"]))
+        .unwrap();
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Ethereum)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.name, Some("CoolNFT".to_string()));
+
+    // Hosts outside a non-empty allowlist are rejected
+    let result = MoralisClient::new(create_test_config(mock_server.uri()))
+        .unwrap()
+        .with_upstream_allowlist(&UpstreamAllowlist::new(["deep-index.moralis.io"]));
+    assert!(matches!(result, Err(MoralisError::Config(_))));
+}
+
+/// Test that non-numeric and overflowing supply values are handled gracefully
+#[tokio::test]
+async fn get_contract_metadata_parses_total_supply_robustly() {
+    let test_address = Address::from([0x63; 20]);
+
+    for (total, expected) in [
+        (json!(2500), Some("2500")),
+        (json!("not-a-number"), None),
+        (json!("99999999999999999999999999999999"), Some("1000000")),
+        (json!(-10), None),
+    ] {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("/nft/{test_address}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "total": total,
+                "result": [{
+                    "token_address": test_address.to_string(),
+                    "token_id": "1",
+                    "contract_type": "ERC721",
+                    "name": "CoolNFT",
+                    "symbol": "CNFT"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = MoralisClient::new(create_test_config(mock_server.uri()))
+            .unwrap()
+            .with_max_parsed_count(1_000_000);
+        let metadata = client
+            .get_contract_metadata(test_address, ChainId::Ethereum)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.total_supply.as_deref(), expected, "total {total}");
+    }
+}
+
+#[tokio::test]
+async fn get_token_metadata_returns_token_fields() {
+    let test_address = Address::from([0x64; 20]);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}/42")))
+        .and(query_param("chain", "eth"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "token_address": test_address.to_string(),
+            "token_id": "42",
+            "contract_type": "ERC721",
+            "name": "CoolNFT",
+            "symbol": "CNFT",
+            "metadata": "{\"name\":\"CoolNFT #42\",\"image\":\"ipfs://token-42\"}",
+            "normalized_metadata": {
+                "name": "CoolNFT #42",
+                "description": "The forty-second token"
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{test_address}/43")))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    let client = MoralisClient::new(create_test_config(mock_server.uri())).unwrap();
+    let token = client
+        .get_token_metadata(test_address, ChainId::Ethereum, "42")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(token.token_id, "42");
+    assert_eq!(token.name.as_deref(), Some("CoolNFT #42"));
+    assert_eq!(token.description.as_deref(), Some("The forty-second token"));
+    assert_eq!(token.image.as_deref(), Some("ipfs://token-42"));
+    assert_eq!(token.contract.name.as_deref(), Some("CoolNFT"));
+
+    let missing = client
+        .get_token_metadata(test_address, ChainId::Ethereum, "43")
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+
+    let invalid = client
+        .get_token_metadata(test_address, ChainId::Ethereum, "../42")
+        .await;
+    assert!(matches!(invalid, Err(MoralisError::Config(_))));
+}
@@ -0,0 +1,468 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for `ApiRegistry` caching and ordering of provider lookups
+
+use std::time::Duration;
+
+use alloy_primitives::Address;
+use api_client::ContractType;
+use external_apis::{
+    ApiProvider, ApiRegistry, BreakerState, CircuitBreakerConfig, ConflictResolution,
+    MetadataMergeMode, MoralisClient, MoralisConfig, NegativeLookupCache, PinaxClient, PinaxConfig,
+    RegistryError,
+};
+use serde_json::json;
+use shared_types::ChainId;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn moralis_client(mock_server: &MockServer) -> MoralisClient {
+    MoralisClient::new(MoralisConfig {
+        base_url: mock_server.uri(),
+        api_key: "test-api-key".to_string(),
+        timeout_seconds: 10,
+        connect_timeout_seconds: 1,
+        health_check_timeout_seconds: 5,
+        max_retries: 1,
+    })
+    .expect("valid Moralis config")
+}
+
+/// Start a Moralis mock that has no NFTs for `address` and expects `calls` requests
+async fn moralis_without_metadata(address: Address, calls: u64) -> (MockServer, MoralisClient) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{address}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "result": [] })))
+        .expect(calls)
+        .mount(&mock_server)
+        .await;
+
+    let client = moralis_client(&mock_server);
+    (mock_server, client)
+}
+
+/// A repeated lookup of a known-missing address within the TTL skips the providers
+#[tokio::test]
+async fn negative_lookup_is_cached_within_ttl() {
+    let address = Address::from([0x56; 20]);
+    let (mock_server, client) = moralis_without_metadata(address, 1).await;
+    let registry = ApiRegistry::with_clients(Some(client), None).with_negative_cache(
+        NegativeLookupCache::with_settings(Duration::from_secs(60), 100),
+    );
+
+    for _ in 0..2 {
+        let result = registry
+            .get_contract_metadata(address, ChainId::Ethereum)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    let stats = registry.negative_cache_stats();
+    assert_eq!(stats.stores, 1);
+    assert_eq!(stats.hits, 1);
+    // Misses are not stored as positive metadata entries
+    assert_eq!(registry.cache_stats().cache_stores, 0);
+    mock_server.verify().await;
+}
+
+/// Without negative caching every lookup of a missing address reaches the providers
+#[tokio::test]
+async fn negative_lookup_is_not_cached_when_disabled() {
+    let address = Address::from([0x57; 20]);
+    let (mock_server, client) = moralis_without_metadata(address, 2).await;
+    let registry = ApiRegistry::with_clients(Some(client), None)
+        .with_negative_cache(NegativeLookupCache::disabled());
+
+    for _ in 0..2 {
+        let result = registry
+            .get_contract_metadata(address, ChainId::Ethereum)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    assert_eq!(registry.negative_cache_stats().hits, 0);
+    mock_server.verify().await;
+}
+
+/// A cached lookup reports no providers, a fresh one the providers it called
+#[tokio::test]
+async fn lookup_reports_providers_consulted() {
+    let address = Address::from([0x58; 20]);
+    let (mock_server, client) = moralis_without_metadata(address, 1).await;
+    let registry = ApiRegistry::with_clients(Some(client), None).with_negative_cache(
+        NegativeLookupCache::with_settings(Duration::from_secs(60), 100),
+    );
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert!(lookup.result.unwrap().is_none());
+    assert_eq!(lookup.providers_consulted, 1);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert!(lookup.result.unwrap().is_none());
+    assert_eq!(lookup.providers_consulted, 0);
+    mock_server.verify().await;
+}
+
+/// Start a Moralis mock answering `address` with `response` and expecting `calls` requests
+async fn moralis_responding(
+    address: Address,
+    response: ResponseTemplate,
+    calls: u64,
+) -> (MockServer, MoralisClient) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{address}")))
+        .respond_with(response)
+        .expect(calls)
+        .mount(&mock_server)
+        .await;
+
+    let client = moralis_client(&mock_server);
+    (mock_server, client)
+}
+
+/// Start a Pinax mock answering every query with `response` and expecting `calls` requests
+async fn pinax_responding(response: ResponseTemplate, calls: u64) -> (MockServer, PinaxClient) {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(response)
+        .expect(calls)
+        .mount(&mock_server)
+        .await;
+
+    let config = PinaxConfig::new(mock_server.uri(), "user", "auth", "test-db", 10, 5, 1)
+        .expect("valid Pinax config");
+    let client = PinaxClient::new(config).expect("valid Pinax client");
+    (mock_server, client)
+}
+
+fn moralis_metadata(address: Address) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "result": [{
+            "token_address": address.to_string(),
+            "token_id": "1",
+            "contract_type": "ERC721",
+            "name": "Moralis NFT",
+            "symbol": "MOR"
+        }]
+    }))
+}
+
+fn pinax_metadata() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "data": [{ "name": "Pinax NFT", "symbol": "PNX", "description": null }]
+    }))
+}
+
+/// A healthy higher-priority provider answers without the lower-priority one being called
+#[tokio::test]
+async fn higher_priority_client_is_tried_first() {
+    let address = Address::from([0x59; 20]);
+    let (moralis_server, moralis) = moralis_responding(address, moralis_metadata(address), 0).await;
+    let (pinax_server, pinax) = pinax_responding(pinax_metadata(), 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 1)
+        .register_with_priority(pinax, 5);
+    assert_eq!(registry.client_names(), vec!["pinax", "moralis"]);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata found");
+    assert_eq!(metadata.name.as_deref(), Some("Pinax NFT"));
+    assert_eq!(lookup.providers_consulted, 1);
+    let query = lookup.pinax_query.expect("Pinax was queried");
+    let requests = pinax_server.received_requests().await.unwrap();
+    assert_eq!(query.as_bytes(), requests[0].body.as_slice());
+
+    // A cached answer sent no query
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert_eq!(lookup.pinax_query, None);
+    moralis_server.verify().await;
+    pinax_server.verify().await;
+}
+
+/// A batch lookup fetches every uncached address from Pinax in one query, then
+/// falls back to the other providers for the addresses Pinax does not know
+#[tokio::test]
+async fn batch_lookup_queries_pinax_once() {
+    let addresses = [
+        Address::from([0x61; 20]),
+        Address::from([0x62; 20]),
+        Address::from([0x63; 20]),
+    ];
+    let pinax_batch = ResponseTemplate::new(200).set_body_json(json!({
+        "data": [
+            { "contract": format!("{:#x}", addresses[0]), "name": "First", "token_standard": "erc721" },
+            { "contract": format!("{:#x}", addresses[1]), "name": "Second", "token_standard": "erc1155" }
+        ]
+    }));
+    let (pinax_server, pinax) = pinax_responding(pinax_batch, 1).await;
+    let (moralis_server, moralis) =
+        moralis_responding(addresses[2], moralis_metadata(addresses[2]), 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 1)
+        .register_with_priority(pinax, 5);
+
+    let lookups = registry
+        .lookup_contract_metadata_batch(&addresses, ChainId::Ethereum)
+        .await;
+    assert_eq!(lookups.len(), 3);
+    for (address, name, provider, consulted) in [
+        (addresses[0], "First", ApiProvider::Pinax, 1),
+        (addresses[1], "Second", ApiProvider::Pinax, 1),
+        (addresses[2], "Moralis NFT", ApiProvider::Moralis, 2),
+    ] {
+        let lookup = &lookups[&address];
+        let metadata = lookup.result.as_ref().unwrap().as_ref().expect("metadata");
+        assert_eq!(metadata.name.as_deref(), Some(name));
+        assert_eq!(lookup.provider, Some(provider));
+        assert_eq!(lookup.providers_consulted, consulted);
+    }
+
+    // Every address reports the shared query, including those Pinax did not know
+    let requests = pinax_server.received_requests().await.unwrap();
+    let executed = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(executed.contains(" IN ("), "{executed}");
+    assert!(
+        lookups
+            .values()
+            .all(|lookup| lookup.pinax_query.as_deref() == Some(executed.as_str()))
+    );
+
+    // Every answer was cached, so repeating the batch calls no provider
+    let lookups = registry
+        .lookup_contract_metadata_batch(&addresses, ChainId::Ethereum)
+        .await;
+    assert!(
+        lookups
+            .values()
+            .all(|lookup| lookup.providers_consulted == 0)
+    );
+    pinax_server.verify().await;
+    moralis_server.verify().await;
+}
+
+/// A failing higher-priority provider still fails over to the lower-priority one
+#[tokio::test]
+async fn failover_reaches_lower_priority_client() {
+    let address = Address::from([0x5a; 20]);
+    let (moralis_server, moralis) = moralis_responding(address, moralis_metadata(address), 1).await;
+    let (pinax_server, pinax) = pinax_responding(ResponseTemplate::new(401), 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 0)
+        .register_with_priority(pinax, 10);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata found");
+    assert_eq!(metadata.name.as_deref(), Some("Moralis NFT"));
+    assert_eq!(lookup.providers_consulted, 2);
+    moralis_server.verify().await;
+    pinax_server.verify().await;
+}
+
+/// With cross-checking, conflicting answers resolve to the more complete metadata
+/// even when it comes from the lower-priority provider
+#[tokio::test]
+async fn cross_check_prefers_more_complete_conflicting_metadata() {
+    let address = Address::from([0x5c; 20]);
+    let (moralis_server, moralis) = moralis_responding(address, moralis_metadata(address), 1).await;
+    let (pinax_server, pinax) = pinax_responding(pinax_metadata(), 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 1)
+        .register_with_priority(pinax, 5)
+        .with_cross_check(true);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata found");
+    // Moralis also reports the contract type, Pinax only name and symbol
+    assert_eq!(metadata.name.as_deref(), Some("Moralis NFT"));
+    assert_eq!(lookup.providers_consulted, 2);
+    assert_eq!(lookup.provider, Some(ApiProvider::Moralis));
+    let conflict = lookup.conflict.expect("conflict reported");
+    assert_eq!(conflict.chosen, ApiProvider::Moralis);
+    assert_eq!(conflict.rejected, ApiProvider::Pinax);
+    assert_eq!(conflict.fields, vec!["name"]);
+    assert_eq!(conflict.resolution, ConflictResolution::MoreComplete);
+    assert_eq!(
+        conflict.to_string(),
+        "moralis and pinax disagree on name; using moralis metadata (more complete)"
+    );
+
+    // The chosen metadata is cached, so the repeat lookup calls neither provider
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert_eq!(
+        lookup
+            .result
+            .unwrap()
+            .expect("metadata cached")
+            .name
+            .as_deref(),
+        Some("Moralis NFT")
+    );
+    assert!(lookup.conflict.is_none());
+    moralis_server.verify().await;
+    pinax_server.verify().await;
+}
+
+/// Cross-checking providers that agree reports no conflict
+#[tokio::test]
+async fn cross_check_without_conflict_keeps_primary_answer() {
+    let address = Address::from([0x5d; 20]);
+    let (moralis_server, moralis) = moralis_responding(address, moralis_metadata(address), 1).await;
+    let pinax_agreeing = ResponseTemplate::new(200).set_body_json(json!({
+        "data": [{ "name": "moralis nft", "symbol": "MOR", "description": null }]
+    }));
+    let (pinax_server, pinax) = pinax_responding(pinax_agreeing, 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 1)
+        .register_with_priority(pinax, 5)
+        .with_cross_check(true);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata found");
+    assert_eq!(metadata.name.as_deref(), Some("moralis nft"));
+    assert_eq!(lookup.provider, Some(ApiProvider::Pinax));
+    assert!(lookup.conflict.is_none());
+    moralis_server.verify().await;
+    pinax_server.verify().await;
+}
+
+/// In merge mode every provider is consulted and each contributes the fields
+/// the higher-priority answers lack
+#[tokio::test]
+async fn merge_mode_combines_fields_of_every_provider() {
+    let address = Address::from([0x5e; 20]);
+    let moralis_with_supply = ResponseTemplate::new(200).set_body_json(json!({
+        "total": 10000,
+        "result": [{
+            "token_address": address.to_string(),
+            "token_id": "1",
+            "contract_type": "ERC721",
+            "name": "Moralis NFT",
+            "symbol": "MOR"
+        }]
+    }));
+    let (moralis_server, moralis) = moralis_responding(address, moralis_with_supply, 1).await;
+    let (pinax_server, pinax) = pinax_responding(pinax_metadata(), 1).await;
+    let registry = ApiRegistry::new()
+        .register_with_priority(moralis, 1)
+        .register_with_priority(pinax, 5)
+        .with_merge_mode(MetadataMergeMode::Merge);
+
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata found");
+    // Pinax answers first, so its name and symbol win
+    assert_eq!(metadata.name.as_deref(), Some("Pinax NFT"));
+    assert_eq!(metadata.symbol.as_deref(), Some("PNX"));
+    // Moralis fills what Pinax does not know
+    assert_eq!(metadata.total_supply.as_deref(), Some("10000"));
+    assert_eq!(metadata.contract_type, Some(ContractType::Erc721));
+    assert_eq!(lookup.providers_consulted, 2);
+    assert_eq!(lookup.provider, Some(ApiProvider::Pinax));
+    assert!(lookup.conflict.is_none());
+
+    // The merged metadata is cached
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    let metadata = lookup.result.unwrap().expect("metadata cached");
+    assert_eq!(metadata.total_supply.as_deref(), Some("10000"));
+    assert_eq!(lookup.providers_consulted, 0);
+    moralis_server.verify().await;
+    pinax_server.verify().await;
+}
+
+/// Repeated failures open the breaker, which skips the provider until a probe succeeds
+#[tokio::test]
+async fn circuit_breaker_skips_failing_client_until_cooldown() {
+    let address = Address::from([0x5b; 20]);
+    let moralis_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{address}")))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&moralis_server)
+        .await;
+    let registry = ApiRegistry::with_clients(Some(moralis_client(&moralis_server)), None)
+        .with_circuit_breaker(CircuitBreakerConfig {
+            failure_threshold: 3,
+            failure_window: Duration::from_secs(60),
+            cooldown: Duration::from_millis(300),
+        });
+    let received = || async {
+        moralis_server
+            .received_requests()
+            .await
+            .expect("request recording enabled")
+            .len()
+    };
+
+    for _ in 0..3 {
+        let lookup = registry
+            .lookup_contract_metadata(address, ChainId::Ethereum)
+            .await;
+        assert!(matches!(
+            lookup.result,
+            Err(RegistryError::AllClientsFailed { .. })
+        ));
+    }
+    assert_eq!(
+        registry.circuit_breaker_state(&ApiProvider::Moralis),
+        Some(BreakerState::Open)
+    );
+    assert_eq!(registry.open_circuit_breakers(), vec!["moralis"]);
+
+    // While open, lookups fail fast without reaching the provider
+    let calls = received().await;
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert!(matches!(
+        lookup.result,
+        Err(RegistryError::NoHealthyClients)
+    ));
+    assert_eq!(lookup.providers_consulted, 0);
+    assert_eq!(received().await, calls);
+
+    // After the cooldown a successful probe closes the breaker
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/nft/{address}")))
+        .respond_with(moralis_metadata(address))
+        .with_priority(1)
+        .mount(&moralis_server)
+        .await;
+    let lookup = registry
+        .lookup_contract_metadata(address, ChainId::Ethereum)
+        .await;
+    assert!(lookup.result.unwrap().is_some());
+    assert_eq!(lookup.providers_consulted, 1);
+    assert_eq!(
+        registry.circuit_breaker_state(&ApiProvider::Moralis),
+        Some(BreakerState::Closed)
+    );
+    assert!(registry.open_circuit_breakers().is_empty());
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration tests for `SimpleHashClient`
+
+use alloy_primitives::Address;
+use api_client::{ApiClient, ApiError, HealthStatus};
+use external_apis::{SimpleHashClient, SimpleHashConfig};
+use serde_json::json;
+use shared_types::ChainId;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+/// Create a test `SimpleHashClient` talking to the mock server
+fn create_test_client(base_url: String) -> SimpleHashClient {
+    SimpleHashClient::new(SimpleHashConfig {
+        base_url,
+        api_key: "test-api-key".to_string(),
+        timeout_seconds: 10,
+        connect_timeout_seconds: 1,
+        health_check_timeout_seconds: 5,
+        max_retries: 0,
+    })
+    .expect("valid SimpleHash config")
+}
+
+/// Test successful collection metadata retrieval
+#[tokio::test]
+async fn get_contract_metadata_success() {
+    let mock_server = MockServer::start().await;
+    let client = create_test_client(mock_server.uri());
+    let test_address = Address::from([0x12; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v0/nfts/collections/polygon/{test_address}"
+        )))
+        .and(header("X-API-KEY", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next_cursor": null,
+            "collections": [{
+                "collection_id": "abc123",
+                "name": "Cool Cats",
+                "external_url": "https://coolcats.example",
+                "twitter_username": "coolcats",
+                "discord_url": "https://discord.gg/coolcats",
+                "distinct_nft_count": 9999,
+                "distinct_owner_count": 4321,
+                "spam_score": 12
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let metadata = client
+        .get_contract_metadata(test_address, ChainId::Polygon)
+        .await
+        .unwrap()
+        .expect("metadata");
+
+    assert_eq!(metadata.address, test_address);
+    assert_eq!(metadata.name.as_deref(), Some("Cool Cats"));
+    assert_eq!(metadata.total_supply.as_deref(), Some("9999"));
+    assert_eq!(metadata.holder_count, Some(4321));
+    assert_eq!(metadata.twitter.as_deref(), Some("@coolcats"));
+    assert!(metadata.has_social_presence());
+    assert_eq!(metadata.additional_data["spam_score"], 12);
+}
+
+/// Test contract unknown to `SimpleHash`, either as an empty list or a 404
+#[tokio::test]
+async fn get_contract_metadata_not_found() {
+    let mock_server = MockServer::start().await;
+    let client = create_test_client(mock_server.uri());
+    let empty_address = Address::from([0x56; 20]);
+    let missing_address = Address::from([0x57; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v0/nfts/collections/ethereum/{empty_address}"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "next_cursor": null,
+            "collections": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v0/nfts/collections/ethereum/{missing_address}"
+        )))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    for address in [empty_address, missing_address] {
+        let result = client
+            .get_contract_metadata(address, ChainId::Ethereum)
+            .await
+            .unwrap();
+        assert!(result.is_none(), "{address}");
+    }
+}
+
+/// Test API authentication failure
+#[tokio::test]
+async fn get_contract_metadata_unauthorized() {
+    let mock_server = MockServer::start().await;
+    let client = create_test_client(mock_server.uri());
+    let test_address = Address::from([0x78; 20]);
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v0/nfts/collections/base/{test_address}"
+        )))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v0/chains"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    match client
+        .get_contract_metadata(test_address, ChainId::Base)
+        .await
+    {
+        Err(ApiError::Authentication { .. }) => {}
+        other => panic!("Expected Authentication error, got: {other:?}"),
+    }
+    assert!(matches!(
+        client.health_check().await,
+        Ok(HealthStatus::Down { .. })
+    ));
+}
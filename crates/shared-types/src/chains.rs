@@ -12,6 +12,8 @@ use std::{fmt, str::FromStr};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use utoipa::ToSchema;
 
+use crate::Locale;
+
 /// Supported blockchain chain identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
 pub enum ChainId {
@@ -70,13 +72,7 @@ impl ChainId {
 
     /// Returns a status message for the chain's implementation
     pub fn status_message(self) -> &'static str {
-        match self.implementation_status() {
-            ChainImplementationStatus::Full => "fully supported",
-            ChainImplementationStatus::Partial => {
-                "partially supported - some features may be limited"
-            }
-            ChainImplementationStatus::Planned => "not yet implemented",
-        }
+        Locale::En.chain_support(self.implementation_status())
     }
 
     /// Returns all supported chain IDs
@@ -108,6 +104,8 @@ impl ChainId {
                     ChainCapability::MoralisMetadata,
                     ChainCapability::PinaxAnalytics,
                     ChainCapability::SpamPrediction,
+                    ChainCapability::RealTimeUpdates,
+                    ChainCapability::TokenMetadata,
                 ]
             }
         }
@@ -267,8 +265,10 @@ pub enum ChainCapability {
     PinaxAnalytics,
     /// AI-powered spam detection
     SpamPrediction,
-    /// Real-time updates via WebSocket connections
+    /// Real-time contract status results via the `/v1/ws/status` WebSocket
     RealTimeUpdates,
+    /// Token-level NFT metadata retrieval for classifying individual tokens
+    TokenMetadata,
 }
 
 impl fmt::Display for ChainImplementationStatus {
@@ -299,6 +299,7 @@ impl fmt::Display for ChainCapability {
             Self::PinaxAnalytics => write!(f, "pinax_analytics"),
             Self::SpamPrediction => write!(f, "spam_prediction"),
             Self::RealTimeUpdates => write!(f, "realtime_updates"),
+            Self::TokenMetadata => write!(f, "token_metadata"),
         }
     }
 }
@@ -360,7 +361,15 @@ mod tests {
                 caps.contains(&ChainCapability::SpamPrediction),
                 "Chain {chain:?} missing Spam"
             );
-            assert_eq!(caps.len(), 3, "Chain {chain:?} should have 3 capabilities");
+            assert!(
+                caps.contains(&ChainCapability::TokenMetadata),
+                "Chain {chain:?} missing token metadata"
+            );
+            assert!(
+                caps.contains(&ChainCapability::RealTimeUpdates),
+                "Chain {chain:?} missing real-time updates"
+            );
+            assert_eq!(caps.len(), 5, "Chain {chain:?} should have 5 capabilities");
         }
     }
 
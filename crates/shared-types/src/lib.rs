@@ -8,7 +8,9 @@
 //! in the NFT API workspace, avoiding circular dependencies.
 
 pub mod chains;
+pub mod messages;
 pub mod spam_status;
 
 pub use chains::{ChainCapability, ChainId, ChainImplementationStatus, ChainStatus};
+pub use messages::Locale;
 pub use spam_status::ContractSpamStatus;
@@ -0,0 +1,300 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Localized catalog of human-readable status messages
+//!
+//! Messages are selected by [`Locale`], which is negotiated from the
+//! `Accept-Language` request header. English is the fallback for any locale
+//! without a translation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ChainImplementationStatus, ContractSpamStatus};
+
+/// Language used for human-readable messages
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// English
+    #[default]
+    En,
+    /// Spanish
+    Es,
+}
+
+impl Locale {
+    /// All locales with a message catalog
+    pub const SUPPORTED: [Locale; 2] = [Locale::En, Locale::Es];
+
+    /// BCP 47 language code of this locale
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Match a language tag (e.g. `es` or `es-MX`) on its primary subtag
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.trim();
+        Self::SUPPORTED
+            .into_iter()
+            .find(|locale| primary.eq_ignore_ascii_case(locale.code()))
+    }
+
+    /// Pick the preferred supported locale from an `Accept-Language` header value
+    ///
+    /// Language ranges are ranked by their `q` weight, ties keep header order.
+    /// Falls back to English when no range names a supported language.
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut best: Option<(f32, Locale)> = None;
+        for range in accept_language.split(',') {
+            let mut parts = range.split(';');
+            let Some(locale) = parts.next().and_then(Self::from_tag) else {
+                continue;
+            };
+            let weight = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if weight > 0.0 && best.is_none_or(|(best_weight, _)| weight > best_weight) {
+                best = Some((weight, locale));
+            }
+        }
+        best.map_or_else(Self::default, |(_, locale)| locale)
+    }
+
+    /// Message describing a classification status
+    pub fn status_message(self, status: &ContractSpamStatus) -> &'static str {
+        match (self, status) {
+            (Locale::En, ContractSpamStatus::Spam) => "AI analysis classified as spam",
+            (Locale::En, ContractSpamStatus::Legitimate) => "AI analysis classified as legitimate",
+            (Locale::En, ContractSpamStatus::Inconclusive) => {
+                "AI analysis was inconclusive, defaulting to not spam"
+            }
+            (Locale::En, ContractSpamStatus::NoData) => "no data found for the contract",
+            (Locale::En, ContractSpamStatus::Error | ContractSpamStatus::MetadataFetchError) => {
+                "unable to retrieve contract data from external services"
+            }
+            (Locale::En, ContractSpamStatus::PredictorError) => "AI analysis failed",
+            (Locale::En, ContractSpamStatus::PredictorUnavailable) => {
+                "AI analysis unavailable, returning contract metadata only"
+            }
+            (Locale::Es, ContractSpamStatus::Spam) => "el análisis de IA lo clasificó como spam",
+            (Locale::Es, ContractSpamStatus::Legitimate) => {
+                "el análisis de IA lo clasificó como legítimo"
+            }
+            (Locale::Es, ContractSpamStatus::Inconclusive) => {
+                "el análisis de IA no fue concluyente, se asume que no es spam"
+            }
+            (Locale::Es, ContractSpamStatus::NoData) => "no se encontraron datos para el contrato",
+            (Locale::Es, ContractSpamStatus::Error | ContractSpamStatus::MetadataFetchError) => {
+                "no se pudieron obtener los datos del contrato de los servicios externos"
+            }
+            (Locale::Es, ContractSpamStatus::PredictorError) => "el análisis de IA falló",
+            (Locale::Es, ContractSpamStatus::PredictorUnavailable) => {
+                "el análisis de IA no está disponible, solo se devuelven los metadatos del contrato"
+            }
+        }
+    }
+
+    /// Message for an analyzed contract whose metadata was found on `chain`
+    pub fn metadata_found(self, chain: &str, detail: &str) -> String {
+        match self {
+            Locale::En => format!("contract metadata found on {chain}, {detail}"),
+            Locale::Es => format!("metadatos del contrato encontrados en {chain}, {detail}"),
+        }
+    }
+
+    /// Message for a contract without metadata on `chain`
+    pub fn no_data_found(self, chain: &str) -> String {
+        format!(
+            "{} {}",
+            self.status_message(&ContractSpamStatus::NoData),
+            self.on(chain)
+        )
+    }
+
+    /// Message for a contract whose metadata could not be fetched on `chain`
+    pub fn metadata_unavailable(self, chain: &str) -> String {
+        match self {
+            Locale::En => {
+                format!("unable to retrieve contract data from external services for {chain}")
+            }
+            Locale::Es => format!(
+                "no se pudieron obtener los datos del contrato de los servicios externos para {chain}"
+            ),
+        }
+    }
+
+    /// How far a chain's implementation supports contract analysis
+    pub fn chain_support(self, status: ChainImplementationStatus) -> &'static str {
+        match (self, status) {
+            (Locale::En, ChainImplementationStatus::Full) => "fully supported",
+            (Locale::En, ChainImplementationStatus::Partial) => {
+                "partially supported - some features may be limited"
+            }
+            (Locale::En, ChainImplementationStatus::Planned) => "not yet implemented",
+            (Locale::Es, ChainImplementationStatus::Full) => "totalmente compatible",
+            (Locale::Es, ChainImplementationStatus::Partial) => {
+                "parcialmente compatible - algunas funciones pueden estar limitadas"
+            }
+            (Locale::Es, ChainImplementationStatus::Planned) => "aún no implementado",
+        }
+    }
+
+    /// Message for an analyzed contract whose metadata was found on a
+    /// partially supported `chain`
+    pub fn partial_metadata_found(self, chain: &str, detail: &str) -> String {
+        let support = self.chain_support(ChainImplementationStatus::Partial);
+        match self {
+            Locale::En => format!("contract metadata found on {chain} - {support} - {detail}"),
+            Locale::Es => {
+                format!("metadatos del contrato encontrados en {chain} - {support} - {detail}")
+            }
+        }
+    }
+
+    /// Message for a contract without metadata on a partially supported `chain`
+    pub fn partial_no_data_found(self, chain: &str) -> String {
+        format!(
+            "{} - {}",
+            self.no_data_found(chain),
+            self.chain_support(ChainImplementationStatus::Partial)
+        )
+    }
+
+    /// Message for a contract whose metadata could not be fetched on a
+    /// partially supported `chain`
+    pub fn partial_metadata_unavailable(self, chain: &str) -> String {
+        let support = self.chain_support(ChainImplementationStatus::Partial);
+        match self {
+            Locale::En => format!("unable to retrieve contract data for {chain} - {support}"),
+            Locale::Es => {
+                format!("no se pudieron obtener los datos del contrato para {chain} - {support}")
+            }
+        }
+    }
+
+    /// Message for a contract on a `chain` whose analysis is not implemented yet
+    pub fn analysis_not_implemented(self, chain: &str) -> String {
+        let support = self.chain_support(ChainImplementationStatus::Planned);
+        match self {
+            Locale::En => format!("contract analysis for {chain} is {support}"),
+            Locale::Es => format!("análisis de contratos en {chain}: {support}"),
+        }
+    }
+
+    /// Message for a classification request that failed
+    pub fn prediction_failed(self) -> &'static str {
+        match self {
+            Locale::En => "prediction failed",
+            Locale::Es => "la predicción falló",
+        }
+    }
+
+    /// Message for an address that hit the per-address timeout
+    pub fn processing_timeout(self, chain: &str, timeout_ms: u128) -> String {
+        match self {
+            Locale::En => format!("processing timeout for {chain} after {timeout_ms} ms"),
+            Locale::Es => {
+                format!("tiempo de procesamiento agotado en {chain} tras {timeout_ms} ms")
+            }
+        }
+    }
+
+    /// Message for an address still being analyzed when the latency budget ran out
+    pub fn latency_budget_exceeded(self, chain: &str, budget_ms: u128) -> String {
+        match self {
+            Locale::En => {
+                format!("analysis for {chain} exceeded the {budget_ms} ms latency budget")
+            }
+            Locale::Es => format!(
+                "el análisis en {chain} superó el presupuesto de latencia de {budget_ms} ms"
+            ),
+        }
+    }
+
+    fn on(self, chain: &str) -> String {
+        match self {
+            Locale::En => format!("on {chain}"),
+            Locale::Es => format!("en {chain}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_accept_language() {
+        assert_eq!(Locale::negotiate("es"), Locale::Es);
+        assert_eq!(Locale::negotiate("es-MX,es;q=0.9,en;q=0.8"), Locale::Es);
+        assert_eq!(Locale::negotiate("en-US,es;q=0.5"), Locale::En);
+        assert_eq!(
+            Locale::negotiate("fr;q=1.0, es;q=0.3, en;q=0.7"),
+            Locale::En
+        );
+        assert_eq!(Locale::negotiate("es;q=0"), Locale::En);
+        assert_eq!(Locale::negotiate("fr-FR, de"), Locale::En);
+        assert_eq!(Locale::negotiate("*"), Locale::En);
+        assert_eq!(Locale::negotiate(""), Locale::En);
+    }
+
+    #[test]
+    fn every_status_has_a_message_per_locale() {
+        for locale in Locale::SUPPORTED {
+            for status in [
+                ContractSpamStatus::Spam,
+                ContractSpamStatus::Legitimate,
+                ContractSpamStatus::Inconclusive,
+                ContractSpamStatus::NoData,
+                ContractSpamStatus::Error,
+                ContractSpamStatus::MetadataFetchError,
+                ContractSpamStatus::PredictorError,
+                ContractSpamStatus::PredictorUnavailable,
+            ] {
+                assert!(!locale.status_message(&status).is_empty());
+            }
+        }
+        assert_eq!(
+            Locale::Es.no_data_found("Ethereum"),
+            "no se encontraron datos para el contrato en Ethereum"
+        );
+        assert_eq!(
+            Locale::En.no_data_found("Ethereum"),
+            "no data found for the contract on Ethereum"
+        );
+    }
+
+    #[test]
+    fn partial_and_planned_chain_messages_are_localized() {
+        assert_eq!(
+            Locale::En.partial_no_data_found("Base"),
+            "no data found for the contract on Base - partially supported - some features may be limited"
+        );
+        assert_eq!(
+            Locale::Es.partial_no_data_found("Base"),
+            "no se encontraron datos para el contrato en Base - parcialmente compatible - algunas funciones pueden estar limitadas"
+        );
+        assert_eq!(
+            Locale::Es.partial_metadata_found("Base", "el análisis de IA lo clasificó como spam"),
+            "metadatos del contrato encontrados en Base - parcialmente compatible - algunas funciones pueden estar limitadas - el análisis de IA lo clasificó como spam"
+        );
+        assert!(
+            Locale::Es
+                .partial_metadata_unavailable("Base")
+                .starts_with("no se pudieron obtener los datos del contrato para Base")
+        );
+        assert_eq!(
+            Locale::En.analysis_not_implemented("Base"),
+            "contract analysis for Base is not yet implemented"
+        );
+        assert_eq!(
+            Locale::Es.analysis_not_implemented("Base"),
+            "análisis de contratos en Base: aún no implementado"
+        );
+    }
+}
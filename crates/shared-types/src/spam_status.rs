@@ -7,6 +7,8 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::Locale;
+
 /// Spam classification status for contracts
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -19,11 +21,29 @@ pub enum ContractSpamStatus {
     Inconclusive,
     /// No data available for analysis
     NoData,
-    /// Error occurred during analysis
+    /// Error occurred during analysis, such as a timeout
     Error,
+    /// Contract metadata could not be fetched from any data provider
+    MetadataFetchError,
+    /// Metadata was fetched but the spam predictor failed to classify it
+    PredictorError,
+    /// The spam predictor failed, the fetched metadata is returned unclassified
+    PredictorUnavailable,
 }
 
 impl ContractSpamStatus {
+    /// Every status, in declaration order
+    pub const ALL: [ContractSpamStatus; 8] = [
+        ContractSpamStatus::Spam,
+        ContractSpamStatus::Legitimate,
+        ContractSpamStatus::Inconclusive,
+        ContractSpamStatus::NoData,
+        ContractSpamStatus::Error,
+        ContractSpamStatus::MetadataFetchError,
+        ContractSpamStatus::PredictorError,
+        ContractSpamStatus::PredictorUnavailable,
+    ];
+
     /// Check if the status represents spam
     pub fn is_spam(&self) -> bool {
         matches!(self, ContractSpamStatus::Spam)
@@ -44,23 +64,40 @@ impl ContractSpamStatus {
         matches!(self, ContractSpamStatus::NoData)
     }
 
-    /// Check if an error occurred
+    /// Check if an error occurred, whatever its origin
     pub fn is_error(&self) -> bool {
-        matches!(self, ContractSpamStatus::Error)
+        matches!(
+            self,
+            ContractSpamStatus::Error
+                | ContractSpamStatus::MetadataFetchError
+                | ContractSpamStatus::PredictorError
+                | ContractSpamStatus::PredictorUnavailable
+        )
     }
 
-    /// Get a default message for this status
-    pub fn default_message(&self) -> &'static str {
+    /// Get the wire name of this status, matching its serialized form
+    pub fn as_str(&self) -> &'static str {
         match self {
-            ContractSpamStatus::Spam => "AI analysis classified as spam",
-            ContractSpamStatus::Legitimate => "AI analysis classified as legitimate",
-            ContractSpamStatus::Inconclusive => {
-                "AI analysis was inconclusive, defaulting to not spam"
-            }
-            ContractSpamStatus::NoData => "no data found for the contract",
-            ContractSpamStatus::Error => "unable to retrieve contract data from external services",
+            ContractSpamStatus::Spam => "spam",
+            ContractSpamStatus::Legitimate => "legitimate",
+            ContractSpamStatus::Inconclusive => "inconclusive",
+            ContractSpamStatus::NoData => "no_data",
+            ContractSpamStatus::Error => "error",
+            ContractSpamStatus::MetadataFetchError => "metadata_fetch_error",
+            ContractSpamStatus::PredictorError => "predictor_error",
+            ContractSpamStatus::PredictorUnavailable => "predictor_unavailable",
         }
     }
+
+    /// Get a default message for this status
+    pub fn default_message(&self) -> &'static str {
+        self.localized_message(Locale::En)
+    }
+
+    /// Get the message for this status in the given locale
+    pub fn localized_message(&self, locale: Locale) -> &'static str {
+        locale.status_message(self)
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +117,11 @@ mod tests {
         assert!(!ContractSpamStatus::Legitimate.is_inconclusive());
         assert!(!ContractSpamStatus::Legitimate.is_no_data());
         assert!(!ContractSpamStatus::Legitimate.is_error());
+
+        assert!(ContractSpamStatus::Error.is_error());
+        assert!(ContractSpamStatus::MetadataFetchError.is_error());
+        assert!(ContractSpamStatus::PredictorError.is_error());
+        assert!(ContractSpamStatus::PredictorUnavailable.is_error());
     }
 
     #[test]
@@ -96,6 +138,14 @@ mod tests {
             ContractSpamStatus::NoData.default_message(),
             "no data found for the contract"
         );
+        assert_eq!(
+            ContractSpamStatus::MetadataFetchError.default_message(),
+            "unable to retrieve contract data from external services"
+        );
+        assert_eq!(
+            ContractSpamStatus::PredictorError.default_message(),
+            "AI analysis failed"
+        );
     }
 
     #[test]
@@ -113,6 +163,23 @@ mod tests {
         assert_eq!(serialized, "\"no_data\"");
     }
 
+    #[test]
+    fn as_str_matches_serialized_form() {
+        for status in [
+            ContractSpamStatus::Spam,
+            ContractSpamStatus::Legitimate,
+            ContractSpamStatus::Inconclusive,
+            ContractSpamStatus::NoData,
+            ContractSpamStatus::Error,
+            ContractSpamStatus::MetadataFetchError,
+            ContractSpamStatus::PredictorError,
+            ContractSpamStatus::PredictorUnavailable,
+        ] {
+            let serialized = serde_json::to_string(&status).unwrap();
+            assert_eq!(serialized, format!("\"{}\"", status.as_str()));
+        }
+    }
+
     #[test]
     fn serde_deserialization() {
         let deserialized: ContractSpamStatus = serde_json::from_str("\"spam\"").unwrap();
@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pluggable storage for cached predictions
+//!
+//! [`SpamPredictor`](crate::SpamPredictor) reads and writes cached predictions
+//! through [`PredictionCacheBackend`]. The in-memory [`SpamCache`] is the
+//! default backend; with the `redis` feature, [`RedisCache`](crate::redis_cache::RedisCache)
+//! shares predictions across instances.
+
+use std::fmt;
+
+use futures::future::BoxFuture;
+
+use crate::{
+    cache::{PredictionCacheKey, SpamCache},
+    error::SpamPredictorResult,
+};
+
+/// Storage for cached prediction results
+///
+/// A cached result is `Some(true)` for spam, `Some(false)` for legitimate and
+/// `None` for inconclusive. Backends expire entries after their own TTL.
+pub trait PredictionCacheBackend: fmt::Debug + Send + Sync {
+    /// Short backend name used in logs
+    fn name(&self) -> &'static str;
+
+    /// Look up a cached prediction, `None` on a miss
+    fn get_prediction<'a>(
+        &'a self,
+        key: &'a PredictionCacheKey,
+    ) -> BoxFuture<'a, SpamPredictorResult<Option<Option<bool>>>>;
+
+    /// Store a prediction result
+    fn store_prediction(
+        &self,
+        key: PredictionCacheKey,
+        result: Option<bool>,
+    ) -> BoxFuture<'_, SpamPredictorResult<()>>;
+}
+
+impl PredictionCacheBackend for SpamCache {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn get_prediction<'a>(
+        &'a self,
+        key: &'a PredictionCacheKey,
+    ) -> BoxFuture<'a, SpamPredictorResult<Option<Option<bool>>>> {
+        let cached = SpamCache::get_prediction(self, key);
+        Box::pin(async move { Ok(cached) })
+    }
+
+    fn store_prediction(
+        &self,
+        key: PredictionCacheKey,
+        result: Option<bool>,
+    ) -> BoxFuture<'_, SpamPredictorResult<()>> {
+        SpamCache::store_prediction(self, key, result);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn spam_cache_serves_as_backend() {
+        let backend: &dyn PredictionCacheBackend = &SpamCache::new();
+        let key = PredictionCacheKey::new(
+            "hash".to_string(),
+            "spam_classification".to_string(),
+            "latest".to_string(),
+            "1.0.0".to_string(),
+        );
+
+        assert_eq!(backend.get_prediction(&key).await.unwrap(), None);
+        backend.store_prediction(key.clone(), None).await.unwrap();
+        assert_eq!(backend.get_prediction(&key).await.unwrap(), Some(None));
+    }
+}
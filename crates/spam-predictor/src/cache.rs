@@ -9,15 +9,94 @@
 //! improve prediction latency.
 
 use std::{
+    collections::HashMap,
+    fmt,
     hash::{Hash, Hasher},
+    sync::LazyLock,
     time::{Duration, Instant},
 };
 
 use dashmap::DashMap;
+use prometheus::{IntCounter, IntCounterVec, register_int_counter, register_int_counter_vec};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tracing::{debug, info, trace};
 
-use crate::error::SpamPredictorResult;
+use crate::{
+    config::FewShotExample,
+    error::SpamPredictorResult,
+    snapshot::{CACHE_SNAPSHOT_VERSION, CacheSnapshot, CacheSnapshotEntry},
+};
+
+/// Prediction cache evictions, labeled by `reason`
+pub static SPAM_CACHE_EVICTIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nft_api_spam_cache_evictions_total",
+        "Total number of predictions removed from the spam prediction cache, labeled by reason",
+        &["reason"]
+    )
+    .expect("Failed to create nft_api_spam_cache_evictions_total counter vec")
+});
+
+/// Prediction cache lookups answered from the cache
+pub static SPAM_CACHE_HITS: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "nft_api_spam_cache_hits_total",
+        "Total number of spam prediction cache lookups served from the cache"
+    )
+    .expect("Failed to create nft_api_spam_cache_hits_total counter")
+});
+
+/// Prediction cache lookups that found no valid entry
+pub static SPAM_CACHE_MISSES: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "nft_api_spam_cache_misses_total",
+        "Total number of spam prediction cache lookups without a valid entry"
+    )
+    .expect("Failed to create nft_api_spam_cache_misses_total counter")
+});
+
+/// Part of the cache to clear
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheScope {
+    /// Cached prediction results
+    #[default]
+    Predictions,
+    /// Cached model IDs and prompts, reloaded from the registries on demand
+    Configurations,
+    /// Predictions and configurations
+    All,
+}
+
+/// Why a prediction left the cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvictionReason {
+    /// Least recently used entry removed to make room at capacity
+    Capacity,
+    /// Entry outlived the prediction TTL
+    Expired,
+    /// Entry dropped by an explicit cache clear
+    Cleared,
+}
+
+impl EvictionReason {
+    /// Label value used in logs and metrics
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Capacity => "capacity",
+            Self::Expired => "expired",
+            Self::Cleared => "cleared",
+        }
+    }
+}
+
+impl fmt::Display for EvictionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Cache key for prediction results
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -30,6 +109,10 @@ pub struct PredictionCacheKey {
     pub model_version: String,
     /// Prompt version used
     pub prompt_version: String,
+    /// Hash of the resolved model ID and prompt content, empty when prompt
+    /// content hashing is disabled
+    #[serde(default)]
+    pub config_hash: String,
 }
 
 impl PredictionCacheKey {
@@ -45,9 +128,39 @@ impl PredictionCacheKey {
             model_type,
             model_version,
             prompt_version,
+            config_hash: String::new(),
         }
     }
 
+    /// Tie the key to the resolved model and prompt content, see [`Self::hash_config`]
+    pub fn with_config_hash(mut self, config_hash: String) -> Self {
+        self.config_hash = config_hash;
+        self
+    }
+
+    /// Stable hash of the resolved model ID, prompt text and few-shot examples
+    ///
+    /// Unlike the version strings this changes when a prompt is edited in place,
+    /// and it is stable across restarts so snapshot entries stay valid.
+    pub fn hash_config(
+        model_id: &str,
+        prompt: &str,
+        few_shot_examples: &[FewShotExample],
+    ) -> String {
+        let mut hasher = Sha256::new();
+        // Length prefixes keep adjacent fields from running into each other
+        for field in [model_id, prompt] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        for example in few_shot_examples {
+            hasher.update((example.input.len() as u64).to_le_bytes());
+            hasher.update(example.input.as_bytes());
+            hasher.update([u8::from(example.is_spam)]);
+        }
+        hex::encode(&hasher.finalize()[..16])
+    }
+
     /// Create a cache key from contract metadata
     pub fn from_metadata(
         metadata: &api_client::ContractMetadata,
@@ -81,6 +194,10 @@ impl PredictionCacheKey {
         if let Some(ref contract_type) = metadata.contract_type {
             format!("{:?}", contract_type).hash(&mut hasher);
         }
+        // Token-level classifications are cached apart from their collection
+        if let Some(token_id) = metadata.additional_data.get("token_id") {
+            token_id.to_string().hash(&mut hasher);
+        }
 
         format!("{:x}", hasher.finish())
     }
@@ -140,6 +257,8 @@ pub struct SpamCache {
     max_predictions: usize,
     /// Cache statistics
     stats: DashMap<String, u64>,
+    /// Log every eviction at info level instead of debug
+    log_evictions: bool,
 }
 
 impl Default for SpamCache {
@@ -158,6 +277,7 @@ impl SpamCache {
             prediction_ttl: Duration::from_secs(3600), // 1 hour default
             max_predictions: 10000,                    // 10k predictions max
             stats: DashMap::new(),
+            log_evictions: true,
         }
     }
 
@@ -170,6 +290,44 @@ impl SpamCache {
             prediction_ttl,
             max_predictions,
             stats: DashMap::new(),
+            log_evictions: true,
+        }
+    }
+
+    /// Log evictions with their reason at info level (the default) or only at debug level
+    pub fn with_eviction_logging(mut self, log_evictions: bool) -> Self {
+        self.log_evictions = log_evictions;
+        self
+    }
+
+    /// Count evictions towards `nft_api_spam_cache_evictions_total` and log them
+    fn record_evictions(&self, reason: EvictionReason, count: usize) {
+        if count == 0 {
+            return;
+        }
+        SPAM_CACHE_EVICTIONS
+            .with_label_values(&[reason.as_str()])
+            .inc_by(count as u64);
+        let key = format!("evictions_{}", reason);
+        self.stats
+            .entry(key)
+            .and_modify(|v| *v += count as u64)
+            .or_insert(count as u64);
+
+        if self.log_evictions {
+            info!(
+                reason = reason.as_str(),
+                evicted_entries = count,
+                remaining_predictions = self.predictions.len(),
+                "evicted prediction cache entries"
+            );
+        } else {
+            debug!(
+                reason = reason.as_str(),
+                evicted_entries = count,
+                remaining_predictions = self.predictions.len(),
+                "evicted prediction cache entries"
+            );
         }
     }
 
@@ -179,6 +337,7 @@ impl SpamCache {
             if cached.is_valid(self.prediction_ttl) {
                 cached.accessed();
                 self.increment_stat("cache_hits");
+                SPAM_CACHE_HITS.inc();
 
                 trace!(
                     "Cache hit for prediction key: metadata_hash={}, model={}:{}, prompt={}",
@@ -189,14 +348,17 @@ impl SpamCache {
             } else {
                 // Remove expired entry
                 drop(cached);
-                self.predictions.remove(key);
-                self.increment_stat("cache_expired");
+                if self.predictions.remove(key).is_some() {
+                    self.increment_stat("cache_expired");
+                    self.record_evictions(EvictionReason::Expired, 1);
+                }
 
                 debug!("Expired cache entry removed for key: {:?}", key);
             }
         }
 
         self.increment_stat("cache_misses");
+        SPAM_CACHE_MISSES.inc();
         None
     }
 
@@ -230,6 +392,63 @@ impl SpamCache {
         );
     }
 
+    /// Capture the unexpired predictions as a serializable snapshot
+    pub fn snapshot(&self) -> CacheSnapshot {
+        let entries = self
+            .predictions
+            .iter()
+            .filter(|item| item.value().is_valid(self.prediction_ttl))
+            .map(|item| CacheSnapshotEntry {
+                key: item.key().clone(),
+                result: item.value().result,
+                age_seconds: item.value().cached_at.elapsed().as_secs(),
+            })
+            .collect();
+
+        CacheSnapshot {
+            version: CACHE_SNAPSHOT_VERSION,
+            taken_at: Some(chrono::Utc::now()),
+            entries,
+        }
+    }
+
+    /// Restore predictions from a snapshot, returning the number of restored entries
+    ///
+    /// Entries count the time since the snapshot was taken towards their age.
+    /// Entries that would already be expired are skipped, existing entries are
+    /// kept, and restoring stops once the cache is full.
+    pub fn restore(&self, snapshot: CacheSnapshot) -> usize {
+        let now = Instant::now();
+        let since_snapshot = snapshot.elapsed();
+        let mut restored = 0;
+
+        for entry in snapshot.entries {
+            if self.predictions.len() >= self.max_predictions {
+                break;
+            }
+            let age = Duration::from_secs(entry.age_seconds) + since_snapshot;
+            if age >= self.prediction_ttl || self.predictions.contains_key(&entry.key) {
+                continue;
+            }
+            let Some(cached_at) = now.checked_sub(age) else {
+                continue;
+            };
+
+            self.predictions.insert(
+                entry.key,
+                CachedPrediction {
+                    result: entry.result,
+                    cached_at,
+                    access_count: 0,
+                },
+            );
+            restored += 1;
+        }
+
+        info!("Restored {} cached predictions from snapshot", restored);
+        restored
+    }
+
     /// Evict the least recently used cache entry (enhanced LRU implementation)
     fn evict_oldest_prediction(&self) {
         let mut lru_key: Option<PredictionCacheKey> = None;
@@ -265,16 +484,16 @@ impl SpamCache {
             self.increment_stat("cache_evictions");
 
             if let Some((_, entry)) = evicted_entry {
-                info!(
+                debug!(
                     metadata_hash = key.metadata_hash,
                     model_type = key.model_type,
                     model_version = key.model_version,
                     prompt_version = key.prompt_version,
                     access_count = entry.access_count,
                     age_ms = entry.cached_at.elapsed().as_millis(),
-                    remaining_predictions = self.predictions.len(),
                     "evicted lru cache entry due to capacity limit"
                 );
+                self.record_evictions(EvictionReason::Capacity, 1);
             }
         }
     }
@@ -301,17 +520,34 @@ impl SpamCache {
         self.prompts.insert(version.to_string(), prompt);
     }
 
-    /// Clear all cached predictions
-    pub fn clear_predictions(&self) {
+    /// Clear all cached predictions, returning how many were removed
+    pub fn clear_predictions(&self) -> usize {
+        let cleared = self.predictions.len();
         self.predictions.clear();
+        self.record_evictions(EvictionReason::Cleared, cleared);
         debug!("Cleared all cached predictions");
+        cleared
     }
 
-    /// Clear all cached configurations (models and prompts)
-    pub fn clear_configurations(&self) {
+    /// Clear all cached configurations (models and prompts), returning how
+    /// many entries were removed
+    pub fn clear_configurations(&self) -> usize {
+        let cleared = self.model_registry.len() + self.prompts.len();
         self.model_registry.clear();
         self.prompts.clear();
         debug!("Cleared all cached configurations");
+        cleared
+    }
+
+    /// Clear the entries in `scope`, returning how many were removed
+    ///
+    /// Unlike [`SpamCache::clear_all`], statistics are kept.
+    pub fn clear(&self, scope: CacheScope) -> usize {
+        match scope {
+            CacheScope::Predictions => self.clear_predictions(),
+            CacheScope::Configurations => self.clear_configurations(),
+            CacheScope::All => self.clear_predictions() + self.clear_configurations(),
+        }
     }
 
     /// Clear all caches
@@ -343,8 +579,9 @@ impl SpamCache {
 
         if removed_count > 0 {
             self.increment_stat("cache_expired");
+            self.record_evictions(EvictionReason::Expired, removed_count);
             let stats = self.get_stats();
-            info!(
+            debug!(
                 removed_entries = removed_count,
                 remaining_predictions = stats.prediction_count,
                 utilization_rate = stats.utilization_rate,
@@ -394,6 +631,14 @@ impl SpamCache {
             cache_stores: self.get_stat("cache_stores"),
             cache_evictions: self.get_stat("cache_evictions"),
             cache_expired: self.get_stat("cache_expired"),
+            evictions_by_reason: [
+                EvictionReason::Capacity,
+                EvictionReason::Expired,
+                EvictionReason::Cleared,
+            ]
+            .into_iter()
+            .map(|reason| (reason, self.get_stat(&format!("evictions_{}", reason))))
+            .collect(),
             hit_rate,
             utilization_rate,
             avg_access_count,
@@ -438,6 +683,7 @@ impl SpamCache {
                 "cache_expired".to_string(),
                 self.get_stat("cache_expired") + removed_count as u64,
             );
+            self.record_evictions(EvictionReason::Expired, removed_count);
 
             debug!("Cleaned up {} expired cache entries", removed_count);
         }
@@ -465,6 +711,8 @@ pub struct CacheStats {
     pub cache_evictions: u64,
     /// Number of expired cache entries
     pub cache_expired: u64,
+    /// Number of evicted predictions per eviction reason
+    pub evictions_by_reason: HashMap<EvictionReason, u64>,
     /// Cache hit rate (0.0 to 1.0)
     pub hit_rate: f64,
     /// Cache utilization rate (0.0 to 1.0)
@@ -497,6 +745,9 @@ mod tests {
             creator_address: None,
             is_verified: Some(true),
             contract_type: Some(api_client::ContractType::Erc721),
+            external_url: None,
+            twitter: None,
+            discord: None,
             additional_data: std::collections::HashMap::new(),
         }
     }
@@ -513,6 +764,47 @@ mod tests {
         assert!(!key.metadata_hash.is_empty());
     }
 
+    #[test]
+    fn prompt_text_changes_config_hash() {
+        let metadata = create_test_metadata();
+        let key = |prompt: &str| {
+            PredictionCacheKey::from_metadata(&metadata, "spam_classification", "latest", "1.0.0")
+                .with_config_hash(PredictionCacheKey::hash_config("ft:model-a", prompt, &[]))
+        };
+
+        assert_eq!(key("Classify NFTs"), key("Classify NFTs"));
+        assert_ne!(key("Classify NFTs"), key("Classify NFT contracts"));
+        assert_ne!(
+            PredictionCacheKey::hash_config("ft:model-a", "Classify NFTs", &[]),
+            PredictionCacheKey::hash_config("ft:model-b", "Classify NFTs", &[])
+        );
+        // Field boundaries are part of the hash
+        assert_ne!(
+            PredictionCacheKey::hash_config("ab", "c", &[]),
+            PredictionCacheKey::hash_config("a", "bc", &[])
+        );
+    }
+
+    #[test]
+    fn token_id_changes_metadata_hash() {
+        let collection = create_test_metadata();
+        let mut token = collection.clone();
+        token
+            .additional_data
+            .insert("token_id".to_string(), serde_json::json!("42"));
+
+        let collection_key = PredictionCacheKey::from_metadata(
+            &collection,
+            "spam_classification",
+            "latest",
+            "1.0.0",
+        );
+        let token_key =
+            PredictionCacheKey::from_metadata(&token, "spam_classification", "latest", "1.0.0");
+
+        assert_ne!(collection_key.metadata_hash, token_key.metadata_hash);
+    }
+
     #[test]
     fn prediction_caching() {
         let cache = SpamCache::new();
@@ -554,6 +846,122 @@ mod tests {
         assert_eq!(cache.get_prediction(&key), None);
     }
 
+    fn eviction_count(reason: EvictionReason) -> u64 {
+        SPAM_CACHE_EVICTIONS
+            .with_label_values(&[reason.as_str()])
+            .get()
+    }
+
+    #[test]
+    fn capacity_eviction_increments_labeled_counter() {
+        let cache = SpamCache::with_settings(Duration::from_secs(3600), 2);
+        let before = eviction_count(EvictionReason::Capacity);
+
+        for name in ["Contract1", "Contract2", "Contract3"] {
+            let mut metadata = create_test_metadata();
+            metadata.name = Some(name.to_string());
+            let key = PredictionCacheKey::from_metadata(&metadata, "test_model", "v1", "1.0.0");
+            cache.store_prediction(key, Some(false));
+        }
+
+        // The counter is process-wide, so other tests may add to it concurrently
+        assert!(eviction_count(EvictionReason::Capacity) > before);
+        let stats = cache.get_stats();
+        assert_eq!(stats.evictions_by_reason[&EvictionReason::Capacity], 1);
+        assert_eq!(stats.evictions_by_reason[&EvictionReason::Expired], 0);
+    }
+
+    #[test]
+    fn ttl_expiry_increments_labeled_counter() {
+        let cache = SpamCache::with_settings(Duration::from_millis(10), 1000);
+        let before = eviction_count(EvictionReason::Expired);
+
+        for name in ["Contract1", "Contract2"] {
+            let mut metadata = create_test_metadata();
+            metadata.name = Some(name.to_string());
+            let key = PredictionCacheKey::from_metadata(&metadata, "test_model", "v1", "1.0.0");
+            cache.store_prediction(key, Some(true));
+        }
+        thread::sleep(Duration::from_millis(15));
+        assert_eq!(cache.cleanup_expired().unwrap(), 2);
+
+        assert!(eviction_count(EvictionReason::Expired) >= before + 2);
+        let stats = cache.get_stats();
+        assert_eq!(stats.evictions_by_reason[&EvictionReason::Expired], 2);
+        assert_eq!(stats.evictions_by_reason[&EvictionReason::Capacity], 0);
+    }
+
+    #[test]
+    fn lookups_increment_hit_and_miss_counters() {
+        let cache = SpamCache::new();
+        let key =
+            PredictionCacheKey::from_metadata(&create_test_metadata(), "test_model", "v1", "1.0.0");
+        let hits_before = SPAM_CACHE_HITS.get();
+        let misses_before = SPAM_CACHE_MISSES.get();
+
+        assert_eq!(cache.get_prediction(&key), None);
+        cache.store_prediction(key.clone(), Some(true));
+        assert_eq!(cache.get_prediction(&key), Some(Some(true)));
+        assert_eq!(cache.get_prediction(&key), Some(Some(true)));
+
+        // The counters are process-wide, so other tests may add to them concurrently
+        assert!(SPAM_CACHE_HITS.get() >= hits_before + 2);
+        assert!(SPAM_CACHE_MISSES.get() > misses_before);
+        let families = prometheus::gather();
+        let exported = |name: &str| {
+            families
+                .iter()
+                .find(|family| family.name() == name)
+                .map(|family| family.get_metric()[0].get_counter().value())
+                .unwrap_or_default()
+        };
+        assert!(exported("nft_api_spam_cache_hits_total") >= (hits_before + 2) as f64);
+        assert!(exported("nft_api_spam_cache_misses_total") >= (misses_before + 1) as f64);
+    }
+
+    #[test]
+    fn clearing_predictions_records_cleared_evictions() {
+        let cache = SpamCache::new().with_eviction_logging(false);
+        let key =
+            PredictionCacheKey::from_metadata(&create_test_metadata(), "test_model", "v1", "1.0.0");
+        cache.store_prediction(key, Some(true));
+
+        assert_eq!(cache.clear_predictions(), 1);
+
+        assert_eq!(
+            cache.get_stats().evictions_by_reason[&EvictionReason::Cleared],
+            1
+        );
+    }
+
+    #[test]
+    fn clearing_by_scope_counts_removed_entries() {
+        let cache = SpamCache::new().with_eviction_logging(false);
+        let populate = || {
+            let key = PredictionCacheKey::from_metadata(
+                &create_test_metadata(),
+                "test_model",
+                "v1",
+                "1.0.0",
+            );
+            cache.store_prediction(key, Some(true));
+            cache.store_model("spam_classification", "latest", "model-id".to_string());
+            cache.store_prompt("1.0.0", "Test prompt".to_string());
+        };
+
+        populate();
+        assert_eq!(cache.clear(CacheScope::Configurations), 2);
+        assert_eq!(cache.get_prompt("1.0.0"), None);
+        assert_eq!(cache.get_stats().prediction_count, 1);
+
+        assert_eq!(cache.clear(CacheScope::Predictions), 1);
+        assert_eq!(cache.get_stats().prediction_count, 0);
+
+        populate();
+        assert_eq!(cache.clear(CacheScope::All), 3);
+        assert_eq!(cache.clear(CacheScope::All), 0);
+    }
+
     #[test]
     fn model_caching() {
         let cache = SpamCache::new();
@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sampled capture of model responses for fine-tuning datasets
+//!
+//! When enabled, a fraction of fresh predictions is appended as JSON lines to
+//! a local file, pairing the model input with the raw model response and the
+//! final classification. The file can be shipped to an object store by the
+//! deployment. Email addresses, credentials and URL query strings are redacted
+//! from every captured field before it is written.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use tracing::debug;
+
+use crate::{
+    error::{SpamPredictorError, SpamPredictorResult},
+    types::{ModelSpec, SpamClassification},
+};
+
+/// Replacement for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+// Compile regexes once at startup - safe because patterns are static
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("email regex is valid")
+});
+static SECRET_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:sk-[A-Za-z0-9_-]{16,}|bearer\s+[A-Za-z0-9._~+/=-]{16,})")
+        .expect("secret regex is valid")
+});
+static URL_QUERY_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(https?://[^\s?#]+)\?[^\s#]*").expect("URL regex is valid"));
+
+/// Redact email addresses, API keys, bearer tokens and URL query strings
+pub fn redact(text: &str) -> String {
+    let text = EMAIL_REGEX.replace_all(text, REDACTED);
+    let text = SECRET_REGEX.replace_all(&text, REDACTED);
+    URL_QUERY_REGEX
+        .replace_all(&text, format!("${{1}}?{REDACTED}"))
+        .into_owned()
+}
+
+/// A captured prediction, written as one JSON line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrainingRecord {
+    /// Time the prediction was captured
+    pub captured_at: DateTime<Utc>,
+    /// Model that produced the response
+    pub model_spec: String,
+    /// Redacted contract data sent to the model
+    pub contract_data: String,
+    /// Redacted raw model response
+    pub raw_response: String,
+    /// Final classification: `spam`, `legitimate` or `inconclusive`
+    pub final_classification: String,
+}
+
+impl TrainingRecord {
+    /// Build a redacted record for a prediction
+    pub fn new(
+        model_spec: &ModelSpec,
+        contract_data: &str,
+        raw_response: &str,
+        classification: &SpamClassification,
+    ) -> Self {
+        let final_classification = match classification {
+            SpamClassification::Spam => "spam",
+            SpamClassification::Legitimate => "legitimate",
+            SpamClassification::Inconclusive => "inconclusive",
+        };
+        Self {
+            captured_at: Utc::now(),
+            model_spec: model_spec.to_string(),
+            contract_data: redact(contract_data),
+            raw_response: redact(raw_response),
+            final_classification: final_classification.to_string(),
+        }
+    }
+}
+
+/// Sampled training-data sink appending [`TrainingRecord`]s to a JSON lines file
+#[derive(Debug)]
+pub struct TrainingCapture {
+    /// Fraction of predictions captured, between 0.0 and 1.0
+    rate: f64,
+    /// File records are appended to
+    path: PathBuf,
+    /// Serializes appends so concurrent records never interleave
+    write_lock: Mutex<()>,
+}
+
+impl TrainingCapture {
+    /// Create a sink capturing `rate` of predictions to `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `rate` is not between 0.0 and 1.0.
+    pub fn new(rate: f64, path: impl Into<PathBuf>) -> SpamPredictorResult<Self> {
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(SpamPredictorError::config(format!(
+                "training capture rate must be between 0.0 and 1.0, got {rate}"
+            )));
+        }
+        Ok(Self {
+            rate,
+            path: path.into(),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// Fraction of predictions captured
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// File records are appended to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Decide whether the current prediction is sampled
+    pub fn should_capture(&self) -> bool {
+        self.rate >= 1.0 || (self.rate > 0.0 && rand::random::<f64>() < self.rate)
+    }
+
+    /// Append a record to the capture file
+    pub async fn write(&self, record: &TrainingRecord) -> SpamPredictorResult<()> {
+        let mut line =
+            serde_json::to_vec(record).map_err(|e| SpamPredictorError::json(e.to_string()))?;
+        line.push(b'\n');
+
+        let _guard = self.write_lock.lock().await;
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| {
+                SpamPredictorError::io(format!("opening {}: {}", self.path.display(), e))
+            })?;
+        // tokio files write in the background, so flush before the handle drops
+        let written = match file.write_all(&line).await {
+            Ok(()) => file.flush().await,
+            Err(e) => Err(e),
+        };
+        written.map_err(|e| {
+            SpamPredictorError::io(format!("writing {}: {}", self.path.display(), e))
+        })?;
+
+        debug!("Captured training record to {}", self.path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_emails_secrets_and_query_strings() {
+        let text = "contact admin@example.com, key sk-abcdefghijklmnopqrstuv, \
+                    Bearer abcdefghijklmnopqrstuvwx, image https://cdn.example.com/a.png?token=s3cr3t";
+
+        assert_eq!(
+            redact(text),
+            "contact [REDACTED], key [REDACTED], [REDACTED], \
+             image https://cdn.example.com/a.png?[REDACTED]"
+        );
+    }
+
+    #[test]
+    fn redaction_keeps_contract_addresses() {
+        let text = "address: 0x1111111111111111111111111111111111111111";
+        assert_eq!(redact(text), text);
+    }
+
+    #[test]
+    fn rejects_rate_outside_unit_interval() {
+        assert!(TrainingCapture::new(1.5, "capture.jsonl").is_err());
+        assert!(TrainingCapture::new(-0.1, "capture.jsonl").is_err());
+    }
+
+    #[test]
+    fn sampling_follows_rate_bounds() {
+        let always = TrainingCapture::new(1.0, "capture.jsonl").unwrap();
+        let never = TrainingCapture::new(0.0, "capture.jsonl").unwrap();
+
+        assert!((0..100).all(|_| always.should_capture()));
+        assert!((0..100).all(|_| !never.should_capture()));
+    }
+
+    #[tokio::test]
+    async fn records_are_appended_as_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let capture = TrainingCapture::new(1.0, dir.path().join("capture.jsonl")).unwrap();
+        let spec = ModelSpec::spam_classification_latest();
+
+        capture
+            .write(&TrainingRecord::new(
+                &spec,
+                "name: Free",
+                "True",
+                &SpamClassification::Spam,
+            ))
+            .await
+            .unwrap();
+        capture
+            .write(&TrainingRecord::new(
+                &spec,
+                "name: Art",
+                "False",
+                &SpamClassification::Legitimate,
+            ))
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(capture.path()).unwrap();
+        let records: Vec<TrainingRecord> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].final_classification, "spam");
+        assert_eq!(records[1].contract_data, "name: Art");
+        assert_eq!(records[1].raw_response, "False");
+    }
+}
@@ -9,20 +9,23 @@
 //! to models and prompts with intelligent caching.
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     path::{Path, PathBuf},
     sync::Arc,
 };
 
+use api_client::{RetryPolicy, UpstreamAllowlist};
 use serde::{Deserialize, Serialize};
 use tokio::fs;
 use tracing::{debug, info, warn};
 use url::Url;
 
 use crate::{
+    backend::PredictionCacheBackend,
     cache::SpamCache,
+    capture::TrainingCapture,
     error::{SpamPredictorError, SpamPredictorResult},
-    types::{ModelSpec, ModelType, ModelVersion},
+    types::{ModelInputField, ModelSpec, ModelType, ModelVersion},
 };
 
 /// Model registry configuration loaded from YAML
@@ -35,16 +38,36 @@ pub struct ModelRegistry {
 impl ModelRegistry {
     /// Load model registry from a YAML file
     pub async fn from_file<P: AsRef<Path>>(path: P) -> SpamPredictorResult<Self> {
-        let path = path.as_ref();
+        Self::load(path.as_ref(), false).await
+    }
+
+    /// Load model registry from a YAML file, skipping invalid model entries
+    ///
+    /// See [`ModelRegistry::from_yaml_str_tolerant`] for which entries are skipped.
+    pub async fn from_file_tolerant<P: AsRef<Path>>(path: P) -> SpamPredictorResult<Self> {
+        Self::load(path.as_ref(), true).await
+    }
+
+    async fn load(path: &Path, tolerant: bool) -> SpamPredictorResult<Self> {
         debug!("Loading model registry from: {}", path.display());
 
         let content = fs::read_to_string(path).await.map_err(|e| {
             SpamPredictorError::io(format!("Failed to read {}: {}", path.display(), e))
         })?;
 
-        let registry: ModelRegistry = serde_yaml::from_str(&content).map_err(|e| {
-            SpamPredictorError::yaml(format!("Failed to parse {}: {}", path.display(), e))
-        })?;
+        let registry = if tolerant {
+            Self::from_yaml_str_tolerant(&content).map_err(|e| {
+                SpamPredictorError::model_registry(format!(
+                    "Failed to load {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?
+        } else {
+            serde_yaml::from_str(&content).map_err(|e| {
+                SpamPredictorError::yaml(format!("Failed to parse {}: {}", path.display(), e))
+            })?
+        };
 
         info!(
             "Loaded model registry with {} model types from {}",
@@ -55,6 +78,92 @@ impl ModelRegistry {
         Ok(registry)
     }
 
+    /// Parse a model registry from YAML content
+    pub fn from_yaml_str(content: &str) -> SpamPredictorResult<Self> {
+        serde_yaml::from_str(content).map_err(|e| SpamPredictorError::yaml(e.to_string()))
+    }
+
+    /// Parse a model registry from YAML content, skipping invalid model entries
+    ///
+    /// Entries whose version or model ID is not a non-empty string are logged and
+    /// skipped, as are model types that are not a mapping or have no valid entries.
+    /// Loading still fails if the document itself is malformed or a model type's
+    /// `latest` entry is invalid, since requests for the latest model could not
+    /// be served.
+    pub fn from_yaml_str_tolerant(content: &str) -> SpamPredictorResult<Self> {
+        #[derive(Deserialize)]
+        struct RawModelRegistry {
+            model_registry: HashMap<String, serde_yaml::Value>,
+        }
+
+        let raw: RawModelRegistry =
+            serde_yaml::from_str(content).map_err(|e| SpamPredictorError::yaml(e.to_string()))?;
+
+        let mut model_registry = HashMap::new();
+        for (model_type, versions) in raw.model_registry {
+            let Some(versions) = versions.as_mapping() else {
+                warn!(
+                    "Skipping model type '{}': versions must be a mapping",
+                    model_type
+                );
+                continue;
+            };
+
+            let mut valid_versions = HashMap::new();
+            for (version, model_id) in versions {
+                match (version.as_str(), model_id.as_str()) {
+                    (Some(version), Some(model_id)) if !model_id.is_empty() => {
+                        valid_versions.insert(version.to_string(), model_id.to_string());
+                    }
+                    (Some(ModelVersion::LATEST), _) => {
+                        return Err(SpamPredictorError::model_registry(format!(
+                            "Invalid '{}' model entry for model type '{}'",
+                            ModelVersion::LATEST,
+                            model_type
+                        )));
+                    }
+                    _ => warn!(
+                        "Skipping invalid model entry {:?} for model type '{}'",
+                        version, model_type
+                    ),
+                }
+            }
+
+            if valid_versions.is_empty() {
+                warn!(
+                    "Skipping model type '{}': no valid model entries",
+                    model_type
+                );
+                continue;
+            }
+            model_registry.insert(model_type, valid_versions);
+        }
+
+        Ok(Self { model_registry })
+    }
+
+    /// Model IDs containing any of the given patterns, as `(model_type, version, model_id)`
+    ///
+    /// Results are sorted so error messages are stable.
+    pub fn denylisted_models(&self, patterns: &[String]) -> Vec<(&str, &str, &str)> {
+        let mut matches: Vec<_> = self
+            .model_registry
+            .iter()
+            .flat_map(|(model_type, versions)| {
+                versions.iter().map(move |(version, model_id)| {
+                    (model_type.as_str(), version.as_str(), model_id.as_str())
+                })
+            })
+            .filter(|(_, _, model_id)| {
+                patterns
+                    .iter()
+                    .any(|pattern| !pattern.is_empty() && model_id.contains(pattern.as_str()))
+            })
+            .collect();
+        matches.sort_unstable();
+        matches
+    }
+
     /// Validate that all model IDs are properly formatted
     pub fn validate(&self) -> SpamPredictorResult<()> {
         for (model_type, versions) in &self.model_registry {
@@ -154,6 +263,84 @@ pub struct PromptVersion {
     pub description: String,
     /// The actual system message/prompt
     pub system_message: String,
+    /// Optional path to a JSON file of labeled few-shot examples, resolved
+    /// relative to the prompt registry file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub few_shot_examples_path: Option<PathBuf>,
+    /// Few-shot examples loaded from `few_shot_examples_path`
+    #[serde(skip)]
+    pub few_shot_examples: Vec<FewShotExample>,
+    /// Answer format the prompt instructs the model to produce
+    #[serde(default)]
+    pub output_format: PromptOutputFormat,
+}
+
+/// Answer format a prompt version produces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptOutputFormat {
+    /// A single `True`/`False` label
+    #[default]
+    Categorical,
+    /// A JSON probability distribution over spam, legitimate and inconclusive,
+    /// requested with JSON mode
+    Distribution,
+}
+
+/// A labeled example sent ahead of the contract data to guide base models
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FewShotExample {
+    /// Example contract data, formatted like the real model input
+    pub input: String,
+    /// Whether the example is spam
+    pub is_spam: bool,
+}
+
+impl FewShotExample {
+    /// Expected model answer for this example, matching the fine-tuned label format
+    pub fn expected_output(&self) -> &'static str {
+        if self.is_spam { "True" } else { "False" }
+    }
+}
+
+/// Load and validate few-shot examples from a JSON file
+async fn load_few_shot_examples(path: &Path) -> SpamPredictorResult<Vec<FewShotExample>> {
+    debug!("Loading few-shot examples from: {}", path.display());
+
+    let content = fs::read_to_string(path)
+        .await
+        .map_err(|e| SpamPredictorError::io(format!("Failed to read {}: {}", path.display(), e)))?;
+
+    let examples: Vec<FewShotExample> = serde_json::from_str(&content).map_err(|e| {
+        SpamPredictorError::json(format!("Failed to parse {}: {}", path.display(), e))
+    })?;
+
+    if examples.is_empty() {
+        return Err(SpamPredictorError::prompt_registry(format!(
+            "Few-shot examples file {} contains no examples",
+            path.display()
+        )));
+    }
+
+    if let Some(index) = examples.iter().position(|e| e.input.trim().is_empty()) {
+        return Err(SpamPredictorError::prompt_registry(format!(
+            "Few-shot example {} in {} has an empty input",
+            index,
+            path.display()
+        )));
+    }
+
+    Ok(examples)
+}
+
+/// Parse a prompt version identifier as a semantic version
+fn parse_prompt_semver(version: &str) -> SpamPredictorResult<semver::Version> {
+    semver::Version::parse(version).map_err(|e| {
+        SpamPredictorError::prompt_registry(format!(
+            "Prompt version '{}' is not a valid semantic version (e.g. '1.2.0'): {}",
+            version, e
+        ))
+    })
 }
 
 /// Prompt registry configuration loaded from JSON
@@ -161,24 +348,64 @@ pub struct PromptVersion {
 pub struct PromptRegistry {
     /// All available prompt versions
     pub versions: Vec<PromptVersion>,
-    /// Current/default version to use
+    /// Current/default version to use, or `latest` for the highest semantic version
     pub current_version: String,
 }
 
 impl PromptRegistry {
     /// Load prompt registry from a JSON file
     pub async fn from_file<P: AsRef<Path>>(path: P) -> SpamPredictorResult<Self> {
-        let path = path.as_ref();
+        Self::load(path.as_ref(), false).await
+    }
+
+    /// Load prompt registry from a JSON file, skipping invalid version entries
+    ///
+    /// Versions that fail to parse, fail validation or whose few-shot examples
+    /// cannot be loaded are logged and skipped. [`PromptRegistry::validate`]
+    /// still requires `current_version` to resolve among the remaining versions.
+    pub async fn from_file_tolerant<P: AsRef<Path>>(path: P) -> SpamPredictorResult<Self> {
+        Self::load(path.as_ref(), true).await
+    }
+
+    async fn load(path: &Path, tolerant: bool) -> SpamPredictorResult<Self> {
         debug!("Loading prompt registry from: {}", path.display());
 
         let content = fs::read_to_string(path).await.map_err(|e| {
             SpamPredictorError::io(format!("Failed to read {}: {}", path.display(), e))
         })?;
 
-        let registry: PromptRegistry = serde_json::from_str(&content).map_err(|e| {
+        let parsed = if tolerant {
+            Self::from_json_str_tolerant(&content)
+        } else {
+            serde_json::from_str(&content)
+        };
+        let mut registry: PromptRegistry = parsed.map_err(|e| {
             SpamPredictorError::json(format!("Failed to parse {}: {}", path.display(), e))
         })?;
 
+        // Few-shot example paths are relative to the registry file
+        let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut versions = Vec::with_capacity(registry.versions.len());
+        for mut version in registry.versions {
+            if let Some(ref examples_path) = version.few_shot_examples_path {
+                match load_few_shot_examples(&base_dir.join(examples_path)).await {
+                    Ok(examples) => version.few_shot_examples = examples,
+                    Err(e) if tolerant => {
+                        warn!("Skipping prompt version '{}': {}", version.version, e);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+                debug!(
+                    "Loaded {} few-shot examples for prompt version {}",
+                    version.few_shot_examples.len(),
+                    version.version
+                );
+            }
+            versions.push(version);
+        }
+        registry.versions = versions;
+
         info!(
             "Loaded prompt registry with {} versions from {}",
             registry.versions.len(),
@@ -188,10 +415,58 @@ impl PromptRegistry {
         Ok(registry)
     }
 
-    /// Get a prompt by version
-    pub fn get_prompt(&self, version: &str) -> SpamPredictorResult<String> {
-        let prompt_version = self
-            .versions
+    /// Parse a prompt registry from JSON content, skipping invalid version entries
+    ///
+    /// Entries that do not deserialize, fail [`PromptRegistry::validate_version`]
+    /// or duplicate an earlier version are logged and skipped.
+    pub fn from_json_str_tolerant(content: &str) -> serde_json::Result<Self> {
+        #[derive(Deserialize)]
+        struct RawPromptRegistry {
+            versions: Vec<serde_json::Value>,
+            current_version: String,
+        }
+
+        let raw: RawPromptRegistry = serde_json::from_str(content)?;
+
+        let mut versions: Vec<PromptVersion> = Vec::with_capacity(raw.versions.len());
+        for (index, entry) in raw.versions.into_iter().enumerate() {
+            let version = match serde_json::from_value::<PromptVersion>(entry) {
+                Ok(version) => version,
+                Err(e) => {
+                    warn!("Skipping prompt version entry {}: {}", index, e);
+                    continue;
+                }
+            };
+            if let Err(e) = Self::validate_version(&version) {
+                warn!("Skipping prompt version entry {}: {}", index, e);
+                continue;
+            }
+            if versions.iter().any(|v| v.version == version.version) {
+                warn!(
+                    "Skipping prompt version entry {}: duplicate version '{}'",
+                    index, version.version
+                );
+                continue;
+            }
+            versions.push(version);
+        }
+
+        Ok(Self {
+            versions,
+            current_version: raw.current_version,
+        })
+    }
+
+    /// Alias resolving to the highest semantic version among `versions`
+    pub const LATEST: &'static str = "latest";
+
+    /// Find a prompt version by identifier, resolving the `latest` alias
+    fn find_version(&self, version: &str) -> SpamPredictorResult<&PromptVersion> {
+        if version == Self::LATEST {
+            return self.latest_version();
+        }
+
+        self.versions
             .iter()
             .find(|v| v.version == version)
             .ok_or_else(|| {
@@ -199,9 +474,37 @@ impl PromptRegistry {
                     "Prompt version '{}' not found",
                     version
                 ))
-            })?;
+            })
+    }
+
+    /// Get the prompt version with the highest semantic version
+    pub fn latest_version(&self) -> SpamPredictorResult<&PromptVersion> {
+        let mut latest: Option<(semver::Version, &PromptVersion)> = None;
+        for prompt_version in &self.versions {
+            let parsed = parse_prompt_semver(&prompt_version.version)?;
+            if latest.as_ref().is_none_or(|(highest, _)| parsed > *highest) {
+                latest = Some((parsed, prompt_version));
+            }
+        }
 
-        Ok(prompt_version.system_message.clone())
+        latest
+            .map(|(_, prompt_version)| prompt_version)
+            .ok_or_else(|| {
+                SpamPredictorError::prompt_registry(format!(
+                    "Cannot resolve '{}': no prompt versions configured",
+                    Self::LATEST
+                ))
+            })
+    }
+
+    /// Resolve a version identifier (or the `latest` alias) to a concrete version
+    pub fn resolve_version(&self, version: &str) -> SpamPredictorResult<String> {
+        self.find_version(version).map(|v| v.version.clone())
+    }
+
+    /// Get a prompt by version
+    pub fn get_prompt(&self, version: &str) -> SpamPredictorResult<String> {
+        Ok(self.find_version(version)?.system_message.clone())
     }
 
     /// Get the current/default prompt
@@ -209,6 +512,18 @@ impl PromptRegistry {
         self.get_prompt(&self.current_version)
     }
 
+    /// Get the few-shot examples for a prompt version (empty if none are configured)
+    pub fn get_few_shot_examples(&self, version: &str) -> SpamPredictorResult<&[FewShotExample]> {
+        self.find_version(version)
+            .map(|v| v.few_shot_examples.as_slice())
+    }
+
+    /// Whether the prompt version produces a probability distribution
+    pub fn supports_distribution(&self, version: &str) -> bool {
+        self.find_version(version)
+            .is_ok_and(|v| v.output_format == PromptOutputFormat::Distribution)
+    }
+
     /// Get all available prompt versions
     pub fn get_versions(&self) -> Vec<String> {
         self.versions.iter().map(|v| v.version.clone()).collect()
@@ -216,51 +531,46 @@ impl PromptRegistry {
 
     /// Get prompt version metadata (without the full system message)
     pub fn get_version_info(&self, version: &str) -> Option<PromptVersionInfo> {
-        self.versions
-            .iter()
-            .find(|v| v.version == version)
-            .map(|v| PromptVersionInfo {
-                version: v.version.clone(),
-                date: v.date.clone(),
-                description: v.description.clone(),
-                message_length: v.system_message.len(),
-            })
+        self.find_version(version).ok().map(|v| PromptVersionInfo {
+            version: v.version.clone(),
+            date: v.date.clone(),
+            description: v.description.clone(),
+            message_length: v.system_message.len(),
+        })
     }
 
-    /// Validate the prompt registry
-    pub fn validate(&self) -> SpamPredictorResult<()> {
-        if self.versions.is_empty() {
+    /// Validate a single prompt version entry
+    pub fn validate_version(version: &PromptVersion) -> SpamPredictorResult<()> {
+        if version.version.is_empty() {
             return Err(SpamPredictorError::prompt_registry(
-                "No prompt versions configured".to_string(),
+                "Empty version identifier found".to_string(),
             ));
         }
 
-        // Validate that current version exists
-        if !self
-            .versions
-            .iter()
-            .any(|v| v.version == self.current_version)
-        {
+        // Prompt versions are requested and compared as semantic versions
+        parse_prompt_semver(&version.version)?;
+
+        if version.system_message.is_empty() {
             return Err(SpamPredictorError::prompt_registry(format!(
-                "Current version '{}' not found in available versions",
-                self.current_version
+                "Empty system message for version '{}'",
+                version.version
             )));
         }
 
+        Ok(())
+    }
+
+    /// Validate the prompt registry
+    pub fn validate(&self) -> SpamPredictorResult<()> {
+        if self.versions.is_empty() {
+            return Err(SpamPredictorError::prompt_registry(
+                "No prompt versions configured".to_string(),
+            ));
+        }
+
         // Validate each version
         for version in &self.versions {
-            if version.version.is_empty() {
-                return Err(SpamPredictorError::prompt_registry(
-                    "Empty version identifier found".to_string(),
-                ));
-            }
-
-            if version.system_message.is_empty() {
-                return Err(SpamPredictorError::prompt_registry(format!(
-                    "Empty system message for version '{}'",
-                    version.version
-                )));
-            }
+            Self::validate_version(version)?;
         }
 
         // Check for duplicate versions
@@ -274,6 +584,14 @@ impl PromptRegistry {
             }
         }
 
+        // Validate that current version exists (or that `latest` resolves)
+        if self.find_version(&self.current_version).is_err() {
+            return Err(SpamPredictorError::prompt_registry(format!(
+                "Current version '{}' not found in available versions",
+                self.current_version
+            )));
+        }
+
         Ok(())
     }
 }
@@ -291,6 +609,22 @@ pub struct PromptVersionInfo {
     pub message_length: usize,
 }
 
+/// Chat completions API the spam predictor talks to
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OpenAiProvider {
+    /// OpenAI or an OpenAI-compatible server (e.g. a local model behind
+    /// `base_url`), authenticated with a bearer token
+    #[default]
+    OpenAi,
+    /// Azure OpenAI: `base_url` is the resource endpoint, model IDs name
+    /// deployments and requests authenticate with the `api-key` header
+    Azure {
+        /// Azure OpenAI REST API version, e.g. `2024-06-01`
+        api_version: String,
+    },
+}
+
 /// OpenAI API configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenAiConfig {
@@ -300,12 +634,28 @@ pub struct OpenAiConfig {
     pub base_url: Option<Url>,
     /// Request timeout in seconds
     pub timeout_seconds: u64,
+    /// Connection establishment timeout in seconds
+    #[serde(default = "default_connect_timeout_seconds")]
+    pub connect_timeout_seconds: u64,
     /// Maximum number of tokens in the response
     pub max_tokens: Option<u32>,
     /// Temperature for response generation
     pub temperature: Option<f32>,
     /// Organization ID (optional)
     pub organization_id: Option<String>,
+    /// Retry and backoff policy for API requests
+    #[serde(default = "crate::openai::default_retry_policy")]
+    pub retry_policy: RetryPolicy,
+    /// Hosts the client may connect to (unrestricted when unset)
+    #[serde(default)]
+    pub upstream_allowlist: Option<UpstreamAllowlist>,
+    /// Chat completions API flavor
+    #[serde(default)]
+    pub provider: OpenAiProvider,
+}
+
+fn default_connect_timeout_seconds() -> u64 {
+    1
 }
 
 impl Default for OpenAiConfig {
@@ -314,9 +664,13 @@ impl Default for OpenAiConfig {
             api_key: String::new(),
             base_url: None,
             timeout_seconds: 30,
+            connect_timeout_seconds: default_connect_timeout_seconds(),
             max_tokens: Some(10),
             temperature: Some(0.0),
             organization_id: None,
+            retry_policy: crate::openai::default_retry_policy(),
+            upstream_allowlist: None,
+            provider: OpenAiProvider::OpenAi,
         }
     }
 }
@@ -342,6 +696,12 @@ impl OpenAiConfig {
         self
     }
 
+    /// Set the connection establishment timeout
+    pub fn with_connect_timeout(mut self, connect_timeout_seconds: u64) -> Self {
+        self.connect_timeout_seconds = connect_timeout_seconds;
+        self
+    }
+
     /// Set the maximum tokens for responses
     pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
         self.max_tokens = Some(max_tokens);
@@ -360,6 +720,24 @@ impl OpenAiConfig {
         self
     }
 
+    /// Set the retry and backoff policy
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    pub fn with_upstream_allowlist(mut self, allowlist: UpstreamAllowlist) -> Self {
+        self.upstream_allowlist = Some(allowlist);
+        self
+    }
+
+    /// Select the chat completions API flavor
+    pub fn with_provider(mut self, provider: OpenAiProvider) -> Self {
+        self.provider = provider;
+        self
+    }
+
     /// Validate the OpenAI configuration
     pub fn validate(&self) -> SpamPredictorResult<()> {
         if self.api_key.is_empty() {
@@ -368,10 +746,27 @@ impl OpenAiConfig {
             ));
         }
 
-        if !self.api_key.starts_with("sk-") && !self.api_key.starts_with("test-") {
+        // Azure keys carry no prefix
+        if self.provider == OpenAiProvider::OpenAi
+            && !self.api_key.starts_with("sk-")
+            && !self.api_key.starts_with("test-")
+        {
             warn!("OpenAI API key doesn't match expected format (should start with 'sk-')");
         }
 
+        if let OpenAiProvider::Azure { api_version } = &self.provider {
+            if self.base_url.is_none() {
+                return Err(SpamPredictorError::config(
+                    "Azure OpenAI requires base_url to be set to the resource endpoint".to_string(),
+                ));
+            }
+            if api_version.trim().is_empty() {
+                return Err(SpamPredictorError::config(
+                    "Azure OpenAI api_version cannot be empty".to_string(),
+                ));
+            }
+        }
+
         if self.timeout_seconds == 0 || self.timeout_seconds > 300 {
             return Err(SpamPredictorError::config(format!(
                 "Invalid timeout: {} seconds (must be 1-300)",
@@ -379,6 +774,14 @@ impl OpenAiConfig {
             )));
         }
 
+        if self.connect_timeout_seconds == 0 || self.connect_timeout_seconds > self.timeout_seconds
+        {
+            return Err(SpamPredictorError::config(format!(
+                "Invalid connect timeout: {} seconds (must be 1-{})",
+                self.connect_timeout_seconds, self.timeout_seconds
+            )));
+        }
+
         if let Some(max_tokens) = self.max_tokens
             && (max_tokens == 0 || max_tokens > 4096)
         {
@@ -415,6 +818,29 @@ pub struct SpamPredictorConfig {
     /// Configuration file paths for hot reloading
     pub model_registry_path: PathBuf,
     pub prompt_registry_path: PathBuf,
+    /// Skip invalid registry entries instead of failing the whole load
+    pub tolerant_load: bool,
+    /// Contract metadata fields serialized into the model input
+    pub model_input_fields: Vec<ModelInputField>,
+    /// Include a hash of the resolved model ID and prompt content in prediction
+    /// cache keys, so in-place prompt edits invalidate cached predictions
+    pub prompt_content_hashing: bool,
+    /// Model versions tried in order when the requested model has been
+    /// decommissioned by `OpenAI`
+    pub fallback_model_versions: Vec<ModelVersion>,
+    /// Models tried in order when the requested model is unavailable, e.g.
+    /// rate limited or failing with server errors
+    pub fallback_models: Vec<ModelSpec>,
+    /// Confidence, between 0.0 and 1.0, below which a spam or legitimate
+    /// answer is reported as inconclusive
+    pub confidence_threshold: f64,
+    /// Answer with deterministic classifications derived from the contract
+    /// metadata instead of calling `OpenAI`, for local development and load tests
+    pub mock_predictions: bool,
+    /// Sampled sink for fine-tuning data, disabled when `None`
+    pub training_capture: Option<Arc<TrainingCapture>>,
+    /// Storage for cached predictions, the in-memory `cache` when `None`
+    pub prediction_backend: Option<Arc<dyn PredictionCacheBackend>>,
 }
 
 impl SpamPredictorConfig {
@@ -424,6 +850,30 @@ impl SpamPredictorConfig {
         prompt_registry_path: P2,
         openai_config: OpenAiConfig,
     ) -> SpamPredictorResult<Self>
+    where
+        P1: AsRef<Path>,
+        P2: AsRef<Path>,
+    {
+        Self::from_files_with_tolerance(
+            model_registry_path,
+            prompt_registry_path,
+            openai_config,
+            false,
+        )
+        .await
+    }
+
+    /// Create configuration from file paths, optionally skipping invalid registry entries
+    ///
+    /// With `tolerant_load`, malformed model and prompt version entries are logged
+    /// and skipped as long as `latest` and the current prompt version still resolve.
+    /// Reloads use the same mode.
+    pub async fn from_files_with_tolerance<P1, P2>(
+        model_registry_path: P1,
+        prompt_registry_path: P2,
+        openai_config: OpenAiConfig,
+        tolerant_load: bool,
+    ) -> SpamPredictorResult<Self>
     where
         P1: AsRef<Path>,
         P2: AsRef<Path>,
@@ -434,11 +884,9 @@ impl SpamPredictorConfig {
         let prompt_registry_path =
             Self::validate_and_canonicalize_path(prompt_registry_path.as_ref(), "prompt registry")?;
 
-        let model_registry = ModelRegistry::from_file(&model_registry_path).await?;
-        model_registry.validate()?;
-
-        let prompt_registry = PromptRegistry::from_file(&prompt_registry_path).await?;
-        prompt_registry.validate()?;
+        let (model_registry, prompt_registry) =
+            Self::load_registries(&model_registry_path, &prompt_registry_path, tolerant_load)
+                .await?;
 
         openai_config.validate()?;
 
@@ -454,9 +902,118 @@ impl SpamPredictorConfig {
             cache,
             model_registry_path,
             prompt_registry_path,
+            tolerant_load,
+            model_input_fields: ModelInputField::default_fields(),
+            prompt_content_hashing: true,
+            fallback_model_versions: Vec::new(),
+            fallback_models: Vec::new(),
+            confidence_threshold: 0.0,
+            mock_predictions: false,
+            training_capture: None,
+            prediction_backend: None,
         })
     }
 
+    /// Replace the prediction cache, populating it with the registered models and prompts
+    pub fn with_cache(mut self, cache: SpamCache) -> Self {
+        let cache = Arc::new(cache);
+        Self::populate_cache(&cache, &self.model_registry, &self.prompt_registry);
+        self.cache = cache;
+        self
+    }
+
+    /// Enable or disable prompt content hashing in prediction cache keys
+    pub fn with_prompt_content_hashing(mut self, prompt_content_hashing: bool) -> Self {
+        self.prompt_content_hashing = prompt_content_hashing;
+        self
+    }
+
+    /// Fall back to these model versions, in order, when a model is decommissioned
+    pub fn with_fallback_model_versions(mut self, versions: Vec<ModelVersion>) -> Self {
+        self.fallback_model_versions = versions;
+        self
+    }
+
+    /// Fall back to these models, in order, when a model is unavailable
+    pub fn with_fallback_models(mut self, models: Vec<ModelSpec>) -> Self {
+        self.fallback_models = models;
+        self
+    }
+
+    /// Report spam or legitimate answers below `confidence_threshold` as inconclusive
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the threshold is not between 0.0 and 1.0.
+    pub fn with_confidence_threshold(
+        mut self,
+        confidence_threshold: f64,
+    ) -> SpamPredictorResult<Self> {
+        if !(0.0..=1.0).contains(&confidence_threshold) {
+            return Err(SpamPredictorError::config(format!(
+                "Invalid confidence_threshold: {} (must be 0.0-1.0)",
+                confidence_threshold
+            )));
+        }
+        self.confidence_threshold = confidence_threshold;
+        Ok(self)
+    }
+
+    /// Enable or disable dry-run mode, which never calls `OpenAI`
+    pub fn with_mock_predictions(mut self, mock_predictions: bool) -> Self {
+        self.mock_predictions = mock_predictions;
+        self
+    }
+
+    /// Store cached predictions in `backend` instead of the in-memory cache
+    pub fn with_prediction_backend(mut self, backend: Arc<dyn PredictionCacheBackend>) -> Self {
+        self.prediction_backend = Some(backend);
+        self
+    }
+
+    /// Storage for cached predictions
+    pub fn prediction_backend(&self) -> Arc<dyn PredictionCacheBackend> {
+        match &self.prediction_backend {
+            Some(backend) => Arc::clone(backend),
+            None => Arc::clone(&self.cache) as Arc<dyn PredictionCacheBackend>,
+        }
+    }
+
+    /// Capture a sample of predictions to a training-data sink
+    pub fn with_training_capture(mut self, capture: TrainingCapture) -> Self {
+        self.training_capture = Some(Arc::new(capture));
+        self
+    }
+
+    /// Restrict the model input to the allowlisted metadata fields
+    pub fn with_model_input_fields(mut self, model_input_fields: Vec<ModelInputField>) -> Self {
+        self.model_input_fields = model_input_fields;
+        self
+    }
+
+    /// Load and validate both registries
+    async fn load_registries(
+        model_registry_path: &Path,
+        prompt_registry_path: &Path,
+        tolerant_load: bool,
+    ) -> SpamPredictorResult<(ModelRegistry, PromptRegistry)> {
+        let (model_registry, prompt_registry) = if tolerant_load {
+            (
+                ModelRegistry::from_file_tolerant(model_registry_path).await?,
+                PromptRegistry::from_file_tolerant(prompt_registry_path).await?,
+            )
+        } else {
+            (
+                ModelRegistry::from_file(model_registry_path).await?,
+                PromptRegistry::from_file(prompt_registry_path).await?,
+            )
+        };
+        model_registry.validate()?;
+        prompt_registry.validate()?;
+
+        Ok((model_registry, prompt_registry))
+    }
+
     /// Populate the cache with all models and prompts
     fn populate_cache(
         cache: &SpamCache,
@@ -490,11 +1047,12 @@ impl SpamPredictorConfig {
     pub async fn reload(&mut self) -> SpamPredictorResult<()> {
         debug!("Reloading spam predictor configurations");
 
-        let model_registry = ModelRegistry::from_file(&self.model_registry_path).await?;
-        model_registry.validate()?;
-
-        let prompt_registry = PromptRegistry::from_file(&self.prompt_registry_path).await?;
-        prompt_registry.validate()?;
+        let (model_registry, prompt_registry) = Self::load_registries(
+            &self.model_registry_path,
+            &self.prompt_registry_path,
+            self.tolerant_load,
+        )
+        .await?;
 
         // Clear old cached configurations
         self.cache.clear_configurations();
@@ -546,6 +1104,16 @@ impl SpamPredictorConfig {
         Ok(prompt)
     }
 
+    /// Get the few-shot examples configured for a prompt version
+    pub fn get_few_shot_examples(&self, version: &str) -> SpamPredictorResult<&[FewShotExample]> {
+        self.prompt_registry.get_few_shot_examples(version)
+    }
+
+    /// Whether the prompt version produces a probability distribution
+    pub fn supports_distribution(&self, version: &str) -> bool {
+        self.prompt_registry.supports_distribution(version)
+    }
+
     /// Get configuration summary
     pub fn get_summary(&self) -> ConfigSummary {
         ConfigSummary {
@@ -556,6 +1124,16 @@ impl SpamPredictorConfig {
                 .into_iter()
                 .map(|t| t.as_str().to_string())
                 .collect(),
+            model_versions: self
+                .model_registry
+                .model_registry
+                .iter()
+                .map(|(model_type, versions)| {
+                    let mut versions: Vec<String> = versions.keys().cloned().collect();
+                    versions.sort();
+                    (model_type.clone(), versions)
+                })
+                .collect(),
             prompt_versions: self.prompt_registry.get_versions(),
             current_prompt_version: self.prompt_registry.current_version.clone(),
             cache_stats: self.cache.get_stats(),
@@ -614,6 +1192,40 @@ impl SpamPredictorConfig {
     pub fn has_model_spec(&self, spec: &ModelSpec) -> bool {
         self.model_registry.has_model_spec(spec)
     }
+
+    /// Candidate model IDs for `spec`: its own model followed by the registered
+    /// fallback versions of the same model type, without duplicates
+    pub fn model_candidates(&self, spec: &ModelSpec) -> SpamPredictorResult<Vec<String>> {
+        let mut candidates = vec![self.get_model(spec)?];
+        for version in &self.fallback_model_versions {
+            let fallback = ModelSpec::new(spec.model_type().clone(), version.clone());
+            match self.get_model(&fallback) {
+                Ok(model_id) if !candidates.contains(&model_id) => candidates.push(model_id),
+                Ok(_) => {}
+                Err(_) => debug!("Fallback model {} is not registered, skipping", fallback),
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// Registered fallback model to try after `spec` is unavailable
+    ///
+    /// The chain starts at the first fallback model for a model outside the
+    /// chain and otherwise continues after `spec`, so it always ends.
+    pub fn next_fallback_model(&self, spec: &ModelSpec) -> Option<&ModelSpec> {
+        let start = self
+            .fallback_models
+            .iter()
+            .position(|fallback| fallback == spec)
+            .map_or(0, |index| index + 1);
+        self.fallback_models[start..].iter().find(|fallback| {
+            let registered = self.has_model_spec(fallback);
+            if !registered {
+                debug!("Fallback model {} is not registered, skipping", fallback);
+            }
+            registered && *fallback != spec
+        })
+    }
 }
 
 /// Configuration summary for monitoring and debugging
@@ -621,6 +1233,8 @@ impl SpamPredictorConfig {
 pub struct ConfigSummary {
     /// Available model types
     pub model_types: Vec<String>,
+    /// Registered versions of each model type, sorted
+    pub model_versions: BTreeMap<String, Vec<String>>,
     /// Available prompt versions
     pub prompt_versions: Vec<String>,
     /// Current prompt version
@@ -699,6 +1313,110 @@ model_registry:
         assert_eq!(model_id, "ft:gpt-4o-2024-08-06:semiotic-labs::TEST123");
     }
 
+    #[test]
+    fn denylisted_models_match_patterns() {
+        let registry = ModelRegistry::from_yaml_str(
+            "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o:semiotic-labs::PROD\n    v0: ft:gpt-4o:semiotic-labs:test\n    v1: ft:gpt-4o:semiotic-labs::OLD1\n",
+        )
+        .unwrap();
+
+        let patterns = vec![":test".to_string(), "OLD".to_string()];
+        assert_eq!(
+            registry.denylisted_models(&patterns),
+            vec![
+                ("spam_classification", "v0", "ft:gpt-4o:semiotic-labs:test"),
+                ("spam_classification", "v1", "ft:gpt-4o:semiotic-labs::OLD1"),
+            ]
+        );
+        assert!(registry.denylisted_models(&[]).is_empty());
+        assert!(registry.denylisted_models(&[String::new()]).is_empty());
+    }
+
+    /// Write registries that each contain one malformed entry
+    async fn create_partially_valid_registries(
+        current_version: &str,
+    ) -> (TempDir, PathBuf, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let model_path = temp_dir.path().join("models.yaml");
+        let prompt_path = temp_dir.path().join("prompts.json");
+
+        write(
+            &model_path,
+            r#"
+model_registry:
+  spam_classification:
+    latest: ft:gpt-4o-2024-08-06:semiotic-labs::TEST123
+    v1: ft:gpt-4o-2024-08-06:semiotic-labs::TEST123
+    v0: [not, a, model, id]
+"#,
+        )
+        .await
+        .unwrap();
+
+        let prompts = serde_json::json!({
+            "versions": [
+                {
+                    "version": "1.0.0",
+                    "date": "2025-04-29",
+                    "description": "Initial version",
+                    "system_message": "Classify NFT contracts as spam or legitimate."
+                },
+                {
+                    "version": "1.1.0",
+                    "date": "2025-05-01",
+                    "description": "Missing system message"
+                }
+            ],
+            "current_version": current_version
+        });
+        write(&prompt_path, prompts.to_string()).await.unwrap();
+
+        (temp_dir, model_path, prompt_path)
+    }
+
+    #[tokio::test]
+    async fn tolerant_load_skips_invalid_registry_entries() {
+        let (_temp_dir, model_path, prompt_path) = create_partially_valid_registries("1.0.0").await;
+        let openai_config = OpenAiConfig::new("sk-test-key".to_string());
+
+        let strict =
+            SpamPredictorConfig::from_files(&model_path, &prompt_path, openai_config.clone()).await;
+        assert!(strict.is_err());
+
+        let config = SpamPredictorConfig::from_files_with_tolerance(
+            &model_path,
+            &prompt_path,
+            openai_config,
+            true,
+        )
+        .await
+        .unwrap();
+
+        let models = &config.model_registry.model_registry["spam_classification"];
+        assert_eq!(models.len(), 2);
+        assert!(!models.contains_key("v0"));
+        assert_eq!(config.prompt_registry.get_versions(), vec!["1.0.0"]);
+        assert!(config.get_prompt("1.0.0").is_ok());
+    }
+
+    #[tokio::test]
+    async fn tolerant_load_requires_current_version_and_latest_model() {
+        let (_temp_dir, model_path, prompt_path) = create_partially_valid_registries("1.1.0").await;
+        let result = SpamPredictorConfig::from_files_with_tolerance(
+            &model_path,
+            &prompt_path,
+            OpenAiConfig::new("sk-test-key".to_string()),
+            true,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let result = ModelRegistry::from_yaml_str_tolerant(
+            "model_registry:\n  spam_classification:\n    latest: ''\n    v1: ft:gpt-4o:semiotic-labs::PROD\n",
+        );
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn load_prompt_registry() {
         let (_temp_dir, file_path) = create_test_prompt_registry().await;
@@ -714,6 +1432,114 @@ model_registry:
         assert!(current_prompt.contains("blockchain technology"));
     }
 
+    fn prompt_registry_with_versions(versions: &[&str], current_version: &str) -> PromptRegistry {
+        PromptRegistry {
+            versions: versions
+                .iter()
+                .map(|version| PromptVersion {
+                    version: (*version).to_string(),
+                    date: "2025-01-01".to_string(),
+                    description: format!("Prompt {}", version),
+                    system_message: format!("System message {}", version),
+                    few_shot_examples_path: None,
+                    few_shot_examples: Vec::new(),
+                    output_format: PromptOutputFormat::default(),
+                })
+                .collect(),
+            current_version: current_version.to_string(),
+        }
+    }
+
+    #[test]
+    fn latest_prompt_alias_resolves_to_highest_semver() {
+        let registry =
+            prompt_registry_with_versions(&["1.2.0", "1.10.0", "1.9.3", "0.9.0"], "latest");
+        assert!(registry.validate().is_ok());
+
+        // Compared as semantic versions, not strings
+        assert_eq!(registry.latest_version().unwrap().version, "1.10.0");
+        assert_eq!(registry.resolve_version("latest").unwrap(), "1.10.0");
+        assert_eq!(
+            registry.get_current_prompt().unwrap(),
+            "System message 1.10.0"
+        );
+        assert_eq!(
+            registry.get_prompt("1.2.0").unwrap(),
+            "System message 1.2.0"
+        );
+        assert_eq!(
+            registry.get_version_info("latest").unwrap().version,
+            "1.10.0"
+        );
+    }
+
+    #[test]
+    fn latest_prompt_alias_rejects_non_semver_versions() {
+        let registry = prompt_registry_with_versions(&["1.0.0", "v2-beta"], "latest");
+
+        let error = registry.validate().unwrap_err().to_string();
+        assert!(error.contains("'v2-beta' is not a valid semantic version"));
+
+        let error = registry.get_current_prompt().unwrap_err().to_string();
+        assert!(error.contains("'v2-beta' is not a valid semantic version"));
+    }
+
+    #[tokio::test]
+    async fn load_prompt_registry_with_few_shot_examples() {
+        let temp_dir = TempDir::new().unwrap();
+        write(
+            temp_dir.path().join("examples.json"),
+            r#"[{"input": "NFT Details:\nName: Free Mint", "is_spam": true}]"#,
+        )
+        .await
+        .unwrap();
+        let file_path = temp_dir.path().join("prompts.json");
+        write(
+            &file_path,
+            r#"{
+    "versions": [
+        {
+            "version": "1.0.0",
+            "date": "2025-04-29",
+            "description": "Initial version",
+            "system_message": "Classify as spam or legitimate."
+        },
+        {
+            "version": "2.0.0",
+            "date": "2025-06-01",
+            "description": "Few-shot version",
+            "system_message": "Classify as spam or legitimate.",
+            "few_shot_examples_path": "examples.json"
+        }
+    ],
+    "current_version": "2.0.0"
+}"#,
+        )
+        .await
+        .unwrap();
+
+        let registry = PromptRegistry::from_file(&file_path).await.unwrap();
+        assert!(registry.get_few_shot_examples("1.0.0").unwrap().is_empty());
+
+        let examples = registry.get_few_shot_examples("2.0.0").unwrap();
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].is_spam);
+        assert_eq!(examples[0].expected_output(), "True");
+        assert!(registry.get_few_shot_examples("9.9.9").is_err());
+
+        // An empty examples file is rejected at load time
+        write(temp_dir.path().join("examples.json"), "[]")
+            .await
+            .unwrap();
+        assert!(PromptRegistry::from_file(&file_path).await.is_err());
+
+        // As is a missing one
+        tokio::fs::remove_file(temp_dir.path().join("examples.json"))
+            .await
+            .unwrap();
+        assert!(PromptRegistry::from_file(&file_path).await.is_err());
+    }
+
     #[tokio::test]
     async fn full_configuration() {
         let (_temp_dir1, model_path) = create_test_model_registry().await;
@@ -756,6 +1582,19 @@ model_registry:
 
         let invalid_timeout = OpenAiConfig::new("sk-test".to_string()).with_timeout(500);
         assert!(invalid_timeout.validate().is_err());
+
+        let azure =
+            OpenAiConfig::new("azure-key".to_string()).with_provider(OpenAiProvider::Azure {
+                api_version: "2024-06-01".to_string(),
+            });
+        assert!(azure.validate().is_err());
+        let azure = azure.with_base_url(Url::parse("https://example.openai.azure.com").unwrap());
+        assert!(azure.validate().is_ok());
+
+        let invalid_connect_timeout = OpenAiConfig::new("sk-test".to_string())
+            .with_timeout(5)
+            .with_connect_timeout(10);
+        assert!(invalid_connect_timeout.validate().is_err());
     }
 
     #[test]
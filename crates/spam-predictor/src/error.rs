@@ -83,6 +83,10 @@ pub enum SpamPredictorError {
     #[error("OpenAI API error: {message}")]
     OpenAi { message: String },
 
+    /// The requested model does not exist, typically a decommissioned fine-tune
+    #[error("Model {model_id} not found: {message}")]
+    ModelNotFound { model_id: String, message: String },
+
     /// HTTP request failed
     #[error("HTTP request failed: {message}")]
     Http { message: String },
@@ -165,6 +169,14 @@ impl SpamPredictorError {
         }
     }
 
+    /// Create a model not found error
+    pub fn model_not_found<M: ToString, T: ToString>(model_id: M, message: T) -> Self {
+        Self::ModelNotFound {
+            model_id: model_id.to_string(),
+            message: message.to_string(),
+        }
+    }
+
     /// Create an HTTP error
     pub fn http<T: ToString>(message: T) -> Self {
         Self::Http {
@@ -309,6 +321,7 @@ impl SpamPredictorError {
         matches!(
             self,
             SpamPredictorError::Authentication { .. }
+                | SpamPredictorError::ModelNotFound { .. }
                 | SpamPredictorError::Configuration { .. }
                 | SpamPredictorError::ModelRegistry { .. }
                 | SpamPredictorError::PromptRegistry { .. }
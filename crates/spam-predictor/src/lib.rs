@@ -25,6 +25,9 @@
 //! - [`config`]: Configuration management for models, prompts, and API settings
 //! - [`openai`]: OpenAI API client with fine-tuned model support
 //! - [`cache`]: In-memory caching layer for performance optimization
+//! - [`backend`]: Pluggable prediction cache storage, including Redis behind the `redis` feature
+//! - [`capture`]: Sampled capture of model responses for fine-tuning datasets
+//! - [`snapshot`]: Serialized cache snapshots for warm starts
 //! - [`error`]: Comprehensive error types and handling
 //!
 //! # Example Usage
@@ -61,20 +64,34 @@
 //! # }
 //! ```
 
+pub mod backend;
 pub mod cache;
+pub mod capture;
 pub mod config;
 pub mod error;
 pub mod openai;
 pub mod predictor;
+#[cfg(feature = "redis")]
+pub mod redis_cache;
+pub mod snapshot;
 pub mod types;
 
 // Re-export main types for convenience
-pub use cache::SpamCache;
-pub use config::{ModelRegistry, PromptRegistry, SpamPredictorConfig};
+pub use backend::PredictionCacheBackend;
+pub use cache::{CacheScope, EvictionReason, SpamCache};
+pub use capture::{TrainingCapture, TrainingRecord};
+pub use config::{
+    FewShotExample, ModelRegistry, OpenAiProvider, PromptOutputFormat, PromptRegistry,
+    SpamPredictorConfig,
+};
 pub use error::{SpamPredictorError, SpamPredictorResult};
-pub use openai::OpenAiClient;
+pub use openai::{AzureOpenAiClient, CompletionProvider, OpenAiClient};
 pub use predictor::SpamPredictor;
+#[cfg(feature = "redis")]
+pub use redis_cache::RedisCache;
+pub use snapshot::{CacheSnapshot, CacheSnapshotEntry};
 pub use types::{
-    ConfidenceScore, ModelSpec, ModelType, ModelVersion, NftDetailsContent, PromptVersion,
-    SpamClassification, SpamPredictionRequest, SpamPredictionResult,
+    CONTENT_FILTERED_REASONING, ConfidenceBand, ConfidenceBandThresholds, ConfidenceScore,
+    ModelInputField, ModelSpec, ModelType, ModelVersion, NftDetailsContent, PromptVersion,
+    SpamClassification, SpamDistribution, SpamPredictionRequest, SpamPredictionResult,
 };
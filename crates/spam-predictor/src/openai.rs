@@ -7,23 +7,60 @@
 //! This module provides a high-performance async client for interacting with
 //! OpenAI's API, specifically optimized for fine-tuned model inference
 //! with proper error handling, rate limiting, and response parsing.
+//!
+//! The predictor talks to a [`CompletionProvider`]: [`OpenAiClient`] serves
+//! OpenAI and OpenAI-compatible servers, [`AzureOpenAiClient`] serves Azure
+//! OpenAI deployments.
+
+use std::{
+    fmt,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
 
-use std::time::{Duration, Instant};
-
+use api_client::{RetryPolicy, UpstreamAllowlist};
+use futures::future::BoxFuture;
+use prometheus::{IntCounterVec, register_int_counter_vec};
 use reqwest::{
     Client, ClientBuilder,
-    header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue},
-};
-use serde::{Deserialize, Serialize};
-use tokio_retry::{
-    Retry,
-    strategy::{ExponentialBackoff, jitter},
+    header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue},
 };
+use serde::{Deserialize, Deserializer, Serialize};
+use tokio_retry::Retry;
 use tracing::{Span, debug, error, info, instrument, warn};
 use url::Url;
 use uuid::Uuid;
 
-use crate::error::{ErrorContext, SpamPredictorError, SpamPredictorResult};
+use crate::{
+    config::FewShotExample,
+    error::{ErrorContext, SpamPredictorError, SpamPredictorResult},
+    types::SpamDistribution,
+};
+
+/// Finish reason OpenAI reports when its content filter withheld the completion
+const CONTENT_FILTER_FINISH_REASON: &str = "content_filter";
+
+/// Completions withheld by the provider's content filter, labeled by `model`
+pub static OPENAI_CONTENT_FILTERED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "nft_api_openai_content_filtered_total",
+        "Total number of OpenAI completions withheld by the provider content filter, labeled by model",
+        &["model"]
+    )
+    .expect("Failed to create nft_api_openai_content_filtered_total counter vec")
+});
+
+/// Header Azure OpenAI reads the API key from
+const AZURE_API_KEY_HEADER: HeaderName = HeaderName::from_static("api-key");
+
+/// Header selecting the OpenAI organization requests are billed to
+const OPENAI_ORGANIZATION_HEADER: HeaderName = HeaderName::from_static("openai-organization");
+
+/// Connection establishment timeout used unless configured otherwise
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Most likely alternatives requested per output token with `logprobs`
+const TOP_LOGPROBS: u8 = 1;
 
 /// OpenAI Chat Completion API request
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +79,31 @@ struct ChatCompletionRequest {
     stop: Option<Vec<String>>,
     /// Whether to stream the response
     stream: bool,
+    /// Constrains the output format, e.g. JSON mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response_format: Option<ResponseFormat>,
+    /// Return the log probability of each output token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    logprobs: Option<bool>,
+    /// Number of most likely alternatives to return per output token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    top_logprobs: Option<u8>,
+}
+
+/// Output format constraint for a chat completion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ResponseFormat {
+    /// Format type (e.g. "json_object")
+    r#type: String,
+}
+
+impl ResponseFormat {
+    /// JSON mode: the model must answer with a valid JSON object
+    fn json_object() -> Self {
+        Self {
+            r#type: "json_object".to_string(),
+        }
+    }
 }
 
 /// A single message in the chat conversation
@@ -50,9 +112,18 @@ struct ChatMessage {
     /// Role of the message sender
     role: String,
     /// Content of the message
+    #[serde(deserialize_with = "deserialize_nullable_content")]
     content: String,
 }
 
+/// Filtered completions may carry `"content": null`; treat it as empty
+fn deserialize_nullable_content<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 /// OpenAI Chat Completion API response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ChatCompletionResponse {
@@ -79,6 +150,39 @@ struct ChatChoice {
     message: ChatMessage,
     /// Reason the completion finished
     finish_reason: Option<String>,
+    /// Log probabilities of the output tokens, when requested
+    #[serde(default)]
+    logprobs: Option<ChoiceLogprobs>,
+}
+
+/// Log probability information for a completion choice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ChoiceLogprobs {
+    /// Output tokens in order; absent for refusals
+    #[serde(default)]
+    content: Option<Vec<TokenLogprob>>,
+}
+
+/// Log probability of a single output token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TokenLogprob {
+    /// The token
+    token: String,
+    /// Natural log of the token's probability
+    logprob: f64,
+}
+
+impl ChoiceLogprobs {
+    /// Probability of the first non-blank output token, which carries the
+    /// classification of a single-token answer
+    fn classification_probability(&self) -> Option<f64> {
+        self.content
+            .as_deref()?
+            .iter()
+            .find(|token| !token.token.trim().is_empty())
+            .map(|token| token.logprob.exp())
+            .filter(|probability| (0.0..=1.0).contains(probability))
+    }
 }
 
 /// Token usage statistics
@@ -117,12 +221,20 @@ pub struct PredictionResult {
     pub is_spam: Option<bool>,
     /// Raw response from the model
     pub raw_response: String,
-    /// Confidence score (if available)
-    pub confidence: Option<f32>,
+    /// Probability of the classification token, when the answer was recognized
+    /// and `OpenAI` returned logprobs
+    pub confidence: Option<f64>,
     /// Token usage for this prediction
     pub token_usage: Option<TokenUsage>,
     /// Model used for the prediction
     pub model: String,
+    /// Whether the provider's content filter withheld the completion
+    pub content_filtered: bool,
+}
+
+/// Default retry policy for OpenAI requests: the first attempt plus 3 retries
+pub fn default_retry_policy() -> RetryPolicy {
+    RetryPolicy::default().with_max_attempts(4)
 }
 
 /// OpenAI API client for spam prediction
@@ -132,17 +244,23 @@ pub struct OpenAiClient {
     client: Client,
     /// Base URL for OpenAI API
     base_url: Url,
-    /// API key for authentication
-    #[allow(dead_code)]
-    // Stored for potential future use in error recovery or re-authentication
+    /// API key for authentication, used to rebuild the HTTP client
     api_key: String,
     /// Request timeout
     timeout: Duration,
+    /// Connection establishment timeout
+    connect_timeout: Duration,
+    /// Hosts the client may connect to (unrestricted when unset)
+    upstream_allowlist: Option<UpstreamAllowlist>,
+    /// Azure OpenAI REST API version; OpenAI addressing and auth when `None`
+    azure_api_version: Option<String>,
     /// Default parameters for requests
     default_max_tokens: Option<u32>,
     default_temperature: Option<f32>,
     /// Organization ID (optional)
     organization_id: Option<String>,
+    /// Retry and backoff policy for API requests
+    retry_policy: RetryPolicy,
 }
 
 impl OpenAiClient {
@@ -159,35 +277,15 @@ impl OpenAiClient {
             .unwrap_or_else(|| Url::parse(DEFAULT_API_URL).expect("default OpenAI URL is valid"));
 
         let timeout = Duration::from_secs(timeout_seconds);
-
-        // Create default headers
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", api_key)).map_err(|e| {
-                SpamPredictorError::config(format!("Invalid API key format: {}", e))
-            })?,
-        );
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
-        // if let Some(ref org_id) = organization_id {
-        //     headers.insert(
-        //         "OpenAI-Organization",
-        //         HeaderValue::from_str(org_id).map_err(|e| {
-        //             SpamPredictorError::config(format!("Invalid organization ID: {}", e))
-        //         })?,
-        //     );
-        // }
-
-        // Build HTTP client
-        let client = ClientBuilder::new()
-            .timeout(timeout)
-            .default_headers(headers)
-            .user_agent("spam-predictor/0.1.0")
-            .build()
-            .map_err(|e| {
-                SpamPredictorError::http(format!("Failed to create HTTP client: {}", e))
-            })?;
+        let client = Self::client_builder(
+            &api_key,
+            organization_id.as_deref(),
+            timeout,
+            DEFAULT_CONNECT_TIMEOUT,
+            false,
+        )?
+        .build()
+        .map_err(|e| SpamPredictorError::http(format!("Failed to create HTTP client: {}", e)))?;
 
         info!(
             "Created OpenAI client with base URL: {} and timeout: {}s",
@@ -199,12 +297,108 @@ impl OpenAiClient {
             base_url,
             api_key,
             timeout,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            upstream_allowlist: None,
+            azure_api_version: None,
             default_max_tokens: Some(10), // Short responses for spam classification
             default_temperature: Some(0.0), // Deterministic responses
             organization_id,
+            retry_policy: default_retry_policy(),
         })
     }
 
+    /// HTTP client builder with authentication headers and timeouts
+    ///
+    /// Azure OpenAI expects the key in an `api-key` header instead of a bearer
+    /// token, and has no notion of OpenAI organizations.
+    fn client_builder(
+        api_key: &str,
+        organization_id: Option<&str>,
+        timeout: Duration,
+        connect_timeout: Duration,
+        azure: bool,
+    ) -> SpamPredictorResult<ClientBuilder> {
+        // Create default headers
+        let mut headers = HeaderMap::new();
+        let (auth_header, auth_value) = if azure {
+            (AZURE_API_KEY_HEADER, api_key.to_string())
+        } else {
+            (AUTHORIZATION, format!("Bearer {}", api_key))
+        };
+        headers.insert(
+            auth_header,
+            HeaderValue::from_str(&auth_value).map_err(|e| {
+                SpamPredictorError::config(format!("Invalid API key format: {}", e))
+            })?,
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        if let Some(org_id) = organization_id.filter(|_| !azure) {
+            headers.insert(
+                OPENAI_ORGANIZATION_HEADER,
+                HeaderValue::from_str(org_id).map_err(|e| {
+                    SpamPredictorError::config(format!("Invalid organization ID: {}", e))
+                })?,
+            );
+        }
+
+        Ok(ClientBuilder::new()
+            .timeout(timeout)
+            .connect_timeout(connect_timeout)
+            .default_headers(headers)
+            .user_agent("spam-predictor/0.1.0"))
+    }
+
+    /// Restrict outbound requests to the hosts permitted by `allowlist`
+    pub fn with_upstream_allowlist(
+        mut self,
+        allowlist: &UpstreamAllowlist,
+    ) -> SpamPredictorResult<Self> {
+        allowlist
+            .check_url(&self.base_url)
+            .map_err(|e| SpamPredictorError::config(e.to_string()))?;
+
+        self.upstream_allowlist = Some(allowlist.clone());
+        self.client = self.build_client()?;
+        Ok(self)
+    }
+
+    /// Give up on establishing a connection after `connect_timeout_seconds`
+    ///
+    /// Unreachable hosts then fail fast instead of holding the request for
+    /// the whole request timeout.
+    pub fn with_connect_timeout(
+        mut self,
+        connect_timeout_seconds: u64,
+    ) -> SpamPredictorResult<Self> {
+        self.connect_timeout = Duration::from_secs(connect_timeout_seconds);
+        self.client = self.build_client()?;
+        Ok(self)
+    }
+
+    /// Rebuild the HTTP client from the current timeouts and allowlist
+    fn build_client(&self) -> SpamPredictorResult<Client> {
+        let builder = Self::client_builder(
+            &self.api_key,
+            self.organization_id.as_deref(),
+            self.timeout,
+            self.connect_timeout,
+            self.azure_api_version.is_some(),
+        )?;
+        match &self.upstream_allowlist {
+            Some(allowlist) => allowlist.apply(builder),
+            None => builder,
+        }
+        .build()
+        .map_err(|e| SpamPredictorError::http(format!("Failed to create HTTP client: {}", e)))
+    }
+
+    /// Set the retry and backoff policy for API requests
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Set default maximum tokens for responses
     pub fn with_max_tokens(mut self, max_tokens: u32) -> Self {
         self.default_max_tokens = Some(max_tokens);
@@ -217,12 +411,16 @@ impl OpenAiClient {
         self
     }
 
-    /// Predict spam status for contract metadata
-    #[instrument(skip(self, system_prompt, contract_data), fields(model = %model_id, request_id))]
-    pub async fn predict_spam(
+    /// Predict spam status, prepending few-shot examples as prior user/assistant turns
+    #[instrument(
+        skip(self, system_prompt, few_shot_examples, contract_data),
+        fields(model = %model_id, request_id)
+    )]
+    pub async fn predict_spam_with_examples(
         &self,
         model_id: &str,
         system_prompt: &str,
+        few_shot_examples: &[FewShotExample],
         contract_data: &str,
     ) -> SpamPredictorResult<PredictionResult> {
         let request_id = Uuid::new_v4();
@@ -232,45 +430,126 @@ impl OpenAiClient {
             request_id = %request_id,
             model = %model_id,
             data_length = contract_data.len(),
+            few_shot_examples = few_shot_examples.len(),
             "Starting spam prediction request"
         );
 
-        // Construct the chat messages
-        let messages = vec![
-            ChatMessage {
-                role: "system".to_string(),
-                content: system_prompt.to_string(),
-            },
-            ChatMessage {
-                role: "user".to_string(),
-                content: contract_data.to_string(),
-            },
-        ];
+        let request = ChatCompletionRequest {
+            model: model_id.to_string(),
+            messages: Self::build_messages(system_prompt, few_shot_examples, contract_data),
+            max_tokens: Some(300),
+            temperature: self.default_temperature,
+            top_p: None,
+            stop: None,
+            stream: false,
+            response_format: None,
+            logprobs: Some(true),
+            top_logprobs: Some(TOP_LOGPROBS),
+        };
+
+        let completion = self.send_completion(&request, request_id).await?;
+
+        self.process_completion_response(completion, model_id, request_id)
+            .await
+    }
+
+    /// Predict a probability distribution over spam, legitimate and inconclusive
+    ///
+    /// Requires a prompt that instructs the model to answer with a JSON object
+    /// such as `{"spam": 0.8, "legitimate": 0.15, "inconclusive": 0.05}`; the
+    /// request is sent in JSON mode so the model cannot answer in free text.
+    #[instrument(
+        skip(self, system_prompt, few_shot_examples, contract_data),
+        fields(model = %model_id, request_id)
+    )]
+    pub async fn predict_distribution_with_examples(
+        &self,
+        model_id: &str,
+        system_prompt: &str,
+        few_shot_examples: &[FewShotExample],
+        contract_data: &str,
+    ) -> SpamPredictorResult<SpamDistribution> {
+        let request_id = Uuid::new_v4();
+        Span::current().record("request_id", request_id.to_string());
+
+        info!(
+            request_id = %request_id,
+            model = %model_id,
+            data_length = contract_data.len(),
+            few_shot_examples = few_shot_examples.len(),
+            "Starting spam distribution request"
+        );
 
-        // Create the request
         let request = ChatCompletionRequest {
             model: model_id.to_string(),
-            messages,
+            messages: Self::build_messages(system_prompt, few_shot_examples, contract_data),
             max_tokens: Some(300),
             temperature: self.default_temperature,
             top_p: None,
             stop: None,
             stream: false,
+            response_format: Some(ResponseFormat::json_object()),
+            logprobs: None,
+            top_logprobs: None,
         };
 
-        // Send the request with retry logic
-        // Ensure base URL ends with slash for proper joining
-        let mut base_url = self.base_url.clone();
-        if !base_url.path().ends_with('/') {
-            base_url.set_path(&format!("{}/", base_url.path()));
+        let completion = self.send_completion(&request, request_id).await?;
+        let choice = completion.choices.first().ok_or_else(|| {
+            SpamPredictorError::invalid_response("No choices in completion response".to_string())
+        })?;
+
+        let distribution = SpamDistribution::parse(&choice.message.content)?;
+        debug!(
+            request_id = %request_id,
+            model = %model_id,
+            spam = distribution.spam,
+            legitimate = distribution.legitimate,
+            inconclusive = distribution.inconclusive,
+            "Received spam distribution from OpenAI API"
+        );
+
+        Ok(distribution)
+    }
+
+    /// Construct the chat messages: system prompt, few-shot turns, then the contract
+    fn build_messages(
+        system_prompt: &str,
+        few_shot_examples: &[FewShotExample],
+        contract_data: &str,
+    ) -> Vec<ChatMessage> {
+        let mut messages = Vec::with_capacity(2 + few_shot_examples.len() * 2);
+        messages.push(ChatMessage {
+            role: "system".to_string(),
+            content: system_prompt.to_string(),
+        });
+        for example in few_shot_examples {
+            messages.push(ChatMessage {
+                role: "user".to_string(),
+                content: example.input.clone(),
+            });
+            messages.push(ChatMessage {
+                role: "assistant".to_string(),
+                content: example.expected_output().to_string(),
+            });
         }
-        let url = base_url
-            .join("chat/completions")
-            .map_err(|e| SpamPredictorError::config(format!("Invalid base URL: {}", e)))?;
+        messages.push(ChatMessage {
+            role: "user".to_string(),
+            content: contract_data.to_string(),
+        });
+        messages
+    }
+
+    /// Send a chat completion request with retries and parse the successful response
+    async fn send_completion(
+        &self,
+        request: &ChatCompletionRequest,
+        request_id: Uuid,
+    ) -> SpamPredictorResult<ChatCompletionResponse> {
+        let url = self.completions_url(&request.model)?;
 
         let start_time = Instant::now();
         let response = self
-            .make_retryable_request(&url, &request, request_id)
+            .make_retryable_request(&url, request, request_id)
             .await?;
         let request_duration = start_time.elapsed();
 
@@ -286,18 +565,39 @@ impl OpenAiClient {
 
         if !status.is_success() {
             return self
-                .handle_error_response(status.as_u16(), &response_text)
+                .handle_error_response(status.as_u16(), &request.model, &response_text)
                 .await;
         }
 
         // Parse successful response
-        let completion: ChatCompletionResponse =
-            serde_json::from_str(&response_text).map_err(|e| {
-                SpamPredictorError::invalid_response(format!("Failed to parse response: {}", e))
-            })?;
+        serde_json::from_str(&response_text).map_err(|e| {
+            SpamPredictorError::invalid_response(format!("Failed to parse response: {}", e))
+        })
+    }
 
-        self.process_completion_response(completion, model_id, request_id)
-            .await
+    /// Chat completions endpoint for `model_id`
+    ///
+    /// OpenAI serves every model from `chat/completions` under the base URL;
+    /// Azure OpenAI serves each deployment from its own path and requires an
+    /// `api-version` query parameter.
+    fn completions_url(&self, model_id: &str) -> SpamPredictorResult<Url> {
+        // Ensure base URL ends with slash for proper joining
+        let mut base_url = self.base_url.clone();
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        let path = match &self.azure_api_version {
+            Some(_) => format!("openai/deployments/{model_id}/chat/completions"),
+            None => "chat/completions".to_string(),
+        };
+        let mut url = base_url
+            .join(&path)
+            .map_err(|e| SpamPredictorError::config(format!("Invalid base URL: {}", e)))?;
+        if let Some(api_version) = &self.azure_api_version {
+            url.query_pairs_mut()
+                .append_pair("api-version", api_version);
+        }
+        Ok(url)
     }
 
     /// Make a retryable HTTP request with exponential backoff
@@ -307,11 +607,8 @@ impl OpenAiClient {
         request: &ChatCompletionRequest,
         request_id: Uuid,
     ) -> SpamPredictorResult<reqwest::Response> {
-        // Configure retry strategy: 3 attempts with exponential backoff
-        let retry_strategy = ExponentialBackoff::from_millis(100)
-            .max_delay(Duration::from_secs(10))
-            .take(3) // Maximum 3 attempts
-            .map(jitter); // Add jitter to prevent thundering herd
+        // Shared backoff strategy with capped jitter to prevent thundering herd
+        let retry_strategy = self.retry_policy.delays();
 
         let client = &self.client;
 
@@ -375,11 +672,15 @@ impl OpenAiClient {
     }
 
     /// Handle error responses from OpenAI API
-    async fn handle_error_response(
+    ///
+    /// A 404 reporting an unknown model becomes [`SpamPredictorError::ModelNotFound`],
+    /// which is how `OpenAI` answers requests for decommissioned fine-tunes.
+    async fn handle_error_response<T>(
         &self,
         status_code: u16,
+        model: &str,
         response_text: &str,
-    ) -> SpamPredictorResult<PredictionResult> {
+    ) -> SpamPredictorResult<T> {
         // Try to parse as OpenAI error response
         if let Ok(error_response) = serde_json::from_str::<OpenAiErrorResponse>(response_text) {
             let error_msg = format!(
@@ -394,6 +695,9 @@ impl OpenAiClient {
 
             match status_code {
                 401 | 403 => Err(SpamPredictorError::authentication(error_msg)),
+                404 if error_response.error.code.as_deref() == Some("model_not_found") => Err(
+                    SpamPredictorError::model_not_found(model, error_response.error.message),
+                ),
                 429 => {
                     // Parse retry-after from rate limit errors if available
                     let retry_after = if error_response.error.message.contains("rate limit") {
@@ -411,11 +715,12 @@ impl OpenAiClient {
             let error_msg = format!("HTTP {} error: {}", status_code, response_text);
             error!("{}", error_msg);
 
+            // Other statuses reject the request itself, so they are not retryable
             match status_code {
                 401 | 403 => Err(SpamPredictorError::authentication(error_msg)),
                 429 => Err(SpamPredictorError::rate_limit(60)),
                 500..=599 => Err(SpamPredictorError::service_unavailable(error_msg)),
-                _ => Err(SpamPredictorError::http(error_msg)),
+                _ => Err(SpamPredictorError::openai(error_msg)),
             }
         }
     }
@@ -434,6 +739,27 @@ impl OpenAiClient {
         }
 
         let choice = &completion.choices[0];
+
+        // A filtered completion has no usable answer; don't try to parse it
+        if choice.finish_reason.as_deref() == Some(CONTENT_FILTER_FINISH_REASON) {
+            warn!(
+                request_id = %request_id,
+                model = %model_id,
+                classification = "filtered",
+                "OpenAI content filter withheld the completion"
+            );
+            OPENAI_CONTENT_FILTERED.with_label_values(&[model_id]).inc();
+
+            return Ok(PredictionResult {
+                is_spam: None,
+                raw_response: choice.message.content.clone(),
+                confidence: None,
+                token_usage: completion.usage,
+                model: model_id.to_string(),
+                content_filtered: true,
+            });
+        }
+
         let raw_response = choice.message.content.trim().to_lowercase();
 
         debug!(
@@ -482,12 +808,18 @@ impl OpenAiClient {
             );
         }
 
+        // Only a recognized answer has a meaningful token probability
+        let confidence = is_spam
+            .and(choice.logprobs.as_ref())
+            .and_then(ChoiceLogprobs::classification_probability);
+
         Ok(PredictionResult {
             is_spam,
             raw_response: choice.message.content.clone(),
-            confidence: None, // OpenAI doesn't provide confidence scores
+            confidence,
             token_usage: completion.usage,
             model: model_id.to_string(),
+            content_filtered: false,
         })
     }
 
@@ -558,21 +890,20 @@ impl OpenAiClient {
             top_p: None,
             stop: None,
             stream: false,
+            response_format: None,
+            logprobs: None,
+            top_logprobs: None,
         };
 
-        // Ensure base URL ends with slash for proper joining
-        let mut base_url = self.base_url.clone();
-        if !base_url.path().ends_with('/') {
-            base_url.set_path(&format!("{}/", base_url.path()));
-        }
-        let url = base_url
-            .join("chat/completions")
-            .map_err(|e| SpamPredictorError::config(format!("Invalid base URL: {}", e)))?;
+        let url = self.completions_url(&request.model)?;
 
         match self.client.post(url).json(&request).send().await {
             Ok(response) => {
-                let is_healthy = response.status().is_success() || response.status() == 400;
-                // 400 is acceptable for health check as it means API is responding
+                // 400 is acceptable for health check as it means API is responding,
+                // as is Azure's 404 for the probe model having no deployment
+                let is_healthy = response.status().is_success()
+                    || response.status() == 400
+                    || (self.azure_api_version.is_some() && response.status() == 404);
 
                 if is_healthy {
                     debug!("OpenAI API health check passed");
@@ -619,14 +950,162 @@ pub struct ClientInfo {
     pub default_temperature: Option<f32>,
 }
 
+/// Chat completions backend the spam predictor sends classification requests to
+pub trait CompletionProvider: fmt::Debug + Send + Sync {
+    /// Predict spam status for contract metadata
+    fn predict_spam<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<PredictionResult>> {
+        self.predict_spam_with_examples(model_id, system_prompt, &[], contract_data)
+    }
+
+    /// Predict spam status, prepending few-shot examples as prior user/assistant turns
+    fn predict_spam_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<PredictionResult>>;
+
+    /// Predict a probability distribution over spam, legitimate and inconclusive
+    fn predict_distribution_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<SpamDistribution>>;
+
+    /// Test the connection to the API
+    fn health_check(&self) -> BoxFuture<'_, SpamPredictorResult<bool>>;
+
+    /// Get client information for debugging
+    fn get_info(&self) -> ClientInfo;
+}
+
+impl CompletionProvider for OpenAiClient {
+    fn predict_spam_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<PredictionResult>> {
+        Box::pin(OpenAiClient::predict_spam_with_examples(
+            self,
+            model_id,
+            system_prompt,
+            few_shot_examples,
+            contract_data,
+        ))
+    }
+
+    fn predict_distribution_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<SpamDistribution>> {
+        Box::pin(OpenAiClient::predict_distribution_with_examples(
+            self,
+            model_id,
+            system_prompt,
+            few_shot_examples,
+            contract_data,
+        ))
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, SpamPredictorResult<bool>> {
+        Box::pin(OpenAiClient::health_check(self))
+    }
+
+    fn get_info(&self) -> ClientInfo {
+        OpenAiClient::get_info(self)
+    }
+}
+
+/// Azure OpenAI client
+///
+/// Speaks the same chat completions schema as [`OpenAiClient`], but addresses
+/// each model ID as a deployment under the resource endpoint
+/// (`{endpoint}/openai/deployments/{model_id}/chat/completions?api-version=...`)
+/// and authenticates with the `api-key` header.
+#[derive(Debug, Clone)]
+pub struct AzureOpenAiClient(OpenAiClient);
+
+impl AzureOpenAiClient {
+    /// Switch a configured client to Azure OpenAI addressing and authentication
+    ///
+    /// `client` must have been created with the Azure resource endpoint as its
+    /// base URL; its timeouts, allowlist and defaults are kept.
+    pub fn new(mut client: OpenAiClient, api_version: String) -> SpamPredictorResult<Self> {
+        client.azure_api_version = Some(api_version);
+        client.client = client.build_client()?;
+        info!(
+            "Using Azure OpenAI endpoint {} with API version {}",
+            client.base_url,
+            client.azure_api_version.as_deref().unwrap_or_default()
+        );
+        Ok(Self(client))
+    }
+}
+
+impl CompletionProvider for AzureOpenAiClient {
+    fn predict_spam_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<PredictionResult>> {
+        CompletionProvider::predict_spam_with_examples(
+            &self.0,
+            model_id,
+            system_prompt,
+            few_shot_examples,
+            contract_data,
+        )
+    }
+
+    fn predict_distribution_with_examples<'a>(
+        &'a self,
+        model_id: &'a str,
+        system_prompt: &'a str,
+        few_shot_examples: &'a [FewShotExample],
+        contract_data: &'a str,
+    ) -> BoxFuture<'a, SpamPredictorResult<SpamDistribution>> {
+        CompletionProvider::predict_distribution_with_examples(
+            &self.0,
+            model_id,
+            system_prompt,
+            few_shot_examples,
+            contract_data,
+        )
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, SpamPredictorResult<bool>> {
+        CompletionProvider::health_check(&self.0)
+    }
+
+    fn get_info(&self) -> ClientInfo {
+        self.0.get_info()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use wiremock::{
         Mock, MockServer, ResponseTemplate,
-        matchers::{header, method, path},
+        matchers::{body_partial_json, header, header_exists, method, path, query_param},
     };
 
     use super::*;
+    use crate::types::SpamClassification;
 
     #[tokio::test]
     async fn client_creation() {
@@ -638,6 +1117,20 @@ mod tests {
         assert_eq!(info.default_temperature, Some(0.0));
     }
 
+    #[tokio::test]
+    async fn unreachable_host_fails_within_connect_timeout() {
+        // Non-routable address: packets are dropped, so only the connect timeout ends the attempt
+        let base_url = Url::parse("http://10.255.255.1:81/v1/").unwrap();
+        let client = OpenAiClient::new("sk-test-key".to_string(), Some(base_url), 30, None)
+            .unwrap()
+            .with_connect_timeout(1)
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        assert!(!client.health_check().await.unwrap());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
     #[tokio::test]
     async fn parse_spam_responses() {
         let client = OpenAiClient::new("sk-test".to_string(), None, 30, None).unwrap();
@@ -718,6 +1211,227 @@ mod tests {
         assert!(result.token_usage.is_some());
     }
 
+    #[tokio::test]
+    async fn mock_prediction_sends_organization_header_when_configured() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("openai-organization", "org-test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "true" },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        // Without the configured organization the header must not be sent at all
+        Mock::given(header_exists("openai-organization"))
+            .and(header("authorization", "Bearer sk-no-org"))
+            .respond_with(ResponseTemplate::new(401))
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("authorization", "Bearer sk-no-org"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "false" },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = OpenAiClient::new(
+            "sk-test-key".to_string(),
+            Some(base_url.clone()),
+            30,
+            Some("org-test".to_string()),
+        )
+        .unwrap();
+        let result = client
+            .predict_spam("ft:gpt-4o-2024-08-06:test", "Classify", "Contract data")
+            .await
+            .unwrap();
+        assert_eq!(result.is_spam, Some(true));
+
+        let client = OpenAiClient::new("sk-no-org".to_string(), Some(base_url), 30, None).unwrap();
+        let result = client
+            .predict_spam("ft:gpt-4o-2024-08-06:test", "Classify", "Contract data")
+            .await
+            .unwrap();
+        assert_eq!(result.is_spam, Some(false));
+    }
+
+    #[test]
+    fn invalid_organization_id_is_a_config_error() {
+        let err = OpenAiClient::new(
+            "sk-test".to_string(),
+            None,
+            30,
+            Some("org\ntest".to_string()),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid organization ID"));
+    }
+
+    #[tokio::test]
+    async fn mock_azure_prediction_uses_deployment_url_and_api_key_header() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/openai/deployments/spam-classifier/chat/completions"))
+            .and(query_param("api-version", "2024-10-21"))
+            .and(header("api-key", "azure-test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "gpt-4o-2024-08-06",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": "true"
+                    },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        // A bearer token on an Azure request means the wrong auth style was used
+        Mock::given(header_exists("authorization"))
+            .respond_with(ResponseTemplate::new(401))
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            OpenAiClient::new("azure-test-key".to_string(), Some(base_url), 30, None).unwrap();
+        let client = AzureOpenAiClient::new(client, "2024-10-21".to_string()).unwrap();
+
+        let result = client
+            .predict_spam(
+                "spam-classifier",
+                "Classify as spam or not",
+                "Contract data here",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_spam, Some(true));
+        assert_eq!(result.model, "spam-classifier");
+        assert!(client.health_check().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn mock_content_filtered_prediction() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:filtered",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": null
+                    },
+                    "finish_reason": "content_filter"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            OpenAiClient::new("sk-test-key".to_string(), Some(base_url), 30, None).unwrap();
+        let filtered =
+            OPENAI_CONTENT_FILTERED.with_label_values(&["ft:gpt-4o-2024-08-06:filtered"]);
+        let before = filtered.get();
+
+        let result = client
+            .predict_spam(
+                "ft:gpt-4o-2024-08-06:filtered",
+                "Classify as spam or not",
+                "Contract data here",
+            )
+            .await
+            .unwrap();
+
+        assert!(result.content_filtered);
+        assert_eq!(result.is_spam, None);
+        assert_eq!(result.raw_response, "");
+        assert_eq!(filtered.get(), before + 1);
+    }
+
+    #[tokio::test]
+    async fn mock_distribution_prediction() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        // Distribution requests must be sent in JSON mode
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({
+                "response_format": { "type": "json_object" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "gpt-4o-2024-08-06",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": "{\"spam\": 0.8, \"legitimate\": 0.15, \"inconclusive\": 0.05}"
+                    },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            OpenAiClient::new("sk-test-key".to_string(), Some(base_url), 30, None).unwrap();
+
+        let distribution = client
+            .predict_distribution_with_examples(
+                "gpt-4o-2024-08-06",
+                "Answer with a JSON distribution",
+                &[],
+                "Contract data here",
+            )
+            .await
+            .unwrap();
+
+        let sum = distribution.spam + distribution.legitimate + distribution.inconclusive;
+        assert!((sum - 1.0).abs() < 1e-9);
+        assert_eq!(distribution.spam, 0.8);
+        assert_eq!(distribution.top_classification(), SpamClassification::Spam);
+    }
+
     #[tokio::test]
     async fn mock_error_response() {
         let mock_server = MockServer::start().await;
@@ -751,6 +1465,111 @@ mod tests {
         assert!(result.unwrap_err().is_auth_error());
     }
 
+    #[tokio::test]
+    async fn confidence_derived_from_classification_token_logprob() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({
+                "logprobs": true,
+                "top_logprobs": TOP_LOGPROBS
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "True" },
+                    "logprobs": {
+                        "content": [{
+                            "token": "True",
+                            "logprob": -0.105_360_515_657_826_3,
+                            "bytes": [84, 114, 117, 101],
+                            "top_logprobs": [
+                                { "token": "True", "logprob": -0.105_360_515_657_826_3, "bytes": [84, 114, 117, 101] }
+                            ]
+                        }]
+                    },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            OpenAiClient::new("sk-test-key".to_string(), Some(base_url), 30, None).unwrap();
+
+        let result = client
+            .predict_spam(
+                "ft:gpt-4o-2024-08-06:test",
+                "Classify as spam or not",
+                "Contract data here",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.is_spam, Some(true));
+        let confidence = result.confidence.expect("confidence from logprobs");
+        assert!((confidence - 0.9).abs() < 1e-9, "confidence {confidence}");
+    }
+
+    #[test]
+    fn classification_probability_skips_blank_tokens() {
+        let logprobs: ChoiceLogprobs = serde_json::from_value(serde_json::json!({
+            "content": [
+                { "token": " ", "logprob": -2.0 },
+                { "token": "false", "logprob": 0.0 }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(logprobs.classification_probability(), Some(1.0));
+
+        let refusal: ChoiceLogprobs =
+            serde_json::from_value(serde_json::json!({ "content": null })).unwrap();
+        assert_eq!(refusal.classification_probability(), None);
+    }
+
+    #[tokio::test]
+    async fn mock_model_not_found_response() {
+        let mock_server = MockServer::start().await;
+        let base_url = Url::parse(&mock_server.uri()).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {
+                    "message": "The model `ft:gpt-4o-2024-08-06:test::GONE` does not exist or you do not have access to it.",
+                    "type": "invalid_request_error",
+                    "code": "model_not_found"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            OpenAiClient::new("sk-test-key".to_string(), Some(base_url), 30, None).unwrap();
+
+        let error = client
+            .predict_spam(
+                "ft:gpt-4o-2024-08-06:test::GONE",
+                "Classify as spam or not",
+                "Contract data here",
+            )
+            .await
+            .unwrap_err();
+
+        match error {
+            SpamPredictorError::ModelNotFound { model_id, .. } => {
+                assert_eq!(model_id, "ft:gpt-4o-2024-08-06:test::GONE");
+            }
+            other => panic!("Expected ModelNotFound, got: {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn retry_logic_with_transient_failure() {
         let mock_server = MockServer::start().await;
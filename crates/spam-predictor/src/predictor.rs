@@ -8,20 +8,26 @@
 //! all components to provide high-level spam prediction functionality with
 //! caching, error handling, and observability.
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, PoisonError, RwLock},
+    time::Instant,
+};
 
 use api_client::{ContractMetadata, SpamAnalysis};
 use chrono::Utc;
 use tracing::{debug, info, instrument, warn};
 
 use crate::{
-    cache::PredictionCacheKey,
-    config::SpamPredictorConfig,
-    error::SpamPredictorResult,
-    openai::OpenAiClient,
+    backend::PredictionCacheBackend,
+    cache::{CacheScope, PredictionCacheKey, SpamCache},
+    capture::TrainingRecord,
+    config::{OpenAiProvider, SpamPredictorConfig},
+    error::{SpamPredictorError, SpamPredictorResult},
+    openai::{AzureOpenAiClient, CompletionProvider, OpenAiClient},
     types::{
-        ModelSpec, ModelType, ModelVersion, NftDetailsContent, SpamPredictionRequest,
-        SpamPredictionResult,
+        ConfidenceScore, ModelSpec, ModelType, ModelVersion, NftDetailsContent, PromptVersion,
+        SpamClassification, SpamPredictionRequest, SpamPredictionResult,
     },
 };
 
@@ -31,10 +37,14 @@ use crate::{
 /// and OpenAI API calls to provide high-performance spam prediction for NFT contracts.
 #[derive(Debug, Clone)]
 pub struct SpamPredictor {
-    /// Configuration for models, prompts, and API settings
-    config: Arc<SpamPredictorConfig>,
-    /// OpenAI API client
-    openai_client: Arc<OpenAiClient>,
+    /// Configuration for models, prompts, and API settings, replaced on reload
+    config: Arc<RwLock<Arc<SpamPredictorConfig>>>,
+    /// Prediction cache, kept across configuration reloads
+    cache: Arc<SpamCache>,
+    /// Chat completions client for the configured provider
+    openai_client: Arc<dyn CompletionProvider>,
+    /// Model IDs `OpenAI` reported as not found, skipped until the next reload
+    decommissioned_models: Arc<RwLock<BTreeSet<String>>>,
 }
 
 impl SpamPredictor {
@@ -44,31 +54,47 @@ impl SpamPredictor {
         info!("Initializing SpamPredictor");
 
         // Create OpenAI client
-        let openai_client = Arc::new(
-            OpenAiClient::new(
-                config.openai_config.api_key.clone(),
-                config.openai_config.base_url.clone(),
-                config.openai_config.timeout_seconds,
-                config.openai_config.organization_id.clone(),
-            )?
-            .with_max_tokens(config.openai_config.max_tokens.unwrap_or(10))
-            .with_temperature(config.openai_config.temperature.unwrap_or(0.0)),
-        );
+        let openai_client = OpenAiClient::new(
+            config.openai_config.api_key.clone(),
+            config.openai_config.base_url.clone(),
+            config.openai_config.timeout_seconds,
+            config.openai_config.organization_id.clone(),
+        )?
+        .with_connect_timeout(config.openai_config.connect_timeout_seconds)?
+        .with_max_tokens(config.openai_config.max_tokens.unwrap_or(10))
+        .with_temperature(config.openai_config.temperature.unwrap_or(0.0))
+        .with_retry_policy(config.openai_config.retry_policy.clone());
+        let openai_client = match &config.openai_config.upstream_allowlist {
+            Some(allowlist) => openai_client.with_upstream_allowlist(allowlist)?,
+            None => openai_client,
+        };
+        let openai_client: Arc<dyn CompletionProvider> = match &config.openai_config.provider {
+            OpenAiProvider::OpenAi => Arc::new(openai_client),
+            OpenAiProvider::Azure { api_version } => {
+                Arc::new(AzureOpenAiClient::new(openai_client, api_version.clone())?)
+            }
+        };
 
         // Test OpenAI connection
-        match openai_client.health_check().await {
-            Ok(true) => info!("OpenAI API connection verified"),
-            Ok(false) => warn!("OpenAI API health check failed, but proceeding"),
-            Err(e) => warn!("OpenAI API health check error: {}, but proceeding", e),
+        if config.mock_predictions {
+            warn!("Mock predictions enabled, OpenAI will not be called");
+        } else {
+            match openai_client.health_check().await {
+                Ok(true) => info!("OpenAI API connection verified"),
+                Ok(false) => warn!("OpenAI API health check failed, but proceeding"),
+                Err(e) => warn!("OpenAI API health check error: {}, but proceeding", e),
+            }
         }
 
         let predictor = Self {
-            config: Arc::new(config),
+            cache: Arc::clone(&config.cache),
+            config: Arc::new(RwLock::new(Arc::new(config))),
             openai_client,
+            decommissioned_models: Arc::default(),
         };
 
         // Log configuration summary
-        let summary = predictor.config.get_summary();
+        let summary = predictor.get_config_summary();
         info!(
             "SpamPredictor initialized with {} model types and {} prompt versions",
             summary.model_types.len(),
@@ -114,6 +140,10 @@ impl SpamPredictor {
             reasons.push("No contract symbol available".to_string());
         }
 
+        if !metadata.has_social_presence() {
+            reasons.push("No social presence (no website, Twitter or Discord links)".to_string());
+        }
+
         // Add AI model reasoning with typed information
         let model_spec = request.model_spec();
         if is_spam {
@@ -138,27 +168,72 @@ impl SpamPredictor {
 
     /// Prepare contract metadata for AI model input
     fn prepare_contract_data(&self, metadata: &ContractMetadata) -> SpamPredictorResult<String> {
-        let nft_content = NftDetailsContent::from_metadata(metadata);
+        let nft_content = NftDetailsContent::from_metadata_with_fields(
+            metadata,
+            &self.config().model_input_fields,
+        );
         Ok(nft_content.to_string())
     }
 
+    /// Snapshot of the current configuration
+    fn config(&self) -> Arc<SpamPredictorConfig> {
+        Arc::clone(&self.config.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
     /// Get the current configuration summary
     pub fn get_config_summary(&self) -> crate::config::ConfigSummary {
-        self.config.get_summary()
+        self.config().get_summary()
+    }
+
+    /// Whether `spec` is registered in the current model registry
+    pub fn has_model_spec(&self, spec: &ModelSpec) -> bool {
+        self.config().has_model_spec(spec)
+    }
+
+    /// Whether `version` is registered in the current prompt registry
+    pub fn has_prompt_version(&self, version: &PromptVersion) -> bool {
+        self.config().get_prompt(&version.as_str()).is_ok()
+    }
+
+    /// Prediction cache shared by this predictor
+    pub fn cache(&self) -> &Arc<SpamCache> {
+        &self.cache
     }
 
     /// Get cache statistics
     pub fn get_cache_stats(&self) -> crate::cache::CacheStats {
-        self.config.cache.get_stats()
+        self.cache.get_stats()
     }
 
-    /// Reload configuration from files (hot reload)
+    /// Clear the in-memory cache entries in `scope`, returning how many were removed
+    ///
+    /// A shared prediction backend such as Redis is left untouched.
+    pub fn clear_cache(&self, scope: CacheScope) -> usize {
+        let cleared = self.cache.clear(scope);
+        info!(?scope, cleared, "Cleared spam predictor cache");
+        cleared
+    }
+
+    /// Reload the model and prompt registries from their files (hot reload)
+    ///
+    /// Predictions already in flight finish with the configuration they started
+    /// with. On failure the current configuration stays active. A successful
+    /// reload forgets which models were found decommissioned, so a registry that
+    /// still lists them is retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either registry file cannot be loaded or is invalid.
     pub async fn reload_config(&self) -> SpamPredictorResult<()> {
         info!("Reloading spam predictor configuration");
 
-        // Note: We can't modify the Arc<SpamPredictorConfig>, so this would need
-        // to be implemented at a higher level (e.g., recreating the SpamPredictor)
-        warn!("Hot reload not implemented - requires SpamPredictor recreation");
+        let mut config = SpamPredictorConfig::clone(&self.config());
+        config.reload().await?;
+        *self.config.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(config);
+        self.decommissioned_models
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
 
         Ok(())
     }
@@ -170,12 +245,16 @@ impl SpamPredictor {
 
         let start_time = Instant::now();
 
-        // Check OpenAI API
-        let openai_healthy = match self.openai_client.health_check().await {
-            Ok(healthy) => healthy,
-            Err(e) => {
-                warn!("OpenAI health check failed: {}", e);
-                false
+        // Check OpenAI API, which mock predictions never depend on
+        let openai_healthy = if self.config().mock_predictions {
+            true
+        } else {
+            match self.openai_client.health_check().await {
+                Ok(healthy) => healthy,
+                Err(e) => {
+                    warn!("OpenAI health check failed: {}", e);
+                    false
+                }
             }
         };
 
@@ -193,6 +272,7 @@ impl SpamPredictor {
             openai_healthy,
             config_healthy,
             cache_healthy,
+            decommissioned_models: self.decommissioned_models(),
             check_duration_ms: duration.as_millis() as u64,
             cache_stats: self.get_cache_stats(),
         };
@@ -224,10 +304,10 @@ impl SpamPredictor {
             }
         };
 
+        let config = self.config();
         match (
-            self.config.get_model(&spam_classification_spec),
-            self.config
-                .get_prompt(&self.config.prompt_registry.current_version),
+            config.get_model(&spam_classification_spec),
+            config.get_prompt(&config.prompt_registry.current_version),
         ) {
             (Ok(_), Ok(_)) => true,
             (Err(e), _) => {
@@ -247,9 +327,9 @@ impl SpamPredictor {
         let test_key = "health_check";
         let test_value = "test_value".to_string();
 
-        self.config.cache.store_prompt(test_key, test_value.clone());
+        self.cache.store_prompt(test_key, test_value.clone());
 
-        match self.config.cache.get_prompt(test_key) {
+        match self.cache.get_prompt(test_key) {
             Some(value) if value == test_value => {
                 debug!("Cache health check passed");
                 true
@@ -270,7 +350,7 @@ impl SpamPredictor {
 
     /// Clean up expired cache entries
     pub async fn cleanup_cache(&self) -> SpamPredictorResult<usize> {
-        self.config.cache.cleanup_expired()
+        self.cache.cleanup_expired()
     }
 
     /// Get OpenAI client information
@@ -278,7 +358,110 @@ impl SpamPredictor {
         self.openai_client.get_info()
     }
 
+    /// Model IDs `OpenAI` reported as not found since the last reload
+    pub fn decommissioned_models(&self) -> Vec<String> {
+        self.decommissioned_models
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Resolve the model ID for `spec`, skipping decommissioned models in favour
+    /// of the configured fallback versions
+    fn resolve_model_id(
+        &self,
+        config: &SpamPredictorConfig,
+        spec: &ModelSpec,
+    ) -> SpamPredictorResult<String> {
+        let candidates = config.model_candidates(spec)?;
+        let decommissioned = self
+            .decommissioned_models
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        candidates
+            .iter()
+            .find(|model_id| !decommissioned.contains(*model_id))
+            .cloned()
+            .ok_or_else(|| {
+                SpamPredictorError::model_not_found(
+                    &candidates[0],
+                    "model and all fallback models are decommissioned",
+                )
+            })
+    }
+
+    /// Record the model of a `ModelNotFound` error as decommissioned and retry
+    /// the request with the next fallback model, if one is left
+    async fn retry_with_fallback_model(
+        &self,
+        request: SpamPredictionRequest,
+        error: SpamPredictorError,
+    ) -> SpamPredictorResult<SpamPredictionResult> {
+        let SpamPredictorError::ModelNotFound { model_id, .. } = &error else {
+            return Err(error);
+        };
+        warn!(model_id = %model_id, "Model not found, marking it as decommissioned");
+        let newly_decommissioned = self
+            .decommissioned_models
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(model_id.clone());
+
+        // Each retry decommissions another model, so the chain always ends
+        match self.resolve_model_id(&self.config(), request.model_spec()) {
+            Ok(fallback_model_id) if newly_decommissioned => {
+                info!(
+                    model_id = %model_id,
+                    fallback_model_id = %fallback_model_id,
+                    "Retrying prediction with fallback model"
+                );
+                Box::pin(self.predict_spam_typed(request)).await
+            }
+            _ => self.retry_with_fallback_spec(request, error).await,
+        }
+    }
+
+    /// Retry a request whose model is unavailable with the next configured
+    /// fallback model, if one is left
+    ///
+    /// The request is re-targeted at the fallback model, so its cache key and
+    /// result record the model that actually answered.
+    async fn retry_with_fallback_spec(
+        &self,
+        request: SpamPredictionRequest,
+        error: SpamPredictorError,
+    ) -> SpamPredictorResult<SpamPredictionResult> {
+        let unavailable =
+            error.is_retryable() || matches!(error, SpamPredictorError::ModelNotFound { .. });
+        let config = self.config();
+        let Some(fallback) = config
+            .next_fallback_model(request.model_spec())
+            .filter(|_| unavailable)
+        else {
+            warn!("OpenAI prediction failed: {}", error);
+            return Err(error);
+        };
+        warn!(
+            model_spec = %request.model_spec(),
+            fallback_model_spec = %fallback,
+            error = %error,
+            "Model unavailable, retrying prediction with fallback model"
+        );
+        Box::pin(self.predict_spam_typed(request.with_model_spec(fallback.clone()))).await
+    }
+
     /// Type-safe spam prediction with comprehensive result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model or prompt cannot be resolved, or if the
+    /// `OpenAI` request fails, rather than guessing a classification. A model
+    /// `OpenAI` no longer serves is retried with the configured fallback versions
+    /// before `ModelNotFound` is returned, and an unavailable model (rate
+    /// limited, failing or no longer served) with the configured fallback
+    /// models before its error is returned.
     #[instrument(skip(self, request), fields(
         contract_address = %request.metadata().address,
         model_spec = %request.model_spec(),
@@ -289,6 +472,7 @@ impl SpamPredictor {
         request: SpamPredictionRequest,
     ) -> SpamPredictorResult<SpamPredictionResult> {
         let start_time = Instant::now();
+        let config = self.config();
 
         debug!(
             "Starting type-safe spam prediction for contract {} using {} and prompt {}",
@@ -297,6 +481,50 @@ impl SpamPredictor {
             request.prompt_version()
         );
 
+        // Distributions are only produced by prompts written for them; other
+        // prompts fall back to the categorical answer
+        let use_distribution = request.distribution_requested()
+            && config.supports_distribution(&request.prompt_version().as_str());
+        if request.distribution_requested() && !use_distribution {
+            debug!(
+                "Prompt {} does not support distribution output, using categorical classification",
+                request.prompt_version()
+            );
+        }
+
+        // Get model ID from configuration
+        let model_id = match self.resolve_model_id(&config, request.model_spec()) {
+            Ok(id) => id,
+            Err(e) => {
+                warn!("Model lookup failed: {}", e);
+                return Err(e);
+            }
+        };
+
+        // Get prompt from configuration
+        let prompt = match config.get_prompt(&request.prompt_version().as_str()) {
+            Ok(p) => p,
+            Err(e) => {
+                warn!("Prompt lookup failed: {}", e);
+                return Err(e);
+            }
+        };
+
+        // Dry runs skip the cache so mock answers never mix with real ones
+        if config.mock_predictions {
+            return Ok(Self::mock_prediction(&request, start_time));
+        }
+
+        // Few-shot examples are optional; a prompt without them yields an empty slice
+        let few_shot_examples =
+            match config.get_few_shot_examples(&request.prompt_version().as_str()) {
+                Ok(examples) => examples,
+                Err(e) => {
+                    warn!("Few-shot example lookup failed: {}", e);
+                    &[]
+                }
+            };
+
         // Check cache first
         let cache_key = PredictionCacheKey::from_metadata(
             request.metadata(),
@@ -304,8 +532,34 @@ impl SpamPredictor {
             request.model_spec().version().as_str(),
             &request.prompt_version().as_str(),
         );
+        // Editing a prompt or remapping a model in place must not serve stale predictions
+        let cache_key = if config.prompt_content_hashing {
+            cache_key.with_config_hash(PredictionCacheKey::hash_config(
+                &model_id,
+                &prompt,
+                few_shot_examples,
+            ))
+        } else {
+            cache_key
+        };
 
-        if let Some(cached_result) = self.config.cache.get_prediction(&cache_key) {
+        // The cache only holds the top category, so distribution requests bypass it
+        let prediction_cache = config.prediction_backend();
+        let cached_result = if use_distribution {
+            None
+        } else {
+            match prediction_cache.get_prediction(&cache_key).await {
+                Ok(cached) => cached,
+                Err(e) => {
+                    warn!(
+                        backend = prediction_cache.name(),
+                        "Prediction cache lookup failed, querying the model: {}", e
+                    );
+                    None
+                }
+            }
+        };
+        if let Some(cached_result) = cached_result {
             debug!("Cache hit for prediction key: {:?}", cache_key);
             return Ok(SpamPredictionResult::new(
                 match cached_result {
@@ -321,54 +575,90 @@ impl SpamPredictor {
             ));
         }
 
-        // Get model ID from configuration
-        let model_id = match self.config.get_model(request.model_spec()) {
-            Ok(id) => id,
-            Err(e) => {
-                warn!("Model lookup failed: {}", e);
-                return Ok(SpamPredictionResult::error_fallback(
-                    request.model_spec().clone(),
-                    start_time.elapsed(),
-                ));
-            }
-        };
-
-        // Get prompt from configuration
-        let prompt = match self.config.get_prompt(&request.prompt_version().as_str()) {
-            Ok(p) => p,
-            Err(e) => {
-                warn!("Prompt lookup failed: {}", e);
-                return Ok(SpamPredictionResult::error_fallback(
-                    request.model_spec().clone(),
-                    start_time.elapsed(),
-                ));
-            }
-        };
-
         // Prepare contract data for analysis
         let contract_data = match self.prepare_contract_data(request.metadata()) {
             Ok(data) => data,
             Err(e) => {
                 warn!("Failed to prepare contract data: {}", e);
-                return Ok(SpamPredictionResult::error_fallback(
-                    request.model_spec().clone(),
-                    start_time.elapsed(),
-                ));
+                return Err(e);
             }
         };
 
+        if use_distribution {
+            match self
+                .openai_client
+                .predict_distribution_with_examples(
+                    &model_id,
+                    &prompt,
+                    few_shot_examples,
+                    &contract_data,
+                )
+                .await
+            {
+                Ok(distribution) => {
+                    let result = SpamPredictionResult::from_distribution(
+                        distribution,
+                        request.model_spec().clone(),
+                        start_time.elapsed(),
+                    );
+                    let cached = match result.classification() {
+                        crate::types::SpamClassification::Spam => Some(true),
+                        crate::types::SpamClassification::Legitimate => Some(false),
+                        crate::types::SpamClassification::Inconclusive => None,
+                    };
+                    Self::store_cached_prediction(&*prediction_cache, cache_key, cached).await;
+                    return Ok(result);
+                }
+                // A model that cannot produce a valid distribution still gets a
+                // categorical answer
+                Err(e @ SpamPredictorError::InvalidResponse { .. }) => {
+                    warn!(
+                        "Distribution prediction failed, falling back to categorical: {}",
+                        e
+                    );
+                }
+                Err(e @ SpamPredictorError::ModelNotFound { .. }) => {
+                    return self.retry_with_fallback_model(request, e).await;
+                }
+                Err(e) => return self.retry_with_fallback_spec(request, e).await,
+            }
+        }
+
         // Make prediction via OpenAI
         let prediction_result: SpamPredictorResult<crate::openai::PredictionResult> = self
             .openai_client
-            .predict_spam(&model_id, &prompt, &contract_data)
+            .predict_spam_with_examples(&model_id, &prompt, few_shot_examples, &contract_data)
             .await;
 
         let result = match prediction_result {
+            Ok(openai_result) if openai_result.content_filtered => {
+                // Not cached: the filter verdict says nothing about the contract
+                SpamPredictionResult::content_filtered(
+                    request.model_spec().clone(),
+                    start_time.elapsed(),
+                )
+            }
             Ok(openai_result) => {
-                match openai_result.is_spam {
+                // A borderline answer is not asserted either way
+                let is_spam = match (openai_result.is_spam, openai_result.confidence) {
+                    (Some(is_spam), Some(confidence))
+                        if confidence < config.confidence_threshold =>
+                    {
+                        debug!(
+                            is_spam,
+                            confidence,
+                            threshold = config.confidence_threshold,
+                            "Prediction confidence below threshold, reporting inconclusive"
+                        );
+                        None
+                    }
+                    (is_spam, _) => is_spam,
+                };
+                let result = match is_spam {
                     Some(true) => {
                         // Cache positive result
-                        self.config.cache.store_prediction(cache_key, Some(true));
+                        Self::store_cached_prediction(&*prediction_cache, cache_key, Some(true))
+                            .await;
                         SpamPredictionResult::spam(
                             request.model_spec().clone(),
                             start_time.elapsed(),
@@ -376,7 +666,8 @@ impl SpamPredictor {
                     }
                     Some(false) => {
                         // Cache negative result
-                        self.config.cache.store_prediction(cache_key, Some(false));
+                        Self::store_cached_prediction(&*prediction_cache, cache_key, Some(false))
+                            .await;
                         SpamPredictionResult::legitimate(
                             request.model_spec().clone(),
                             start_time.elapsed(),
@@ -384,23 +675,45 @@ impl SpamPredictor {
                     }
                     None => {
                         // Cache inconclusive result
-                        self.config.cache.store_prediction(cache_key, None);
+                        Self::store_cached_prediction(&*prediction_cache, cache_key, None).await;
                         SpamPredictionResult::inconclusive(
                             request.model_spec().clone(),
                             start_time.elapsed(),
                         )
                     }
-                }
+                };
+                // Without logprobs the classification keeps its default confidence
+                let result = match openai_result
+                    .confidence
+                    .and_then(|confidence| ConfidenceScore::new(confidence).ok())
+                {
+                    Some(confidence) => result.with_confidence(confidence),
+                    None => result,
+                };
+                result.with_raw_response(openai_result.raw_response)
             }
-            Err(e) => {
-                warn!("OpenAI prediction failed: {}", e);
-                SpamPredictionResult::error_fallback(
-                    request.model_spec().clone(),
-                    start_time.elapsed(),
-                )
+            Err(e @ SpamPredictorError::ModelNotFound { .. }) => {
+                return self.retry_with_fallback_model(request, e).await;
             }
+            Err(e) => return self.retry_with_fallback_spec(request, e).await,
         };
 
+        // Only fresh model answers carry a raw response worth capturing
+        if let (Some(capture), Some(raw_response)) =
+            (&config.training_capture, result.raw_response())
+            && capture.should_capture()
+        {
+            let record = TrainingRecord::new(
+                request.model_spec(),
+                &contract_data,
+                raw_response,
+                result.classification(),
+            );
+            if let Err(e) = capture.write(&record).await {
+                warn!("Failed to capture training record: {}", e);
+            }
+        }
+
         info!(
             contract_address = %request.metadata().address,
             is_spam = result.is_spam(),
@@ -413,6 +726,53 @@ impl SpamPredictor {
         Ok(result)
     }
 
+    /// Deterministic dry-run classification of the requested contract
+    ///
+    /// Contracts without a name or symbol are spam, all others legitimate, so
+    /// repeated runs over the same metadata always agree.
+    fn mock_prediction(
+        request: &SpamPredictionRequest,
+        start_time: Instant,
+    ) -> SpamPredictionResult {
+        let metadata = request.metadata();
+        let missing = |field: &Option<String>| field.as_deref().is_none_or(str::is_empty);
+        let classification = if missing(&metadata.name) || missing(&metadata.symbol) {
+            SpamClassification::Spam
+        } else {
+            SpamClassification::Legitimate
+        };
+        debug!(
+            contract_address = %metadata.address,
+            ?classification,
+            "Returning mock prediction"
+        );
+        SpamPredictionResult::new(
+            classification,
+            ConfidenceScore::high(),
+            Some("Mock prediction (dry run), OpenAI was not called".to_string()),
+            request.model_spec().clone(),
+            start_time.elapsed(),
+            false,
+        )
+    }
+
+    /// Store a prediction in the cache backend
+    ///
+    /// A failed write only costs a future cache miss, so it is logged rather
+    /// than failing the prediction.
+    async fn store_cached_prediction(
+        prediction_cache: &dyn PredictionCacheBackend,
+        key: PredictionCacheKey,
+        result: Option<bool>,
+    ) {
+        if let Err(e) = prediction_cache.store_prediction(key, result).await {
+            warn!(
+                backend = prediction_cache.name(),
+                "Failed to store prediction in cache: {}", e
+            );
+        }
+    }
+
     /// Convenience method for spam classification with default settings
     pub async fn classify_spam(
         &self,
@@ -434,6 +794,9 @@ pub struct PredictorHealthStatus {
     pub config_healthy: bool,
     /// Cache functionality
     pub cache_healthy: bool,
+    /// Model IDs `OpenAI` reported as not found since the last reload
+    #[serde(default)]
+    pub decommissioned_models: Vec<String>,
     /// Time taken for health check in milliseconds
     pub check_duration_ms: u64,
     /// Current cache statistics
@@ -449,6 +812,9 @@ mod tests {
     use tokio::fs::write;
 
     use super::*;
+    use crate::types::{
+        CONTENT_FILTERED_REASONING, ModelInputField, PromptVersion, SpamClassification,
+    };
 
     async fn create_test_config() -> (SpamPredictorConfig, TempDir, TempDir) {
         let temp_dir1 = TempDir::new().unwrap();
@@ -502,6 +868,9 @@ model_registry:
             creator_address: Some(Address::ZERO),
             is_verified: Some(true),
             contract_type: Some(api_client::ContractType::Erc721),
+            external_url: None,
+            twitter: None,
+            discord: None,
             additional_data: HashMap::new(),
         }
     }
@@ -517,6 +886,34 @@ model_registry:
         assert!(result.is_ok() || result.unwrap_err().is_auth_error());
     }
 
+    #[tokio::test]
+    async fn reload_config_swaps_registries_for_all_clones() {
+        let (config, _temp1, _temp2) = create_test_config().await;
+        let model_path = config.model_registry_path.clone();
+        let predictor = SpamPredictor::new(config).await.unwrap();
+        let clone = predictor.clone();
+        assert_eq!(
+            predictor.get_config_summary().model_types,
+            vec!["spam_classification"]
+        );
+
+        write(
+            &model_path,
+            "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o-2024-08-06:test::TEST456\n  \
+             nsfw_classification:\n    latest: ft:gpt-4o-2024-08-06:test::NSFW1\n",
+        )
+        .await
+        .unwrap();
+        predictor.reload_config().await.unwrap();
+
+        let mut model_types = clone.get_config_summary().model_types;
+        model_types.sort();
+        assert_eq!(
+            model_types,
+            vec!["nsfw_classification", "spam_classification"]
+        );
+    }
+
     #[tokio::test]
     async fn contract_data_preparation() {
         let (config, _temp1, _temp2) = create_test_config().await;
@@ -536,6 +933,36 @@ model_registry:
         assert!(contract_data.contains("Name: Test NFT Collection"));
         assert!(contract_data.contains("Symbol: TEST"));
         assert!(contract_data.contains("Description: No description available"));
+        assert!(!contract_data.contains("Links:"));
+
+        let mut metadata = create_test_metadata();
+        metadata.twitter = Some("@testnft".to_string());
+        let contract_data = predictor.prepare_contract_data(&metadata).unwrap();
+        assert!(contract_data.contains("Links: twitter @testnft"));
+    }
+
+    #[tokio::test]
+    async fn contract_data_preparation_uses_configured_fields() {
+        let (config, _temp1, _temp2) = create_test_config().await;
+        let config = config
+            .with_model_input_fields(vec![ModelInputField::Name, ModelInputField::HolderCount]);
+        let predictor = SpamPredictor::new(config).await;
+
+        // Skip if OpenAI auth fails
+        if predictor.is_err() {
+            return;
+        }
+        let predictor = predictor.unwrap();
+
+        let mut metadata = create_test_metadata();
+        metadata.holder_count = Some(42);
+        metadata.twitter = Some("@testnft".to_string());
+        let contract_data = predictor.prepare_contract_data(&metadata).unwrap();
+
+        assert_eq!(
+            contract_data,
+            "NFT Details:\nName: Test NFT Collection\nHolder Count: 42"
+        );
     }
 
     #[tokio::test]
@@ -584,6 +1011,11 @@ model_registry:
 
         let summary = ConfigSummary {
             model_types: vec!["spam_classification".to_string()],
+            model_versions: [(
+                "spam_classification".to_string(),
+                vec!["latest".to_string()],
+            )]
+            .into(),
             prompt_versions: vec!["1.0.0".to_string()],
             current_prompt_version: "1.0.0".to_string(),
             cache_stats: SpamCache::new().get_stats(),
@@ -594,4 +1026,866 @@ model_registry:
         assert_eq!(summary.prompt_versions.len(), 1);
         assert!(summary.openai_configured);
     }
+
+    #[tokio::test]
+    async fn few_shot_examples_included_in_request() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "false"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let model_path = temp_dir.path().join("models.yaml");
+        write(
+            &model_path,
+            "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o-2024-08-06:test::TEST123\n",
+        )
+        .await
+        .unwrap();
+        write(
+            temp_dir.path().join("examples.json"),
+            r#"[
+                {"input": "NFT Details:\nName: Free Airdrop\nSymbol: CLAIM\nDescription: Visit claim-now.xyz", "is_spam": true},
+                {"input": "NFT Details:\nName: Art Blocks\nSymbol: BLOCKS\nDescription: Generative art", "is_spam": false}
+            ]"#,
+        )
+        .await
+        .unwrap();
+        let prompt_path = temp_dir.path().join("prompts.json");
+        write(
+            &prompt_path,
+            r#"{
+    "versions": [
+        {
+            "version": "1.0.0",
+            "date": "2025-04-29",
+            "description": "Few-shot version",
+            "system_message": "Classify NFT contracts as spam or legitimate.",
+            "few_shot_examples_path": "examples.json"
+        }
+    ],
+    "current_version": "1.0.0"
+}"#,
+        )
+        .await
+        .unwrap();
+
+        let openai_config = crate::config::OpenAiConfig::new("sk-test-key".to_string())
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let config = SpamPredictorConfig::from_files(model_path, prompt_path, openai_config)
+            .await
+            .unwrap();
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        let result = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap();
+        assert!(!result.is_spam());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let prediction_request = requests
+            .iter()
+            .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+            .find(|body| body["model"] == "ft:gpt-4o-2024-08-06:test::TEST123")
+            .expect("prediction request was sent");
+
+        let messages = prediction_request["messages"].as_array().unwrap();
+        let roles: Vec<&str> = messages
+            .iter()
+            .map(|m| m["role"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            roles,
+            ["system", "user", "assistant", "user", "assistant", "user"]
+        );
+        assert!(
+            messages[1]["content"]
+                .as_str()
+                .unwrap()
+                .contains("Free Airdrop")
+        );
+        assert_eq!(messages[2]["content"], "True");
+        assert_eq!(messages[4]["content"], "False");
+        assert!(
+            messages[5]["content"]
+                .as_str()
+                .unwrap()
+                .contains("Test NFT Collection")
+        );
+    }
+
+    #[tokio::test]
+    async fn prompt_edit_invalidates_cached_prediction() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "false"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let model_path = temp_dir.path().join("models.yaml");
+        write(
+            &model_path,
+            "model_registry:\n  spam_classification:\n    latest: ft:gpt-4o-2024-08-06:test::TEST123\n",
+        )
+        .await
+        .unwrap();
+        let prompt_path = temp_dir.path().join("prompts.json");
+        let write_prompt = |system_message: &'static str| {
+            let prompt_path = prompt_path.clone();
+            async move {
+                let prompts = serde_json::json!({
+                    "versions": [{
+                        "version": "1.0.0",
+                        "date": "2025-04-29",
+                        "description": "Edited in place",
+                        "system_message": system_message
+                    }],
+                    "current_version": "1.0.0"
+                });
+                write(&prompt_path, prompts.to_string()).await.unwrap();
+            }
+        };
+        write_prompt("Classify NFT contracts as spam or legitimate.").await;
+
+        let openai_config = crate::config::OpenAiConfig::new("sk-test-key".to_string())
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let mut config = SpamPredictorConfig::from_files(&model_path, &prompt_path, openai_config)
+            .await
+            .unwrap();
+        let metadata = create_test_metadata();
+        let prediction_requests = || async {
+            mock_server
+                .received_requests()
+                .await
+                .unwrap()
+                .iter()
+                .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+                .filter(|body| body["model"] == "ft:gpt-4o-2024-08-06:test::TEST123")
+                .count()
+        };
+
+        let predictor = SpamPredictor::new(config.clone()).await.unwrap();
+        predictor.classify_spam(&metadata).await.unwrap();
+        predictor.classify_spam(&metadata).await.unwrap();
+        assert_eq!(
+            prediction_requests().await,
+            1,
+            "unchanged prompt hits the cache"
+        );
+
+        // Same version string, different prompt text, same shared cache
+        write_prompt("Classify NFT collections. Answer true for spam.").await;
+        config.reload().await.unwrap();
+        let predictor = SpamPredictor::new(config.clone()).await.unwrap();
+        let result = predictor
+            .predict_spam_typed(SpamPredictionRequest::new(
+                metadata.clone(),
+                ModelSpec::spam_classification_latest(),
+                PromptVersion::v1_0_0(),
+            ))
+            .await
+            .unwrap();
+        assert!(
+            !result.is_cached(),
+            "edited prompt must not reuse the prediction"
+        );
+        assert_eq!(prediction_requests().await, 2);
+
+        predictor.classify_spam(&metadata).await.unwrap();
+        assert_eq!(
+            prediction_requests().await,
+            2,
+            "edited prompt is cached again"
+        );
+    }
+
+    #[tokio::test]
+    async fn creator_reputation_included_in_prompt() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "true"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, _temp1, _temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        let mut metadata = create_test_metadata();
+        metadata.additional_data.insert(
+            "creator_reputation".to_string(),
+            serde_json::Value::String("fresh".to_string()),
+        );
+        let result = predictor.classify_spam(&metadata).await.unwrap();
+        assert!(result.is_spam());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let prediction_request = requests
+            .iter()
+            .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+            .find(|body| body["model"] != "gpt-3.5-turbo")
+            .expect("prediction request was sent");
+
+        let messages = prediction_request["messages"].as_array().unwrap();
+        let user_message = messages.last().unwrap()["content"].as_str().unwrap();
+        assert!(user_message.contains("Creator: fresh"));
+    }
+
+    #[tokio::test]
+    async fn social_presence_reason_in_analysis() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "true"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, _temp1, _temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let predictor = SpamPredictor::new(config).await.unwrap();
+        let model_spec = ModelSpec::spam_classification_latest();
+        let no_presence = "No social presence (no website, Twitter or Discord links)";
+
+        let request = SpamPredictionRequest::new(
+            create_test_metadata(),
+            model_spec.clone(),
+            PromptVersion::v1_0_0(),
+        );
+        let analysis = predictor.analyze_contract(request).await.unwrap();
+        assert!(analysis.reasons.iter().any(|r| r == no_presence));
+
+        let mut metadata = create_test_metadata();
+        metadata.address = Address::repeat_byte(0x11);
+        metadata.external_url = Some("https://example.com".to_string());
+        let request = SpamPredictionRequest::new(metadata, model_spec, PromptVersion::v1_0_0());
+        let analysis = predictor.analyze_contract(request).await.unwrap();
+        assert!(!analysis.reasons.iter().any(|r| r == no_presence));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.iter().any(|r| {
+            String::from_utf8_lossy(&r.body).contains("Links: website https://example.com")
+        }));
+    }
+
+    #[tokio::test]
+    async fn decommissioned_model_falls_back_and_is_reported() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        const RETIRED: &str = "ft:gpt-4o-2024-08-06:test::RETIRED";
+        const FALLBACK: &str = "ft:gpt-4o-2024-08-06:test::FALLBACK";
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": RETIRED })))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {
+                    "message": format!("The model `{RETIRED}` does not exist"),
+                    "type": "invalid_request_error",
+                    "code": "model_not_found"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": FALLBACK,
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "true"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (config, _temp1, _temp2) = create_test_config().await;
+        let models = TempDir::new().unwrap();
+        let model_path = models.path().join("models.yaml");
+        write(
+            &model_path,
+            format!(
+                "model_registry:\n  spam_classification:\n    latest: {RETIRED}\n    v0: {FALLBACK}\n"
+            ),
+        )
+        .await
+        .unwrap();
+        let openai_config = crate::config::OpenAiConfig::new("sk-test-key".to_string())
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let config = SpamPredictorConfig::from_files(
+            model_path,
+            &config.prompt_registry_path,
+            openai_config,
+        )
+        .await
+        .unwrap()
+        .with_fallback_model_versions(vec![ModelVersion::new("v0").unwrap()]);
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        // The retired model is only asked once; later predictions go straight
+        // to the fallback
+        for metadata in [
+            create_test_metadata(),
+            ContractMetadata {
+                address: Address::repeat_byte(0x22),
+                ..create_test_metadata()
+            },
+        ] {
+            let result = predictor.classify_spam(&metadata).await.unwrap();
+            assert_eq!(result.classification(), &SpamClassification::Spam);
+        }
+
+        assert_eq!(predictor.decommissioned_models(), vec![RETIRED.to_string()]);
+        let health = predictor.health_check().await.unwrap();
+        assert_eq!(health.decommissioned_models, vec![RETIRED.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn decommissioned_model_without_fallback_returns_model_not_found() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": {
+                    "message": "The model does not exist",
+                    "type": "invalid_request_error",
+                    "code": "model_not_found"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, _temp1, _temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        let error = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SpamPredictorError::ModelNotFound { .. }));
+        assert_eq!(
+            predictor.decommissioned_models(),
+            vec!["ft:gpt-4o-2024-08-06:test::TEST123".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn unavailable_model_falls_back_to_next_model_spec() {
+        use api_client::RetryPolicy;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        const PRIMARY: &str = "ft:gpt-4o-2024-08-06:test::PRIMARY";
+        const SECONDARY: &str = "ft:gpt-4o-mini-2024-07-18:test::SECONDARY";
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": PRIMARY })))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "error": { "message": "The server is overloaded", "type": "server_error" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": SECONDARY })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": SECONDARY,
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "true"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (config, _temp1, _temp2) = create_test_config().await;
+        let models = TempDir::new().unwrap();
+        let model_path = models.path().join("models.yaml");
+        write(
+            &model_path,
+            format!(
+                "model_registry:\n  spam_classification:\n    latest: {PRIMARY}\n    v2: {SECONDARY}\n"
+            ),
+        )
+        .await
+        .unwrap();
+        let openai_config = crate::config::OpenAiConfig::new("sk-test-key".to_string())
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap())
+            .with_retry_policy(RetryPolicy::default().with_max_attempts(1));
+        let fallback = ModelSpec::new(
+            ModelType::spam_classification(),
+            ModelVersion::new("v2").unwrap(),
+        );
+        let config = SpamPredictorConfig::from_files(
+            model_path,
+            &config.prompt_registry_path,
+            openai_config,
+        )
+        .await
+        .unwrap()
+        .with_fallback_models(vec![fallback.clone()]);
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        let result = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap();
+        assert_eq!(result.classification(), &SpamClassification::Spam);
+        assert_eq!(result.model_used(), &fallback);
+        assert!(!result.is_cached());
+
+        // The answer is cached under the fallback model that produced it
+        let request = SpamPredictionRequest::new(
+            create_test_metadata(),
+            fallback.clone(),
+            PromptVersion::v1_0_0(),
+        );
+        let cached = predictor.predict_spam_typed(request).await.unwrap();
+        assert!(cached.is_cached());
+        assert_eq!(cached.model_used(), &fallback);
+    }
+
+    /// Predictor whose model answers spam with probability `confidence`
+    async fn predictor_answering_spam(
+        confidence: f64,
+        confidence_threshold: Option<f64>,
+    ) -> (SpamPredictor, wiremock::MockServer, TempDir, TempDir) {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "true"},
+                    "logprobs": {
+                        "content": [{ "token": "true", "logprob": confidence.ln() }]
+                    },
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, temp1, temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let config = match confidence_threshold {
+            Some(threshold) => config.with_confidence_threshold(threshold).unwrap(),
+            None => config,
+        };
+        let predictor = SpamPredictor::new(config).await.unwrap();
+        (predictor, mock_server, temp1, temp2)
+    }
+
+    #[tokio::test]
+    async fn confidence_threshold_downgrades_borderline_answers() {
+        // Confident answers keep their classification
+        let (predictor, _server, _temp1, _temp2) = predictor_answering_spam(0.9, Some(0.8)).await;
+        let result = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap();
+        assert_eq!(result.classification(), &SpamClassification::Spam);
+        assert!((result.confidence().as_f64() - 0.9).abs() < 1e-9);
+
+        // Borderline answers are inconclusive, also once cached
+        let (predictor, _server, _temp1, _temp2) = predictor_answering_spam(0.6, Some(0.8)).await;
+        for cached in [false, true] {
+            let result = predictor
+                .classify_spam(&create_test_metadata())
+                .await
+                .unwrap();
+            assert_eq!(result.classification(), &SpamClassification::Inconclusive);
+            assert_eq!(result.is_cached(), cached);
+        }
+
+        // No threshold by default
+        let (predictor, _server, _temp1, _temp2) = predictor_answering_spam(0.1, None).await;
+        let result = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap();
+        assert_eq!(result.classification(), &SpamClassification::Spam);
+    }
+
+    #[tokio::test]
+    async fn confidence_threshold_out_of_range_is_refused() {
+        for threshold in [-0.1, 1.5, f64::NAN] {
+            let (config, _temp1, _temp2) = create_test_config().await;
+            assert!(config.with_confidence_threshold(threshold).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_predictions_never_call_openai() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, _temp1, _temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let predictor = SpamPredictor::new(config.with_mock_predictions(true))
+            .await
+            .unwrap();
+
+        let unnamed = ContractMetadata {
+            address: Address::repeat_byte(0x33),
+            name: None,
+            ..create_test_metadata()
+        };
+        for _ in 0..2 {
+            let result = predictor
+                .classify_spam(&create_test_metadata())
+                .await
+                .unwrap();
+            assert_eq!(result.classification(), &SpamClassification::Legitimate);
+            assert!(!result.is_cached());
+
+            let result = predictor.classify_spam(&unnamed).await.unwrap();
+            assert_eq!(result.classification(), &SpamClassification::Spam);
+            assert!(!result.is_cached());
+        }
+
+        let health = predictor.health_check().await.unwrap();
+        assert!(health.openai_healthy);
+        assert!(health.overall_healthy);
+        mock_server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn rejected_request_does_not_fall_back() {
+        use api_client::RetryPolicy;
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        const PRIMARY: &str = "ft:gpt-4o-2024-08-06:test::PRIMARY";
+        const SECONDARY: &str = "ft:gpt-4o-mini-2024-07-18:test::SECONDARY";
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": PRIMARY })))
+            .respond_with(ResponseTemplate::new(400).set_body_string("malformed request"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(serde_json::json!({ "model": SECONDARY })))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let (config, _temp1, _temp2) = create_test_config().await;
+        let models = TempDir::new().unwrap();
+        let model_path = models.path().join("models.yaml");
+        write(
+            &model_path,
+            format!(
+                "model_registry:\n  spam_classification:\n    latest: {PRIMARY}\n    v2: {SECONDARY}\n"
+            ),
+        )
+        .await
+        .unwrap();
+        let openai_config = crate::config::OpenAiConfig::new("sk-test-key".to_string())
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap())
+            .with_retry_policy(RetryPolicy::default().with_max_attempts(1));
+        let fallback = ModelSpec::new(
+            ModelType::spam_classification(),
+            ModelVersion::new("v2").unwrap(),
+        );
+        let config = SpamPredictorConfig::from_files(
+            model_path,
+            &config.prompt_registry_path,
+            openai_config,
+        )
+        .await
+        .unwrap()
+        .with_fallback_models(vec![fallback]);
+        let predictor = SpamPredictor::new(config).await.unwrap();
+
+        let error = predictor
+            .classify_spam(&create_test_metadata())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(error, SpamPredictorError::OpenAi { .. }),
+            "unexpected error: {error:?}"
+        );
+        assert!(!error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn content_filtered_response_is_inconclusive_and_not_cached() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": null},
+                    "finish_reason": "content_filter"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (mut config, _temp1, _temp2) = create_test_config().await;
+        config.openai_config = config
+            .openai_config
+            .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+        let predictor = SpamPredictor::new(config).await.unwrap();
+        let metadata = create_test_metadata();
+
+        for _ in 0..2 {
+            let result = predictor.classify_spam(&metadata).await.unwrap();
+            assert_eq!(result.classification(), &SpamClassification::Inconclusive);
+            assert_eq!(result.reasoning(), Some(CONTENT_FILTERED_REASONING));
+            assert!(!result.is_cached());
+        }
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let prediction_requests = requests
+            .iter()
+            .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+            .filter(|body| body["model"] != "gpt-3.5-turbo")
+            .count();
+        assert_eq!(prediction_requests, 2);
+    }
+
+    #[tokio::test]
+    async fn training_capture_follows_rate() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        use crate::capture::{TrainingCapture, TrainingRecord};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "True"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let capture_dir = TempDir::new().unwrap();
+        let mut metadata = create_test_metadata();
+        metadata.name = Some("Claim at owner@example.com".to_string());
+
+        for (rate, file) in [(1.0, "always.jsonl"), (0.0, "never.jsonl")] {
+            let (mut config, _temp1, _temp2) = create_test_config().await;
+            config.openai_config = config
+                .openai_config
+                .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+            let path = capture_dir.path().join(file);
+            let config = config.with_training_capture(TrainingCapture::new(rate, &path).unwrap());
+            let predictor = SpamPredictor::new(config).await.unwrap();
+
+            // The second call is a cache hit and has no raw response to capture
+            for _ in 0..2 {
+                predictor.classify_spam(&metadata).await.unwrap();
+            }
+
+            if rate == 0.0 {
+                assert!(!path.exists());
+                continue;
+            }
+            let contents = std::fs::read_to_string(&path).unwrap();
+            let records: Vec<TrainingRecord> = contents
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+            assert_eq!(records.len(), 1);
+            let record = &records[0];
+            assert_eq!(record.model_spec, "spam_classification:latest");
+            assert_eq!(record.raw_response, "True");
+            assert_eq!(record.final_classification, "spam");
+            assert!(record.contract_data.contains("Claim at [REDACTED]"));
+            assert!(!record.contract_data.contains("owner@example.com"));
+        }
+    }
+
+    #[tokio::test]
+    async fn prediction_backend_is_shared_across_predictors() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{body_partial_json, method, path},
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_partial_json(
+                serde_json::json!({ "model": "ft:gpt-4o-2024-08-06:test::TEST123" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "chatcmpl-test",
+                "object": "chat.completion",
+                "created": 1234567890,
+                "model": "ft:gpt-4o-2024-08-06:test::TEST123",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "True"},
+                    "finish_reason": "stop"
+                }]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Stands in for a store such as Redis that several instances share
+        let shared: Arc<dyn PredictionCacheBackend> = Arc::new(SpamCache::new());
+        let metadata = create_test_metadata();
+        let mut results = Vec::new();
+        for _ in 0..2 {
+            let (mut config, _temp1, _temp2) = create_test_config().await;
+            config.openai_config = config
+                .openai_config
+                .with_base_url(url::Url::parse(&mock_server.uri()).unwrap());
+            let predictor = SpamPredictor::new(config.with_prediction_backend(Arc::clone(&shared)))
+                .await
+                .unwrap();
+            results.push(predictor.classify_spam(&metadata).await.unwrap());
+        }
+
+        assert!(!results[0].is_cached());
+        assert!(results[1].is_cached());
+        assert_eq!(results[1].classification(), &SpamClassification::Spam);
+    }
 }
@@ -0,0 +1,290 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Redis-backed prediction cache shared across instances
+//!
+//! Every instance pointed at the same Redis server reads and writes the same
+//! predictions, so a contract classified by one pod is served from cache by
+//! all of them. Entries expire through Redis key TTLs.
+
+use std::{fmt, time::Duration};
+
+use futures::future::BoxFuture;
+use redis::aio::MultiplexedConnection;
+use tracing::info;
+
+use crate::{
+    backend::PredictionCacheBackend,
+    cache::PredictionCacheKey,
+    error::{SpamPredictorError, SpamPredictorResult},
+};
+
+/// Prefix of every key written by [`RedisCache`]
+pub const DEFAULT_KEY_PREFIX: &str = "nft-api:prediction";
+
+/// Prediction cache stored in Redis
+#[derive(Clone)]
+pub struct RedisCache {
+    /// Multiplexed connection shared by all clones
+    connection: MultiplexedConnection,
+    /// Prefix of the Redis keys
+    key_prefix: String,
+    /// Time to live of stored predictions
+    ttl: Duration,
+}
+
+impl fmt::Debug for RedisCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisCache")
+            .field("key_prefix", &self.key_prefix)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RedisCache {
+    /// Connect to the Redis server at `url`, storing predictions for `ttl`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the server cannot be reached.
+    pub async fn connect(url: &str, ttl: Duration) -> SpamPredictorResult<Self> {
+        let client = redis::Client::open(url)
+            .map_err(|e| SpamPredictorError::cache(format!("invalid Redis URL: {e}")))?;
+        let connection = client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| SpamPredictorError::cache(format!("connecting to Redis: {e}")))?;
+
+        info!("Connected prediction cache to Redis");
+        Ok(Self {
+            connection,
+            key_prefix: DEFAULT_KEY_PREFIX.to_string(),
+            ttl,
+        })
+    }
+
+    /// Use a custom key prefix, e.g. to separate deployments sharing a server
+    pub fn with_key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Redis key of a prediction
+    fn redis_key(&self, key: &PredictionCacheKey) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}",
+            self.key_prefix,
+            key.model_type,
+            key.model_version,
+            key.prompt_version,
+            key.config_hash,
+            key.metadata_hash
+        )
+    }
+}
+
+impl PredictionCacheBackend for RedisCache {
+    fn name(&self) -> &'static str {
+        "redis"
+    }
+
+    fn get_prediction<'a>(
+        &'a self,
+        key: &'a PredictionCacheKey,
+    ) -> BoxFuture<'a, SpamPredictorResult<Option<Option<bool>>>> {
+        Box::pin(async move {
+            let mut connection = self.connection.clone();
+            let value: Option<String> = redis::cmd("GET")
+                .arg(self.redis_key(key))
+                .query_async(&mut connection)
+                .await
+                .map_err(|e| SpamPredictorError::cache(format!("Redis GET failed: {e}")))?;
+            value
+                .map(|value| serde_json::from_str(&value))
+                .transpose()
+                .map_err(|e| SpamPredictorError::cache(format!("invalid cached prediction: {e}")))
+        })
+    }
+
+    fn store_prediction(
+        &self,
+        key: PredictionCacheKey,
+        result: Option<bool>,
+    ) -> BoxFuture<'_, SpamPredictorResult<()>> {
+        Box::pin(async move {
+            let mut connection = self.connection.clone();
+            let value = serde_json::to_string(&result)
+                .map_err(|e| SpamPredictorError::json(e.to_string()))?;
+            let ttl_ms = u64::try_from(self.ttl.as_millis())
+                .unwrap_or(u64::MAX)
+                .max(1);
+            redis::cmd("SET")
+                .arg(self.redis_key(&key))
+                .arg(value)
+                .arg("PX")
+                .arg(ttl_ms)
+                .query_async::<()>(&mut connection)
+                .await
+                .map_err(|e| SpamPredictorError::cache(format!("Redis SET failed: {e}")))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    type Store = Arc<Mutex<HashMap<String, (String, Instant)>>>;
+
+    /// In-process stand-in for Redis speaking just enough RESP for the cache:
+    /// `GET`, `SET key value PX ms`, and `+OK` for everything else
+    async fn start_fake_redis() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let store = Store::default();
+
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let store = Arc::clone(&store);
+                tokio::spawn(async move {
+                    let (reader, mut writer) = socket.into_split();
+                    let mut reader = BufReader::new(reader);
+                    while let Some(command) = read_command(&mut reader).await {
+                        let reply = execute(&store, &command);
+                        if writer.write_all(reply.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        format!("redis://{addr}")
+    }
+
+    async fn read_command(
+        reader: &mut BufReader<tokio::net::tcp::OwnedReadHalf>,
+    ) -> Option<Vec<String>> {
+        let mut line = String::new();
+        reader.read_line(&mut line).await.ok().filter(|&n| n > 0)?;
+        let count: usize = line.trim().strip_prefix('*')?.parse().ok()?;
+        let mut args = Vec::with_capacity(count);
+        for _ in 0..count {
+            line.clear();
+            reader.read_line(&mut line).await.ok()?;
+            line.clear();
+            reader.read_line(&mut line).await.ok()?;
+            args.push(line.trim_end_matches("\r\n").to_string());
+        }
+        Some(args)
+    }
+
+    fn execute(store: &Store, command: &[String]) -> String {
+        let mut store = store.lock().unwrap();
+        match command[0].to_uppercase().as_str() {
+            "GET" => match store.get(&command[1]) {
+                Some((value, expires_at)) if Instant::now() < *expires_at => {
+                    format!("${}\r\n{}\r\n", value.len(), value)
+                }
+                _ => "$-1\r\n".to_string(),
+            },
+            "SET" => {
+                let ttl = Duration::from_millis(command[4].parse().unwrap());
+                store.insert(
+                    command[1].clone(),
+                    (command[2].clone(), Instant::now() + ttl),
+                );
+                "+OK\r\n".to_string()
+            }
+            _ => "+OK\r\n".to_string(),
+        }
+    }
+
+    fn key(metadata_hash: &str) -> PredictionCacheKey {
+        PredictionCacheKey::new(
+            metadata_hash.to_string(),
+            "spam_classification".to_string(),
+            "latest".to_string(),
+            "1.0.0".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn predictions_are_shared_across_instances() {
+        let url = start_fake_redis().await;
+        let writer = RedisCache::connect(&url, Duration::from_secs(60))
+            .await
+            .unwrap();
+        let reader = RedisCache::connect(&url, Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        assert_eq!(reader.get_prediction(&key("spam")).await.unwrap(), None);
+        writer
+            .store_prediction(key("spam"), Some(true))
+            .await
+            .unwrap();
+        writer.store_prediction(key("unknown"), None).await.unwrap();
+
+        assert_eq!(
+            reader.get_prediction(&key("spam")).await.unwrap(),
+            Some(Some(true))
+        );
+        assert_eq!(
+            reader.get_prediction(&key("unknown")).await.unwrap(),
+            Some(None)
+        );
+    }
+
+    #[tokio::test]
+    async fn key_prefix_separates_deployments() {
+        let url = start_fake_redis().await;
+        let staging = RedisCache::connect(&url, Duration::from_secs(60))
+            .await
+            .unwrap()
+            .with_key_prefix("staging");
+        let production = RedisCache::connect(&url, Duration::from_secs(60))
+            .await
+            .unwrap();
+
+        staging
+            .store_prediction(key("spam"), Some(true))
+            .await
+            .unwrap();
+        assert_eq!(production.get_prediction(&key("spam")).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn predictions_expire_after_ttl() {
+        let url = start_fake_redis().await;
+        let cache = RedisCache::connect(&url, Duration::from_millis(50))
+            .await
+            .unwrap();
+
+        cache
+            .store_prediction(key("spam"), Some(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            cache.get_prediction(&key("spam")).await.unwrap(),
+            Some(Some(true))
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(cache.get_prediction(&key("spam")).await.unwrap(), None);
+    }
+}
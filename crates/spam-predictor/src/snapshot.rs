@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: 2025 Semiotic Labs
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialized snapshots of the prediction cache
+//!
+//! A snapshot lets a freshly started instance warm its [`SpamCache`] without
+//! re-querying OpenAI. Snapshots are JSON documents that can be read from a
+//! local file or fetched over HTTP(S), e.g. from a CDN or object store, and
+//! written to a local file. Fetches honor the configured upstream allowlist.
+//!
+//! Each entry records its age, and the snapshot its write time, so restored
+//! entries keep their original expiry and keep aging while no instance runs.
+
+use std::{path::Path, time::Duration};
+
+use api_client::UpstreamAllowlist;
+use chrono::{DateTime, Utc};
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::{
+    cache::{PredictionCacheKey, SpamCache},
+    error::{SpamPredictorError, SpamPredictorResult},
+};
+
+/// Current snapshot format version
+pub const CACHE_SNAPSHOT_VERSION: u32 = 1;
+
+/// A cached prediction together with its age at snapshot time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheSnapshotEntry {
+    /// Cache key of the prediction
+    pub key: PredictionCacheKey,
+    /// Cached prediction result
+    pub result: Option<bool>,
+    /// Seconds the entry had been cached when the snapshot was taken
+    pub age_seconds: u64,
+}
+
+/// Serializable contents of the prediction cache
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheSnapshot {
+    /// Snapshot format version
+    pub version: u32,
+    /// Time the snapshot was taken; older snapshots without it restore entries
+    /// with the age they had at snapshot time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taken_at: Option<DateTime<Utc>>,
+    /// Unexpired predictions at snapshot time
+    pub entries: Vec<CacheSnapshotEntry>,
+}
+
+impl CacheSnapshot {
+    /// Time elapsed since the snapshot was taken, zero when unknown
+    pub fn elapsed(&self) -> Duration {
+        self.taken_at
+            .and_then(|taken_at| (Utc::now() - taken_at).to_std().ok())
+            .unwrap_or_default()
+    }
+
+    /// Read a snapshot from a local path or an `http(s)://` URL
+    ///
+    /// URLs are fetched through a client restricted to `allowlist`, when given.
+    pub async fn read(
+        source: &str,
+        allowlist: Option<&UpstreamAllowlist>,
+    ) -> SpamPredictorResult<Self> {
+        let snapshot: Self = if source.starts_with("http://") || source.starts_with("https://") {
+            Self::fetch(source, allowlist)
+                .await
+                .map_err(|e| SpamPredictorError::http(format!("fetching {}: {}", source, e)))?
+                .json()
+                .await
+                .map_err(|e| SpamPredictorError::json(format!("parsing {}: {}", source, e)))?
+        } else {
+            let contents = tokio::fs::read_to_string(source)
+                .await
+                .map_err(|e| SpamPredictorError::io(format!("reading {}: {}", source, e)))?;
+            serde_json::from_str(&contents)
+                .map_err(|e| SpamPredictorError::json(format!("parsing {}: {}", source, e)))?
+        };
+
+        if snapshot.version != CACHE_SNAPSHOT_VERSION {
+            return Err(SpamPredictorError::cache(format!(
+                "unsupported cache snapshot version {} in {}",
+                snapshot.version, source
+            )));
+        }
+        Ok(snapshot)
+    }
+
+    /// Request `source`, checking it against `allowlist` first
+    async fn fetch(
+        source: &str,
+        allowlist: Option<&UpstreamAllowlist>,
+    ) -> Result<reqwest::Response, String> {
+        let url = Url::parse(source).map_err(|e| e.to_string())?;
+        let builder = Client::builder();
+        let client = match allowlist {
+            Some(allowlist) => {
+                allowlist.check_url(&url).map_err(|e| e.to_string())?;
+                allowlist.apply(builder)
+            }
+            None => builder,
+        }
+        .build()
+        .map_err(|e| e.to_string())?;
+
+        client
+            .get(url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Write the snapshot to `path`, replacing any previous snapshot atomically
+    pub async fn write(&self, path: impl AsRef<Path>) -> SpamPredictorResult<()> {
+        let path = path.as_ref();
+        let contents =
+            serde_json::to_vec(self).map_err(|e| SpamPredictorError::json(e.to_string()))?;
+        let temp_path = path.with_extension("tmp");
+        tokio::fs::write(&temp_path, contents).await.map_err(|e| {
+            SpamPredictorError::io(format!("writing {}: {}", temp_path.display(), e))
+        })?;
+        tokio::fs::rename(&temp_path, path)
+            .await
+            .map_err(|e| SpamPredictorError::io(format!("writing {}: {}", path.display(), e)))?;
+
+        debug!(
+            "Wrote cache snapshot with {} entries to {}",
+            self.entries.len(),
+            path.display()
+        );
+        Ok(())
+    }
+}
+
+impl SpamCache {
+    /// Load a snapshot from `source` into the cache, returning the number of restored entries
+    ///
+    /// Snapshots fetched over HTTP(S) must be permitted by `allowlist`, when given.
+    pub async fn load_snapshot(
+        &self,
+        source: &str,
+        allowlist: Option<&UpstreamAllowlist>,
+    ) -> SpamPredictorResult<usize> {
+        let snapshot = CacheSnapshot::read(source, allowlist).await?;
+        Ok(self.restore(snapshot))
+    }
+
+    /// Write the unexpired predictions to `path`, returning the number of written entries
+    pub async fn write_snapshot(&self, path: impl AsRef<Path>) -> SpamPredictorResult<usize> {
+        let snapshot = self.snapshot();
+        snapshot.write(path).await?;
+        Ok(snapshot.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn key(metadata_hash: &str) -> PredictionCacheKey {
+        PredictionCacheKey::new(
+            metadata_hash.to_string(),
+            "spam_classification".to_string(),
+            "latest".to_string(),
+            "1.0.0".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn snapshot_round_trip_through_file() {
+        let cache = SpamCache::new();
+        cache.store_prediction(key("spam"), Some(true));
+        cache.store_prediction(key("legit"), Some(false));
+        cache.store_prediction(key("unknown"), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache-snapshot.json");
+        assert_eq!(cache.write_snapshot(&path).await.unwrap(), 3);
+
+        let restored = SpamCache::new();
+        let source = path.display().to_string();
+        assert_eq!(restored.load_snapshot(&source, None).await.unwrap(), 3);
+        assert_eq!(restored.get_prediction(&key("spam")), Some(Some(true)));
+        assert_eq!(restored.get_prediction(&key("legit")), Some(Some(false)));
+        assert_eq!(restored.get_prediction(&key("unknown")), Some(None));
+    }
+
+    #[test]
+    fn snapshot_skips_entries_older_than_ttl() {
+        let snapshot = CacheSnapshot {
+            version: CACHE_SNAPSHOT_VERSION,
+            taken_at: None,
+            entries: vec![
+                CacheSnapshotEntry {
+                    key: key("fresh"),
+                    result: Some(true),
+                    age_seconds: 10,
+                },
+                CacheSnapshotEntry {
+                    key: key("stale"),
+                    result: Some(true),
+                    age_seconds: 120,
+                },
+            ],
+        };
+
+        let cache = SpamCache::with_settings(Duration::from_secs(60), 100);
+        assert_eq!(cache.restore(snapshot), 1);
+        assert_eq!(cache.get_prediction(&key("fresh")), Some(Some(true)));
+        assert_eq!(cache.get_prediction(&key("stale")), None);
+    }
+
+    #[tokio::test]
+    async fn entries_age_while_the_snapshot_is_on_disk() {
+        let cache = SpamCache::with_settings(Duration::from_secs(60), 100);
+        cache.store_prediction(key("spam"), Some(true));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache-snapshot.json");
+        cache.write_snapshot(&path).await.unwrap();
+        let source = path.display().to_string();
+
+        // Restarting right away keeps the entry
+        let restored = SpamCache::with_settings(Duration::from_secs(60), 100);
+        assert_eq!(restored.load_snapshot(&source, None).await.unwrap(), 1);
+
+        // After a downtime longer than the TTL the entry has expired
+        let mut snapshot = CacheSnapshot::read(&source, None).await.unwrap();
+        snapshot.taken_at = snapshot
+            .taken_at
+            .map(|taken_at| taken_at - chrono::Duration::seconds(90));
+        let restored = SpamCache::with_settings(Duration::from_secs(60), 100);
+        assert_eq!(restored.restore(snapshot), 0);
+        assert_eq!(restored.get_prediction(&key("spam")), None);
+    }
+
+    #[test]
+    fn snapshot_without_write_time_is_still_readable() {
+        let snapshot: CacheSnapshot = serde_json::from_str(
+            r#"{"version":1,"entries":[{"key":{"metadata_hash":"spam","model_type":"spam_classification","model_version":"latest","prompt_version":"1.0.0"},"result":true,"age_seconds":10}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(snapshot.taken_at, None);
+        assert_eq!(snapshot.elapsed(), Duration::ZERO);
+        assert_eq!(SpamCache::new().restore(snapshot), 1);
+    }
+
+    #[tokio::test]
+    async fn snapshot_is_fetched_over_http() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let cache = SpamCache::new();
+        cache.store_prediction(key("spam"), Some(true));
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/snapshots/cache.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(cache.snapshot()))
+            .mount(&server)
+            .await;
+
+        let restored = SpamCache::new();
+        let source = format!("{}/snapshots/cache.json", server.uri());
+        assert_eq!(restored.load_snapshot(&source, None).await.unwrap(), 1);
+        assert_eq!(restored.get_prediction(&key("spam")), Some(Some(true)));
+
+        // The mock server is on loopback, which the allowlist rejects by default
+        let allowlist = UpstreamAllowlist::default();
+        let error = SpamCache::new()
+            .load_snapshot(&source, Some(&allowlist))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("private address"), "{error}");
+
+        let allowlist = allowlist.with_private_networks(true);
+        assert_eq!(
+            SpamCache::new()
+                .load_snapshot(&source, Some(&allowlist))
+                .await
+                .unwrap(),
+            1
+        );
+    }
+}
@@ -161,7 +161,7 @@ impl std::fmt::Display for PromptVersion {
 }
 
 /// Model specification combining type and version with validation
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ModelSpec {
     model_type: ModelType,
     version: ModelVersion,
@@ -207,6 +207,7 @@ pub struct SpamPredictionRequest {
     metadata: ContractMetadata,
     model_spec: ModelSpec,
     prompt_version: PromptVersion,
+    distribution: bool,
 }
 
 impl SpamPredictionRequest {
@@ -220,6 +221,7 @@ impl SpamPredictionRequest {
             metadata,
             model_spec,
             prompt_version,
+            distribution: false,
         }
     }
 
@@ -229,9 +231,25 @@ impl SpamPredictionRequest {
             metadata,
             model_spec: ModelSpec::spam_classification_latest(),
             prompt_version: PromptVersion::v1_0_0(),
+            distribution: false,
         }
     }
 
+    /// Request a probability distribution instead of a categorical answer
+    ///
+    /// Only honored when the prompt version declares distribution output;
+    /// otherwise the prediction falls back to a categorical result.
+    pub fn with_distribution(mut self, distribution: bool) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
+    /// Target another model, e.g. a fallback for an unavailable one
+    pub fn with_model_spec(mut self, model_spec: ModelSpec) -> Self {
+        self.model_spec = model_spec;
+        self
+    }
+
     /// Get the contract metadata
     pub fn metadata(&self) -> &ContractMetadata {
         &self.metadata
@@ -246,6 +264,11 @@ impl SpamPredictionRequest {
     pub fn prompt_version(&self) -> &PromptVersion {
         &self.prompt_version
     }
+
+    /// Whether a probability distribution was requested
+    pub fn distribution_requested(&self) -> bool {
+        self.distribution
+    }
 }
 
 /// Spam classification with explicit states
@@ -332,8 +355,188 @@ impl ConfidenceScore {
     pub fn as_f64(&self) -> f64 {
         self.0
     }
+
+    /// Coarse band of this score under the given thresholds
+    pub fn band(&self, thresholds: &ConfidenceBandThresholds) -> ConfidenceBand {
+        if self.0 >= thresholds.high {
+            ConfidenceBand::High
+        } else if self.0 >= thresholds.medium {
+            ConfidenceBand::Medium
+        } else {
+            ConfidenceBand::Low
+        }
+    }
+}
+
+/// Default lowest score in the medium confidence band
+pub const DEFAULT_CONFIDENCE_MEDIUM_THRESHOLD: f64 = 0.5;
+
+/// Default lowest score in the high confidence band
+pub const DEFAULT_CONFIDENCE_HIGH_THRESHOLD: f64 = 0.8;
+
+/// Coarse confidence level for clients that do not want raw scores
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfidenceBand {
+    /// Score below the medium threshold
+    Low,
+    /// Score at or above the medium threshold and below the high threshold
+    Medium,
+    /// Score at or above the high threshold
+    High,
+}
+
+impl ConfidenceBand {
+    /// Every band, from lowest to highest
+    pub const ALL: [ConfidenceBand; 3] = [Self::Low, Self::Medium, Self::High];
+}
+
+/// Lower bounds (inclusive) of the medium and high confidence bands
+///
+/// The defaults place [`ConfidenceScore::high`], [`ConfidenceScore::medium`] and
+/// [`ConfidenceScore::low`] in their namesake bands.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceBandThresholds {
+    /// Lowest score in the medium band
+    pub medium: f64,
+    /// Lowest score in the high band
+    pub high: f64,
+}
+
+impl Default for ConfidenceBandThresholds {
+    fn default() -> Self {
+        Self {
+            medium: DEFAULT_CONFIDENCE_MEDIUM_THRESHOLD,
+            high: DEFAULT_CONFIDENCE_HIGH_THRESHOLD,
+        }
+    }
 }
 
+impl ConfidenceBandThresholds {
+    /// Score range of `band` as an inclusive lower and exclusive upper bound
+    ///
+    /// The high band has no upper bound as it includes the maximum score of 1.0.
+    pub fn bounds(&self, band: ConfidenceBand) -> (f64, Option<f64>) {
+        match band {
+            ConfidenceBand::Low => (0.0, Some(self.medium)),
+            ConfidenceBand::Medium => (self.medium, Some(self.high)),
+            ConfidenceBand::High => (self.high, None),
+        }
+    }
+
+    /// Validate that `0.0 <= medium <= high <= 1.0`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a threshold is outside `0.0..=1.0` or `medium` exceeds `high`
+    pub fn validate(&self) -> SpamPredictorResult<()> {
+        if !(0.0..=1.0).contains(&self.medium) || !(0.0..=1.0).contains(&self.high) {
+            return Err(SpamPredictorError::config(
+                "Confidence band thresholds must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        if self.medium > self.high {
+            return Err(SpamPredictorError::config(format!(
+                "Medium confidence threshold {} exceeds high threshold {}",
+                self.medium, self.high
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Allowed deviation from 1.0 for the sum of a distribution's probabilities
+const DISTRIBUTION_SUM_TOLERANCE: f64 = 0.01;
+
+/// Probability distribution over spam classifications
+///
+/// Returned by prompts that request JSON output such as
+/// `{ "spam": 0.8, "legitimate": 0.15, "inconclusive": 0.05 }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpamDistribution {
+    /// Probability that the contract is spam
+    pub spam: f64,
+    /// Probability that the contract is legitimate
+    pub legitimate: f64,
+    /// Probability that the analysis is inconclusive
+    pub inconclusive: f64,
+}
+
+impl SpamDistribution {
+    /// Parse and validate a JSON distribution returned by the model
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response is not a JSON distribution, a probability
+    /// is outside `0.0..=1.0`, or the probabilities do not sum to 1.0
+    pub fn parse(raw: &str) -> SpamPredictorResult<Self> {
+        let distribution: Self = serde_json::from_str(raw.trim()).map_err(|e| {
+            SpamPredictorError::invalid_response(format!("Invalid distribution response: {}", e))
+        })?;
+        distribution.validate()?;
+        Ok(distribution)
+    }
+
+    /// Validate probability bounds and that the probabilities sum to 1.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first violated constraint
+    pub fn validate(&self) -> SpamPredictorResult<()> {
+        for (label, probability) in self.probabilities() {
+            if !(0.0..=1.0).contains(&probability) {
+                return Err(SpamPredictorError::invalid_response(format!(
+                    "Distribution probability for {} must be between 0.0 and 1.0, got {}",
+                    label, probability
+                )));
+            }
+        }
+
+        let sum = self.spam + self.legitimate + self.inconclusive;
+        if (sum - 1.0).abs() > DISTRIBUTION_SUM_TOLERANCE {
+            return Err(SpamPredictorError::invalid_response(format!(
+                "Distribution probabilities must sum to 1.0, got {:.3}",
+                sum
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Category with the highest probability; ties resolve to inconclusive
+    pub fn top_classification(&self) -> SpamClassification {
+        if self.spam > self.legitimate && self.spam > self.inconclusive {
+            SpamClassification::Spam
+        } else if self.legitimate > self.spam && self.legitimate > self.inconclusive {
+            SpamClassification::Legitimate
+        } else {
+            SpamClassification::Inconclusive
+        }
+    }
+
+    /// Probability assigned to the given classification
+    pub fn probability(&self, classification: &SpamClassification) -> f64 {
+        match classification {
+            SpamClassification::Spam => self.spam,
+            SpamClassification::Legitimate => self.legitimate,
+            SpamClassification::Inconclusive => self.inconclusive,
+        }
+    }
+
+    fn probabilities(&self) -> [(&'static str, f64); 3] {
+        [
+            ("spam", self.spam),
+            ("legitimate", self.legitimate),
+            ("inconclusive", self.inconclusive),
+        ]
+    }
+}
+
+/// Reasoning reported when the provider's content filter withheld the model's answer
+pub const CONTENT_FILTERED_REASONING: &str = "response filtered by provider";
+
 /// Comprehensive spam prediction result with confidence and reasoning
 #[derive(Debug, Clone)]
 pub struct SpamPredictionResult {
@@ -343,6 +546,8 @@ pub struct SpamPredictionResult {
     model_used: ModelSpec,
     processing_time: Duration,
     cached: bool,
+    distribution: Option<SpamDistribution>,
+    raw_response: Option<String>,
 }
 
 impl SpamPredictionResult {
@@ -362,6 +567,8 @@ impl SpamPredictionResult {
             model_used,
             processing_time,
             cached,
+            distribution: None,
+            raw_response: None,
         }
     }
 
@@ -374,6 +581,8 @@ impl SpamPredictionResult {
             model_used,
             processing_time,
             cached: false,
+            distribution: None,
+            raw_response: None,
         }
     }
 
@@ -386,6 +595,8 @@ impl SpamPredictionResult {
             model_used,
             processing_time,
             cached: false,
+            distribution: None,
+            raw_response: None,
         }
     }
 
@@ -398,6 +609,22 @@ impl SpamPredictionResult {
             model_used,
             processing_time,
             cached: false,
+            distribution: None,
+            raw_response: None,
+        }
+    }
+
+    /// Create inconclusive result for a completion withheld by the provider's content filter
+    pub fn content_filtered(model_used: ModelSpec, processing_time: Duration) -> Self {
+        Self {
+            classification: SpamClassification::Inconclusive,
+            confidence: ConfidenceScore::low(),
+            reasoning: Some(CONTENT_FILTERED_REASONING.to_string()),
+            model_used,
+            processing_time,
+            cached: false,
+            distribution: None,
+            raw_response: None,
         }
     }
 
@@ -410,6 +637,29 @@ impl SpamPredictionResult {
             model_used,
             processing_time,
             cached: false,
+            distribution: None,
+            raw_response: None,
+        }
+    }
+
+    /// Create a result from a probability distribution, classified by its top category
+    pub fn from_distribution(
+        distribution: SpamDistribution,
+        model_used: ModelSpec,
+        processing_time: Duration,
+    ) -> Self {
+        let classification = distribution.top_classification();
+        let confidence = ConfidenceScore::new(distribution.probability(&classification))
+            .unwrap_or_else(|_| ConfidenceScore::low());
+        Self {
+            reasoning: Some(classification.message().to_string()),
+            classification,
+            confidence,
+            model_used,
+            processing_time,
+            cached: false,
+            distribution: Some(distribution),
+            raw_response: None,
         }
     }
 
@@ -419,6 +669,18 @@ impl SpamPredictionResult {
         self
     }
 
+    /// Replace the confidence score, e.g. with one derived from token probabilities
+    pub fn with_confidence(mut self, confidence: ConfidenceScore) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Attach the model's unparsed answer
+    pub fn with_raw_response(mut self, raw_response: impl Into<String>) -> Self {
+        self.raw_response = Some(raw_response.into());
+        self
+    }
+
     /// Get the classification
     pub fn classification(&self) -> &SpamClassification {
         &self.classification
@@ -458,44 +720,255 @@ impl SpamPredictionResult {
     pub fn is_cached(&self) -> bool {
         self.cached
     }
+
+    /// Get the probability distribution, present only for distribution predictions
+    pub fn distribution(&self) -> Option<&SpamDistribution> {
+        self.distribution.as_ref()
+    }
+
+    /// Get the model's unparsed answer, present only for categorical predictions
+    /// answered by the model
+    pub fn raw_response(&self) -> Option<&str> {
+        self.raw_response.as_deref()
+    }
+}
+
+/// Contract metadata field that can be included in the model input
+///
+/// Operators tune the prompt with an allowlist of these fields; the default set
+/// matches what fine-tuned models were trained on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelInputField {
+    /// Collection name
+    Name,
+    /// Collection symbol
+    Symbol,
+    /// Collection description
+    Description,
+    /// Creator reputation, when creator enrichment is enabled
+    Creator,
+    /// Collection website and social links
+    Links,
+    /// Token standard (ERC-721, ERC-1155, ...)
+    ContractType,
+    /// Total token supply
+    TotalSupply,
+    /// Size class of the total supply (empty, single, small, medium, large, massive)
+    SupplyBucket,
+    /// Number of unique holders
+    HolderCount,
+    /// Number of transactions involving the contract
+    TransactionCount,
+    /// Block the contract was created in
+    CreationBlock,
+    /// Whether the contract source is verified
+    IsVerified,
+}
+
+impl ModelInputField {
+    /// Fields included in the model input unless configured otherwise
+    pub const DEFAULT: [ModelInputField; 5] = [
+        ModelInputField::Name,
+        ModelInputField::Symbol,
+        ModelInputField::Description,
+        ModelInputField::Creator,
+        ModelInputField::Links,
+    ];
+
+    /// The default field allowlist
+    pub fn default_fields() -> Vec<Self> {
+        Self::DEFAULT.to_vec()
+    }
+
+    /// Configuration name of the field
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Symbol => "symbol",
+            Self::Description => "description",
+            Self::Creator => "creator",
+            Self::Links => "links",
+            Self::ContractType => "contract_type",
+            Self::TotalSupply => "total_supply",
+            Self::SupplyBucket => "supply_bucket",
+            Self::HolderCount => "holder_count",
+            Self::TransactionCount => "transaction_count",
+            Self::CreationBlock => "creation_block",
+            Self::IsVerified => "is_verified",
+        }
+    }
+
+    /// Label and value of an optional field that is not part of the default set
+    fn extra_line(self, metadata: &ContractMetadata) -> Option<(String, String)> {
+        let (label, value) = match self {
+            Self::Name | Self::Symbol | Self::Description | Self::Creator | Self::Links => {
+                return None;
+            }
+            Self::ContractType => (
+                "Contract Type",
+                metadata
+                    .contract_type
+                    .as_ref()
+                    .and_then(|contract_type| serde_json::to_value(contract_type).ok())
+                    .and_then(|value| value.as_str().map(ToString::to_string)),
+            ),
+            Self::TotalSupply => ("Total Supply", metadata.total_supply.clone()),
+            Self::SupplyBucket => (
+                "Supply Bucket",
+                metadata.supply_bucket().map(|bucket| bucket.to_string()),
+            ),
+            Self::HolderCount => (
+                "Holder Count",
+                metadata.holder_count.map(|count| count.to_string()),
+            ),
+            Self::TransactionCount => (
+                "Transaction Count",
+                metadata.transaction_count.map(|count| count.to_string()),
+            ),
+            Self::CreationBlock => (
+                "Creation Block",
+                metadata.creation_block.map(|block| block.to_string()),
+            ),
+            Self::IsVerified => (
+                "Verified",
+                metadata
+                    .is_verified
+                    .map(|verified| if verified { "yes" } else { "no" }.to_string()),
+            ),
+        };
+        value.map(|value| (label.to_string(), value))
+    }
 }
 
 /// NFT details content for OpenAI message formatting
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NftDetailsContent {
-    pub name: String,
-    pub symbol: String,
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Creator reputation summary, present only when creator enrichment is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    /// Collection website and social links, present only when any is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<String>,
+    /// Opt-in fields outside the default set, as label and value pairs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra: Vec<(String, String)>,
 }
 
 impl NftDetailsContent {
+    /// Build the model input from the default field set
     pub fn from_metadata(metadata: &ContractMetadata) -> Self {
+        Self::from_metadata_with_fields(metadata, &ModelInputField::DEFAULT)
+    }
+
+    /// Build the model input from the allowlisted `fields` only
+    ///
+    /// Name, symbol and description fall back to placeholders when allowlisted
+    /// but unknown; other fields are omitted when unknown.
+    pub fn from_metadata_with_fields(
+        metadata: &ContractMetadata,
+        fields: &[ModelInputField],
+    ) -> Self {
+        let includes = |field| fields.contains(&field);
         Self {
-            name: metadata
-                .name
-                .clone()
-                .unwrap_or_else(|| "Unknown".to_string()),
-            symbol: metadata
-                .symbol
-                .clone()
-                .unwrap_or_else(|| "Unknown".to_string()),
-            description: metadata
-                .additional_data
-                .get("description")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "No description available".to_string()),
+            name: includes(ModelInputField::Name).then(|| {
+                metadata
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| "Unknown".to_string())
+            }),
+            symbol: includes(ModelInputField::Symbol).then(|| {
+                metadata
+                    .symbol
+                    .clone()
+                    .unwrap_or_else(|| "Unknown".to_string())
+            }),
+            description: includes(ModelInputField::Description).then(|| {
+                metadata
+                    .additional_data
+                    .get("description")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "No description available".to_string())
+            }),
+            creator: includes(ModelInputField::Creator)
+                .then(|| Self::creator_summary(metadata))
+                .flatten(),
+            links: includes(ModelInputField::Links)
+                .then(|| Self::links_summary(metadata))
+                .flatten(),
+            extra: fields
+                .iter()
+                .filter_map(|field| field.extra_line(metadata))
+                .collect(),
+        }
+    }
+
+    /// Summarize social links, e.g. `website https://example.com, twitter @example`
+    fn links_summary(metadata: &ContractMetadata) -> Option<String> {
+        let links: Vec<String> = [
+            ("website", &metadata.external_url),
+            ("twitter", &metadata.twitter),
+            ("discord", &metadata.discord),
+        ]
+        .into_iter()
+        .filter_map(|(label, link)| {
+            link.as_deref()
+                .map(str::trim)
+                .filter(|link| !link.is_empty())
+                .map(|link| format!("{} {}", label, link))
+        })
+        .collect();
+
+        (!links.is_empty()).then(|| links.join(", "))
+    }
+
+    /// Summarize creator enrichment fields, e.g. `known (creator.eth)` or `fresh`
+    fn creator_summary(metadata: &ContractMetadata) -> Option<String> {
+        let reputation = metadata
+            .additional_data
+            .get("creator_reputation")
+            .and_then(|v| v.as_str())?;
+
+        match metadata
+            .additional_data
+            .get("creator_name")
+            .and_then(|v| v.as_str())
+        {
+            Some(name) => Some(format!("{} ({})", reputation, name)),
+            None => Some(reputation.to_string()),
         }
     }
 }
 
 impl fmt::Display for NftDetailsContent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "NFT Details:\nName: {}\nSymbol: {}\nDescription: {}",
-            self.name, self.symbol, self.description
-        )
+        write!(f, "NFT Details:")?;
+        if let Some(ref name) = self.name {
+            write!(f, "\nName: {}", name)?;
+        }
+        if let Some(ref symbol) = self.symbol {
+            write!(f, "\nSymbol: {}", symbol)?;
+        }
+        if let Some(ref description) = self.description {
+            write!(f, "\nDescription: {}", description)?;
+        }
+        if let Some(ref creator) = self.creator {
+            write!(f, "\nCreator: {}", creator)?;
+        }
+        if let Some(ref links) = self.links {
+            write!(f, "\nLinks: {}", links)?;
+        }
+        for (label, value) in &self.extra {
+            write!(f, "\n{}: {}", label, value)?;
+        }
+        Ok(())
     }
 }
 
@@ -503,6 +976,72 @@ impl fmt::Display for NftDetailsContent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn spam_distribution_parse_and_top_category() {
+        let distribution =
+            SpamDistribution::parse(r#"{"spam": 0.8, "legitimate": 0.15, "inconclusive": 0.05}"#)
+                .unwrap();
+        assert_eq!(distribution.spam, 0.8);
+        assert_eq!(distribution.top_classification(), SpamClassification::Spam);
+
+        let distribution =
+            SpamDistribution::parse(r#"{"spam": 0.1, "legitimate": 0.7, "inconclusive": 0.2}"#)
+                .unwrap();
+        assert_eq!(
+            distribution.top_classification(),
+            SpamClassification::Legitimate
+        );
+
+        // Ties resolve to inconclusive
+        let distribution =
+            SpamDistribution::parse(r#"{"spam": 0.5, "legitimate": 0.5, "inconclusive": 0.0}"#)
+                .unwrap();
+        assert_eq!(
+            distribution.top_classification(),
+            SpamClassification::Inconclusive
+        );
+    }
+
+    #[test]
+    fn spam_distribution_rejects_invalid_probabilities() {
+        // Probabilities must sum to 1.0 (within tolerance)
+        let error =
+            SpamDistribution::parse(r#"{"spam": 0.8, "legitimate": 0.8, "inconclusive": 0.0}"#)
+                .unwrap_err();
+        assert!(error.to_string().contains("sum to 1.0"));
+        assert!(
+            SpamDistribution::parse(r#"{"spam": 0.6, "legitimate": 0.3, "inconclusive": 0.095}"#)
+                .is_ok()
+        );
+
+        // Each probability must be within bounds
+        assert!(
+            SpamDistribution::parse(r#"{"spam": 1.5, "legitimate": -0.5, "inconclusive": 0.0}"#)
+                .is_err()
+        );
+
+        // Categorical answers are not distributions
+        assert!(SpamDistribution::parse("True").is_err());
+        assert!(SpamDistribution::parse(r#"{"spam": 1.0}"#).is_err());
+    }
+
+    #[test]
+    fn prediction_result_from_distribution() {
+        let distribution = SpamDistribution {
+            spam: 0.15,
+            legitimate: 0.8,
+            inconclusive: 0.05,
+        };
+        let result = SpamPredictionResult::from_distribution(
+            distribution.clone(),
+            ModelSpec::spam_classification_latest(),
+            Duration::from_millis(5),
+        );
+        assert_eq!(result.classification(), &SpamClassification::Legitimate);
+        assert_eq!(result.confidence().as_f64(), 0.8);
+        assert_eq!(result.distribution(), Some(&distribution));
+    }
+
     #[test]
     fn model_type_validation() {
         assert!(ModelType::new("spam_classification").is_ok());
@@ -559,6 +1098,164 @@ mod tests {
         assert_eq!(ConfidenceScore::low().as_f64(), 0.3);
     }
 
+    #[test]
+    fn confidence_band_boundaries() {
+        let thresholds = ConfidenceBandThresholds::default();
+        let band = |score: f64| ConfidenceScore::new(score).unwrap().band(&thresholds);
+
+        assert_eq!(band(0.0), ConfidenceBand::Low);
+        assert_eq!(band(0.4999), ConfidenceBand::Low);
+        assert_eq!(band(0.5), ConfidenceBand::Medium);
+        assert_eq!(band(0.7999), ConfidenceBand::Medium);
+        assert_eq!(band(0.8), ConfidenceBand::High);
+        assert_eq!(band(1.0), ConfidenceBand::High);
+
+        // The named scores fall into their namesake bands
+        assert_eq!(
+            ConfidenceScore::high().band(&thresholds),
+            ConfidenceBand::High
+        );
+        assert_eq!(
+            ConfidenceScore::medium().band(&thresholds),
+            ConfidenceBand::Medium
+        );
+        assert_eq!(
+            ConfidenceScore::low().band(&thresholds),
+            ConfidenceBand::Low
+        );
+
+        let thresholds = ConfidenceBandThresholds {
+            medium: 0.3,
+            high: 0.3,
+        };
+        assert_eq!(
+            ConfidenceScore::low().band(&thresholds),
+            ConfidenceBand::High
+        );
+        assert!(thresholds.validate().is_ok());
+
+        assert!(
+            ConfidenceBandThresholds {
+                medium: 0.9,
+                high: 0.8
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            ConfidenceBandThresholds {
+                medium: 0.5,
+                high: 1.5
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            ConfidenceBandThresholds {
+                medium: f64::NAN,
+                high: 0.8
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn nft_details_content_creator_summary() {
+        let mut metadata = ContractMetadata::minimal(alloy_primitives::Address::ZERO);
+        metadata.name = Some("Test".to_string());
+        metadata.symbol = Some("TST".to_string());
+
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert!(content.creator.is_none());
+        assert!(!content.to_string().contains("Creator:"));
+
+        metadata.additional_data.insert(
+            "creator_reputation".to_string(),
+            serde_json::Value::String("fresh".to_string()),
+        );
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert!(content.to_string().ends_with("\nCreator: fresh"));
+
+        metadata.additional_data.insert(
+            "creator_reputation".to_string(),
+            serde_json::Value::String("known".to_string()),
+        );
+        metadata.additional_data.insert(
+            "creator_name".to_string(),
+            serde_json::Value::String("creator.eth".to_string()),
+        );
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert_eq!(content.creator.as_deref(), Some("known (creator.eth)"));
+    }
+
+    #[test]
+    fn nft_details_content_links_summary() {
+        let mut metadata = ContractMetadata::minimal(alloy_primitives::Address::ZERO);
+
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert!(content.links.is_none());
+        assert!(!content.to_string().contains("Links:"));
+
+        metadata.external_url = Some("https://example.com".to_string());
+        metadata.discord = Some("https://discord.gg/example".to_string());
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert!(
+            content.to_string().ends_with(
+                "\nLinks: website https://example.com, discord https://discord.gg/example"
+            )
+        );
+    }
+
+    #[test]
+    fn nft_details_content_only_includes_allowlisted_fields() {
+        let mut metadata = ContractMetadata::minimal(alloy_primitives::Address::ZERO);
+        metadata.name = Some("Test".to_string());
+        metadata.symbol = Some("TST".to_string());
+        metadata.external_url = Some("https://example.com".to_string());
+        metadata.contract_type = Some(api_client::ContractType::Erc721);
+        metadata.is_verified = Some(true);
+
+        let content = NftDetailsContent::from_metadata_with_fields(
+            &metadata,
+            &[
+                ModelInputField::Symbol,
+                ModelInputField::ContractType,
+                ModelInputField::IsVerified,
+                ModelInputField::HolderCount,
+            ],
+        );
+        assert_eq!(
+            content.to_string(),
+            "NFT Details:\nSymbol: TST\nContract Type: ERC721\nVerified: yes"
+        );
+
+        // The default set keeps the original prompt layout
+        let content = NftDetailsContent::from_metadata(&metadata);
+        assert_eq!(
+            content.to_string(),
+            "NFT Details:\nName: Test\nSymbol: TST\nDescription: No description available\nLinks: website https://example.com"
+        );
+    }
+
+    #[test]
+    fn nft_details_content_includes_supply_bucket() {
+        let mut metadata = ContractMetadata::minimal(alloy_primitives::Address::ZERO);
+        metadata.total_supply = Some("5000".to_string());
+        let fields = [ModelInputField::TotalSupply, ModelInputField::SupplyBucket];
+
+        let content = NftDetailsContent::from_metadata_with_fields(&metadata, &fields);
+        assert_eq!(
+            content.to_string(),
+            "NFT Details:\nTotal Supply: 5000\nSupply Bucket: medium"
+        );
+
+        // Unparseable supplies have no bucket
+        metadata.total_supply = Some("unknown".to_string());
+        let content = NftDetailsContent::from_metadata_with_fields(&metadata, &fields);
+        assert_eq!(content.to_string(), "NFT Details:\nTotal Supply: unknown");
+    }
+
     #[test]
     fn spam_classification_behavior() {
         assert!(SpamClassification::Spam.is_spam());